@@ -0,0 +1,333 @@
+//! Per-worktree activity tracking for timesheet-style questions ("how long did I spend on
+//! the billing feature?"). Three kinds of activity are tracked:
+//!
+//! - **Session** runs - derived on read from the `started_at`/`ended_at` already recorded on
+//!   every [`crate::chat::types::RunEntry`], so no new storage is needed for this part.
+//! - **Commit**s - recorded from `commit_changes`/`commit_patch_hunks` in `projects/commands.rs`.
+//! - **Terminal** sessions - recorded in `terminal/pty.rs` when a terminal exits or is
+//!   killed via `kill_terminal`.
+//!
+//! Commits and terminal sessions are appended as lines to a single `activity.jsonl` shared by
+//! every worktree under the app data directory, since individual entries are tiny and a
+//! per-worktree index would be overkill here. Recording is best-effort: a failure to append
+//! is logged and swallowed rather than surfaced, the same way other fire-and-forget
+//! side-effects in this codebase are handled, so a full disk never blocks a commit or a
+//! terminal session from completing.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::http_server::EmitExt;
+
+/// How often the weekly summary sweep checks whether a week has elapsed.
+const SWEEP_INTERVAL_SECS: u64 = 60 * 60; // 1 hour
+
+/// How often a weekly summary is emitted.
+const SUMMARY_INTERVAL_SECS: u64 = 7 * 24 * 60 * 60; // 7 days
+
+/// Global mutex to prevent concurrent appends from interleaving lines in `activity.jsonl`.
+static ACTIVITY_LOG_LOCK: Mutex<()> = Mutex::new(());
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// What kind of activity a recorded entry represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    Session,
+    Commit,
+    Terminal,
+}
+
+/// A single recorded activity entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ActivityEntry {
+    worktree_id: String,
+    kind: ActivityKind,
+    started_at: u64,
+    duration_secs: u64,
+}
+
+/// How far back `get_worktree_activity` should look.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityRange {
+    Day,
+    Week,
+    Month,
+    All,
+}
+
+impl ActivityRange {
+    /// The oldest `started_at` that counts toward this range, as of now. `None` means no
+    /// cutoff (everything counts).
+    fn cutoff(self) -> Option<u64> {
+        let now = now();
+        match self {
+            ActivityRange::Day => Some(now.saturating_sub(24 * 60 * 60)),
+            ActivityRange::Week => Some(now.saturating_sub(7 * 24 * 60 * 60)),
+            ActivityRange::Month => Some(now.saturating_sub(30 * 24 * 60 * 60)),
+            ActivityRange::All => None,
+        }
+    }
+}
+
+/// Time spent on a worktree, broken down by activity kind, over a range.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WorktreeActivityTotals {
+    pub session_seconds: u64,
+    pub commit_count: u64,
+    pub terminal_seconds: u64,
+    pub total_seconds: u64,
+}
+
+fn activity_log_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::data_dir::resolve(app)?.join("activity.jsonl"))
+}
+
+/// Append a commit or terminal activity entry to the log. Best-effort: logs and swallows
+/// errors rather than failing the caller's commit/terminal action over a telemetry write.
+pub fn record(
+    app: &AppHandle,
+    worktree_id: &str,
+    kind: ActivityKind,
+    started_at: u64,
+    duration_secs: u64,
+) {
+    if let Err(e) = record_inner(app, worktree_id, kind, started_at, duration_secs) {
+        log::warn!("Failed to record {kind:?} activity for worktree {worktree_id}: {e}");
+    }
+}
+
+fn record_inner(
+    app: &AppHandle,
+    worktree_id: &str,
+    kind: ActivityKind,
+    started_at: u64,
+    duration_secs: u64,
+) -> Result<(), String> {
+    let _guard = ACTIVITY_LOG_LOCK.lock().unwrap();
+    let path = activity_log_path(app)?;
+
+    let entry = ActivityEntry {
+        worktree_id: worktree_id.to_string(),
+        kind,
+        started_at,
+        duration_secs,
+    };
+    let line = serde_json::to_string(&entry)
+        .map_err(|e| format!("Failed to serialize activity entry: {e}"))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open activity log: {e}"))?;
+    writeln!(file, "{line}").map_err(|e| format!("Failed to write activity log: {e}"))?;
+
+    Ok(())
+}
+
+fn read_entries(app: &AppHandle) -> Result<Vec<ActivityEntry>, String> {
+    let path = activity_log_path(app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read activity log: {e}"))?;
+
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Session run time for `worktree_id` since `cutoff` (or all time if `None`), derived from
+/// every session's `RunEntry.started_at`/`ended_at` rather than a separate log.
+fn session_seconds_since(
+    app: &AppHandle,
+    worktree_id: &str,
+    cutoff: Option<u64>,
+) -> Result<u64, String> {
+    let mut total = 0u64;
+
+    for session_id in crate::chat::storage::list_all_session_ids(app)? {
+        let Some(metadata) = crate::chat::storage::load_metadata(app, &session_id)? else {
+            continue;
+        };
+        if metadata.worktree_id != worktree_id {
+            continue;
+        }
+
+        for run in &metadata.runs {
+            if cutoff.is_some_and(|c| run.started_at < c) {
+                continue;
+            }
+            let ended_at = run.ended_at.unwrap_or_else(now);
+            total += ended_at.saturating_sub(run.started_at);
+        }
+    }
+
+    Ok(total)
+}
+
+/// Totals for `worktree_id` over `cutoff` (or all time if `None`), combining derived session
+/// time with the recorded commit/terminal entries. Shared by `get_worktree_activity` and the
+/// weekly summary sweep.
+fn totals_since(
+    app: &AppHandle,
+    worktree_id: &str,
+    cutoff: Option<u64>,
+    entries: &[ActivityEntry],
+) -> Result<WorktreeActivityTotals, String> {
+    let session_seconds = session_seconds_since(app, worktree_id, cutoff)?;
+
+    let mut commit_count = 0u64;
+    let mut terminal_seconds = 0u64;
+    for entry in entries {
+        if entry.worktree_id != worktree_id {
+            continue;
+        }
+        if cutoff.is_some_and(|c| entry.started_at < c) {
+            continue;
+        }
+        match entry.kind {
+            ActivityKind::Commit => commit_count += 1,
+            ActivityKind::Terminal => terminal_seconds += entry.duration_secs,
+            ActivityKind::Session => {}
+        }
+    }
+
+    Ok(WorktreeActivityTotals {
+        session_seconds,
+        commit_count,
+        terminal_seconds,
+        total_seconds: session_seconds + terminal_seconds,
+    })
+}
+
+/// Break down how much time was spent on `worktree_id` within `range`: session run time,
+/// number of commits, and terminal active time.
+#[tauri::command]
+pub async fn get_worktree_activity(
+    app: AppHandle,
+    worktree_id: String,
+    range: ActivityRange,
+) -> Result<WorktreeActivityTotals, String> {
+    let entries = read_entries(&app)?;
+    totals_since(&app, &worktree_id, range.cutoff(), &entries)
+}
+
+/// Payload for the `activity:weekly-summary` event.
+#[derive(Debug, Clone, Serialize)]
+struct WeeklySummaryEvent {
+    worktree_id: String,
+    worktree_name: String,
+    totals: WorktreeActivityTotals,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct SummaryState {
+    #[serde(default)]
+    last_emitted_at: u64,
+}
+
+fn summary_state_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::data_dir::resolve(app)?.join("activity-summary-state.json"))
+}
+
+fn load_summary_state(app: &AppHandle) -> SummaryState {
+    let Ok(path) = summary_state_path(app) else {
+        return SummaryState::default();
+    };
+    std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_summary_state(app: &AppHandle, state: &SummaryState) -> Result<(), String> {
+    let path = summary_state_path(app)?;
+    let json = serde_json::to_string_pretty(state)
+        .map_err(|e| format!("Failed to serialize activity summary state: {e}"))?;
+
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, json)
+        .map_err(|e| format!("Failed to write activity summary state: {e}"))?;
+    std::fs::rename(&temp_path, &path)
+        .map_err(|e| format!("Failed to finalize activity summary state: {e}"))?;
+    Ok(())
+}
+
+/// Emit a weekly summary for every worktree with activity in the past week, if a week has
+/// elapsed since the last summary.
+fn maybe_emit_weekly_summary(app: &AppHandle) {
+    let mut state = load_summary_state(app);
+    if now().saturating_sub(state.last_emitted_at) < SUMMARY_INTERVAL_SECS {
+        return;
+    }
+
+    let data = match crate::projects::storage::load_projects_data(app) {
+        Ok(data) => data,
+        Err(e) => {
+            log::warn!("Weekly activity summary: failed to load projects: {e}");
+            return;
+        }
+    };
+    let entries = read_entries(app).unwrap_or_default();
+    let cutoff = ActivityRange::Week.cutoff();
+
+    for worktree in &data.worktrees {
+        let totals = match totals_since(app, &worktree.id, cutoff, &entries) {
+            Ok(totals) => totals,
+            Err(e) => {
+                log::warn!(
+                    "Weekly activity summary: failed to total activity for {}: {e}",
+                    worktree.id
+                );
+                continue;
+            }
+        };
+
+        if totals.total_seconds == 0 && totals.commit_count == 0 {
+            continue;
+        }
+
+        let event = WeeklySummaryEvent {
+            worktree_id: worktree.id.clone(),
+            worktree_name: worktree.name.clone(),
+            totals,
+        };
+
+        if let Err(e) = app.emit_all("activity:weekly-summary", &event) {
+            log::warn!("Failed to emit weekly activity summary: {e}");
+        }
+    }
+
+    state.last_emitted_at = now();
+    if let Err(e) = save_summary_state(app, &state) {
+        log::warn!("Failed to save activity summary state: {e}");
+    }
+}
+
+/// Start the background weekly activity summary sweep.
+///
+/// Spawned once from `lib.rs::run()`, mirroring `trash::start_expiry_sweep`'s shape: runs
+/// for the lifetime of the app regardless of window focus.
+pub fn start_weekly_summary_sweep(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(Duration::from_secs(SWEEP_INTERVAL_SECS));
+        maybe_emit_weekly_summary(&app);
+    });
+}
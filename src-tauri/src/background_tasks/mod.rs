@@ -16,9 +16,12 @@ use std::time::Duration;
 use tauri::AppHandle;
 
 use crate::gh_cli::config::resolve_gh_binary;
+use crate::gh_cli::fetch_gh_rate_limit;
 use crate::projects::git_status::{get_branch_status, ActiveWorktreeInfo, GitBranchStatus};
-use crate::projects::pr_status::{get_pr_status, PrStatus};
+use crate::projects::github_issues::{get_worktree_issue_refs, is_issue_closed, parse_context_key};
+use crate::projects::pr_status::{get_pr_status, PrState, PrStatus};
 use crate::http_server::EmitExt;
+use serde::Serialize;
 
 pub mod commands;
 
@@ -51,6 +54,9 @@ pub const MAX_REMOTE_POLL_INTERVAL: u64 = 600;
 /// Default remote polling interval in seconds (1 minute)
 pub const DEFAULT_REMOTE_POLL_INTERVAL: u64 = 60;
 
+/// Remaining-quota threshold below which remote polling backs off to `MAX_REMOTE_POLL_INTERVAL`
+const LOW_RATE_LIMIT_THRESHOLD: u32 = 100;
+
 /// Manages background tasks for the application
 ///
 /// The task manager runs a polling loop that periodically checks git status
@@ -247,6 +253,15 @@ impl BackgroundTaskManager {
                                         status.check_status
                                     );
 
+                                    if status.state == PrState::Merged {
+                                        check_linked_issue_closed(
+                                            &app,
+                                            &gh,
+                                            &info.worktree_id,
+                                            *pr_number,
+                                        );
+                                    }
+
                                     if let Err(e) = emit_pr_status(&app, status) {
                                         log::error!("Failed to emit PR status event: {e}");
                                     }
@@ -255,6 +270,33 @@ impl BackgroundTaskManager {
                                     log::warn!("Failed to get PR status for #{}: {e}", pr_number);
                                 }
                             }
+
+                            // Back off the remote poll interval when the API quota is running
+                            // low, so we don't burn through it while the app sits idle/focused.
+                            match fetch_gh_rate_limit(&gh) {
+                                Ok(status) => {
+                                    let low_quota = status.core.remaining < LOW_RATE_LIMIT_THRESHOLD
+                                        || status.graphql.remaining < LOW_RATE_LIMIT_THRESHOLD;
+                                    if low_quota {
+                                        log::warn!(
+                                            "GitHub API quota running low (core={}, graphql={}), backing off remote polling",
+                                            status.core.remaining,
+                                            status.graphql.remaining
+                                        );
+                                        remote_poll_interval_secs
+                                            .store(MAX_REMOTE_POLL_INTERVAL, Ordering::Relaxed);
+                                    } else if remote_poll_interval_secs.load(Ordering::Relaxed)
+                                        == MAX_REMOTE_POLL_INTERVAL
+                                    {
+                                        // Quota recovered - return to the default cadence
+                                        remote_poll_interval_secs
+                                            .store(DEFAULT_REMOTE_POLL_INTERVAL, Ordering::Relaxed);
+                                    }
+                                }
+                                Err(e) => {
+                                    log::trace!("Could not check gh rate limit: {e}");
+                                }
+                            }
                         }
                     }
                 }
@@ -362,6 +404,22 @@ impl BackgroundTaskManager {
         self.poll_interval_secs.load(Ordering::Relaxed)
     }
 
+    /// Whether the app window currently has focus (see `set_focused`). Used by
+    /// `chat::claude` to decide whether a run should get reduced CPU/IO priority.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused.load(Ordering::Relaxed)
+    }
+
+    /// The worktree currently focused in the UI, if any. Used by `notifications::rules` to
+    /// evaluate "unfocused worktree" conditions without duplicating focus tracking.
+    pub fn active_worktree_id(&self) -> Option<String> {
+        self.active_worktree
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|info| info.worktree_id.clone())
+    }
+
     /// Set the remote polling interval in seconds
     ///
     /// The interval will be clamped to the valid range (30-600 seconds).
@@ -408,3 +466,51 @@ fn emit_pr_status(app: &AppHandle, status: PrStatus) -> Result<(), String> {
     app.emit_all("pr:status-update", &status)
         .map_err(|e| format!("Failed to emit pr:status-update event: {e}"))
 }
+
+/// Event payload emitted when a merged PR's linked issue has also closed
+#[derive(Debug, Clone, Serialize)]
+struct IssueClosedWithPr {
+    worktree_id: String,
+    pr_number: u32,
+    issue_number: u32,
+}
+
+/// If the worktree's PR just merged and it's linked to an issue via `link_pr_to_issue`,
+/// check whether that issue has also closed and emit a confirmation event if so.
+fn check_linked_issue_closed(
+    app: &AppHandle,
+    gh: &std::path::Path,
+    worktree_id: &str,
+    pr_number: u32,
+) {
+    let issue_keys = match get_worktree_issue_refs(app, worktree_id) {
+        Ok(keys) => keys,
+        Err(e) => {
+            log::warn!("Failed to look up linked issues for worktree {worktree_id}: {e}");
+            return;
+        }
+    };
+
+    for key in issue_keys {
+        let Some((owner, repo, issue_number)) = parse_context_key(&key) else {
+            continue;
+        };
+
+        match is_issue_closed(app, gh, &owner, &repo, issue_number) {
+            Ok(true) => {
+                let payload = IssueClosedWithPr {
+                    worktree_id: worktree_id.to_string(),
+                    pr_number,
+                    issue_number,
+                };
+                if let Err(e) = app.emit_all("github:issue-closed-with-pr", &payload) {
+                    log::error!("Failed to emit github:issue-closed-with-pr event: {e}");
+                }
+            }
+            Ok(false) => {}
+            Err(e) => {
+                log::warn!("Failed to check issue #{issue_number} state: {e}");
+            }
+        }
+    }
+}
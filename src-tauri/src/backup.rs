@@ -0,0 +1,243 @@
+//! Full backup and restore of app data: projects/worktrees metadata, sessions, saved
+//! contexts, follow-ups, the offline message queue, and preferences (including the tokens
+//! and API keys preferences carries, e.g. `http_server_token`, `openai_compat_api_key`).
+//! Packaged as a single zip via `create_backup`, restorable on this machine or a new one
+//! via `restore_backup` - the two commands this crate otherwise has no equivalent of, and
+//! the thing to run before a machine migration or a risky upgrade.
+//!
+//! Deliberately excluded: `search-index.db` (derived from sessions, rebuilt by
+//! `rebuild_search_index`) and `migrations-backup/` (pre-migration copies, not user data).
+//! Pasted images (`pasted-images/`) are included only when `include_images` is set, since
+//! they can dwarf everything else in the backup for image-heavy sessions.
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Manifest written as `backup-manifest.json` at the root of every backup archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BackupManifest {
+    backup_format_version: u32,
+    created_at: u64,
+    included_images: bool,
+}
+
+/// How `restore_backup` should reconcile the archive's contents with whatever is already
+/// on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestoreMode {
+    /// Keep existing files where the archive doesn't overlap; archive entries win on
+    /// conflicts (e.g. a session that exists in both).
+    Merge,
+    /// Wipe the current app data directory before extracting the archive.
+    Replace,
+}
+
+/// Summary of what `create_backup` wrote, returned for UI confirmation.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupSummary {
+    pub output_path: String,
+    pub file_count: usize,
+    pub included_images: bool,
+}
+
+fn add_file_to_zip(
+    zip: &mut ZipWriter<File>,
+    archive_path: &Path,
+    full_path: &Path,
+    options: SimpleFileOptions,
+) -> Result<(), String> {
+    let name = archive_path
+        .to_str()
+        .ok_or_else(|| format!("Non-UTF8 path in backup: {}", archive_path.display()))?;
+    zip.start_file(name, options)
+        .map_err(|e| format!("Failed to start zip entry {name}: {e}"))?;
+    let mut file =
+        File::open(full_path).map_err(|e| format!("Failed to open {}: {e}", full_path.display()))?;
+    std::io::copy(&mut file, zip)
+        .map_err(|e| format!("Failed to write {name} into backup: {e}"))?;
+    Ok(())
+}
+
+/// Recursively add every file under `dir` to the archive, rooted at `archive_prefix`.
+fn add_dir_to_zip(
+    zip: &mut ZipWriter<File>,
+    dir: &Path,
+    archive_prefix: &Path,
+    options: SimpleFileOptions,
+    file_count: &mut usize,
+) -> Result<(), String> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {e}", dir.display()))?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let archive_path = archive_prefix.join(entry.file_name());
+
+        if path.is_dir() {
+            add_dir_to_zip(zip, &path, &archive_path, options, file_count)?;
+        } else {
+            add_file_to_zip(zip, &archive_path, &path, options)?;
+            *file_count += 1;
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a full backup archive at `output_path`. Set `include_images` to also bundle
+/// pasted images - off by default since they can be large and are re-attachable by hand.
+#[tauri::command]
+pub async fn create_backup(
+    app: AppHandle,
+    output_path: String,
+    include_images: bool,
+) -> Result<BackupSummary, String> {
+    let app_data_dir = crate::data_dir::resolve(&app)?;
+
+    let output = File::create(&output_path)
+        .map_err(|e| format!("Failed to create backup file {output_path}: {e}"))?;
+    let mut zip = ZipWriter::new(output);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let created_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let manifest = BackupManifest {
+        backup_format_version: BACKUP_FORMAT_VERSION,
+        created_at,
+        included_images: include_images,
+    };
+    let manifest_json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize backup manifest: {e}"))?;
+    zip.start_file("backup-manifest.json", options)
+        .map_err(|e| format!("Failed to start backup manifest entry: {e}"))?;
+    zip.write_all(manifest_json.as_bytes())
+        .map_err(|e| format!("Failed to write backup manifest: {e}"))?;
+
+    let mut file_count = 0;
+
+    for name in ["preferences.json", "projects.json", "ui-state.json", "offline-queue.json"] {
+        let path = app_data_dir.join(name);
+        if path.exists() {
+            add_file_to_zip(&mut zip, Path::new(name), &path, options)?;
+            file_count += 1;
+        }
+    }
+
+    for dir in ["sessions", "session-context", "followups"] {
+        add_dir_to_zip(
+            &mut zip,
+            &app_data_dir.join(dir),
+            Path::new(dir),
+            options,
+            &mut file_count,
+        )?;
+    }
+
+    if include_images {
+        add_dir_to_zip(
+            &mut zip,
+            &app_data_dir.join("pasted-images"),
+            Path::new("pasted-images"),
+            options,
+            &mut file_count,
+        )?;
+    }
+
+    zip.finish()
+        .map_err(|e| format!("Failed to finalize backup archive: {e}"))?;
+
+    log::info!("Created backup at {output_path} ({file_count} files)");
+    Ok(BackupSummary {
+        output_path,
+        file_count,
+        included_images: include_images,
+    })
+}
+
+/// Restore app data from a backup archive created by `create_backup`.
+///
+/// `RestoreMode::Replace` deletes the directories/files a backup can contain (see the
+/// module doc comment for the list) before extracting, so nothing from the current machine
+/// survives the restore. `RestoreMode::Merge` extracts on top of what's there, letting
+/// archive entries overwrite files with the same name/path - safe for combining a backup
+/// with work done on this machine after the backup was taken, though identically-named
+/// sessions are not deep-merged, just overwritten.
+#[tauri::command]
+pub async fn restore_backup(
+    app: AppHandle,
+    input_path: String,
+    mode: RestoreMode,
+) -> Result<(), String> {
+    let app_data_dir = crate::data_dir::resolve(&app)?;
+
+    let file = File::open(&input_path)
+        .map_err(|e| format!("Failed to open backup file {input_path}: {e}"))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| format!("Failed to open backup archive: {e}"))?;
+
+    if mode == RestoreMode::Replace {
+        for name in ["preferences.json", "projects.json", "ui-state.json", "offline-queue.json"] {
+            let path = app_data_dir.join(name);
+            if path.exists() {
+                std::fs::remove_file(&path)
+                    .map_err(|e| format!("Failed to remove {}: {e}", path.display()))?;
+            }
+        }
+        for dir in ["sessions", "session-context", "followups", "pasted-images"] {
+            let path = app_data_dir.join(dir);
+            if path.exists() {
+                std::fs::remove_dir_all(&path)
+                    .map_err(|e| format!("Failed to remove {}: {e}", path.display()))?;
+            }
+        }
+    }
+
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|e| format!("Failed to read backup entry {i}: {e}"))?;
+        let Some(relative_path) = entry.enclosed_name() else {
+            log::warn!("Skipping unsafe path in backup archive: {}", entry.name());
+            continue;
+        };
+        if relative_path == PathBuf::from("backup-manifest.json") {
+            continue;
+        }
+
+        let dest_path = app_data_dir.join(&relative_path);
+        if entry.is_dir() {
+            std::fs::create_dir_all(&dest_path)
+                .map_err(|e| format!("Failed to create {}: {e}", dest_path.display()))?;
+            continue;
+        }
+
+        if let Some(parent) = dest_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {e}", parent.display()))?;
+        }
+
+        let mut contents = Vec::new();
+        entry
+            .read_to_end(&mut contents)
+            .map_err(|e| format!("Failed to read {} from backup: {e}", relative_path.display()))?;
+        std::fs::write(&dest_path, contents)
+            .map_err(|e| format!("Failed to write {}: {e}", dest_path.display()))?;
+    }
+
+    log::info!("Restored backup from {input_path} (mode: {mode:?})");
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//! Provider abstraction for the AI backends that can drive a chat session.
+//!
+//! The Claude CLI (see `super::claude`) is wrapped by `ClaudeCliProvider`; the
+//! OpenAI-compatible, Ollama and Codex CLI backends implement the same trait, with the
+//! backend choice persisted per session via `Session::selected_provider`.
+//!
+//! `send_chat_message` does not yet dispatch through this trait — it still calls
+//! `super::claude::execute_claude_detached` directly, because that call site also
+//! implements Claude-specific resume-retry behavior (detecting an expired/invalid
+//! `--resume` session ID from the CLI's error text and retrying without it) that
+//! doesn't have an equivalent defined for other backends yet. Routing
+//! `send_chat_message` through this trait is left as follow-up work.
+
+use super::claude::ClaudeResponse;
+use super::types::ThinkingLevel;
+
+/// Identifier for the Claude CLI backend, as stored in `Session::selected_provider`.
+pub const CLAUDE_PROVIDER_ID: &str = "claude";
+
+/// Parameters needed to spawn one turn of conversation with an AI backend.
+///
+/// Mirrors what `execute_claude_detached` already takes, grouped into a struct so
+/// future providers aren't stuck matching Claude's exact positional argument list.
+pub struct SpawnRequest<'a> {
+    pub session_id: &'a str,
+    pub worktree_id: &'a str,
+    /// Plain text of the latest user message, for backends (unlike the Claude CLI)
+    /// that don't read it from `input_file` themselves.
+    pub message: &'a str,
+    pub input_file: &'a std::path::Path,
+    pub output_file: &'a std::path::Path,
+    pub working_dir: &'a std::path::Path,
+    pub resume_session_id: Option<&'a str>,
+    pub model: Option<&'a str>,
+    pub execution_mode: Option<&'a str>,
+    pub thinking_level: Option<&'a ThinkingLevel>,
+    pub allowed_tools: Option<&'a [String]>,
+    pub disable_thinking_in_non_plan_modes: bool,
+    pub parallel_execution_prompt_enabled: bool,
+    pub ai_language: Option<&'a str>,
+}
+
+/// A chat backend capable of spawning a turn, streaming its output, and being cancelled.
+///
+/// Resume is expressed through `SpawnRequest::resume_session_id` rather than a
+/// separate method, since resuming a conversation is just a parameter to the same
+/// spawn call for every backend shape this codebase is expected to support (CLI
+/// subprocess or HTTP request), not a distinct operation.
+pub trait AiProvider {
+    /// Stable identifier stored in `Session::selected_provider` (e.g. "claude").
+    fn id(&self) -> &'static str;
+
+    /// Spawn one turn of conversation and block until it completes, streaming
+    /// `chat:*` events to the frontend as output arrives. Returns the backend's
+    /// process ID (for `chat::registry` bookkeeping) and the assembled response.
+    fn spawn(
+        &self,
+        app: &tauri::AppHandle,
+        request: SpawnRequest<'_>,
+    ) -> Result<(u32, ClaudeResponse), String>;
+
+    /// Cancel an in-flight spawn for `session_id`, if one is running. `force` skips the
+    /// graceful SIGINT/SIGTERM ladder and kills the process immediately - see
+    /// `chat::registry::cancel_process`.
+    ///
+    /// Cancellation is already PID/process-tree based (see `chat::registry`), which
+    /// works identically regardless of backend, so the default implementation
+    /// delegates there instead of requiring every provider to reimplement it.
+    fn cancel(
+        &self,
+        app: &tauri::AppHandle,
+        session_id: &str,
+        worktree_id: &str,
+        force: bool,
+    ) -> Result<bool, String> {
+        super::registry::cancel_process(app, session_id, worktree_id, force, None)
+    }
+}
+
+/// The Claude CLI backend — the only provider that existed before this abstraction
+/// was introduced, wrapping `execute_claude_detached` without changing its behavior.
+pub struct ClaudeCliProvider;
+
+impl AiProvider for ClaudeCliProvider {
+    fn id(&self) -> &'static str {
+        CLAUDE_PROVIDER_ID
+    }
+
+    fn spawn(
+        &self,
+        app: &tauri::AppHandle,
+        request: SpawnRequest<'_>,
+    ) -> Result<(u32, ClaudeResponse), String> {
+        super::claude::execute_claude_detached(
+            app,
+            request.session_id,
+            request.worktree_id,
+            request.input_file,
+            request.output_file,
+            request.working_dir,
+            request.resume_session_id,
+            request.model,
+            request.execution_mode,
+            request.thinking_level,
+            request.allowed_tools,
+            request.disable_thinking_in_non_plan_modes,
+            request.parallel_execution_prompt_enabled,
+            request.ai_language,
+            request.message,
+        )
+    }
+}
+
+/// Resolve the `AiProvider` configured for a session's `selected_provider` value.
+/// Unknown or unset provider IDs fall back to the Claude CLI, since that's the only
+/// backend that existed before provider selection was introduced.
+pub fn resolve_provider(selected_provider: Option<&str>) -> Box<dyn AiProvider> {
+    match selected_provider {
+        Some(super::openai_compat::OPENAI_COMPAT_PROVIDER_ID) => {
+            Box::new(super::openai_compat::OpenAiCompatProvider)
+        }
+        Some(super::ollama::OLLAMA_PROVIDER_ID) => Box::new(super::ollama::OllamaProvider),
+        Some(super::codex::CODEX_PROVIDER_ID) => Box::new(super::codex::CodexCliProvider),
+        Some(id) if id != CLAUDE_PROVIDER_ID => {
+            log::warn!("Unknown AI provider '{id}', falling back to Claude CLI");
+            Box::new(ClaudeCliProvider)
+        }
+        _ => Box::new(ClaudeCliProvider),
+    }
+}
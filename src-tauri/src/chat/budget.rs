@@ -0,0 +1,257 @@
+//! Monthly AI usage budgets, with a soft warning threshold and optional hard
+//! enforcement in `commands::send_chat_message`.
+//!
+//! A budget can be set per-project (`Project::monthly_budget_usd`) or as a
+//! global fallback (`AppPreferences::global_monthly_budget_usd`) for projects
+//! with no budget of their own. Spend is month-to-date estimated cost, reusing
+//! `usage::cost_since` rather than re-scanning sessions.
+
+use serde::Serialize;
+use tauri::AppHandle;
+
+use crate::http_server::EmitExt;
+use crate::projects::storage::load_projects_data;
+
+/// Spend relative to budget reaches this fraction before a `budget:warning` event fires.
+const WARNING_THRESHOLD: f64 = 0.8;
+
+/// Month-to-date budget status for a project (or the global scope, if the project has
+/// no budget of its own and no project_id was resolvable).
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct BudgetStatus {
+    /// Project this status applies to, if scoped to one (`None` for the global budget).
+    pub project_id: Option<String>,
+    /// The effective monthly limit in USD, if any budget is configured.
+    pub limit_usd: Option<f64>,
+    /// Estimated spend since the start of the current month.
+    pub spent_usd: f64,
+    /// `true` once `spent_usd` has reached `limit_usd`.
+    pub exceeded: bool,
+    /// `true` once `spent_usd` has crossed `WARNING_THRESHOLD` of `limit_usd`.
+    pub warning: bool,
+}
+
+/// Unix timestamp (UTC) for midnight on the first day of the current month.
+///
+/// No `chrono` dependency in this crate, so this walks civil days back from `now`
+/// using Howard Hinnant's days-from-civil / civil-from-days algorithm rather than
+/// pulling one in for a single calculation.
+fn start_of_month(now: u64) -> u64 {
+    const DAY_SECS: i64 = 24 * 60 * 60;
+    let days_since_epoch = now as i64 / DAY_SECS;
+
+    // civil_from_days: days since 1970-01-01 -> (year, month, day)
+    let z = days_since_epoch + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as i64; // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+    let year = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let month = if mp < 10 { mp + 3 } else { mp - 9 }; // [1, 12]
+    let year = if month <= 2 { year + 1 } else { year };
+
+    // days_from_civil: first of that month back to days-since-epoch
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as i64; // [0, 399]
+    let mp = if month > 2 { month - 3 } else { month + 9 }; // [0, 11]
+    let doy = (153 * mp + 2) / 5; // day-of-year for the 1st of this month
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    (days * DAY_SECS).max(0) as u64
+}
+
+/// Resolve the effective monthly budget limit for a project: its own budget if set,
+/// otherwise the global fallback.
+fn effective_limit(
+    project_id: Option<&str>,
+    projects_data: &crate::projects::types::ProjectsData,
+    global_budget: Option<f64>,
+) -> Option<f64> {
+    let project_budget = project_id.and_then(|id| {
+        projects_data
+            .find_project(id)
+            .and_then(|p| p.monthly_budget_usd)
+    });
+    project_budget.or(global_budget)
+}
+
+/// Compute month-to-date budget status for a worktree's project, falling back to the
+/// global budget if the project has none of its own (or couldn't be resolved).
+pub fn status_for_worktree(app: &AppHandle, worktree_id: &str) -> Result<BudgetStatus, String> {
+    let projects_data = load_projects_data(app)?;
+    let project_id = projects_data
+        .find_worktree(worktree_id)
+        .map(|w| w.project_id.clone());
+
+    let prefs = crate::load_preferences_sync(app)?;
+    let limit_usd = effective_limit(
+        project_id.as_deref(),
+        &projects_data,
+        prefs.global_monthly_budget_usd,
+    );
+
+    let now = super::run_log::now_timestamp();
+    let since = start_of_month(now);
+
+    let spent_usd = match &project_id {
+        Some(id) => {
+            let worktree_ids: std::collections::HashSet<String> = projects_data
+                .worktrees_for_project(id)
+                .into_iter()
+                .map(|w| w.id.clone())
+                .collect();
+            super::usage::cost_since(app, since, Some(&worktree_ids))?
+        }
+        None => super::usage::cost_since(app, since, None)?,
+    };
+
+    let exceeded = limit_usd.is_some_and(|limit| spent_usd >= limit);
+    let warning = limit_usd.is_some_and(|limit| spent_usd >= limit * WARNING_THRESHOLD);
+
+    Ok(BudgetStatus {
+        project_id,
+        limit_usd,
+        spent_usd,
+        exceeded,
+        warning,
+    })
+}
+
+/// Tauri command wrapper around `status_for_worktree`, for the UI to poll/display.
+#[tauri::command]
+pub async fn get_budget_status(
+    app: AppHandle,
+    worktree_id: String,
+) -> Result<BudgetStatus, String> {
+    status_for_worktree(&app, &worktree_id)
+}
+
+/// Payload for the `budget:warning` event, emitted after a run while month-to-date
+/// spend is at or past `WARNING_THRESHOLD` of the effective limit.
+#[derive(Serialize, Clone)]
+pub struct BudgetWarningEvent {
+    pub worktree_id: String,
+    pub status: BudgetStatus,
+}
+
+/// Check budget status for `worktree_id` and emit `budget:warning` if spend is at or past
+/// the warning threshold. This fires after every run that ends above the threshold, not
+/// only the first one to cross it. Called after a run completes, alongside
+/// `usage::emit_usage_updated`.
+pub(super) fn check_and_emit_warning(app: &AppHandle, worktree_id: &str) {
+    let status = match status_for_worktree(app, worktree_id) {
+        Ok(status) => status,
+        Err(err) => {
+            log::warn!("Could not compute budget status for {worktree_id}: {err}");
+            return;
+        }
+    };
+    if !status.warning {
+        return;
+    }
+    let worktree_id = worktree_id.to_string();
+    let _ = app.emit_all(
+        "budget:warning",
+        &BudgetWarningEvent {
+            worktree_id,
+            status,
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_of_month_mid_month() {
+        // 2024-03-15 12:34:56 UTC -> 2024-03-01 00:00:00 UTC
+        assert_eq!(start_of_month(1_710_506_096), 1_709_251_200);
+    }
+
+    #[test]
+    fn test_start_of_month_already_first_of_month() {
+        // 2024-03-01 00:00:00 UTC is already the start of its own month.
+        assert_eq!(start_of_month(1_709_251_200), 1_709_251_200);
+    }
+
+    #[test]
+    fn test_start_of_month_crosses_year_boundary() {
+        // 2024-01-15 00:00:00 UTC -> 2024-01-01 00:00:00 UTC
+        assert_eq!(start_of_month(1_705_276_800), 1_704_067_200);
+    }
+
+    #[test]
+    fn test_start_of_month_leap_year_february() {
+        // 2024-02-29 23:59:59 UTC (leap day) -> 2024-02-01 00:00:00 UTC
+        assert_eq!(start_of_month(1_709_251_199), 1_706_745_600);
+    }
+
+    #[test]
+    fn test_start_of_month_december_to_january_rollover() {
+        // 2023-12-31 23:59:59 UTC -> 2023-12-01 00:00:00 UTC
+        assert_eq!(start_of_month(1_704_067_199), 1_701_388_800);
+    }
+
+    fn test_project(id: &str, monthly_budget_usd: Option<f64>) -> crate::projects::types::Project {
+        crate::projects::types::Project {
+            id: id.to_string(),
+            name: "Test Project".to_string(),
+            path: String::new(),
+            default_branch: String::new(),
+            added_at: 0,
+            order: 0,
+            parent_id: None,
+            is_folder: false,
+            avatar_path: None,
+            gh_account: None,
+            gitea_host: None,
+            gitea_token: None,
+            monthly_budget_usd,
+            run_priority: 0,
+            env_vars: Vec::new(),
+            dotenv_allowlist: Vec::new(),
+            instructions: None,
+            auto_commit_after_run: false,
+            remote: None,
+            shell: None,
+            shell_startup_command: None,
+            sandbox: crate::projects::types::SandboxConfig::default(),
+            notification_webhooks: Vec::new(),
+            muted: false,
+        }
+    }
+
+    #[test]
+    fn test_effective_limit_prefers_project_budget_over_global() {
+        let mut projects_data = crate::projects::types::ProjectsData::default();
+        projects_data
+            .projects
+            .push(test_project("proj-1", Some(50.0)));
+
+        assert_eq!(
+            effective_limit(Some("proj-1"), &projects_data, Some(100.0)),
+            Some(50.0)
+        );
+    }
+
+    #[test]
+    fn test_effective_limit_falls_back_to_global() {
+        let mut projects_data = crate::projects::types::ProjectsData::default();
+        projects_data.projects.push(test_project("proj-1", None));
+
+        assert_eq!(
+            effective_limit(Some("proj-1"), &projects_data, Some(100.0)),
+            Some(100.0)
+        );
+    }
+
+    #[test]
+    fn test_effective_limit_none_when_unset() {
+        let projects_data = crate::projects::types::ProjectsData::default();
+        assert_eq!(effective_limit(None, &projects_data, None), None);
+    }
+}
@@ -1,5 +1,7 @@
 use tauri::Manager;
 
+use super::file_context::get_session_file_context_paths;
+use super::retrieval::retrieve_relevant_snippets;
 use super::types::{CompactMetadata, ContentBlock, ThinkingLevel, ToolCall, UsageData};
 use crate::http_server::EmitExt;
 use crate::projects::github_issues::{
@@ -28,30 +30,30 @@ pub struct ClaudeResponse {
 
 /// Payload for text chunk events sent to frontend
 #[derive(serde::Serialize, Clone)]
-struct ChunkEvent {
-    session_id: String,
-    worktree_id: String, // Kept for backward compatibility
-    content: String,
+pub(super) struct ChunkEvent {
+    pub session_id: String,
+    pub worktree_id: String, // Kept for backward compatibility
+    pub content: String,
 }
 
 /// Payload for tool use events sent to frontend
 #[derive(serde::Serialize, Clone)]
-struct ToolUseEvent {
-    session_id: String,
-    worktree_id: String, // Kept for backward compatibility
-    id: String,
-    name: String,
-    input: serde_json::Value,
+pub(super) struct ToolUseEvent {
+    pub session_id: String,
+    pub worktree_id: String, // Kept for backward compatibility
+    pub id: String,
+    pub name: String,
+    pub input: serde_json::Value,
     /// Parent tool use ID for sub-agent tool calls (for parallel task attribution)
     #[serde(skip_serializing_if = "Option::is_none")]
-    parent_tool_use_id: Option<String>,
+    pub parent_tool_use_id: Option<String>,
 }
 
 /// Payload for done events sent to frontend
 #[derive(serde::Serialize, Clone)]
-struct DoneEvent {
-    session_id: String,
-    worktree_id: String, // Kept for backward compatibility
+pub(super) struct DoneEvent {
+    pub session_id: String,
+    pub worktree_id: String, // Kept for backward compatibility
 }
 
 /// Payload for error events sent to frontend
@@ -62,12 +64,27 @@ pub struct ErrorEvent {
     pub error: String,
 }
 
+/// Payload for pre/post-run hook failure events sent to frontend
+#[derive(serde::Serialize, Clone)]
+pub struct HookFailedEvent {
+    pub session_id: String,
+    pub worktree_id: String,
+    /// Which hook failed ("pre_run" or "post_run")
+    pub hook: String,
+    pub error: String,
+}
+
 /// Payload for cancelled events sent to frontend
 #[derive(serde::Serialize, Clone)]
 pub struct CancelledEvent {
     pub session_id: String,
     pub worktree_id: String, // Kept for backward compatibility
     pub undo_send: bool, // True if user message should be restored to input (instant cancellation)
+    /// Why the run was cancelled, e.g. `"timeout"` when `execution_timeout_seconds` in
+    /// `jean.json` elapsed. `None` for a user-initiated cancel, to avoid changing the
+    /// payload shape for the common case.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
 }
 
 /// Payload for tool block position events sent to frontend
@@ -97,6 +114,38 @@ struct ToolResultEvent {
     output: String,
 }
 
+/// Payload for sub-agent (Task tool) lifecycle events sent to frontend.
+/// `id` is the `Task` tool call's own id, which doubles as the sub-agent id - every nested
+/// stream message for that sub-agent carries it as `parent_tool_use_id`.
+#[derive(serde::Serialize, Clone)]
+struct SubagentStartedEvent {
+    session_id: String,
+    worktree_id: String, // Kept for backward compatibility
+    id: String,
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subagent_type: Option<String>,
+}
+
+/// Payload for sub-agent progress events - one per content block produced inside a
+/// running sub-agent (its own text or tool calls).
+#[derive(serde::Serialize, Clone)]
+struct SubagentProgressEvent {
+    session_id: String,
+    worktree_id: String, // Kept for backward compatibility
+    id: String,
+    content: String,
+}
+
+/// Payload for sub-agent completion events, carrying the `Task` tool's final result.
+#[derive(serde::Serialize, Clone)]
+struct SubagentCompletedEvent {
+    session_id: String,
+    worktree_id: String, // Kept for backward compatibility
+    id: String,
+    output: String,
+}
+
 /// A single permission denial from Claude CLI
 #[derive(serde::Serialize, Clone)]
 struct PermissionDenial {
@@ -151,6 +200,8 @@ fn build_claude_args(
     disable_thinking_in_non_plan_modes: bool,
     parallel_execution_prompt_enabled: bool,
     ai_language: Option<&str>,
+    worktree_path: &std::path::Path,
+    user_message: &str,
 ) -> (Vec<String>, Vec<(String, String)>) {
     let mut args = Vec::new();
     let mut env_vars = Vec::new();
@@ -164,7 +215,7 @@ fn build_claude_args(
     args.push("--verbose".to_string());
 
     // Add app data directories
-    if let Ok(app_data_dir) = app.path().app_data_dir() {
+    if let Ok(app_data_dir) = crate::data_dir::resolve(app) {
         if cfg!(debug_assertions) {
             args.push("--add-dir".to_string());
             args.push(app_data_dir.to_string_lossy().to_string());
@@ -175,6 +226,7 @@ fn build_claude_args(
                 "session-context",
                 "git-context",
                 "combined-contexts",
+                "file-context",
             ] {
                 args.push("--add-dir".to_string());
                 args.push(app_data_dir.join(subdir).to_string_lossy().to_string());
@@ -301,6 +353,12 @@ fn build_claude_args(
         }
     }
 
+    // Managed project/worktree instructions (see `instructions::resolve_instructions`) -
+    // separate from any repo-local CLAUDE.md, configured from the frontend instead.
+    if let Some(instructions) = super::instructions::resolve_instructions(app, worktree_id) {
+        system_prompt_parts.push(instructions);
+    }
+
     // Collect all context files (issues and PRs) and concatenate into a single file
     let mut all_context_paths: Vec<std::path::PathBuf> = Vec::new();
 
@@ -347,7 +405,7 @@ fn build_claude_args(
     }
 
     // Check for attached saved context files
-    if let Ok(app_data_dir) = app.path().app_data_dir() {
+    if let Ok(app_data_dir) = crate::data_dir::resolve(app) {
         let saved_contexts_dir = app_data_dir.join("session-context");
         if saved_contexts_dir.exists() {
             let prefix = format!("{worktree_id}-context-");
@@ -374,10 +432,50 @@ fn build_claude_args(
         }
     }
 
+    // Check for attached file/directory context (session-scoped, see file_context.rs)
+    match get_session_file_context_paths(app, session_id) {
+        Ok(file_context_paths) => all_context_paths.extend(file_context_paths),
+        Err(e) => log::warn!("Failed to load file context for session {session_id}: {e}"),
+    }
+
+    // Auto-attach lexically relevant snippets for this message (see retrieval.rs). Short
+    // messages ("yes", "continue") aren't worth retrieving over, so skip below a minimum
+    // length rather than spending the walk on a query with no useful terms.
+    if user_message.trim().len() >= 12 {
+        match retrieve_relevant_snippets(&worktree_path.to_string_lossy(), user_message, 3) {
+            Ok(snippets) if !snippets.is_empty() => {
+                if let Ok(app_data_dir) = crate::data_dir::resolve(app) {
+                    let retrieved_dir = app_data_dir.join("retrieved-context");
+                    if std::fs::create_dir_all(&retrieved_dir).is_ok() {
+                        let mut retrieved_content = String::new();
+                        for snippet in &snippets {
+                            retrieved_content.push_str(&format!(
+                                "### {} (lines {}-{})\n\n```\n{}\n```\n\n",
+                                snippet.relative_path,
+                                snippet.start_line,
+                                snippet.end_line,
+                                snippet.snippet
+                            ));
+                        }
+                        let retrieved_file =
+                            retrieved_dir.join(format!("{session_id}-retrieved.md"));
+                        if std::fs::write(&retrieved_file, &retrieved_content).is_ok() {
+                            all_context_paths.push(retrieved_file);
+                        }
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(e) => {
+                log::warn!("Failed to retrieve relevant context for session {session_id}: {e}")
+            }
+        }
+    }
+
     // If we have context files OR system prompt parts, create a combined context file
     let has_system_prompts = !system_prompt_parts.is_empty();
     if !all_context_paths.is_empty() || has_system_prompts {
-        if let Ok(app_data_dir) = app.path().app_data_dir() {
+        if let Ok(app_data_dir) = crate::data_dir::resolve(app) {
             let combined_contexts_dir = app_data_dir.join("combined-contexts");
             let _ = std::fs::create_dir_all(&combined_contexts_dir);
 
@@ -405,6 +503,14 @@ fn build_claude_args(
                     s.contains("session-context") && s.contains("-context-")
                 })
                 .count();
+            let file_context_count = all_context_paths
+                .iter()
+                .filter(|p| p.to_string_lossy().contains("file-context"))
+                .count();
+            let retrieved_count = all_context_paths
+                .iter()
+                .filter(|p| p.to_string_lossy().contains("retrieved-context"))
+                .count();
 
             // Build combined content with header
             let mut combined_content = String::new();
@@ -426,7 +532,12 @@ fn build_claude_args(
                 combined_content
                     .push_str("You should be aware of this when working on this task.\n\n");
 
-                if issue_count > 0 || pr_count > 0 || saved_context_count > 0 {
+                if issue_count > 0
+                    || pr_count > 0
+                    || saved_context_count > 0
+                    || file_context_count > 0
+                    || retrieved_count > 0
+                {
                     combined_content.push_str("**Summary:**\n");
                     if issue_count > 0 {
                         combined_content.push_str(&format!("- {} GitHub Issue(s)\n", issue_count));
@@ -439,6 +550,14 @@ fn build_claude_args(
                         combined_content
                             .push_str(&format!("- {} Saved Context(s)\n", saved_context_count));
                     }
+                    if file_context_count > 0 {
+                        combined_content
+                            .push_str(&format!("- {} Attached File(s)\n", file_context_count));
+                    }
+                    if retrieved_count > 0 {
+                        combined_content
+                            .push_str(&format!("- {} Retrieved Snippet(s)\n", retrieved_count));
+                    }
                     combined_content.push_str("\n---\n\n");
                 }
             }
@@ -487,9 +606,70 @@ fn build_claude_args(
         env_vars.push(("JEAN_CLAUDE_SESSION_ID".to_string(), claude_sid.to_string()));
     }
 
+    // User-configured project/session env vars (see `env_vars::resolve_env_vars`). Applied
+    // last so a user-configured key can't be silently shadowed by the debug vars above, and
+    // any JEAN_MAX_THINKING_TOKENS-style surprise is at least visible in the CLI command log.
+    env_vars.extend(super::env_vars::resolve_env_vars(app, worktree_id, session_id));
+
     (args, env_vars)
 }
 
+/// Whether an error returned from `execute_claude_detached` looks like a transient failure
+/// (API overload, rate limiting, or a dropped connection) rather than a real failure in the
+/// conversation itself, and is therefore worth retrying automatically.
+pub(super) fn classify_transient_error(error: &str) -> bool {
+    let lower = error.to_lowercase();
+    const TRANSIENT_MARKERS: &[&str] = &[
+        "overloaded",
+        "rate limit",
+        "rate_limit",
+        "429",
+        "500",
+        "502",
+        "503",
+        "504",
+        "529",
+        "timed out",
+        "timeout",
+        "network",
+        "connection reset",
+        "econnreset",
+        "temporarily unavailable",
+        "fetch failed",
+        "socket hang up",
+    ];
+    TRANSIENT_MARKERS.iter().any(|marker| lower.contains(marker))
+}
+
+/// Whether an error returned from `execute_claude_detached` looks like the account has run
+/// out of usage quota (subscription plan limit or API billing limit) rather than a
+/// transient backend issue - these aren't worth auto-retrying (see `classify_transient_error`)
+/// and instead surface a `chat:quota_exceeded` event so the UI can explain the outage.
+pub(super) fn classify_quota_error(error: &str) -> bool {
+    let lower = error.to_lowercase();
+    const QUOTA_MARKERS: &[&str] = &[
+        "usage limit",
+        "usage_limit",
+        "quota exceeded",
+        "plan limit",
+        "exceeded your",
+        "upgrade your plan",
+        "billing limit",
+        "insufficient_quota",
+        "insufficient quota",
+    ];
+    QUOTA_MARKERS.iter().any(|marker| lower.contains(marker))
+}
+
+/// Payload for the `chat:quota_exceeded` event, emitted when a run fails because the
+/// account has run out of usage quota (see `classify_quota_error`).
+#[derive(serde::Serialize, Clone)]
+pub struct QuotaExceededEvent {
+    pub session_id: String,
+    pub worktree_id: String,
+    pub error: String,
+}
+
 /// Execute Claude CLI in detached mode.
 ///
 /// Spawns Claude CLI as a fully detached process that survives Jean quitting.
@@ -511,6 +691,7 @@ pub fn execute_claude_detached(
     disable_thinking_in_non_plan_modes: bool,
     parallel_execution_prompt_enabled: bool,
     ai_language: Option<&str>,
+    user_message: &str,
 ) -> Result<(u32, ClaudeResponse), String> {
     use super::detached::spawn_detached_claude;
     use crate::claude_cli::get_cli_binary_path;
@@ -560,6 +741,8 @@ pub fn execute_claude_detached(
         disable_thinking_in_non_plan_modes,
         parallel_execution_prompt_enabled,
         ai_language,
+        working_dir,
+        user_message,
     );
 
     // Log the full Claude CLI command for debugging
@@ -575,14 +758,32 @@ pub fn execute_claude_detached(
         .map(|(k, v)| (k.as_str(), v.as_str()))
         .collect();
 
+    // Wrap in an OS-level sandbox if this project has opted in (see `chat::sandbox`).
+    // Unchanged (cli_path, args) when sandboxing is disabled or unavailable.
+    let sandbox_config = super::sandbox::resolve_sandbox_config(app, worktree_id);
+    let (run_path, run_args) =
+        super::sandbox::wrap_command(&cli_path, &args, working_dir, &sandbox_config);
+
+    // De-prioritize this run if the user opted in and either another run is already active
+    // or the app is in the background - see `AppPreferences::low_priority_background_runs`.
+    let low_priority = crate::load_preferences_sync(app)
+        .map(|prefs| prefs.low_priority_background_runs)
+        .unwrap_or(false)
+        && (!super::registry::get_running_sessions().is_empty()
+            || !app
+                .try_state::<crate::background_tasks::BackgroundTaskManager>()
+                .map(|state| state.is_focused())
+                .unwrap_or(true));
+
     // Spawn detached process
     let pid = spawn_detached_claude(
-        &cli_path,
-        &args,
+        &run_path,
+        &run_args,
         input_file,
         output_file,
         working_dir,
         &env_refs,
+        low_priority,
     )
     .map_err(|e| {
         let error_msg = format!("Failed to start Claude CLI: {e}");
@@ -601,17 +802,35 @@ pub fn execute_claude_detached(
     log::trace!("Detached Claude CLI spawned with PID: {pid}");
 
     // Register the process for cancellation
-    super::registry::register_process(session_id.to_string(), pid);
+    super::registry::register_process(app, session_id.to_string(), pid);
+
+    // Resolve the per-project execution timeout, if any, from jean.json. Off by default -
+    // see `JeanConfig::execution_timeout_seconds`.
+    let execution_timeout = crate::projects::git::read_jean_config(&working_dir.to_string_lossy())
+        .and_then(|config| config.execution_timeout_seconds)
+        .map(std::time::Duration::from_secs);
+
+    // Detect (and cache) the CLI version so unrecognized stream-json event types can be
+    // tagged with it in diagnostics - see `stream_format::detect_version`.
+    let cli_version = super::stream_format::detect_version(&cli_path);
 
     // Tail the output file for real-time updates
     // Use match to ensure unregister_process is always called, even on error
-    let response = match tail_claude_output(app, session_id, worktree_id, output_file, pid) {
+    let response = match tail_claude_output(
+        app,
+        session_id,
+        worktree_id,
+        output_file,
+        pid,
+        execution_timeout,
+        cli_version,
+    ) {
         Ok(resp) => {
-            super::registry::unregister_process(session_id);
+            super::registry::unregister_process(app, session_id);
             resp
         }
         Err(e) => {
-            super::registry::unregister_process(session_id);
+            super::registry::unregister_process(app, session_id);
             return Err(e);
         }
     };
@@ -638,8 +857,11 @@ pub fn tail_claude_output(
     worktree_id: &str,
     output_file: &std::path::Path,
     pid: u32,
+    execution_timeout: Option<std::time::Duration>,
+    cli_version: Option<super::stream_format::CliVersion>,
 ) -> Result<ClaudeResponse, String> {
     use super::detached::is_process_alive;
+    use super::stream_format::{classify_event, StreamEventKind};
     use super::tail::{NdjsonTailer, POLL_INTERVAL};
     use std::time::{Duration, Instant};
 
@@ -654,9 +876,15 @@ pub fn tail_claude_output(
     let mut tool_calls: Vec<ToolCall> = Vec::new();
     let mut content_blocks: Vec<ContentBlock> = Vec::new();
     let mut current_parent_tool_use_id: Option<String> = None;
+    // Tool call ids of `Task` tool uses seen so far, i.e. active/finished sub-agents - used to
+    // recognize nested stream messages (progress) and their tool_result (completion).
+    let mut subagent_ids: Vec<String> = Vec::new();
     let mut completed = false;
     let mut cancelled = false;
     let mut usage: Option<UsageData> = None;
+    // Set when the final "result" message reports `is_error: true` (e.g. the API was
+    // overloaded or the connection dropped mid-run) - see `classify_transient_error`.
+    let mut result_error: Option<String> = None;
 
     // Timeout configuration:
     // - Startup timeout: Wait up to 120 seconds for first Claude output (API connection time)
@@ -716,8 +944,8 @@ pub fn tail_claude_output(
 
             let msg_type = msg.get("type").and_then(|v| v.as_str()).unwrap_or("");
 
-            match msg_type {
-                "assistant" => {
+            match classify_event(msg_type) {
+                Some(StreamEventKind::Assistant) => {
                     if let Some(message) = msg.get("message") {
                         if let Some(blocks) = message.get("content").and_then(|c| c.as_array()) {
                             for block in blocks {
@@ -748,6 +976,26 @@ pub fn tail_claude_output(
                                             if let Err(e) = app.emit_all("chat:chunk", &event) {
                                                 log::error!("Failed to emit chunk: {e}");
                                             }
+
+                                            // Also surface as sub-agent progress if this text
+                                            // came from inside a running Task sub-agent
+                                            if let Some(parent_id) = &current_parent_tool_use_id {
+                                                if subagent_ids.contains(parent_id) {
+                                                    let event = SubagentProgressEvent {
+                                                        session_id: session_id.to_string(),
+                                                        worktree_id: worktree_id.to_string(),
+                                                        id: parent_id.clone(),
+                                                        content: text.to_string(),
+                                                    };
+                                                    if let Err(e) =
+                                                        app.emit_all("chat:subagent-progress", &event)
+                                                    {
+                                                        log::error!(
+                                                            "Failed to emit subagent-progress: {e}"
+                                                        );
+                                                    }
+                                                }
+                                            }
                                         }
                                     }
                                     "tool_use" => {
@@ -766,6 +1014,53 @@ pub fn tail_claude_output(
                                             .cloned()
                                             .unwrap_or(serde_json::Value::Null);
 
+                                        if name == "Task" && current_parent_tool_use_id.is_none() {
+                                            subagent_ids.push(id.clone());
+
+                                            let description = input
+                                                .get("description")
+                                                .and_then(|v| v.as_str())
+                                                .or_else(|| input.get("prompt").and_then(|v| v.as_str()))
+                                                .unwrap_or("")
+                                                .to_string();
+                                            let subagent_type = input
+                                                .get("subagent_type")
+                                                .and_then(|v| v.as_str())
+                                                .map(|s| s.to_string());
+
+                                            let event = SubagentStartedEvent {
+                                                session_id: session_id.to_string(),
+                                                worktree_id: worktree_id.to_string(),
+                                                id: id.clone(),
+                                                description,
+                                                subagent_type,
+                                            };
+                                            if let Err(e) =
+                                                app.emit_all("chat:subagent-started", &event)
+                                            {
+                                                log::error!(
+                                                    "Failed to emit subagent-started: {e}"
+                                                );
+                                            }
+                                        } else if let Some(parent_id) = &current_parent_tool_use_id
+                                        {
+                                            if subagent_ids.contains(parent_id) {
+                                                let event = SubagentProgressEvent {
+                                                    session_id: session_id.to_string(),
+                                                    worktree_id: worktree_id.to_string(),
+                                                    id: parent_id.clone(),
+                                                    content: format!("Used tool: {name}"),
+                                                };
+                                                if let Err(e) = app
+                                                    .emit_all("chat:subagent-progress", &event)
+                                                {
+                                                    log::error!(
+                                                        "Failed to emit subagent-progress: {e}"
+                                                    );
+                                                }
+                                            }
+                                        }
+
                                         tool_calls.push(ToolCall {
                                             id: id.clone(),
                                             name: name.clone(),
@@ -861,7 +1156,7 @@ pub fn tail_claude_output(
                         }
                     }
                 }
-                "user" => {
+                Some(StreamEventKind::User) => {
                     // User messages contain tool results
                     if let Some(message) = msg.get("message") {
                         if let Some(blocks) = message.get("content").and_then(|c| c.as_array()) {
@@ -894,12 +1189,30 @@ pub fn tail_claude_output(
                                     if let Err(e) = app.emit_all("chat:tool_result", &event) {
                                         log::error!("Failed to emit tool_result: {e}");
                                     }
+
+                                    // A tool_result for a Task tool call id is the sub-agent's
+                                    // final output
+                                    if subagent_ids.contains(&tool_id.to_string()) {
+                                        let event = SubagentCompletedEvent {
+                                            session_id: session_id.to_string(),
+                                            worktree_id: worktree_id.to_string(),
+                                            id: tool_id.to_string(),
+                                            output: output.to_string(),
+                                        };
+                                        if let Err(e) =
+                                            app.emit_all("chat:subagent-completed", &event)
+                                        {
+                                            log::error!(
+                                                "Failed to emit subagent-completed: {e}"
+                                            );
+                                        }
+                                    }
                                 }
                             }
                         }
                     }
                 }
-                "result" => {
+                Some(StreamEventKind::Result) => {
                     // Final result - Claude CLI completed
                     if full_content.is_empty() {
                         if let Some(result) = msg.get("result").and_then(|v| v.as_str()) {
@@ -907,6 +1220,20 @@ pub fn tail_claude_output(
                         }
                     }
 
+                    // The CLI reports failures (API overloaded, network drop, max turns, etc.)
+                    // as a "result" message with `is_error: true` rather than a non-zero exit
+                    // code, so we have to check for it here instead of relying on the process
+                    // exit status.
+                    if msg.get("is_error").and_then(|v| v.as_bool()).unwrap_or(false) {
+                        let subtype = msg.get("subtype").and_then(|v| v.as_str()).unwrap_or("");
+                        let detail = msg
+                            .get("result")
+                            .and_then(|v| v.as_str())
+                            .filter(|s| !s.is_empty())
+                            .unwrap_or(subtype);
+                        result_error = Some(format!("Claude CLI reported an error: {detail}"));
+                    }
+
                     // Extract token usage data
                     if let Some(usage_obj) = msg.get("usage") {
                         usage = Some(UsageData {
@@ -997,7 +1324,7 @@ pub fn tail_claude_output(
                     completed = true;
                     log::trace!("Received result message - Claude CLI completed");
                 }
-                "system" => {
+                Some(StreamEventKind::System) => {
                     let subtype = msg.get("subtype").and_then(|v| v.as_str()).unwrap_or("");
                     if subtype == "compact_boundary" {
                         log::trace!("Detected compact_boundary system message");
@@ -1026,7 +1353,21 @@ pub fn tail_claude_output(
                         }
                     }
                 }
-                _ => {}
+                None => {
+                    log::warn!(
+                        "Unrecognized stream-json event type '{msg_type}' from Claude CLI \
+                         {cli_version:?}, emitting diagnostic instead of dropping it"
+                    );
+                    let diagnostic = super::stream_format::UnsupportedEventDiagnostic {
+                        session_id: session_id.to_string(),
+                        worktree_id: worktree_id.to_string(),
+                        event_type: msg_type.to_string(),
+                        cli_version: cli_version.map(|v| v.to_string()),
+                    };
+                    if let Err(e) = app.emit_all("chat:unsupported_event", &diagnostic) {
+                        log::error!("Failed to emit unsupported_event diagnostic: {e}");
+                    }
+                }
             }
         }
 
@@ -1044,6 +1385,27 @@ pub fn tail_claude_output(
             break;
         }
 
+        // Enforce the per-project execution timeout (jean.json's `execution_timeout_seconds`),
+        // if configured. Cancel gracefully (force: false) through the same registry path a
+        // user-initiated cancel would take, so the CLI gets the usual SIGINT/SIGTERM ladder
+        // instead of being killed mid-write.
+        if let Some(timeout) = execution_timeout {
+            if started_at.elapsed() > timeout {
+                log::warn!(
+                    "Execution timeout ({timeout:?}) exceeded for session {session_id}, cancelling"
+                );
+                let _ = super::registry::cancel_process(
+                    app,
+                    session_id,
+                    worktree_id,
+                    false,
+                    Some("timeout"),
+                );
+                cancelled = true;
+                break;
+            }
+        }
+
         // Timeout logic depends on whether we've received Claude output yet
         let process_alive = is_process_alive(pid);
 
@@ -1101,6 +1463,12 @@ pub fn tail_claude_output(
         tool_calls.len()
     );
 
+    if let Some(error) = result_error {
+        if !cancelled {
+            return Err(error);
+        }
+    }
+
     Ok(ClaudeResponse {
         content: full_content,
         session_id: claude_session_id,
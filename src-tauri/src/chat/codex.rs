@@ -0,0 +1,237 @@
+//! OpenAI Codex CLI backend.
+//!
+//! Spawns the `codex` CLI (configured via `AppPreferences::codex_cli_path`, defaulting
+//! to `codex` on `PATH`) in its non-interactive `exec --json` mode, parses its NDJSON
+//! event stream, and maps it onto the same `ContentBlock`/`ToolCall` types and
+//! `chat:*` events the Claude CLI backend produces.
+//!
+//! Unlike `super::claude`, Codex is spawned as a plain attached child process rather
+//! than detached via `super::detached::spawn_detached_claude` — Codex has no equivalent
+//! to Claude's file-tailing/resume-after-quit contract, so there's nothing to gain from
+//! detaching it yet. Its PID is still registered in `chat::registry` so the existing
+//! process-tree-based cancellation works identically. `resume_session_id` is accepted
+//! but unused, since Codex's own session/rollout format isn't mapped to Jean's resume
+//! model yet — each turn is sent as a fresh `codex exec` invocation.
+
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+
+use serde::Deserialize;
+use tauri::AppHandle;
+
+use super::ai_provider::{AiProvider, SpawnRequest};
+use super::claude::{ChunkEvent, ClaudeResponse, DoneEvent, ErrorEvent, ToolUseEvent};
+use super::types::{ContentBlock, ToolCall, UsageData};
+use crate::http_server::EmitExt;
+
+/// Identifier stored in `Session::selected_provider` for this backend.
+pub const CODEX_PROVIDER_ID: &str = "codex";
+
+pub struct CodexCliProvider;
+
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum CodexEvent {
+    #[serde(rename = "agent_message")]
+    AgentMessage { message: String },
+    #[serde(rename = "exec_command_begin")]
+    ExecCommandBegin {
+        call_id: String,
+        command: Vec<String>,
+    },
+    #[serde(rename = "exec_command_end")]
+    ExecCommandEnd {
+        call_id: String,
+        #[serde(default)]
+        exit_code: i64,
+    },
+    #[serde(rename = "token_count")]
+    TokenCount {
+        #[serde(default)]
+        input_tokens: u64,
+        #[serde(default)]
+        output_tokens: u64,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl AiProvider for CodexCliProvider {
+    fn id(&self) -> &'static str {
+        CODEX_PROVIDER_ID
+    }
+
+    fn spawn(
+        &self,
+        app: &AppHandle,
+        request: SpawnRequest<'_>,
+    ) -> Result<(u32, ClaudeResponse), String> {
+        let preferences = crate::load_preferences_sync(app)?;
+        let binary = preferences
+            .codex_cli_path
+            .filter(|p| !p.trim().is_empty())
+            .unwrap_or_else(|| "codex".to_string());
+
+        let mut child = Command::new(&binary)
+            .args(["exec", "--json", "--cd"])
+            .arg(request.working_dir)
+            .arg(request.message)
+            .current_dir(request.working_dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                let error_msg = format!(
+                    "Failed to start Codex CLI ('{binary}'): {e}. Install it or set the path in Settings."
+                );
+                emit_error(app, request.session_id, request.worktree_id, &error_msg);
+                error_msg
+            })?;
+
+        let pid = child.id();
+        super::registry::register_process(app, request.session_id.to_string(), pid);
+
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| "Failed to capture Codex CLI stdout".to_string())?;
+
+        let mut full_content = String::new();
+        let mut tool_calls: Vec<ToolCall> = Vec::new();
+        let mut content_blocks: Vec<ContentBlock> = Vec::new();
+        let mut usage: Option<UsageData> = None;
+
+        for line in BufReader::new(stdout).lines() {
+            let line = match line {
+                Ok(l) => l,
+                Err(e) => {
+                    log::trace!("Failed to read Codex CLI output line: {e}");
+                    continue;
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let event: CodexEvent = match serde_json::from_str(&line) {
+                Ok(e) => e,
+                Err(e) => {
+                    log::trace!("Skipping unparsable Codex event: {e}");
+                    continue;
+                }
+            };
+
+            match event {
+                CodexEvent::AgentMessage { message } => {
+                    full_content.push_str(&message);
+                    content_blocks.push(ContentBlock::Text {
+                        text: message.clone(),
+                    });
+                    let _ = app.emit_all(
+                        "chat:chunk",
+                        &ChunkEvent {
+                            session_id: request.session_id.to_string(),
+                            worktree_id: request.worktree_id.to_string(),
+                            content: message,
+                        },
+                    );
+                }
+                CodexEvent::ExecCommandBegin { call_id, command } => {
+                    let input = serde_json::json!({ "command": command.join(" ") });
+                    tool_calls.push(ToolCall {
+                        id: call_id.clone(),
+                        name: "Bash".to_string(),
+                        input: input.clone(),
+                        output: None,
+                        parent_tool_use_id: None,
+                    });
+                    content_blocks.push(ContentBlock::ToolUse {
+                        tool_call_id: call_id.clone(),
+                    });
+                    let _ = app.emit_all(
+                        "chat:tool_use",
+                        &ToolUseEvent {
+                            session_id: request.session_id.to_string(),
+                            worktree_id: request.worktree_id.to_string(),
+                            id: call_id,
+                            name: "Bash".to_string(),
+                            input,
+                            parent_tool_use_id: None,
+                        },
+                    );
+                }
+                CodexEvent::ExecCommandEnd { call_id, exit_code } => {
+                    if let Some(tool_call) = tool_calls.iter_mut().find(|t| t.id == call_id) {
+                        tool_call.output = Some(format!("exit code: {exit_code}"));
+                    }
+                }
+                CodexEvent::TokenCount {
+                    input_tokens,
+                    output_tokens,
+                } => {
+                    usage = Some(UsageData {
+                        input_tokens,
+                        output_tokens,
+                        cache_read_input_tokens: 0,
+                        cache_creation_input_tokens: 0,
+                    });
+                }
+                CodexEvent::Other => {}
+            }
+        }
+
+        let status = child
+            .wait()
+            .map_err(|e| format!("Failed to wait for Codex CLI: {e}"))?;
+        super::registry::unregister_process(app, request.session_id);
+
+        if !status.success() {
+            let stderr_output = child
+                .stderr
+                .take()
+                .map(|stderr| {
+                    BufReader::new(stderr)
+                        .lines()
+                        .map_while(Result::ok)
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                })
+                .unwrap_or_default();
+            let error_msg = format!("Codex CLI exited with {status}: {stderr_output}");
+            emit_error(app, request.session_id, request.worktree_id, &error_msg);
+            return Err(error_msg);
+        }
+
+        let _ = app.emit_all(
+            "chat:done",
+            &DoneEvent {
+                session_id: request.session_id.to_string(),
+                worktree_id: request.worktree_id.to_string(),
+            },
+        );
+
+        Ok((
+            pid,
+            ClaudeResponse {
+                content: full_content,
+                session_id: String::new(), // Codex's own rollout ID isn't mapped to Jean's resume model yet
+                tool_calls,
+                content_blocks,
+                cancelled: false,
+                usage,
+            },
+        ))
+    }
+}
+
+fn emit_error(app: &AppHandle, session_id: &str, worktree_id: &str, error: &str) {
+    let _ = app.emit_all(
+        "chat:error",
+        &ErrorEvent {
+            session_id: session_id.to_string(),
+            worktree_id: worktree_id.to_string(),
+            error: error.to_string(),
+        },
+    );
+}
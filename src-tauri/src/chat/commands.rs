@@ -11,11 +11,11 @@ use super::registry::cancel_process;
 use super::run_log;
 use super::storage::{
     delete_session_data, get_data_dir, get_index_path, get_session_dir, load_metadata,
-    load_sessions, with_sessions_mut,
+    load_sessions, with_metadata_mut, with_sessions_mut,
 };
 use super::types::{
-    AllSessionsEntry, AllSessionsResponse, ChatMessage, ClaudeContext, MessageRole, RunStatus,
-    Session, ThinkingLevel, WorktreeSessions,
+    AllSessionsEntry, AllSessionsResponse, ChatMessage, ClaudeContext, MessageRole, RunEntry,
+    RunStatus, Session, ThinkingLevel, WorktreeSessions,
 };
 use crate::claude_cli::get_cli_binary_path;
 use crate::platform::silent_command;
@@ -57,23 +57,7 @@ pub async fn get_sessions(
     if include_message_counts.unwrap_or(false) {
         for session in &mut sessions.sessions {
             if let Ok(Some(metadata)) = load_metadata(&app, &session.id) {
-                // Count messages: each run has 1 user message, plus 1 assistant message if not undo_send
-                let count: u32 = metadata
-                    .runs
-                    .iter()
-                    .map(|run| {
-                        let is_undo_send = run.status == RunStatus::Cancelled
-                            && run.assistant_message_id.is_none();
-                        if is_undo_send {
-                            0
-                        } else if run.assistant_message_id.is_some() {
-                            2 // user + assistant
-                        } else {
-                            1 // just user (still running or cancelled without response)
-                        }
-                    })
-                    .sum();
-                session.message_count = Some(count);
+                session.message_count = Some(metadata.count_messages());
             }
         }
     }
@@ -127,13 +111,21 @@ pub async fn list_all_sessions(app: AppHandle) -> Result<AllSessionsResponse, St
     Ok(AllSessionsResponse { entries })
 }
 
-/// Get a single session with full message history
+/// Get a single session with message history.
+///
+/// By default loads the full history. Pass `limit` to load only the most recent `limit`
+/// messages (or the `limit` messages immediately before `before_message_id`, for scrolling
+/// further back) - this avoids reading every run's log file from disk just to open an old,
+/// long-running session. `session.message_count` is always populated so the frontend knows
+/// whether there's more history than what was returned, regardless of pagination.
 #[tauri::command]
 pub async fn get_session(
     app: AppHandle,
     worktree_id: String,
     worktree_path: String,
     session_id: String,
+    limit: Option<u32>,
+    before_message_id: Option<String>,
 ) -> Result<Session, String> {
     log::trace!("Getting session: {session_id}");
     let sessions = load_sessions(&app, &worktree_path, &worktree_id)?;
@@ -143,7 +135,18 @@ pub async fn get_session(
         .ok_or_else(|| format!("Session not found: {session_id}"))?;
 
     // Load messages from NDJSON (single source of truth)
-    let mut messages = run_log::load_session_messages(&app, &session_id)?;
+    let mut messages = if limit.is_some() || before_message_id.is_some() {
+        let page = run_log::load_session_messages_page(
+            &app,
+            &session_id,
+            limit.map(|l| l as usize),
+            before_message_id.as_deref(),
+        )?;
+        session.has_more_messages = Some(page.has_more);
+        page.messages
+    } else {
+        run_log::load_session_messages(&app, &session_id)?
+    };
 
     // Apply approved plan status from session metadata
     for msg in &mut messages {
@@ -156,6 +159,15 @@ pub async fn get_session(
     Ok(session)
 }
 
+/// Get the total message count for a session without reading any run log from disk -
+/// reuses the same metadata-only formula as `get_sessions`'s `include_message_counts`.
+#[tauri::command]
+pub async fn get_message_count(app: AppHandle, session_id: String) -> Result<u32, String> {
+    let metadata = load_metadata(&app, &session_id)?
+        .ok_or_else(|| format!("Session not found: {session_id}"))?;
+    Ok(metadata.count_messages())
+}
+
 /// Create a new session tab
 #[tauri::command]
 pub async fn create_session(
@@ -182,6 +194,354 @@ pub async fn create_session(
     })
 }
 
+/// Fork a session at a specific message into a new session tab.
+///
+/// Copies every run up to and including the run containing `at_message_id` - runs store a
+/// user message and its assistant reply as a single unit, so the fork point snaps to the end
+/// of that run rather than landing mid-reply. The underlying Claude CLI conversation is only
+/// resumed (via `claude_session_id`) when forking at the session's last run; `--resume` replays
+/// the CLI's own full history rather than a prefix of it, so forking earlier starts a fresh
+/// Claude conversation for the new session while still showing the copied history in the UI.
+#[tauri::command]
+pub async fn fork_session(
+    app: AppHandle,
+    worktree_id: String,
+    worktree_path: String,
+    session_id: String,
+    at_message_id: String,
+    new_name: Option<String>,
+) -> Result<Session, String> {
+    log::trace!("Forking session {session_id} at message {at_message_id}");
+
+    let source_metadata = load_metadata(&app, &session_id)?
+        .ok_or_else(|| format!("Session not found: {session_id}"))?;
+
+    let fork_index = source_metadata
+        .runs
+        .iter()
+        .position(|run| {
+            run.user_message_id == at_message_id
+                || run.assistant_message_id.as_deref() == Some(at_message_id.as_str())
+        })
+        .ok_or_else(|| format!("Message not found in session: {at_message_id}"))?;
+
+    let forked_runs: Vec<RunEntry> = source_metadata.runs[..=fork_index].to_vec();
+    let is_last_run = fork_index == source_metadata.runs.len() - 1;
+    let forked_claude_session_id = if is_last_run {
+        source_metadata.claude_session_id.clone()
+    } else {
+        None
+    };
+
+    let source_session = load_sessions(&app, &worktree_path, &worktree_id)?
+        .find_session(&session_id)
+        .cloned()
+        .ok_or_else(|| format!("Session not found: {session_id}"))?;
+
+    let forked_session = with_sessions_mut(&app, &worktree_path, &worktree_id, |sessions| {
+        let name = new_name.unwrap_or_else(|| format!("{} (fork)", source_session.name));
+        let mut session = Session::new(name, sessions.sessions.len() as u32);
+        session.selected_model = source_session.selected_model.clone();
+        session.selected_provider = source_session.selected_provider.clone();
+        session.selected_thinking_level = source_session.selected_thinking_level.clone();
+        session.claude_session_id = forked_claude_session_id;
+
+        sessions.sessions.push(session.clone());
+        sessions.active_session_id = Some(session.id.clone());
+
+        Ok(session)
+    })?;
+
+    let source_dir = get_session_dir(&app, &session_id)?;
+    let dest_dir = get_session_dir(&app, &forked_session.id)?;
+    for run in &forked_runs {
+        let file_name = format!("{}.jsonl", run.run_id);
+        let src = source_dir.join(&file_name);
+        if src.exists() {
+            std::fs::copy(&src, dest_dir.join(&file_name))
+                .map_err(|e| format!("Failed to copy run log {}: {e}", run.run_id))?;
+        }
+    }
+
+    with_metadata_mut(
+        &app,
+        &forked_session.id,
+        &worktree_id,
+        &forked_session.name,
+        forked_session.order,
+        |metadata| {
+            metadata.runs = forked_runs;
+            Ok(())
+        },
+    )?;
+
+    log::trace!(
+        "Forked session {session_id} into {} at message {at_message_id}",
+        forked_session.id
+    );
+
+    get_session(app, worktree_id, worktree_path, forked_session.id, None, None).await
+}
+
+/// Run the same prompt against several models/providers in parallel, each in its own
+/// temporary session forked from `session_id`'s current history, so the replies can be
+/// compared side by side without disturbing the original conversation.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn compare_models(
+    app: AppHandle,
+    worktree_id: String,
+    worktree_path: String,
+    session_id: String,
+    message: String,
+    models: Vec<String>,
+    execution_mode: Option<String>,
+    thinking_level: Option<ThinkingLevel>,
+) -> Result<super::types::CompareModelsResponse, String> {
+    if models.is_empty() {
+        return Err("At least one model is required".to_string());
+    }
+
+    log::trace!("Comparing models {models:?} for session: {session_id}");
+
+    let source_session = load_sessions(&app, &worktree_path, &worktree_id)?
+        .find_session(&session_id)
+        .cloned()
+        .ok_or_else(|| format!("Session not found: {session_id}"))?;
+
+    let last_message_id = load_metadata(&app, &session_id)?
+        .and_then(|metadata| metadata.runs.last().cloned())
+        .map(|run| run.assistant_message_id.unwrap_or(run.user_message_id));
+
+    let compare_futures = models.iter().map(|model| {
+        let app = app.clone();
+        let worktree_id = worktree_id.clone();
+        let worktree_path = worktree_path.clone();
+        let session_id = session_id.clone();
+        let source_name = source_session.name.clone();
+        let last_message_id = last_message_id.clone();
+        let message = message.clone();
+        let model = model.clone();
+        let execution_mode = execution_mode.clone();
+        let thinking_level = thinking_level.clone();
+
+        async move {
+            let temp_name = format!("{source_name} — {model}");
+
+            let temp_session = match &last_message_id {
+                Some(at_message_id) => {
+                    fork_session(
+                        app.clone(),
+                        worktree_id.clone(),
+                        worktree_path.clone(),
+                        session_id.clone(),
+                        at_message_id.clone(),
+                        Some(temp_name),
+                    )
+                    .await
+                }
+                None => create_session(app.clone(), worktree_id.clone(), worktree_path.clone(), Some(temp_name))
+                    .await,
+            };
+
+            let temp_session = match temp_session {
+                Ok(s) => s,
+                Err(e) => {
+                    return super::types::ModelCompareResult {
+                        model,
+                        session_id: String::new(),
+                        message: None,
+                        error: Some(e),
+                    }
+                }
+            };
+
+            let result = send_chat_message(
+                app,
+                temp_session.id.clone(),
+                worktree_id,
+                worktree_path,
+                message,
+                Some(model.clone()),
+                execution_mode,
+                thinking_level,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+            match result {
+                Ok(assistant_msg) => super::types::ModelCompareResult {
+                    model,
+                    session_id: temp_session.id,
+                    message: Some(assistant_msg),
+                    error: None,
+                },
+                Err(e) => super::types::ModelCompareResult {
+                    model,
+                    session_id: temp_session.id,
+                    message: None,
+                    error: Some(e),
+                },
+            }
+        }
+    });
+
+    let results = futures_util::future::join_all(compare_futures).await;
+
+    Ok(super::types::CompareModelsResponse { results })
+}
+
+/// Payload for the `chat:retrying` event, emitted each time `send_chat_message` is about to
+/// automatically resume a session after a transient Claude CLI error (see
+/// `claude::classify_transient_error`).
+#[derive(serde::Serialize, Clone)]
+pub struct RetryingEvent {
+    pub session_id: String,
+    pub worktree_id: String,
+    pub attempt: u32,
+    pub max_attempts: u32,
+    pub error: String,
+}
+
+/// Payload for the `broadcast:progress` event, emitted as each worktree's run in a
+/// `broadcast_prompt` call starts and finishes, so the frontend can show an aggregate
+/// progress view without polling for the final result.
+#[derive(serde::Serialize, Clone)]
+pub struct BroadcastProgressEvent {
+    pub worktree_id: String,
+    pub session_id: String,
+    /// "started" | "completed" | "failed"
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Run the same prompt across several worktrees in parallel (e.g. "update the license
+/// header" across five services), each in a new session created for the occasion.
+/// Mirrors `compare_models`'s fan-out-and-join shape, but fans out across worktrees
+/// instead of models, and emits `broadcast:progress` events so the frontend can render
+/// an aggregate view as runs complete rather than only the final summary.
+#[tauri::command]
+pub async fn broadcast_prompt(
+    app: AppHandle,
+    worktree_ids: Vec<String>,
+    message: String,
+    model: Option<String>,
+) -> Result<super::types::BroadcastPromptResponse, String> {
+    if worktree_ids.is_empty() {
+        return Err("At least one worktree is required".to_string());
+    }
+
+    log::trace!("Broadcasting prompt to worktrees: {worktree_ids:?}");
+
+    let first_line = message.lines().next().unwrap_or(&message);
+    let short_message: String = if first_line.chars().count() > 40 {
+        format!("{}…", first_line.chars().take(40).collect::<String>())
+    } else {
+        first_line.to_string()
+    };
+    let session_name = format!("Broadcast: {short_message}");
+
+    let broadcast_futures = worktree_ids.iter().map(|worktree_id| {
+        let app = app.clone();
+        let worktree_id = worktree_id.clone();
+        let message = message.clone();
+        let model = model.clone();
+        let session_name = session_name.clone();
+
+        async move {
+            let worktree =
+                match crate::projects::get_worktree(app.clone(), worktree_id.clone()).await {
+                    Ok(w) => w,
+                    Err(e) => {
+                        return super::types::BroadcastPromptResult {
+                            worktree_id,
+                            session_id: String::new(),
+                            message: None,
+                            error: Some(e),
+                        };
+                    }
+                };
+
+            let session = match create_session(
+                app.clone(),
+                worktree_id.clone(),
+                worktree.path.clone(),
+                Some(session_name),
+            )
+            .await
+            {
+                Ok(s) => s,
+                Err(e) => {
+                    return super::types::BroadcastPromptResult {
+                        worktree_id,
+                        session_id: String::new(),
+                        message: None,
+                        error: Some(e),
+                    };
+                }
+            };
+
+            let _ = app.emit_all(
+                "broadcast:progress",
+                &BroadcastProgressEvent {
+                    worktree_id: worktree_id.clone(),
+                    session_id: session.id.clone(),
+                    status: "started".to_string(),
+                    error: None,
+                },
+            );
+
+            let result = send_chat_message(
+                app.clone(),
+                session.id.clone(),
+                worktree_id.clone(),
+                worktree.path.clone(),
+                message,
+                model,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+            let (chat_message, error) = match result {
+                Ok(assistant_msg) => (Some(assistant_msg), None),
+                Err(e) => (None, Some(e)),
+            };
+
+            let _ = app.emit_all(
+                "broadcast:progress",
+                &BroadcastProgressEvent {
+                    worktree_id: worktree_id.clone(),
+                    session_id: session.id.clone(),
+                    status: if error.is_some() { "failed" } else { "completed" }.to_string(),
+                    error: error.clone(),
+                },
+            );
+
+            super::types::BroadcastPromptResult {
+                worktree_id,
+                session_id: session.id,
+                message: chat_message,
+                error,
+            }
+        }
+    });
+
+    let results = futures_util::future::join_all(broadcast_futures).await;
+
+    Ok(super::types::BroadcastPromptResponse { results })
+}
+
 /// Rename a session tab
 #[tauri::command]
 pub async fn rename_session(
@@ -254,7 +614,7 @@ pub async fn update_session_state(
 
 /// Extract pasted image paths from message content
 /// Matches: [Image attached: /path/to/image.png - Use the Read tool to view this image]
-fn extract_image_paths(content: &str) -> Vec<String> {
+pub(super) fn extract_image_paths(content: &str) -> Vec<String> {
     use regex::Regex;
     // Lazy static would be better, but for simplicity we'll compile here
     let re = Regex::new(r"\[Image attached: (.+?) - Use the Read tool to view this image\]")
@@ -266,7 +626,7 @@ fn extract_image_paths(content: &str) -> Vec<String> {
 
 /// Extract pasted text file paths from message content
 /// Matches: [Text file attached: /path/to/file.txt - Use the Read tool to view this file]
-fn extract_text_file_paths(content: &str) -> Vec<String> {
+pub(super) fn extract_text_file_paths(content: &str) -> Vec<String> {
     use regex::Regex;
     let re = Regex::new(r"\[Text file attached: (.+?) - Use the Read tool to view this file\]")
         .expect("Invalid regex");
@@ -301,7 +661,7 @@ pub async fn close_session(
     log::trace!("Closing session: {session_id}");
 
     // Cancel any running process first (outside lock - doesn't touch sessions file)
-    let _ = cancel_process(&app, &session_id, &worktree_id);
+    let _ = cancel_process(&app, &session_id, &worktree_id, false, None);
 
     // Collect pasted file paths for cleanup (outside lock - read-only NDJSON access)
     let mut files_to_delete: Vec<String> = Vec::new();
@@ -380,7 +740,7 @@ pub async fn archive_session(
     log::trace!("Archiving session: {session_id}");
 
     // Cancel any running process first (outside lock)
-    let _ = cancel_process(&app, &session_id, &worktree_id);
+    let _ = cancel_process(&app, &session_id, &worktree_id, false, None);
 
     // Load messages from NDJSON to check if session has content (outside lock - read-only)
     let messages = run_log::load_session_messages(&app, &session_id).unwrap_or_default();
@@ -606,6 +966,7 @@ pub async fn restore_session_with_base(
         cached_unpushed_count: None,
         order: 0,
         archived_at: None,
+        instructions_override: None,
     };
 
     projects_data.add_worktree(new_worktree.clone());
@@ -646,7 +1007,11 @@ pub async fn restore_session_with_base(
     })
 }
 
-/// Permanently delete an archived session
+/// Delete an archived session
+///
+/// Moves the session to the trash (see `crate::trash`) rather than deleting it outright: it's
+/// removed from the worktree's session index, but its data directory on disk is left alone
+/// until the trash entry is restored or purged.
 #[tauri::command]
 pub async fn delete_archived_session(
     app: AppHandle,
@@ -654,9 +1019,9 @@ pub async fn delete_archived_session(
     worktree_path: String,
     session_id: String,
 ) -> Result<(), String> {
-    log::trace!("Permanently deleting archived session: {session_id}");
+    log::trace!("Moving archived session to trash: {session_id}");
 
-    with_sessions_mut(&app, &worktree_path, &worktree_id, |sessions| {
+    let removed = with_sessions_mut(&app, &worktree_path, &worktree_id, |sessions| {
         let session_idx = sessions
             .sessions
             .iter()
@@ -667,10 +1032,13 @@ pub async fn delete_archived_session(
             return Err("Cannot delete non-archived session. Archive it first.".to_string());
         }
 
-        sessions.sessions.remove(session_idx);
-        log::trace!("Archived session permanently deleted: {session_id}");
-        Ok(())
-    })
+        Ok(sessions.sessions.remove(session_idx))
+    })?;
+
+    crate::trash::trash_archived_session(&app, worktree_id, worktree_path, removed)?;
+
+    log::trace!("Archived session moved to trash: {session_id}");
+    Ok(())
 }
 
 /// List archived sessions for a worktree
@@ -821,6 +1189,7 @@ pub async fn send_chat_message(
     parallel_execution_prompt_enabled: Option<bool>,
     ai_language: Option<String>,
     allowed_tools: Option<Vec<String>>,
+    override_budget: Option<bool>,
 ) -> Result<ChatMessage, String> {
     log::trace!("Sending chat message for session: {session_id}, worktree: {worktree_id}, model: {model:?}, execution_mode: {execution_mode:?}, thinking: {thinking_level:?}, disable_thinking_for_mode: {disable_thinking_for_mode:?}, allowed_tools: {allowed_tools:?}");
 
@@ -833,6 +1202,86 @@ pub async fn send_chat_message(
         return Err("Worktree path cannot be empty".to_string());
     }
 
+    // Refuse to start a new run once the worktree's project has exceeded its monthly
+    // budget, unless the caller explicitly overrides it for this one message.
+    if !override_budget.unwrap_or(false) {
+        let status = super::budget::status_for_worktree(&app, &worktree_id)?;
+        if status.exceeded {
+            return Err(format!(
+                "Monthly AI usage budget of ${:.2} exceeded (spent ${:.2} so far this month). \
+                 Pass override_budget to send this message anyway.",
+                status.limit_usd.unwrap_or(0.0),
+                status.spent_usd
+            ));
+        }
+    }
+
+    // If this session already has a run in flight, queue this message instead of
+    // stomping it - `queue::dispatch_next` resubmits it once the current run completes.
+    if super::registry::is_process_running(&session_id) {
+        let queued = super::queue::enqueue(
+            &app,
+            session_id.clone(),
+            worktree_id,
+            worktree_path,
+            message,
+            model,
+            execution_mode,
+            thinking_level,
+            disable_thinking_for_mode,
+            parallel_execution_prompt_enabled,
+            ai_language,
+            allowed_tools,
+        );
+        return Ok(ChatMessage {
+            id: queued.id,
+            session_id,
+            role: MessageRole::User,
+            content: queued.message,
+            timestamp: queued.queued_at,
+            model: queued.model,
+            execution_mode: queued.execution_mode,
+            thinking_level: queued.thinking_level.map(|t| format!("{t:?}").to_lowercase()),
+            queued: true,
+            ..Default::default()
+        });
+    }
+
+    // If the global concurrency cap is set and already met, wait in the priority run
+    // queue instead of starting a new process - `run_queue::dispatch_next` resubmits it
+    // once a running process (any session) finishes and frees a slot.
+    let max_concurrent_runs = crate::load_preferences_sync(&app)?.max_concurrent_runs;
+    if let Some(max_concurrent_runs) = max_concurrent_runs {
+        if super::registry::get_running_sessions().len() >= max_concurrent_runs as usize {
+            let queued = super::run_queue::enqueue(
+                &app,
+                session_id.clone(),
+                worktree_id,
+                worktree_path,
+                message,
+                model,
+                execution_mode,
+                thinking_level,
+                disable_thinking_for_mode,
+                parallel_execution_prompt_enabled,
+                ai_language,
+                allowed_tools,
+            );
+            return Ok(ChatMessage {
+                id: queued.id,
+                session_id,
+                role: MessageRole::User,
+                content: queued.message,
+                timestamp: queued.queued_at,
+                model: queued.model,
+                execution_mode: queued.execution_mode,
+                thinking_level: queued.thinking_level.map(|t| format!("{t:?}").to_lowercase()),
+                queued: true,
+                ..Default::default()
+            });
+        }
+    }
+
     // Load sessions
     let mut sessions = load_sessions(&app, &worktree_path, &worktree_id)?;
 
@@ -984,6 +1433,143 @@ pub async fn send_chat_message(
         .find_session(&session_id)
         .and_then(|s| s.claude_session_id.clone());
 
+    // If `compaction::maybe_compact_session` cleared the Claude session ID on a previous
+    // run, this is the first message of a fresh CLI conversation - prepend the digest it
+    // left behind so the model doesn't lose the context that was compacted away. Only the
+    // message sent to the CLI is affected; the user's own message (`message`, used for the
+    // run log and display) is left untouched.
+    let pending_compaction_digest = if claude_session_id.is_none() {
+        match load_metadata(&app, &session_id) {
+            Ok(Some(metadata)) => metadata.pending_compaction_digest,
+            Ok(None) => None,
+            Err(e) => {
+                log::warn!(
+                    "Failed to load pending compaction digest for session {session_id}: {e}"
+                );
+                None
+            }
+        }
+    } else {
+        None
+    };
+    if pending_compaction_digest.is_some() {
+        let clear_result = with_metadata_mut(
+            &app,
+            &session_id,
+            &worktree_id,
+            &session_name,
+            session_order,
+            |metadata| {
+                metadata.pending_compaction_digest = None;
+                Ok(())
+            },
+        );
+        if let Err(e) = clear_result {
+            log::warn!("Failed to clear pending compaction digest for session {session_id}: {e}");
+        }
+    }
+    let message_for_claude = match &pending_compaction_digest {
+        Some(digest) => format!(
+            "Here is a summary of our conversation so far, which was compacted to save context:\n\n{digest}\n\n---\n\n{message}"
+        ),
+        None => message.clone(),
+    };
+
+    // Resolve the configured AI provider for this session. Only the Claude CLI is
+    // actually dispatched below today (see chat::ai_provider's module doc comment
+    // for why), but resolving it here surfaces a misconfigured provider selection
+    // in the logs rather than silently ignoring it.
+    let selected_provider = sessions
+        .find_session(&session_id)
+        .and_then(|s| s.selected_provider.clone());
+    let provider = super::ai_provider::resolve_provider(selected_provider.as_deref());
+    log::trace!("Using AI provider: {}", provider.id());
+
+    // If this is a Claude CLI session and the backend isn't currently usable (not
+    // installed, unauthenticated, or unreachable), don't fail the send outright - queue
+    // the message durably so it survives an app restart, and
+    // `offline_queue::dispatch_pending` resubmits it once `check_claude_cli_auth` reports
+    // the backend working again (the frontend already polls that command).
+    if provider.id() == super::ai_provider::CLAUDE_PROVIDER_ID {
+        let auth_status = crate::claude_cli::check_claude_cli_auth(app.clone()).await?;
+        if !auth_status.authenticated {
+            let reason = auth_status
+                .error
+                .unwrap_or_else(|| "Claude CLI is not available".to_string());
+            let queued = super::offline_queue::enqueue(
+                &app,
+                session_id.clone(),
+                worktree_id,
+                worktree_path,
+                message,
+                model,
+                execution_mode,
+                thinking_level,
+                disable_thinking_for_mode,
+                parallel_execution_prompt_enabled,
+                ai_language,
+                allowed_tools,
+                reason.clone(),
+            )?;
+            return Ok(ChatMessage {
+                id: queued.id,
+                session_id,
+                role: MessageRole::User,
+                content: queued.message,
+                timestamp: queued.queued_at,
+                model: queued.model,
+                execution_mode: queued.execution_mode,
+                thinking_level: queued.thinking_level.map(|t| format!("{t:?}").to_lowercase()),
+                queued: true,
+                offline_reason: Some(reason),
+                ..Default::default()
+            });
+        }
+    }
+
+    // Look up the project's root path and current branch (for the JEAN_* env vars hook
+    // scripts receive), falling back to the worktree itself if the project record is gone.
+    let (hook_root_path, hook_branch) = load_projects_data(&app)
+        .ok()
+        .and_then(|data| {
+            let worktree = data.find_worktree(&worktree_id)?;
+            let project = data.find_project(&worktree.project_id)?;
+            Some((project.path.clone(), worktree.branch.clone()))
+        })
+        .unwrap_or_else(|| (worktree_path.clone(), String::new()));
+
+    // Run the project's configured `pre_run` hook (jean.json), if any, before dispatching
+    // to the provider. A failing hook aborts the run - same contract as the worktree
+    // creation setup script.
+    let pre_hook_output = match crate::projects::git::read_jean_config(&worktree_path)
+        .and_then(|config| config.scripts.pre_run)
+    {
+        Some(script) => match crate::projects::git::run_hook_script(
+            &worktree_path,
+            &hook_root_path,
+            &hook_branch,
+            "pre_run",
+            &script,
+            &super::env_vars::resolve_env_vars(&app, &worktree_id, &session_id),
+        ) {
+            Ok(output) => Some(output),
+            Err(e) => {
+                log::error!("Pre-run hook failed for session {session_id}: {e}");
+                let hook_event = super::claude::HookFailedEvent {
+                    session_id: session_id.clone(),
+                    worktree_id: worktree_id.clone(),
+                    hook: "pre_run".to_string(),
+                    error: e.clone(),
+                };
+                if let Err(emit_err) = app.emit_all("chat:hook_failed", &hook_event) {
+                    log::error!("Failed to emit chat:hook_failed event: {emit_err}");
+                }
+                return Err(format!("Pre-run hook failed:\n{e}"));
+            }
+        },
+        None => None,
+    };
+
     // Start NDJSON run log for crash recovery
     let mut run_log_writer = run_log::start_run(
         &app,
@@ -1001,13 +1587,33 @@ pub async fn send_chat_message(
             .as_deref(),
     )?;
 
+    if let Some(output) = &pre_hook_output {
+        if let Err(e) = run_log_writer.set_pre_hook_output(output) {
+            log::warn!("Failed to record pre-run hook output: {e}");
+        }
+    }
+
+    // Record a rollback-able snapshot of the worktree before this run touches it, if enabled.
+    // A failure here doesn't abort the run - unlike the pre_run hook, this is a convenience
+    // feature, and a missing snapshot just means rollback_to_snapshot won't be available.
+    if crate::load_preferences_sync(&app)?.pre_run_snapshots_enabled {
+        match crate::projects::git::create_snapshot(&worktree_path, run_log_writer.run_id()) {
+            Ok(snapshot_ref) => {
+                if let Err(e) = run_log_writer.set_snapshot_ref(&snapshot_ref) {
+                    log::warn!("Failed to record snapshot ref: {e}");
+                }
+            }
+            Err(e) => log::warn!("Failed to create pre-run snapshot for session {session_id}: {e}"),
+        }
+    }
+
     // Get file paths for detached execution
     let input_file = run_log_writer.input_file_path()?;
     let output_file = run_log_writer.output_file_path()?;
     let run_id = run_log_writer.run_id().to_string();
 
     // Write input file with the user message
-    run_log::write_input_file(&app, &session_id, &run_id, &message)?;
+    run_log::write_input_file(&app, &session_id, &run_id, &message_for_claude)?;
 
     // Use passed parameter for thinking override (computed by frontend based on preference + manual override)
     let disable_thinking_in_non_plan_modes = disable_thinking_for_mode.unwrap_or(false);
@@ -1016,8 +1622,12 @@ pub async fn send_chat_message(
     let parallel_execution_prompt = parallel_execution_prompt_enabled.unwrap_or(false);
 
     // Execute Claude CLI in detached mode
-    // If resume fails with "session not found", retry without the session ID
+    // If resume fails with "session not found", retry without the session ID. If it fails
+    // with a transient error (API overloaded, network drop), resume the same session with
+    // bounded retries and backoff - see `classify_transient_error`.
+    const MAX_TRANSIENT_RETRIES: u32 = 3;
     let mut claude_session_id_for_call = claude_session_id.clone();
+    let mut transient_retries = 0u32;
     let (pid, claude_response) = loop {
         log::trace!("About to call execute_claude_detached...");
 
@@ -1036,6 +1646,7 @@ pub async fn send_chat_message(
             disable_thinking_in_non_plan_modes,
             parallel_execution_prompt,
             ai_language.as_deref(),
+            &message_for_claude,
         ) {
             Ok((pid, response)) => {
                 log::trace!("execute_claude_detached succeeded (PID: {pid})");
@@ -1067,6 +1678,40 @@ pub async fn send_chat_message(
                     continue;
                 }
 
+                if super::claude::classify_transient_error(&e)
+                    && transient_retries < MAX_TRANSIENT_RETRIES
+                {
+                    transient_retries += 1;
+                    let backoff = std::time::Duration::from_secs(2u64.pow(transient_retries));
+                    log::warn!(
+                        "Transient Claude CLI error (attempt {transient_retries}/\
+                         {MAX_TRANSIENT_RETRIES}), resuming session in {backoff:?}: {e}"
+                    );
+                    let _ = app.emit_all(
+                        "chat:retrying",
+                        &RetryingEvent {
+                            session_id: session_id.clone(),
+                            worktree_id: worktree_id.clone(),
+                            attempt: transient_retries,
+                            max_attempts: MAX_TRANSIENT_RETRIES,
+                            error: e,
+                        },
+                    );
+                    tokio::time::sleep(backoff).await;
+                    continue;
+                }
+
+                if super::claude::classify_quota_error(&e) {
+                    let _ = app.emit_all(
+                        "chat:quota_exceeded",
+                        &super::claude::QuotaExceededEvent {
+                            session_id: session_id.clone(),
+                            worktree_id: worktree_id.clone(),
+                            error: e.clone(),
+                        },
+                    );
+                }
+
                 log::error!("execute_claude_detached FAILED: {e}");
                 return Err(e);
             }
@@ -1130,6 +1775,9 @@ pub async fn send_chat_message(
             thinking_level: None,
             recovered: false,
             usage: None,
+            queued: false,
+            offline_reason: None,
+            retry_count: None,
         });
     }
 
@@ -1150,6 +1798,13 @@ pub async fn send_chat_message(
         thinking_level: None,
         recovered: false,
         usage: claude_response.usage.clone(),
+        queued: false,
+        offline_reason: None,
+        retry_count: if transient_retries > 0 {
+            Some(transient_retries)
+        } else {
+            None
+        },
     };
     // Note: Assistant message is stored in NDJSON, not sessions JSON.
     // Messages are loaded from NDJSON on demand via load_session_messages().
@@ -1170,6 +1825,88 @@ pub async fn send_chat_message(
         {
             log::warn!("Failed to complete run log: {e}");
         }
+
+        // Run the project's configured `post_run` hook (jean.json), if any. Unlike the
+        // pre-run hook this never fails the overall send - the reply has already landed -
+        // but its output (or error) is attached to the run log and a failure is surfaced
+        // as an event so the frontend can still flag it.
+        if let Some(script) = crate::projects::git::read_jean_config(&worktree_path)
+            .and_then(|config| config.scripts.post_run)
+        {
+            let hook_result = crate::projects::git::run_hook_script(
+                &worktree_path,
+                &hook_root_path,
+                &hook_branch,
+                "post_run",
+                &script,
+                &super::env_vars::resolve_env_vars(&app, &worktree_id, &session_id),
+            );
+            if let Err(e) = &hook_result {
+                log::error!("Post-run hook failed for session {session_id}: {e}");
+                let hook_event = super::claude::HookFailedEvent {
+                    session_id: session_id.clone(),
+                    worktree_id: worktree_id.clone(),
+                    hook: "post_run".to_string(),
+                    error: e.clone(),
+                };
+                if let Err(emit_err) = app.emit_all("chat:hook_failed", &hook_event) {
+                    log::error!("Failed to emit chat:hook_failed event: {emit_err}");
+                }
+            }
+            if let Err(e) = run_log_writer.set_post_hook_result(hook_result) {
+                log::warn!("Failed to record post-run hook result: {e}");
+            }
+        }
+
+        // Auto-commit this run's changes, if the project has opted in (see
+        // `Project::auto_commit_after_run`). Best-effort: a failure here just means the
+        // changes stay uncommitted, same as if auto-commit were off.
+        let auto_commit_enabled = load_projects_data(&app)
+            .ok()
+            .and_then(|data| {
+                let worktree = data.find_worktree(&worktree_id)?;
+                let project = data.find_project(&worktree.project_id)?;
+                Some(project.auto_commit_after_run)
+            })
+            .unwrap_or(false);
+        if auto_commit_enabled && crate::projects::git::has_uncommitted_changes(&worktree_path) {
+            let tag = format!("session {session_id}, run {}", run_log_writer.run_id());
+            match crate::projects::create_commit_with_ai(
+                app.clone(),
+                worktree_path.clone(),
+                None,
+                false,
+                None,
+                Some(tag),
+            )
+            .await
+            {
+                Ok(response) => log::trace!("Auto-committed run as {}", response.commit_hash),
+                Err(e) => log::warn!("Auto-commit failed for session {session_id}: {e}"),
+            }
+        }
+
+        // Scan this run's output for TODO/FIXME-style follow-ups and persist any new ones
+        // (see `followups::record_followups_for_run`).
+        let tool_outputs: Vec<String> = assistant_msg
+            .tool_calls
+            .iter()
+            .filter_map(|call| call.output.clone())
+            .collect();
+        super::followups::record_followups_for_run(
+            &app,
+            &worktree_id,
+            &session_id,
+            run_log_writer.run_id(),
+            &assistant_msg.content,
+            &tool_outputs,
+        );
+
+        // Keep the search index in sync so the new messages show up in `search_messages`
+        // without waiting for a full `rebuild_search_index`. Best-effort, like the above.
+        if let Err(e) = super::search_index::reindex_session(&app, &session_id) {
+            log::warn!("Failed to reindex session {session_id} for search: {e}");
+        }
     }
 
     // Atomically save session metadata (claude_session_id for resumption)
@@ -1187,7 +1924,31 @@ pub async fn send_chat_message(
         log::trace!("Chat message cancelled but partial response saved for session: {session_id}");
     } else {
         log::trace!("Chat message sent and response received for session: {session_id}");
+
+        // Check whether this run pushed the session's estimated context usage past the
+        // compaction threshold, and if so, digest it and reset for a fresh CLI conversation.
+        // Reuses the session-recap model since this is already a "summarize this session"
+        // workload, not a new user-facing preference.
+        if let Ok(prefs) = crate::load_preferences(app.clone()).await {
+            super::compaction::maybe_compact_session(
+                &app,
+                &worktree_id,
+                &session_id,
+                &session_name,
+                session_order,
+                &prefs.session_recap_model,
+            );
+        }
     }
+
+    // Now that this run has finished (and the process is unregistered), dispatch
+    // whatever was queued behind it, if anything.
+    super::queue::dispatch_next(app.clone(), session_id.clone());
+
+    // A process just freed up, which may have room for a run waiting in the global
+    // priority queue (see `run_queue`), regardless of which session it belongs to.
+    super::run_queue::dispatch_next(app.clone());
+
     Ok(assistant_msg)
 }
 
@@ -1248,6 +2009,28 @@ pub async fn set_session_model(
     })
 }
 
+/// Set the selected AI provider for a session (e.g. "claude")
+#[tauri::command]
+pub async fn set_session_provider(
+    app: AppHandle,
+    worktree_id: String,
+    worktree_path: String,
+    session_id: String,
+    provider: String,
+) -> Result<(), String> {
+    log::trace!("Setting provider for session {session_id}: {provider}");
+
+    with_sessions_mut(&app, &worktree_path, &worktree_id, |sessions| {
+        if let Some(session) = sessions.find_session_mut(&session_id) {
+            session.selected_provider = Some(provider);
+            log::trace!("Provider selection saved");
+            Ok(())
+        } else {
+            Err(format!("Session not found: {session_id}"))
+        }
+    })
+}
+
 /// Set the selected thinking level for a session
 #[tauri::command]
 pub async fn set_session_thinking_level(
@@ -1270,16 +2053,42 @@ pub async fn set_session_thinking_level(
     })
 }
 
-/// Cancel a running Claude chat request for a session
+/// Set the session-level environment variable overrides for a session (see
+/// `Project::env_vars` for the project-level defaults these override by key).
+#[tauri::command]
+pub async fn set_session_env_vars(
+    app: AppHandle,
+    worktree_id: String,
+    worktree_path: String,
+    session_id: String,
+    env_vars: Vec<crate::projects::types::EnvVarEntry>,
+) -> Result<(), String> {
+    log::trace!("Setting {} env var override(s) for session {session_id}", env_vars.len());
+
+    with_sessions_mut(&app, &worktree_path, &worktree_id, |sessions| {
+        if let Some(session) = sessions.find_session_mut(&session_id) {
+            session.env_vars = env_vars;
+            log::trace!("Session env vars saved");
+            Ok(())
+        } else {
+            Err(format!("Session not found: {session_id}"))
+        }
+    })
+}
+
+/// Cancel a running Claude chat request for a session.
+/// By default escalates gracefully (SIGINT -> SIGTERM -> SIGKILL, see
+/// `registry::cancel_process`); pass `force: true` to kill immediately instead.
 /// Returns true if a process was found and cancelled, false if no process was running
 #[tauri::command]
 pub async fn cancel_chat_message(
     app: AppHandle,
     session_id: String,
     worktree_id: String,
+    force: Option<bool>,
 ) -> Result<bool, String> {
     log::trace!("Cancel chat message requested for session: {session_id}");
-    cancel_process(&app, &session_id, &worktree_id)
+    cancel_process(&app, &session_id, &worktree_id, force.unwrap_or(false), None)
 }
 
 /// Check if any sessions have running Claude processes
@@ -1289,6 +2098,14 @@ pub fn has_running_sessions() -> bool {
     !super::registry::get_running_sessions().is_empty()
 }
 
+/// CPU%, memory, and child-process count for every currently running Claude and terminal
+/// process, for an on-demand resource monitor view. The same data is also broadcast
+/// periodically as `process:stats` by `registry::start_process_stats_sweep`.
+#[tauri::command]
+pub fn get_process_stats() -> Vec<super::registry::TrackedProcessStats> {
+    super::registry::collect_process_stats()
+}
+
 /// Save a cancelled message to chat history
 /// Called by frontend when a response is cancelled mid-stream
 #[tauri::command]
@@ -1532,10 +2349,7 @@ pub async fn delete_pasted_image(app: AppHandle, path: String) -> Result<(), Str
 
     // Validate that the path is within allowed directories
     let path_str = file_path.to_string_lossy();
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(&app)?;
     let app_data_str = app_data_dir.to_string_lossy();
 
     // Check if path is in old .jean/images/ or new app data pasted-images/
@@ -1630,10 +2444,7 @@ pub async fn delete_pasted_text(app: AppHandle, path: String) -> Result<(), Stri
 
     // Validate that the path is within allowed directories
     let path_str = file_path.to_string_lossy();
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(&app)?;
     let app_data_str = app_data_dir.to_string_lossy();
 
     // Check if path is in old .jean/pastes/ or new app data pasted-texts/
@@ -1670,10 +2481,7 @@ pub async fn read_pasted_text(app: AppHandle, path: String) -> Result<ReadTextRe
 
     // Validate that the path is within allowed directories
     let path_str = file_path.to_string_lossy();
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(&app)?;
     let app_data_str = app_data_dir.to_string_lossy();
 
     // Check if path is in old .jean/pastes/ or new app data pasted-texts/
@@ -2035,22 +2843,16 @@ pub async fn read_context_file(app: AppHandle, path: String) -> Result<String, S
 
 /// Delete a saved context file
 ///
-/// Validates that the path is within the session-context directory.
-/// Also removes any custom name from the metadata file.
+/// Validates that the path is within the session-context directory. Moves the file to the
+/// trash (see `crate::trash`) rather than deleting it outright, so it can be restored.
 #[tauri::command]
 pub async fn delete_context_file(app: AppHandle, path: String) -> Result<(), String> {
-    log::trace!("Deleting context file: {path}");
+    log::trace!("Moving context file to trash: {path}");
 
     // Validate path is within session-context directory
     let contexts_dir = get_saved_contexts_dir(&app)?;
     let file_path = std::path::PathBuf::from(&path);
 
-    // Extract filename before deletion for metadata cleanup
-    let filename = file_path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .map(|s| s.to_string());
-
     // Check if file exists first
     if !file_path.exists() {
         log::warn!("Context file not found: {path}");
@@ -2069,20 +2871,14 @@ pub async fn delete_context_file(app: AppHandle, path: String) -> Result<(), Str
         return Err("Invalid context file path".to_string());
     }
 
-    std::fs::remove_file(&file_path).map_err(|e| format!("Failed to delete context file: {e}"))?;
-
-    // Remove from metadata if present
-    if let Some(filename) = filename {
-        let mut metadata = load_saved_contexts_metadata(&app);
-        if metadata.names.remove(&filename).is_some() {
-            // Only save if we actually removed something
-            if let Err(e) = save_saved_contexts_metadata(&app, &metadata) {
-                log::warn!("Failed to update metadata after delete: {e}");
-            }
-        }
-    }
+    let display_name = file_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(&path)
+        .to_string();
+    crate::trash::trash_context_file(&app, &file_path, display_name)?;
 
-    log::trace!("Context file deleted: {path}");
+    log::trace!("Context file moved to trash: {path}");
     Ok(())
 }
 
@@ -2159,7 +2955,7 @@ Format the summary as clean markdown. Be concise but capture the reasoning behin
 const CONTEXT_SUMMARY_SCHEMA: &str = r#"{"type":"object","properties":{"summary":{"type":"string","description":"The markdown context summary including main goal, key decisions with rationale, trade-offs considered, problems solved, current state, unresolved questions, key files/patterns, and next steps"},"slug":{"type":"string","description":"A 2-4 word lowercase hyphenated slug describing the main topic (e.g. implement-magic-commands, fix-auth-bug)"}},"required":["summary","slug"]}"#;
 
 /// Format chat messages into a conversation history string for summarization
-fn format_messages_for_summary(messages: &[ChatMessage]) -> String {
+pub(super) fn format_messages_for_summary(messages: &[ChatMessage]) -> String {
     if messages.is_empty() {
         return "No messages in this conversation.".to_string();
     }
@@ -2190,7 +2986,7 @@ fn format_messages_for_summary(messages: &[ChatMessage]) -> String {
 /// Extract text or JSON content from stream-json output
 /// Handles both regular text responses and JSON schema structured responses
 /// For --json-schema, Claude returns structured output via a tool call named "StructuredOutput"
-fn extract_text_from_stream_json(output: &str) -> Result<String, String> {
+pub(super) fn extract_text_from_stream_json(output: &str) -> Result<String, String> {
     let mut text_content = String::new();
     let mut structured_output: Option<serde_json::Value> = None;
 
@@ -2516,10 +3312,7 @@ pub async fn get_session_debug_info(
     session_id: String,
 ) -> Result<SessionDebugInfo, String> {
     // Get app data directory
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(&app)?;
 
     let app_data_str = app_data_dir.to_str().unwrap_or("unknown").to_string();
 
@@ -2716,13 +3509,18 @@ pub async fn resume_session(
         tauri::async_runtime::spawn(async move {
             log::trace!("Starting tail task for run: {run_id_clone}, session: {session_id_clone}");
 
-            // Tail the output file
+            // Tail the output file. `execution_timeout_seconds` isn't enforced on resume since
+            // this path reattaches to a run from before the app restarted and has no
+            // worktree path to resolve jean.json from - the elapsed time tracked here would
+            // also only cover time since resume, not since the run actually started.
             let result = super::claude::tail_claude_output(
                 &app_clone,
                 &session_id_clone,
                 &worktree_id_clone,
                 &output_file,
                 pid,
+                None,
+                None,
             );
 
             match result {
@@ -2803,6 +3601,16 @@ pub async fn check_resumable_sessions(
     Ok(resumable)
 }
 
+/// List runs already journaled as recoverable (resumable or crashed) by a prior startup
+/// recovery pass, for a settings pane that wants to show recovery history without
+/// re-triggering recovery itself. See [`super::run_log::list_recoverable_runs`].
+#[tauri::command]
+pub async fn list_recoverable_runs(
+    app: AppHandle,
+) -> Result<Vec<super::run_log::RecoveredRun>, String> {
+    super::run_log::list_recoverable_runs(&app)
+}
+
 // ============================================================================
 // Session Digest Commands (for context recall after switching)
 // ============================================================================
@@ -0,0 +1,226 @@
+//! Proactive, app-level session compaction.
+//!
+//! This is distinct from the Claude CLI's own internal auto-compaction (surfaced to us as
+//! `compact_boundary` system messages in `claude.rs`) - that happens *inside* a single
+//! `--resume`d conversation and is entirely the CLI's business. This module instead
+//! decides, between runs, whether to stop resuming the CLI conversation at all.
+//!
+//! `--resume` is the only continuity mechanism we have (see `run_log::write_input_file` -
+//! each run only ever sends the one new message, never history), so the estimated context
+//! usage computed by `context_usage` growing close to the model's window means the *next*
+//! `--resume`d call is at real risk of failing or silently truncating. When that happens,
+//! `maybe_compact_session` summarizes the conversation so far into a digest, clears
+//! `claude_session_id` so the next call starts a fresh CLI conversation, and stashes the
+//! digest on `SessionMetadata::pending_compaction_digest` for `send_chat_message` to prepend
+//! to that next message. The user-visible transcript (`metadata.runs`) is left untouched -
+//! compaction only changes what gets sent to the CLI, not what the user can scroll back to.
+
+use std::io::Write;
+use std::process::Stdio;
+
+use tauri::AppHandle;
+
+use super::context_usage::usage_for_session;
+use super::run_log::load_session_messages;
+use super::storage::with_metadata_mut;
+use crate::claude_cli::get_cli_binary_path;
+use crate::http_server::EmitExt;
+use crate::platform::silent_command;
+
+/// Estimated usage reaches this fraction of the model's context window before compaction
+/// kicks in. Stricter than `context_usage::WARNING_THRESHOLD` (0.8) - that's an early
+/// heads-up for the UI, this is the point where we actually intervene.
+const COMPACTION_THRESHOLD: f64 = 0.95;
+
+const COMPACTION_DIGEST_SCHEMA: &str = r#"{"type":"object","properties":{"digest":{"type":"string","description":"A thorough summary of the conversation so far - goals, decisions made, important facts, and current state - detailed enough that the assistant can continue the work without the original messages"}},"required":["digest"]}"#;
+
+const COMPACTION_DIGEST_PROMPT: &str = r#"You are a summarization assistant. Your ONLY job is to summarize the following conversation transcript. Do NOT continue the conversation or take any actions. Just summarize.
+
+CONVERSATION TRANSCRIPT:
+{conversation}
+
+END OF TRANSCRIPT.
+
+This transcript is being compacted because it has grown too large to keep resending in full. Write a thorough digest that preserves everything needed to continue the work: the overall goal, decisions already made and why, important facts established, and the current state. Be detailed rather than terse - this digest replaces the transcript entirely."#;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct CompactionDigestResponse {
+    digest: String,
+}
+
+/// Execute one-shot Claude CLI call for a full compaction digest with JSON schema
+/// (non-streaming). Mirrors `commands::execute_digest_claude`'s plumbing with a fuller
+/// schema/prompt suited to replacing the conversation entirely, not just recapping it.
+fn execute_compaction_digest_claude(
+    app: &AppHandle,
+    prompt: &str,
+    model: &str,
+) -> Result<CompactionDigestResponse, String> {
+    let cli_path = get_cli_binary_path(app)?;
+
+    if !cli_path.exists() {
+        return Err("Claude CLI not installed".to_string());
+    }
+
+    let mut cmd = silent_command(&cli_path);
+    cmd.args([
+        "--print",
+        "--input-format",
+        "stream-json",
+        "--output-format",
+        "stream-json",
+        "--verbose",
+        "--model",
+        model,
+        "--no-session-persistence",
+        "--max-turns",
+        "2",
+        "--json-schema",
+        COMPACTION_DIGEST_SCHEMA,
+        "--permission-mode",
+        "plan",
+    ]);
+
+    cmd.stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to spawn Claude CLI: {e}"))?;
+
+    {
+        let stdin = child.stdin.as_mut().ok_or("Failed to open stdin")?;
+        let input_message = serde_json::json!({
+            "type": "user",
+            "message": {
+                "role": "user",
+                "content": prompt
+            }
+        });
+        writeln!(stdin, "{input_message}").map_err(|e| format!("Failed to write to stdin: {e}"))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to wait for Claude CLI: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        return Err(format!(
+            "Claude CLI failed (exit code {:?}): stderr={}, stdout={}",
+            output.status.code(),
+            stderr.trim(),
+            stdout.trim()
+        ));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let text_content = super::commands::extract_text_from_stream_json(&stdout)?;
+
+    if text_content.trim().is_empty() {
+        return Err("Empty response from Claude CLI".to_string());
+    }
+
+    serde_json::from_str(&text_content)
+        .map_err(|e| format!("Failed to parse structured response: {e}"))
+}
+
+/// Payload for the `context:compacted` event, emitted whenever `maybe_compact_session`
+/// actually compacts a session (as opposed to checking and finding it unnecessary).
+#[derive(serde::Serialize, Clone)]
+pub struct CompactionEvent {
+    pub worktree_id: String,
+    pub session_id: String,
+}
+
+/// If `session_id`'s estimated context usage has crossed `COMPACTION_THRESHOLD`, summarize
+/// its conversation into a digest, clear `claude_session_id` so the next run starts a fresh
+/// CLI conversation, and stash the digest for `send_chat_message` to re-inject into that
+/// next message. Returns `true` if compaction happened.
+///
+/// Best-effort: any failure here (digest generation, metadata update) is logged and treated
+/// as "did not compact" rather than surfaced to the caller - a session that isn't compacted
+/// still works today via the CLI's own internal auto-compaction, so this is never worth
+/// failing an otherwise-successful run over.
+pub(super) fn maybe_compact_session(
+    app: &AppHandle,
+    worktree_id: &str,
+    session_id: &str,
+    session_name: &str,
+    order: u32,
+    model: &str,
+) -> bool {
+    let report = match usage_for_session(app, worktree_id, session_id) {
+        Ok(report) => report,
+        Err(e) => {
+            log::warn!("Could not compute context usage for session {session_id}: {e}");
+            return false;
+        }
+    };
+
+    let Some(percent_used) = report.percent_used else {
+        return false;
+    };
+    if percent_used < COMPACTION_THRESHOLD {
+        return false;
+    }
+
+    let messages = match load_session_messages(app, session_id) {
+        Ok(messages) => messages,
+        Err(e) => {
+            log::warn!("Could not load messages to compact session {session_id}: {e}");
+            return false;
+        }
+    };
+    if messages.len() < 2 {
+        return false;
+    }
+
+    let conversation_history = super::commands::format_messages_for_summary(&messages);
+    let prompt = COMPACTION_DIGEST_PROMPT.replace("{conversation}", &conversation_history);
+
+    let response = match execute_compaction_digest_claude(app, &prompt, model) {
+        Ok(response) => response,
+        Err(e) => {
+            log::warn!("Failed to generate compaction digest for session {session_id}: {e}");
+            return false;
+        }
+    };
+
+    let result = with_metadata_mut(
+        app,
+        session_id,
+        worktree_id,
+        session_name,
+        order,
+        |metadata| {
+            metadata.claude_session_id = None;
+            metadata.pending_compaction_digest = Some(response.digest);
+            Ok(())
+        },
+    );
+
+    if let Err(e) = result {
+        log::warn!("Failed to save compaction digest for session {session_id}: {e}");
+        return false;
+    }
+
+    log::trace!(
+        "Compacted session {session_id} (worktree {worktree_id}, {:.0}% of context window)",
+        percent_used * 100.0
+    );
+
+    let worktree_id = worktree_id.to_string();
+    let session_id = session_id.to_string();
+    let _ = app.emit_all(
+        "context:compacted",
+        &CompactionEvent {
+            worktree_id,
+            session_id,
+        },
+    );
+
+    true
+}
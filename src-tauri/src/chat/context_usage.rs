@@ -0,0 +1,168 @@
+//! Context-window usage estimation.
+//!
+//! Claude CLI sessions are resumed via `--resume` rather than replayed by us, so we never
+//! see the model's own token accounting between runs. This estimates usage instead: the
+//! session's message text plus attached context (linked GitHub issues/PRs, saved contexts)
+//! counted at ~4 characters per token, a common rule of thumb for English text - not an
+//! exact count, but enough to warn before the CLI's own auto-compaction kicks in.
+
+use serde::Serialize;
+use tauri::AppHandle;
+
+use super::run_log::load_session_messages;
+use super::storage::load_metadata;
+use crate::http_server::EmitExt;
+use crate::projects::github_issues::{
+    get_github_contexts_dir, get_worktree_issue_refs, get_worktree_pr_refs,
+};
+
+/// Estimated usage reaches this fraction of the model's context window before a
+/// `context:warning` event fires.
+const WARNING_THRESHOLD: f64 = 0.8;
+
+/// Characters per token, for the estimate. Matches the rule of thumb used elsewhere for
+/// rough token counts of English/code text.
+const CHARS_PER_TOKEN: u64 = 4;
+
+/// Estimated context-window usage for a session.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextUsageReport {
+    pub session_id: String,
+    /// Rough token count across conversation messages and attached context files.
+    pub estimated_tokens: u64,
+    /// The model's context window, if recognized (`None` for unrecognized models).
+    pub context_window: Option<u64>,
+    /// `estimated_tokens / context_window`, if the window is known.
+    pub percent_used: Option<f64>,
+    /// `true` once `percent_used` has crossed `WARNING_THRESHOLD`.
+    pub warning: bool,
+}
+
+/// Best-effort context window lookup by substring match against Claude model names.
+/// Returns `None` for unrecognized models rather than guessing.
+fn context_window_for_model(model: &str) -> Option<u64> {
+    let model = model.to_lowercase();
+    if model.contains("opus") || model.contains("sonnet") || model.contains("haiku") {
+        Some(200_000)
+    } else {
+        None
+    }
+}
+
+fn estimate_tokens(chars: u64) -> u64 {
+    chars.div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Total bytes of context currently attached to a worktree: linked GitHub issue/PR
+/// context files plus saved contexts, a subset of what `claude::build_claude_args`
+/// bundles into the combined context file passed to the CLI. Attached file snapshots,
+/// retrieved snippets and project instructions are not counted.
+fn attached_context_bytes(app: &AppHandle, worktree_id: &str) -> u64 {
+    let mut total = 0u64;
+
+    if let Ok(contexts_dir) = get_github_contexts_dir(app) {
+        if let Ok(issue_keys) = get_worktree_issue_refs(app, worktree_id) {
+            for key in issue_keys {
+                if let Some((repo_key, number)) = key.rsplit_once('-') {
+                    let file_path = contexts_dir.join(format!("{repo_key}-issue-{number}.md"));
+                    if let Ok(meta) = std::fs::metadata(&file_path) {
+                        total += meta.len();
+                    }
+                }
+            }
+        }
+        if let Ok(pr_keys) = get_worktree_pr_refs(app, worktree_id) {
+            for key in pr_keys {
+                if let Some((repo_key, number)) = key.rsplit_once('-') {
+                    let file_path = contexts_dir.join(format!("{repo_key}-pr-{number}.md"));
+                    if let Ok(meta) = std::fs::metadata(&file_path) {
+                        total += meta.len();
+                    }
+                }
+            }
+        }
+    }
+
+    if let Ok(app_data_dir) = crate::data_dir::resolve(app) {
+        let saved_contexts_dir = app_data_dir.join("session-context");
+        let prefix = format!("{worktree_id}-context-");
+        if let Ok(entries) = std::fs::read_dir(&saved_contexts_dir) {
+            for entry in entries.flatten() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if name.starts_with(&prefix) && name.ends_with(".md") {
+                    if let Ok(meta) = entry.metadata() {
+                        total += meta.len();
+                    }
+                }
+            }
+        }
+    }
+
+    total
+}
+
+/// Estimate context-window usage for a session: its conversation messages plus whatever
+/// context is currently attached to the worktree.
+pub fn usage_for_session(
+    app: &AppHandle,
+    worktree_id: &str,
+    session_id: &str,
+) -> Result<ContextUsageReport, String> {
+    let messages = load_session_messages(app, session_id)?;
+    let message_chars: u64 = messages
+        .iter()
+        .map(|m| m.content.chars().count() as u64)
+        .sum();
+    let context_bytes = attached_context_bytes(app, worktree_id);
+
+    let estimated_tokens = estimate_tokens(message_chars + context_bytes);
+
+    let model = load_metadata(app, session_id)?
+        .and_then(|metadata| metadata.runs.last().and_then(|run| run.model.clone()));
+    let context_window = model.as_deref().and_then(context_window_for_model);
+    let percent_used = context_window.map(|window| estimated_tokens as f64 / window as f64);
+    let warning = percent_used.is_some_and(|pct| pct >= WARNING_THRESHOLD);
+
+    Ok(ContextUsageReport {
+        session_id: session_id.to_string(),
+        estimated_tokens,
+        context_window,
+        percent_used,
+        warning,
+    })
+}
+
+/// Tauri command wrapper around `usage_for_session`, for the UI to poll/display.
+#[tauri::command]
+pub async fn get_session_context_usage(
+    app: AppHandle,
+    worktree_id: String,
+    session_id: String,
+) -> Result<ContextUsageReport, String> {
+    usage_for_session(&app, &worktree_id, &session_id)
+}
+
+/// Payload for the `context:warning` event, emitted when a run pushes a session's
+/// estimated usage past `WARNING_THRESHOLD` of its model's context window.
+#[derive(Serialize, Clone)]
+pub struct ContextWarningEvent {
+    pub worktree_id: String,
+    pub report: ContextUsageReport,
+}
+
+/// Check context usage for `session_id` and emit `context:warning` if it's crossed the
+/// warning threshold. Called after a run completes, alongside `budget::check_and_emit_warning`.
+pub(super) fn check_and_emit_warning(app: &AppHandle, worktree_id: &str, session_id: &str) {
+    let report = match usage_for_session(app, worktree_id, session_id) {
+        Ok(report) => report,
+        Err(err) => {
+            log::warn!("Could not compute context usage for session {session_id}: {err}");
+            return;
+        }
+    };
+    if !report.warning {
+        return;
+    }
+    let worktree_id = worktree_id.to_string();
+    let _ = app.emit_all("context:warning", &ContextWarningEvent { worktree_id, report });
+}
@@ -36,6 +36,7 @@ pub fn spawn_detached_claude(
     output_file: &Path,
     working_dir: &Path,
     env_vars: &[(&str, &str)],
+    low_priority: bool,
 ) -> Result<u32, String> {
     // Build the shell command:
     // cat input.jsonl | nohup /path/to/claude [args] >> output.jsonl 2>&1 & echo $!
@@ -78,16 +79,24 @@ pub fn spawn_detached_claude(
         .collect::<Vec<_>>()
         .join(" ");
 
+    // Optionally de-prioritize the process (see `platform::priority`). `nice`/`ionice` exec
+    // their argument in place, so this doesn't change the PID `echo $!` reports below.
+    let nice_prefix = if low_priority {
+        format!("{} ", crate::platform::priority::nice_prefix())
+    } else {
+        String::new()
+    };
+
     // The full shell command - use cat pipe instead of file redirection
     // Claude CLI with --print requires piped stdin, not file redirection
     // NOTE: env vars must be placed AFTER the pipe so they apply to Claude, not cat
     let shell_cmd = if env_exports.is_empty() {
         format!(
-            "cat {input_path_escaped} | nohup {cli_path_escaped} {args_str} >> {output_path_escaped} 2>&1 & echo $!"
+            "cat {input_path_escaped} | {nice_prefix}nohup {cli_path_escaped} {args_str} >> {output_path_escaped} 2>&1 & echo $!"
         )
     } else {
         format!(
-            "cat {input_path_escaped} | {env_exports} nohup {cli_path_escaped} {args_str} >> {output_path_escaped} 2>&1 & echo $!"
+            "cat {input_path_escaped} | {env_exports} {nice_prefix}nohup {cli_path_escaped} {args_str} >> {output_path_escaped} 2>&1 & echo $!"
         )
     };
 
@@ -174,6 +183,7 @@ pub fn spawn_detached_claude(
     output_file: &Path,
     working_dir: &Path,
     env_vars: &[(&str, &str)],
+    low_priority: bool,
 ) -> Result<u32, String> {
     use std::fs::OpenOptions;
     use std::io::Write;
@@ -196,13 +206,18 @@ pub fn spawn_detached_claude(
     // Build command - run claude.exe directly
     // NOTE: silent_command sets CREATE_NO_WINDOW, but creation_flags() replaces
     // (doesn't merge), so we must re-specify both flags here.
+    let mut creation_flags = CREATE_NEW_PROCESS_GROUP | CREATE_NO_WINDOW;
+    if low_priority {
+        creation_flags |= crate::platform::priority::BELOW_NORMAL_PRIORITY_CLASS;
+    }
+
     let mut cmd = silent_command(cli_path);
     cmd.args(args)
         .current_dir(working_dir)
         .stdin(Stdio::piped())
         .stdout(out_file)
         .stderr(err_file)
-        .creation_flags(CREATE_NEW_PROCESS_GROUP | CREATE_NO_WINDOW);
+        .creation_flags(creation_flags);
 
     // Set environment variables
     for (key, value) in env_vars {
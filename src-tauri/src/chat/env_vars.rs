@@ -0,0 +1,60 @@
+//! Resolves the environment variables injected into the Claude CLI process (and
+//! `jean.json` pre/post-run hook scripts) for a given session.
+//!
+//! Two layers can configure this, both editable from the frontend via
+//! `projects::set_project_env_vars` and `chat::set_session_env_vars`:
+//! - Project-level (`Project::env_vars`) - applies to every session under that project.
+//! - Session-level (`SessionMetadata::env_vars`) - overrides a project-level entry with
+//!   the same key, for one session only.
+//!
+//! Neither layer has real secret storage behind it - both are plaintext in
+//! `projects.json`/`metadata.json`, same as `Project::gitea_token`. The `sensitive` flag
+//! on `EnvVarEntry` only tells the frontend to mask the value in UI.
+
+use tauri::AppHandle;
+
+use crate::projects::storage::load_projects_data;
+use crate::projects::types::EnvVarEntry;
+
+use super::storage::load_metadata;
+
+/// Resolve the merged, deduplicated list of `(key, value)` pairs to inject when spawning
+/// the Claude CLI (or a hook script) for `session_id` in `worktree_id`. Session-level
+/// entries take precedence over project-level ones with the same key; any lookup failure
+/// (worktree/project not found, no metadata yet) is treated as "no entries at that layer"
+/// rather than an error, since a missing env var configuration shouldn't block a run.
+pub fn resolve_env_vars(
+    app: &AppHandle,
+    worktree_id: &str,
+    session_id: &str,
+) -> Vec<(String, String)> {
+    let project_vars: Vec<EnvVarEntry> = load_projects_data(app)
+        .ok()
+        .and_then(|data| {
+            let worktree = data.find_worktree(worktree_id)?;
+            let project = data.find_project(&worktree.project_id)?;
+            Some(project.env_vars.clone())
+        })
+        .unwrap_or_default();
+
+    let session_vars: Vec<EnvVarEntry> = load_metadata(app, session_id)
+        .ok()
+        .flatten()
+        .map(|metadata| metadata.env_vars)
+        .unwrap_or_default();
+
+    let mut merged: Vec<(String, String)> = project_vars
+        .into_iter()
+        .map(|entry| (entry.key, entry.value))
+        .collect();
+
+    for entry in session_vars {
+        if let Some(existing) = merged.iter_mut().find(|(k, _)| *k == entry.key) {
+            existing.1 = entry.value;
+        } else {
+            merged.push((entry.key, entry.value));
+        }
+    }
+
+    merged
+}
@@ -0,0 +1,207 @@
+//! Exporting a session's transcript to a self-contained file, so it can be shared in a
+//! PR description or archived outside the app.
+
+use serde::{Deserialize, Serialize};
+
+use super::commands::{extract_image_paths, extract_text_file_paths};
+use super::storage::load_metadata;
+use super::types::{ChatMessage, ContentBlock, MessageRole, ToolCall};
+
+/// Output format for `export_session`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+    Json,
+}
+
+/// Write a session's transcript to `output_path` in the requested format.
+///
+/// Pasted images are referenced by their saved path in Markdown/JSON, and embedded as
+/// base64 data URIs in HTML so the exported file stays viewable on its own.
+#[tauri::command]
+pub async fn export_session(
+    app: tauri::AppHandle,
+    worktree_id: String,
+    session_id: String,
+    format: ExportFormat,
+    include_tool_calls: bool,
+    output_path: String,
+) -> Result<(), String> {
+    log::trace!(
+        "Exporting session {session_id} (worktree {worktree_id}) to {output_path} as {format:?}"
+    );
+
+    let metadata = load_metadata(&app, &session_id)?
+        .ok_or_else(|| format!("Session not found: {session_id}"))?;
+    let messages = super::run_log::load_session_messages(&app, &session_id)?;
+
+    let rendered = match format {
+        ExportFormat::Markdown => render_markdown(&metadata.name, &messages, include_tool_calls),
+        ExportFormat::Html => render_html(&metadata.name, &messages, include_tool_calls),
+        ExportFormat::Json => render_json(&metadata.name, &messages, include_tool_calls)?,
+    };
+
+    std::fs::write(&output_path, rendered)
+        .map_err(|e| format!("Failed to write export file: {e}"))
+}
+
+fn tool_call_summary(tool_call: &ToolCall, include_output: bool) -> String {
+    let input = serde_json::to_string(&tool_call.input).unwrap_or_default();
+    let mut summary = format!("**{}**({input})", tool_call.name);
+    if include_output {
+        if let Some(output) = &tool_call.output {
+            summary.push_str(&format!("\n```\n{output}\n```"));
+        }
+    }
+    summary
+}
+
+fn render_markdown(session_name: &str, messages: &[ChatMessage], include_tool_calls: bool) -> String {
+    let mut out = format!("# {session_name}\n\n");
+
+    for message in messages {
+        let speaker = match message.role {
+            MessageRole::User => "User",
+            MessageRole::Assistant => "Assistant",
+        };
+        out.push_str(&format!("## {speaker}\n\n"));
+
+        if message.content_blocks.is_empty() {
+            out.push_str(&message.content);
+            out.push_str("\n\n");
+        } else {
+            for block in &message.content_blocks {
+                match block {
+                    ContentBlock::Text { text } => {
+                        out.push_str(text);
+                        out.push_str("\n\n");
+                    }
+                    ContentBlock::Thinking { thinking } => {
+                        out.push_str(&format!("> _Thinking: {thinking}_\n\n"));
+                    }
+                    ContentBlock::ToolUse { tool_call_id } => {
+                        if !include_tool_calls {
+                            continue;
+                        }
+                        if let Some(tool_call) =
+                            message.tool_calls.iter().find(|tc| &tc.id == tool_call_id)
+                        {
+                            out.push_str(&tool_call_summary(tool_call, true));
+                            out.push_str("\n\n");
+                        }
+                    }
+                }
+            }
+        }
+
+        for image_path in extract_image_paths(&message.content) {
+            out.push_str(&format!("![attached image]({image_path})\n\n"));
+        }
+        for text_path in extract_text_file_paths(&message.content) {
+            out.push_str(&format!("[attached file]({text_path})\n\n"));
+        }
+    }
+
+    out
+}
+
+fn render_html(session_name: &str, messages: &[ChatMessage], include_tool_calls: bool) -> String {
+    let mut body = String::new();
+
+    for message in messages {
+        let speaker = match message.role {
+            MessageRole::User => "User",
+            MessageRole::Assistant => "Assistant",
+        };
+        body.push_str(&format!("<h2>{}</h2>\n", html_escape(speaker)));
+        body.push_str(&format!(
+            "<pre>{}</pre>\n",
+            html_escape(&message.content)
+        ));
+
+        if include_tool_calls {
+            for tool_call in &message.tool_calls {
+                body.push_str(&format!(
+                    "<details><summary>{}</summary><pre>{}</pre></details>\n",
+                    html_escape(&tool_call.name),
+                    html_escape(&tool_call_summary(tool_call, true))
+                ));
+            }
+        }
+
+        for image_path in extract_image_paths(&message.content) {
+            match std::fs::read(&image_path) {
+                Ok(bytes) => {
+                    use base64::Engine;
+                    let data = base64::engine::general_purpose::STANDARD.encode(bytes);
+                    let mime = mime_for_path(&image_path);
+                    body.push_str(&format!(
+                        "<img src=\"data:{mime};base64,{data}\" alt=\"attached image\" />\n"
+                    ));
+                }
+                Err(e) => {
+                    log::warn!("Could not embed image {image_path} in export: {e}");
+                }
+            }
+        }
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>{}</title></head><body>\n{body}</body></html>\n",
+        html_escape(session_name)
+    )
+}
+
+fn render_json(
+    session_name: &str,
+    messages: &[ChatMessage],
+    include_tool_calls: bool,
+) -> Result<String, String> {
+    #[derive(Serialize)]
+    struct ExportedSession<'a> {
+        name: &'a str,
+        messages: Vec<ChatMessage>,
+    }
+
+    let messages = if include_tool_calls {
+        messages.to_vec()
+    } else {
+        messages
+            .iter()
+            .cloned()
+            .map(|mut m| {
+                m.tool_calls.clear();
+                m
+            })
+            .collect()
+    };
+
+    serde_json::to_string_pretty(&ExportedSession {
+        name: session_name,
+        messages,
+    })
+    .map_err(|e| format!("Failed to serialize session export: {e}"))
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn mime_for_path(path: &str) -> &'static str {
+    let lower = path.to_lowercase();
+    if lower.ends_with(".png") {
+        "image/png"
+    } else if lower.ends_with(".jpg") || lower.ends_with(".jpeg") {
+        "image/jpeg"
+    } else if lower.ends_with(".gif") {
+        "image/gif"
+    } else if lower.ends_with(".webp") {
+        "image/webp"
+    } else {
+        "application/octet-stream"
+    }
+}
@@ -0,0 +1,262 @@
+//! Attach arbitrary files/directories to a session's prompt context.
+//!
+//! Unlike linked GitHub issues/PRs (`github_issues.rs`, shared and reference-counted across
+//! worktrees) this is purely per-session: a user points at a file or folder and a snapshot
+//! of its contents is stashed under `file-context/` for `claude::build_claude_args` to fold
+//! into the combined context file on the session's next run, alongside issue/PR/saved
+//! contexts. Snapshots are static copies taken at attach time, not live file references -
+//! if the underlying file changes, the attachment must be re-added to pick that up.
+
+use ignore::WalkBuilder;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+use uuid::Uuid;
+
+/// Maximum snapshot size per file. Larger files are truncated (with a note in the
+/// snapshot) rather than rejected outright, matching `save_pasted_text`'s "best effort,
+/// don't block the user" approach.
+const MAX_FILE_BYTES: usize = 256 * 1024;
+
+/// Cap on how many files a single `attach_file_context` call will expand a directory
+/// into, so pointing it at a large repo doesn't silently snapshot thousands of files.
+const MAX_FILES_PER_CALL: usize = 200;
+
+/// A single attached file's metadata, as returned to the frontend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileContextAttachment {
+    pub id: String,
+    /// Absolute path of the original file at attach time.
+    pub original_path: String,
+    /// Filename, for display.
+    pub display_name: String,
+    /// Size of the snapshot actually stored (after truncation, if any).
+    pub size: u64,
+    /// True if the original file was larger than `MAX_FILE_BYTES` and got truncated.
+    pub truncated: bool,
+    pub attached_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct FileContextIndex {
+    attachments: Vec<FileContextAttachment>,
+}
+
+fn get_file_context_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = crate::data_dir::resolve(app)?;
+    let dir = app_data_dir.join("file-context");
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create file-context directory: {e}"))?;
+    Ok(dir)
+}
+
+fn index_path(app: &AppHandle, session_id: &str) -> Result<PathBuf, String> {
+    Ok(get_file_context_dir(app)?.join(format!("{session_id}.index.json")))
+}
+
+fn snapshot_path(
+    app: &AppHandle,
+    session_id: &str,
+    attachment_id: &str,
+) -> Result<PathBuf, String> {
+    Ok(get_file_context_dir(app)?.join(format!("{session_id}-{attachment_id}.md")))
+}
+
+fn load_index(app: &AppHandle, session_id: &str) -> Result<FileContextIndex, String> {
+    let path = index_path(app, session_id)?;
+    if !path.exists() {
+        return Ok(FileContextIndex::default());
+    }
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read file-context index: {e}"))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse file-context index: {e}"))
+}
+
+fn save_index(app: &AppHandle, session_id: &str, index: &FileContextIndex) -> Result<(), String> {
+    let path = index_path(app, session_id)?;
+    let content = serde_json::to_string_pretty(index)
+        .map_err(|e| format!("Failed to serialize file-context index: {e}"))?;
+    std::fs::write(&path, content).map_err(|e| format!("Failed to write file-context index: {e}"))
+}
+
+/// Crude binary-file detection: a NUL byte in the first few KB means "not text", the same
+/// heuristic git itself uses.
+fn is_probably_binary(bytes: &[u8]) -> bool {
+    bytes.iter().take(8000).any(|&b| b == 0)
+}
+
+/// Expand `paths` into a flat list of individual file paths, walking directories
+/// (respecting .gitignore, like `list_worktree_files`) and capping the total at
+/// `MAX_FILES_PER_CALL`.
+fn expand_paths(paths: &[String]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for raw_path in paths {
+        if files.len() >= MAX_FILES_PER_CALL {
+            break;
+        }
+
+        let path = Path::new(raw_path);
+        if path.is_dir() {
+            let walker = WalkBuilder::new(path)
+                .hidden(false)
+                .git_ignore(true)
+                .git_global(true)
+                .git_exclude(true)
+                .require_git(false)
+                .build();
+
+            for entry in walker {
+                if files.len() >= MAX_FILES_PER_CALL {
+                    break;
+                }
+                let entry = match entry {
+                    Ok(e) => e,
+                    Err(e) => {
+                        log::warn!("Failed to walk {raw_path}: {e}");
+                        continue;
+                    }
+                };
+                let entry_path = entry.path();
+                if entry_path.is_file() {
+                    files.push(entry_path.to_path_buf());
+                }
+            }
+        } else if path.is_file() {
+            files.push(path.to_path_buf());
+        } else {
+            log::warn!("Skipping attach path that is neither file nor directory: {raw_path}");
+        }
+    }
+
+    files
+}
+
+/// Snapshot `paths` (files and/or directories) into the given session's file context,
+/// for `claude::build_claude_args` to fold into the next run's prompt.
+#[tauri::command]
+pub async fn attach_file_context(
+    app: AppHandle,
+    worktree_id: String,
+    session_id: String,
+    paths: Vec<String>,
+) -> Result<Vec<FileContextAttachment>, String> {
+    log::trace!(
+        "Attaching {} path(s) as file context for session {session_id} (worktree {worktree_id})",
+        paths.len()
+    );
+
+    let files = expand_paths(&paths);
+    let mut index = load_index(&app, &session_id)?;
+    let mut attached = Vec::new();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    for file_path in files {
+        let raw_bytes = match std::fs::read(&file_path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                log::warn!("Failed to read {file_path:?} for file context: {e}");
+                continue;
+            }
+        };
+
+        if is_probably_binary(&raw_bytes) {
+            log::trace!("Skipping binary file for file context: {file_path:?}");
+            continue;
+        }
+
+        let truncated = raw_bytes.len() > MAX_FILE_BYTES;
+        let text = String::from_utf8_lossy(&raw_bytes[..raw_bytes.len().min(MAX_FILE_BYTES)]);
+
+        let attachment_id = Uuid::new_v4().to_string();
+        let display_name = file_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| file_path.to_string_lossy().to_string());
+        let original_path = file_path.to_string_lossy().to_string();
+
+        let mut snapshot = format!("## {original_path}\n\n```\n{text}\n```\n");
+        if truncated {
+            snapshot.push_str(&format!(
+                "\n*Truncated to {MAX_FILE_BYTES} bytes of {} total.*\n",
+                raw_bytes.len()
+            ));
+        }
+
+        let snapshot_file = snapshot_path(&app, &session_id, &attachment_id)?;
+        std::fs::write(&snapshot_file, &snapshot)
+            .map_err(|e| format!("Failed to write file context snapshot: {e}"))?;
+
+        let attachment = FileContextAttachment {
+            id: attachment_id,
+            original_path,
+            display_name,
+            size: snapshot.len() as u64,
+            truncated,
+            attached_at: now,
+        };
+        index.attachments.push(attachment.clone());
+        attached.push(attachment);
+    }
+
+    save_index(&app, &session_id, &index)?;
+
+    log::trace!(
+        "Attached {} file(s) as context for session {session_id}",
+        attached.len()
+    );
+    Ok(attached)
+}
+
+/// List files currently attached as context to a session.
+#[tauri::command]
+pub async fn list_file_context(
+    app: AppHandle,
+    session_id: String,
+) -> Result<Vec<FileContextAttachment>, String> {
+    Ok(load_index(&app, &session_id)?.attachments)
+}
+
+/// Remove a single attached file from a session's context.
+#[tauri::command]
+pub async fn remove_file_context(
+    app: AppHandle,
+    session_id: String,
+    attachment_id: String,
+) -> Result<(), String> {
+    let mut index = load_index(&app, &session_id)?;
+    let before = index.attachments.len();
+    index.attachments.retain(|a| a.id != attachment_id);
+
+    if index.attachments.len() == before {
+        return Err(format!("Attachment not found: {attachment_id}"));
+    }
+
+    let snapshot_file = snapshot_path(&app, &session_id, &attachment_id)?;
+    if snapshot_file.exists() {
+        std::fs::remove_file(&snapshot_file)
+            .map_err(|e| format!("Failed to delete file context snapshot: {e}"))?;
+    }
+
+    save_index(&app, &session_id, &index)
+}
+
+/// Get the snapshot file paths attached to `session_id`, for folding into the combined
+/// context file alongside issue/PR/saved contexts. Returns only paths that still exist.
+pub(super) fn get_session_file_context_paths(
+    app: &AppHandle,
+    session_id: &str,
+) -> Result<Vec<PathBuf>, String> {
+    let index = load_index(app, session_id)?;
+    Ok(index
+        .attachments
+        .into_iter()
+        .filter_map(|a| snapshot_path(app, session_id, &a.id).ok())
+        .filter(|p| p.exists())
+        .collect())
+}
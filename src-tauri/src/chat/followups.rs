@@ -0,0 +1,163 @@
+//! Extracts TODO/FIXME-style follow-up items from a run's output and persists them per
+//! worktree, so action items the AI mentions in passing aren't lost once the run scrolls
+//! out of view. `commands::send_chat_message` scans each completed run's assistant text
+//! and tool results via `record_followups_for_run`; the frontend lists and checks them
+//! off via `list_followups`/`set_followup_completed`.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use uuid::Uuid;
+
+use crate::projects::storage::sanitize_directory_name;
+
+static FOLLOWUPS_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// A TODO/FIXME/follow-up item found in a run's output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Followup {
+    pub id: String,
+    pub worktree_id: String,
+    pub session_id: String,
+    pub run_id: String,
+    pub text: String,
+    #[serde(default)]
+    pub completed: bool,
+    pub created_at: u64,
+}
+
+fn followups_path(app: &AppHandle, worktree_id: &str) -> Result<PathBuf, String> {
+    let app_data_dir = crate::data_dir::resolve(app)?;
+    let dir = app_data_dir.join("followups");
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create followups directory: {e}"))?;
+    Ok(dir.join(format!("{}.json", sanitize_directory_name(worktree_id))))
+}
+
+fn load_followups_internal(app: &AppHandle, worktree_id: &str) -> Result<Vec<Followup>, String> {
+    let path = followups_path(app, worktree_id)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read followups file: {e}"))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse followups: {e}"))
+}
+
+fn save_followups_internal(
+    app: &AppHandle,
+    worktree_id: &str,
+    followups: &[Followup],
+) -> Result<(), String> {
+    let path = followups_path(app, worktree_id)?;
+    let json_content = serde_json::to_string_pretty(followups)
+        .map_err(|e| format!("Failed to serialize followups: {e}"))?;
+
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, json_content)
+        .map_err(|e| format!("Failed to write followups file: {e}"))?;
+    std::fs::rename(&temp_path, &path)
+        .map_err(|e| format!("Failed to finalize followups file: {e}"))
+}
+
+/// Pull TODO/FIXME-marked lines out of a block of text, returning each as `"MARKER: rest
+/// of line"`. Best-effort line-based heuristic, not a full markdown/code parser - good
+/// enough for the inline reminders assistant text and tool output tend to contain.
+fn extract_followups(text: &str) -> Vec<String> {
+    let pattern = r"(?i)^\s*(?:[-*]\s*|\d+[.)]\s*)?(TODO|FIXME)\b[:\s-]*(.+)$";
+    let marker = Regex::new(pattern).expect("Invalid regex");
+
+    text.lines()
+        .filter_map(|line| {
+            let caps = marker.captures(line)?;
+            let rest = caps.get(2)?.as_str().trim();
+            if rest.is_empty() {
+                None
+            } else {
+                Some(format!("{}: {rest}", caps[1].to_uppercase()))
+            }
+        })
+        .collect()
+}
+
+/// Scan a completed run's assistant text and tool outputs for follow-up items and persist
+/// any new ones for `worktree_id`. Skips text that's an exact match for an existing,
+/// not-yet-completed entry so a reminder mentioned across several runs isn't duplicated
+/// endlessly. Best-effort - a failure here is logged and otherwise ignored, since it must
+/// never affect whether a run counts as having succeeded.
+pub fn record_followups_for_run(
+    app: &AppHandle,
+    worktree_id: &str,
+    session_id: &str,
+    run_id: &str,
+    assistant_content: &str,
+    tool_outputs: &[String],
+) {
+    let mut found: Vec<String> = extract_followups(assistant_content);
+    for output in tool_outputs {
+        found.extend(extract_followups(output));
+    }
+    if found.is_empty() {
+        return;
+    }
+
+    let _lock = FOLLOWUPS_LOCK.lock().unwrap();
+    let mut followups = match load_followups_internal(app, worktree_id) {
+        Ok(f) => f,
+        Err(e) => {
+            log::warn!("Failed to load followups for worktree {worktree_id}: {e}");
+            return;
+        }
+    };
+
+    let created_at = super::run_log::now_timestamp();
+    for text in found {
+        if followups.iter().any(|f| !f.completed && f.text == text) {
+            continue;
+        }
+        followups.push(Followup {
+            id: Uuid::new_v4().to_string(),
+            worktree_id: worktree_id.to_string(),
+            session_id: session_id.to_string(),
+            run_id: run_id.to_string(),
+            text,
+            completed: false,
+            created_at,
+        });
+    }
+
+    if let Err(e) = save_followups_internal(app, worktree_id, &followups) {
+        log::warn!("Failed to save followups for worktree {worktree_id}: {e}");
+    }
+}
+
+/// List follow-up items recorded for a worktree, oldest first.
+#[tauri::command]
+pub async fn list_followups(app: AppHandle, worktree_id: String) -> Result<Vec<Followup>, String> {
+    let _lock = FOLLOWUPS_LOCK.lock().unwrap();
+    load_followups_internal(&app, &worktree_id)
+}
+
+/// Mark a follow-up item completed or not. Returns the updated item.
+#[tauri::command]
+pub async fn set_followup_completed(
+    app: AppHandle,
+    worktree_id: String,
+    followup_id: String,
+    completed: bool,
+) -> Result<Followup, String> {
+    let _lock = FOLLOWUPS_LOCK.lock().unwrap();
+    let mut followups = load_followups_internal(&app, &worktree_id)?;
+    let followup = followups
+        .iter_mut()
+        .find(|f| f.id == followup_id)
+        .ok_or_else(|| format!("Followup not found: {followup_id}"))?;
+    followup.completed = completed;
+    let updated = followup.clone();
+    save_followups_internal(&app, &worktree_id, &followups)?;
+    Ok(updated)
+}
@@ -0,0 +1,279 @@
+//! Importing a transcript into a new session, so work started outside Jean (in the
+//! Claude Code terminal, or a session exported by `export_session`) can continue here.
+//!
+//! Two source formats are accepted:
+//! - Jean's own JSON export (`export::ExportFormat::Json`): `{ "name", "messages" }`.
+//! - A Claude Code project transcript from `~/.claude/projects/<hash>/<session>.jsonl`:
+//!   one JSON object per line, `{"type": "user"|"assistant", "message": {...}, ...}`,
+//!   using the same content-block schema Jean's own run logs already parse.
+//!
+//! Only user/assistant turns are imported; a trailing user message with no matching
+//! assistant reply is dropped (logged), since there is no completed run to represent it.
+
+use super::run_log::{now_timestamp, start_run};
+use super::storage::with_sessions_mut;
+use super::types::{ChatMessage, ContentBlock, MessageRole, Session, ToolCall, UsageData};
+
+/// Ingest an exported transcript or Claude Code project session file into a new session
+/// in `worktree_id`. Returns the newly created session.
+#[tauri::command]
+pub async fn import_session(
+    app: tauri::AppHandle,
+    worktree_id: String,
+    path: String,
+) -> Result<Session, String> {
+    log::trace!("Importing session for worktree {worktree_id} from {path}");
+
+    let content =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read import file: {e}"))?;
+
+    let messages = parse_jean_export(&content)
+        .or_else(|| parse_claude_code_transcript(&content))
+        .ok_or_else(|| "File is not a recognized Jean export or Claude Code transcript".to_string())?;
+
+    if messages.is_empty() {
+        return Err("No messages found to import".to_string());
+    }
+
+    let session = with_sessions_mut(&app, "", &worktree_id, |sessions| {
+        let session_number = sessions.next_session_number();
+        let session = Session::new(format!("Imported {session_number}"), sessions.sessions.len() as u32);
+        sessions.sessions.push(session.clone());
+        sessions.active_session_id = Some(session.id.clone());
+        Ok(session)
+    })?;
+
+    let mut pending_user: Option<&ChatMessage> = None;
+    for message in &messages {
+        match message.role {
+            MessageRole::User => pending_user = Some(message),
+            MessageRole::Assistant => {
+                let Some(user_message) = pending_user.take() else {
+                    log::warn!("Skipping assistant message with no preceding user message during import");
+                    continue;
+                };
+                import_run(&app, &worktree_id, &session, user_message, message)?;
+            }
+        }
+    }
+    if pending_user.is_some() {
+        log::warn!("Skipping trailing user message with no assistant reply during import");
+    }
+
+    log::trace!("Imported {} message(s) into session {}", messages.len(), session.id);
+    Ok(session)
+}
+
+/// Create a completed run from one imported user/assistant turn.
+fn import_run(
+    app: &tauri::AppHandle,
+    worktree_id: &str,
+    session: &Session,
+    user_message: &ChatMessage,
+    assistant_message: &ChatMessage,
+) -> Result<(), String> {
+    let mut writer = start_run(
+        app,
+        &session.id,
+        worktree_id,
+        &session.name,
+        session.order,
+        &user_message.id,
+        &user_message.content,
+        user_message.model.as_deref(),
+        user_message.execution_mode.as_deref(),
+        user_message.thinking_level.as_deref(),
+    )?;
+
+    let line = serde_json::json!({
+        "type": "assistant",
+        "message": {
+            "content": content_blocks_to_wire(assistant_message),
+        },
+    });
+    writer.write_line(&line.to_string())?;
+
+    writer.complete(
+        &assistant_message.id,
+        None,
+        assistant_message.usage.clone(),
+    )
+}
+
+/// Re-serialize an assistant message's content/tool calls back into the raw content-block
+/// wire shape `parse_run_to_message` expects, so the imported run log replays identically.
+fn content_blocks_to_wire(message: &ChatMessage) -> Vec<serde_json::Value> {
+    if message.content_blocks.is_empty() {
+        return vec![serde_json::json!({"type": "text", "text": message.content})];
+    }
+
+    message
+        .content_blocks
+        .iter()
+        .map(|block| match block {
+            ContentBlock::Text { text } => serde_json::json!({"type": "text", "text": text}),
+            ContentBlock::Thinking { thinking } => {
+                serde_json::json!({"type": "thinking", "thinking": thinking})
+            }
+            ContentBlock::ToolUse { tool_call_id } => {
+                let tool_call = message.tool_calls.iter().find(|tc| &tc.id == tool_call_id);
+                match tool_call {
+                    Some(tc) => serde_json::json!({
+                        "type": "tool_use",
+                        "id": tc.id,
+                        "name": tc.name,
+                        "input": tc.input,
+                    }),
+                    None => serde_json::json!({"type": "text", "text": ""}),
+                }
+            }
+        })
+        .collect()
+}
+
+/// Try parsing `content` as Jean's own JSON export (`export::render_json`'s shape).
+fn parse_jean_export(content: &str) -> Option<Vec<ChatMessage>> {
+    #[derive(serde::Deserialize)]
+    struct ExportedSession {
+        messages: Vec<ChatMessage>,
+    }
+    serde_json::from_str::<ExportedSession>(content)
+        .ok()
+        .map(|e| e.messages)
+}
+
+/// Parse a Claude Code `~/.claude/projects/<hash>/<session>.jsonl` transcript: one JSON
+/// object per line, each a `{"type": "user"|"assistant", "message": {...}}` record.
+/// Unrecognized line types (e.g. "summary") are skipped.
+fn parse_claude_code_transcript(content: &str) -> Option<Vec<ChatMessage>> {
+    let mut messages = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let record: serde_json::Value = serde_json::from_str(line).ok()?;
+        let record_type = record.get("type").and_then(|v| v.as_str()).unwrap_or("");
+        let Some(message) = record.get("message") else {
+            continue;
+        };
+
+        match record_type {
+            "user" => {
+                let content = message
+                    .get("content")
+                    .map(extract_text_content)
+                    .unwrap_or_default();
+                messages.push(ChatMessage {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    role: MessageRole::User,
+                    content,
+                    timestamp: now_timestamp(),
+                    ..Default::default()
+                });
+            }
+            "assistant" => {
+                let mut content = String::new();
+                let mut content_blocks = Vec::new();
+                let mut tool_calls = Vec::new();
+                let mut usage = None;
+
+                if let Some(blocks) = message.get("content").and_then(|c| c.as_array()) {
+                    for block in blocks {
+                        match block.get("type").and_then(|v| v.as_str()).unwrap_or("") {
+                            "text" => {
+                                if let Some(text) = block.get("text").and_then(|v| v.as_str()) {
+                                    content.push_str(text);
+                                    content_blocks.push(ContentBlock::Text {
+                                        text: text.to_string(),
+                                    });
+                                }
+                            }
+                            "thinking" => {
+                                if let Some(thinking) =
+                                    block.get("thinking").and_then(|v| v.as_str())
+                                {
+                                    content_blocks.push(ContentBlock::Thinking {
+                                        thinking: thinking.to_string(),
+                                    });
+                                }
+                            }
+                            "tool_use" => {
+                                let id = block
+                                    .get("id")
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or_default()
+                                    .to_string();
+                                tool_calls.push(ToolCall {
+                                    id: id.clone(),
+                                    name: block
+                                        .get("name")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or_default()
+                                        .to_string(),
+                                    input: block.get("input").cloned().unwrap_or(serde_json::Value::Null),
+                                    output: None,
+                                    parent_tool_use_id: None,
+                                });
+                                content_blocks.push(ContentBlock::ToolUse { tool_call_id: id });
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+
+                if let Some(u) = message.get("usage") {
+                    usage = Some(UsageData {
+                        input_tokens: u.get("input_tokens").and_then(|v| v.as_u64()).unwrap_or(0),
+                        output_tokens: u.get("output_tokens").and_then(|v| v.as_u64()).unwrap_or(0),
+                        cache_read_input_tokens: u
+                            .get("cache_read_input_tokens")
+                            .and_then(|v| v.as_u64())
+                            .unwrap_or(0),
+                        cache_creation_input_tokens: u
+                            .get("cache_creation_input_tokens")
+                            .and_then(|v| v.as_u64())
+                            .unwrap_or(0),
+                    });
+                }
+
+                messages.push(ChatMessage {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    role: MessageRole::Assistant,
+                    content,
+                    content_blocks,
+                    tool_calls,
+                    timestamp: now_timestamp(),
+                    usage,
+                    ..Default::default()
+                });
+            }
+            _ => continue,
+        }
+    }
+
+    if messages.is_empty() {
+        None
+    } else {
+        Some(messages)
+    }
+}
+
+/// Claude Code's `message.content` is either a plain string or an array of content
+/// blocks (user turns with tool results use the array form); flatten either to text.
+fn extract_text_content(content: &serde_json::Value) -> String {
+    if let Some(text) = content.as_str() {
+        return text.to_string();
+    }
+    content
+        .as_array()
+        .map(|blocks| {
+            blocks
+                .iter()
+                .filter_map(|b| b.get("text").and_then(|v| v.as_str()))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,26 @@
+//! Resolves the managed "instructions" document injected into every run's system prompt
+//! (see `Project::instructions` / `Worktree::instructions_override`).
+//!
+//! This is separate from any repo-local CLAUDE.md: it's configured from the frontend and
+//! stored in `projects.json`, not checked into the project's own git history. A worktree
+//! override takes precedence over the project default when both are set.
+
+use tauri::AppHandle;
+
+use crate::projects::storage::load_projects_data;
+
+/// Resolve the instructions document to append to the system prompt for `worktree_id`.
+/// Returns `None` if neither the worktree nor its project has one configured, or if the
+/// worktree/project can't be looked up - a missing instructions document shouldn't block a
+/// run.
+pub fn resolve_instructions(app: &AppHandle, worktree_id: &str) -> Option<String> {
+    let data = load_projects_data(app).ok()?;
+    let worktree = data.find_worktree(worktree_id)?;
+
+    if let Some(instructions) = &worktree.instructions_override {
+        return Some(instructions.clone());
+    }
+
+    let project = data.find_project(&worktree.project_id)?;
+    project.instructions.clone()
+}
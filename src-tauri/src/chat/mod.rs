@@ -1,12 +1,60 @@
+pub mod ai_provider;
+pub mod budget;
 mod claude;
+mod codex;
 mod commands;
+mod compaction;
+pub mod context_usage;
 pub mod detached;
+mod env_vars;
+pub mod export;
+mod file_context;
+mod followups;
+mod instructions;
+pub mod import;
 mod naming;
+mod offline_queue;
+mod ollama;
+mod openai_compat;
+mod pipeline;
+mod plan_impact;
+pub mod queue;
 pub mod registry;
+pub mod retention;
+mod retrieval;
 pub mod run_log;
+mod run_log_retention;
+pub mod run_queue;
+mod sandbox;
+mod search_index;
+mod stream_format;
+pub mod schedule;
+mod snapshots;
 pub mod storage;
 pub mod tail;
 pub mod types;
+pub mod usage;
 
+pub use budget::get_budget_status;
 pub use commands::*;
+pub use context_usage::get_session_context_usage;
+pub use export::export_session;
+pub use file_context::{attach_file_context, list_file_context, remove_file_context};
+pub use followups::{list_followups, set_followup_completed};
+pub use import::import_session;
+pub use offline_queue::{cancel_offline_queued_message, dispatch_pending, list_offline_queue};
+pub use queue::{cancel_queued_message, list_queued_messages};
+pub use ollama::list_ollama_models;
+pub use retention::preview_retention_policy;
+pub use pipeline::{
+    cancel_pipeline, create_pipeline, list_pipelines, run_pipeline, PipelineStepInput,
+};
+pub use plan_impact::get_plan_impact;
+pub use retrieval::retrieve_relevant_context;
+pub use run_log_retention::{compress_old_run_logs, get_run_log, list_runs};
+pub use run_queue::{cancel_queued_run, list_queued_runs};
+pub use search_index::{rebuild_search_index, search_messages};
+pub use schedule::{cancel_scheduled_prompt, list_scheduled_prompts, schedule_prompt};
+pub use snapshots::{gc_old_snapshots, list_snapshots, rollback_to_snapshot};
 pub use storage::{preserve_base_sessions, restore_base_sessions, with_sessions_mut};
+pub use usage::get_usage_report;
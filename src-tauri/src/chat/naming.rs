@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 use std::io::Write;
 use std::path::PathBuf;
 use std::process::Stdio;
-use tauri::{AppHandle, Manager};
+use tauri::AppHandle;
 use crate::http_server::EmitExt;
 
 /// Request for combined naming (session + branch)
@@ -363,7 +363,7 @@ fn generate_names(app: &AppHandle, request: &NamingRequest) -> Result<NamingOutp
         // Add directories for Claude to read attachments
         // In dev mode: full directory access (useful for debugging)
         // In prod mode: only specific directories (security)
-        if let Ok(app_data_dir) = app.path().app_data_dir() {
+        if let Ok(app_data_dir) = crate::data_dir::resolve(app) {
             if cfg!(debug_assertions) {
                 cmd.arg("--add-dir").arg(&app_data_dir);
                 log::trace!("Added full app data directory to naming scope: {app_data_dir:?}");
@@ -0,0 +1,218 @@
+//! Durable queue for chat messages that couldn't be dispatched because the Claude CLI
+//! backend is unavailable - not installed, unauthenticated, or unreachable.
+//!
+//! Unlike `queue`/`run_queue` (in-memory, for transient backpressure within a running
+//! instance), this queue is persisted to disk so a message isn't lost if the app is
+//! closed while offline. `commands::send_chat_message` enqueues here instead of erroring
+//! when `claude_cli::check_claude_cli_auth` reports the backend unavailable for a
+//! Claude CLI session, and `dispatch_pending` drains it once that check starts
+//! succeeding again - called from `check_claude_cli_auth` itself, since the frontend
+//! already polls that command on an interval.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use uuid::Uuid;
+
+use crate::http_server::EmitExt;
+
+use super::types::ThinkingLevel;
+
+/// Guards read-modify-write races on offline-queue.json, same role as projects.json's lock.
+static OFFLINE_QUEUE_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// A chat message waiting for the Claude CLI backend to become available again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfflineQueuedMessage {
+    pub id: String,
+    pub session_id: String,
+    pub worktree_id: String,
+    pub worktree_path: String,
+    pub message: String,
+    pub model: Option<String>,
+    pub execution_mode: Option<String>,
+    pub thinking_level: Option<ThinkingLevel>,
+    pub disable_thinking_for_mode: Option<bool>,
+    pub parallel_execution_prompt_enabled: Option<bool>,
+    pub ai_language: Option<String>,
+    pub allowed_tools: Option<Vec<String>>,
+    /// Why it was queued (e.g. "Claude CLI not installed"), shown to the user.
+    pub reason: String,
+    pub queued_at: u64,
+}
+
+fn queue_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = crate::data_dir::resolve(app)?;
+    std::fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data directory: {e}"))?;
+    Ok(app_data_dir.join("offline-queue.json"))
+}
+
+fn load_queue_internal(app: &AppHandle) -> Result<Vec<OfflineQueuedMessage>, String> {
+    let path = queue_path(app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read offline queue file: {e}"))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse offline queue: {e}"))
+}
+
+fn save_queue_internal(app: &AppHandle, queue: &[OfflineQueuedMessage]) -> Result<(), String> {
+    let path = queue_path(app)?;
+    let json_content = serde_json::to_string_pretty(queue)
+        .map_err(|e| format!("Failed to serialize offline queue: {e}"))?;
+
+    // Write to a temporary file first, then rename (atomic operation)
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, json_content)
+        .map_err(|e| format!("Failed to write offline queue file: {e}"))?;
+    std::fs::rename(&temp_path, &path)
+        .map_err(|e| format!("Failed to finalize offline queue file: {e}"))
+}
+
+/// Add a message to the durable offline queue and emit `offline_queue:updated`.
+#[allow(clippy::too_many_arguments)]
+pub fn enqueue(
+    app: &AppHandle,
+    session_id: String,
+    worktree_id: String,
+    worktree_path: String,
+    message: String,
+    model: Option<String>,
+    execution_mode: Option<String>,
+    thinking_level: Option<ThinkingLevel>,
+    disable_thinking_for_mode: Option<bool>,
+    parallel_execution_prompt_enabled: Option<bool>,
+    ai_language: Option<String>,
+    allowed_tools: Option<Vec<String>>,
+    reason: String,
+) -> Result<OfflineQueuedMessage, String> {
+    let queued = OfflineQueuedMessage {
+        id: Uuid::new_v4().to_string(),
+        session_id,
+        worktree_id,
+        worktree_path,
+        message,
+        model,
+        execution_mode,
+        thinking_level,
+        disable_thinking_for_mode,
+        parallel_execution_prompt_enabled,
+        ai_language,
+        allowed_tools,
+        reason,
+        queued_at: super::run_log::now_timestamp(),
+    };
+
+    {
+        let _lock = OFFLINE_QUEUE_LOCK.lock().unwrap();
+        let mut queue = load_queue_internal(app)?;
+        queue.push(queued.clone());
+        save_queue_internal(app, &queue)?;
+    }
+
+    emit_updated(app);
+    Ok(queued)
+}
+
+/// List messages currently waiting for the Claude CLI backend to become available.
+#[tauri::command]
+pub async fn list_offline_queue(app: AppHandle) -> Result<Vec<OfflineQueuedMessage>, String> {
+    let _lock = OFFLINE_QUEUE_LOCK.lock().unwrap();
+    load_queue_internal(&app)
+}
+
+/// Remove one offline-queued message by ID. Returns `true` if it was found and removed.
+#[tauri::command]
+pub async fn cancel_offline_queued_message(
+    app: AppHandle,
+    message_id: String,
+) -> Result<bool, String> {
+    let removed = {
+        let _lock = OFFLINE_QUEUE_LOCK.lock().unwrap();
+        let mut queue = load_queue_internal(&app)?;
+        let before = queue.len();
+        queue.retain(|m| m.id != message_id);
+        let removed = before != queue.len();
+        if removed {
+            save_queue_internal(&app, &queue)?;
+        }
+        removed
+    };
+
+    if removed {
+        emit_updated(&app);
+    }
+    Ok(removed)
+}
+
+/// Payload for the `offline_queue:updated` event, emitted whenever the queue changes.
+#[derive(Serialize, Clone)]
+struct OfflineQueueUpdatedEvent {
+    messages: Vec<OfflineQueuedMessage>,
+}
+
+fn emit_updated(app: &AppHandle) {
+    let messages = {
+        let _lock = OFFLINE_QUEUE_LOCK.lock().unwrap();
+        load_queue_internal(app).unwrap_or_default()
+    };
+    let _ = app.emit_all("offline_queue:updated", &OfflineQueueUpdatedEvent { messages });
+}
+
+/// Drain every message waiting in the offline queue, resubmitting each as a fresh
+/// `send_chat_message` call. Called from `claude_cli::check_claude_cli_auth` once it
+/// reports the backend authenticated again. A message that's still blocked (e.g. another
+/// backend issue) just gets re-queued by that same `send_chat_message` call, so nothing
+/// is lost if the drain fires on a false-positive auth check.
+pub fn dispatch_pending(app: AppHandle) {
+    let pending = {
+        let _lock = OFFLINE_QUEUE_LOCK.lock().unwrap();
+        match load_queue_internal(&app) {
+            Ok(queue) if !queue.is_empty() => {
+                if let Err(e) = save_queue_internal(&app, &[]) {
+                    log::warn!("Failed to clear offline queue before dispatch: {e}");
+                    return;
+                }
+                queue
+            }
+            Ok(_) => return,
+            Err(e) => {
+                log::warn!("Failed to load offline queue for dispatch: {e}");
+                return;
+            }
+        }
+    };
+
+    emit_updated(&app);
+    log::trace!("Dispatching {} offline-queued message(s)", pending.len());
+
+    for queued in pending {
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = super::commands::send_chat_message(
+                app,
+                queued.session_id,
+                queued.worktree_id,
+                queued.worktree_path,
+                queued.message,
+                queued.model,
+                queued.execution_mode,
+                queued.thinking_level,
+                queued.disable_thinking_for_mode,
+                queued.parallel_execution_prompt_enabled,
+                queued.ai_language,
+                queued.allowed_tools,
+                None,
+            )
+            .await
+            {
+                log::warn!("Offline-queued message failed to send: {e}");
+            }
+        });
+    }
+}
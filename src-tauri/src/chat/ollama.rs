@@ -0,0 +1,237 @@
+//! Local Ollama daemon backend.
+//!
+//! Talks to a locally running `ollama serve` daemon (default `http://localhost:11434`,
+//! configurable via `AppPreferences::ollama_base_url`) so simple tasks can run fully
+//! offline and free, with no API key. Like `super::openai_compat`, this sends the
+//! latest user message as a one-turn request and streams the reply back through the
+//! same `chat:chunk`/`chat:done` events — multi-turn context is tracked as the same
+//! follow-up work noted there, since neither backend yet threads prior turns through.
+
+use std::io::{BufRead, BufReader};
+
+use serde::Deserialize;
+use tauri::AppHandle;
+
+use super::ai_provider::{AiProvider, SpawnRequest};
+use super::claude::{ChunkEvent, ClaudeResponse, DoneEvent, ErrorEvent};
+use super::types::{ContentBlock, UsageData};
+use crate::http_server::EmitExt;
+
+/// Identifier stored in `Session::selected_provider` for this backend.
+pub const OLLAMA_PROVIDER_ID: &str = "ollama";
+
+pub struct OllamaProvider;
+
+/// One entry from `GET /api/tags`
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OllamaModel {
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+struct TagsResponse {
+    #[serde(default)]
+    models: Vec<OllamaModel>,
+}
+
+#[derive(Deserialize)]
+struct ChatStreamLine {
+    #[serde(default)]
+    message: Option<ChatStreamMessage>,
+    #[serde(default)]
+    done: bool,
+    #[serde(default)]
+    prompt_eval_count: Option<u64>,
+    #[serde(default)]
+    eval_count: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct ChatStreamMessage {
+    #[serde(default)]
+    content: String,
+}
+
+/// List models available on the local Ollama daemon, for use in a model picker.
+/// Returns a friendly error (rather than a raw connection error) when the daemon
+/// isn't running, since that's the expected state for anyone who hasn't set it up.
+#[tauri::command]
+pub async fn list_ollama_models(app: AppHandle) -> Result<Vec<OllamaModel>, String> {
+    let preferences = crate::load_preferences_sync(&app)?;
+    let base_url = preferences.ollama_base_url;
+
+    let client = reqwest::blocking::Client::builder()
+        .user_agent("Jean-App/1.0")
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    let response = client
+        .get(format!("{}/api/tags", base_url.trim_end_matches('/')))
+        .send()
+        .map_err(|e| ollama_unreachable_message(&base_url, &e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Ollama returned an error listing models: {}",
+            response.status()
+        ));
+    }
+
+    let parsed: TagsResponse = response
+        .json()
+        .map_err(|e| format!("Failed to parse Ollama model list: {e}"))?;
+    Ok(parsed.models)
+}
+
+fn ollama_unreachable_message(base_url: &str, error: &reqwest::Error) -> String {
+    format!(
+        "Could not reach Ollama at {base_url} — is it running? Start it with `ollama serve`. ({error})"
+    )
+}
+
+impl AiProvider for OllamaProvider {
+    fn id(&self) -> &'static str {
+        OLLAMA_PROVIDER_ID
+    }
+
+    fn spawn(
+        &self,
+        app: &AppHandle,
+        request: SpawnRequest<'_>,
+    ) -> Result<(u32, ClaudeResponse), String> {
+        let preferences = crate::load_preferences_sync(app)?;
+        let base_url = preferences.ollama_base_url;
+        let model = preferences
+            .ollama_model
+            .filter(|m| !m.trim().is_empty())
+            .unwrap_or_else(|| "llama3".to_string());
+
+        let client = reqwest::blocking::Client::builder()
+            .user_agent("Jean-App/1.0")
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+        let response = client
+            .post(format!("{}/api/chat", base_url.trim_end_matches('/')))
+            .json(&serde_json::json!({
+                "model": model,
+                "stream": true,
+                "messages": [{"role": "user", "content": request.message}],
+            }))
+            .send()
+            .map_err(|e| {
+                let error_msg = ollama_unreachable_message(&base_url, &e);
+                emit_error(app, request.session_id, request.worktree_id, &error_msg);
+                error_msg
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().unwrap_or_default();
+            let error_msg = format!("Ollama returned {status}: {body}");
+            emit_error(app, request.session_id, request.worktree_id, &error_msg);
+            return Err(error_msg);
+        }
+
+        let mut full_content = String::new();
+        let mut content_blocks: Vec<ContentBlock> = Vec::new();
+        let mut usage: Option<UsageData> = None;
+
+        let mut reader = BufReader::new(response);
+        let mut line = String::new();
+        while reader
+            .read_line(&mut line)
+            .map_err(|e| format!("Failed to read stream: {e}"))?
+            > 0
+        {
+            let data = line.trim().to_string();
+            line.clear();
+            if data.is_empty() {
+                continue;
+            }
+
+            let chunk: ChatStreamLine = match serde_json::from_str(&data) {
+                Ok(c) => c,
+                Err(e) => {
+                    log::trace!("Skipping unparsable Ollama stream line: {e}");
+                    continue;
+                }
+            };
+
+            if let Some(message) = chunk.message {
+                if !message.content.is_empty() {
+                    full_content.push_str(&message.content);
+                    content_blocks.push(ContentBlock::Text {
+                        text: message.content.clone(),
+                    });
+                    let _ = app.emit_all(
+                        "chat:chunk",
+                        &ChunkEvent {
+                            session_id: request.session_id.to_string(),
+                            worktree_id: request.worktree_id.to_string(),
+                            content: message.content,
+                        },
+                    );
+                }
+            }
+
+            if chunk.done {
+                if let (Some(prompt_tokens), Some(completion_tokens)) =
+                    (chunk.prompt_eval_count, chunk.eval_count)
+                {
+                    usage = Some(UsageData {
+                        input_tokens: prompt_tokens,
+                        output_tokens: completion_tokens,
+                        cache_read_input_tokens: 0,
+                        cache_creation_input_tokens: 0,
+                    });
+                }
+                break;
+            }
+        }
+
+        let _ = app.emit_all(
+            "chat:done",
+            &DoneEvent {
+                session_id: request.session_id.to_string(),
+                worktree_id: request.worktree_id.to_string(),
+            },
+        );
+
+        Ok((
+            0, // No OS process backs this provider, so there's nothing to register for cancellation
+            ClaudeResponse {
+                content: full_content,
+                session_id: String::new(), // No server-side session concept to resume
+                tool_calls: Vec::new(), // Ollama's tool-calling API doesn't apply to the plain /api/chat streaming shape used here
+                content_blocks,
+                cancelled: false,
+                usage,
+            },
+        ))
+    }
+
+    fn cancel(
+        &self,
+        _app: &AppHandle,
+        _session_id: &str,
+        _worktree_id: &str,
+        _force: bool,
+    ) -> Result<bool, String> {
+        // spawn() blocks for the duration of the HTTP request with no PID to kill;
+        // there is no in-flight request to cancel once spawn() has returned.
+        Ok(false)
+    }
+}
+
+fn emit_error(app: &AppHandle, session_id: &str, worktree_id: &str, error: &str) {
+    let _ = app.emit_all(
+        "chat:error",
+        &ErrorEvent {
+            session_id: session_id.to_string(),
+            worktree_id: worktree_id.to_string(),
+            error: error.to_string(),
+        },
+    );
+}
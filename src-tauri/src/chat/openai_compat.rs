@@ -0,0 +1,263 @@
+//! OpenAI-compatible chat-completions backend.
+//!
+//! Talks to any server implementing the OpenAI `/chat/completions` streaming API
+//! (OpenRouter, vLLM, LM Studio, etc.) via the base URL/API key/model configured in
+//! `AppPreferences` (`openai_compat_base_url`/`openai_compat_api_key`/`openai_compat_model`).
+//! The key is stored alongside other persisted secrets in the preferences file (see
+//! `http_server_token`) — this codebase has no OS keychain integration yet, so that's
+//! the existing precedent for "secret storage" rather than true keychain storage.
+//!
+//! Scope: this sends the single latest user message as a one-turn request and streams
+//! the assistant's reply back through the same `chat:chunk`/`chat:tool_use`/`chat:done`
+//! events the Claude CLI backend emits. It does NOT yet thread prior conversation
+//! turns through `messages` (there's no local transcript format shared between
+//! backends yet), so `resume_session_id` is accepted but unused — multi-turn context
+//! for this provider is tracked as follow-up work.
+
+use std::io::{BufRead, BufReader};
+
+use serde::Deserialize;
+use tauri::AppHandle;
+
+use super::ai_provider::{AiProvider, SpawnRequest};
+use super::claude::{ChunkEvent, ClaudeResponse, DoneEvent, ErrorEvent, ToolUseEvent};
+use super::types::{ContentBlock, ToolCall, UsageData};
+use crate::http_server::EmitExt;
+
+/// Identifier stored in `Session::selected_provider` for this backend.
+pub const OPENAI_COMPAT_PROVIDER_ID: &str = "openai_compat";
+
+pub struct OpenAiCompatProvider;
+
+#[derive(Deserialize)]
+struct StreamChunk {
+    choices: Vec<StreamChoice>,
+    #[serde(default)]
+    usage: Option<StreamUsage>,
+}
+
+#[derive(Deserialize)]
+struct StreamChoice {
+    delta: StreamDelta,
+}
+
+#[derive(Deserialize, Default)]
+struct StreamDelta {
+    #[serde(default)]
+    content: Option<String>,
+    #[serde(default)]
+    tool_calls: Vec<StreamToolCall>,
+}
+
+#[derive(Deserialize)]
+struct StreamToolCall {
+    #[serde(default)]
+    id: Option<String>,
+    function: StreamToolCallFunction,
+}
+
+#[derive(Deserialize)]
+struct StreamToolCallFunction {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    arguments: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StreamUsage {
+    prompt_tokens: u64,
+    completion_tokens: u64,
+}
+
+impl AiProvider for OpenAiCompatProvider {
+    fn id(&self) -> &'static str {
+        OPENAI_COMPAT_PROVIDER_ID
+    }
+
+    fn spawn(
+        &self,
+        app: &AppHandle,
+        request: SpawnRequest<'_>,
+    ) -> Result<(u32, ClaudeResponse), String> {
+        let preferences = crate::load_preferences_sync(app)?;
+        let base_url = preferences
+            .openai_compat_base_url
+            .filter(|url| !url.trim().is_empty())
+            .ok_or_else(|| {
+                "OpenAI-compatible provider is not configured: set a base URL in Settings"
+                    .to_string()
+            })?;
+        let model = preferences
+            .openai_compat_model
+            .filter(|m| !m.trim().is_empty())
+            .unwrap_or_else(|| "gpt-4o-mini".to_string());
+
+        let url = format!("{}/chat/completions", base_url.trim_end_matches('/'));
+
+        let client = reqwest::blocking::Client::builder()
+            .user_agent("Jean-App/1.0")
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+        let mut req = client.post(&url).json(&serde_json::json!({
+            "model": model,
+            "stream": true,
+            "messages": [{"role": "user", "content": request.message}],
+        }));
+        if let Some(api_key) = preferences
+            .openai_compat_api_key
+            .filter(|k| !k.trim().is_empty())
+        {
+            req = req.bearer_auth(api_key);
+        }
+
+        let response = req.send().map_err(|e| {
+            let error_msg = format!("Failed to reach OpenAI-compatible endpoint: {e}");
+            emit_error(app, request.session_id, request.worktree_id, &error_msg);
+            error_msg
+        })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().unwrap_or_default();
+            let error_msg = format!("OpenAI-compatible endpoint returned {status}: {body}");
+            emit_error(app, request.session_id, request.worktree_id, &error_msg);
+            return Err(error_msg);
+        }
+
+        let mut full_content = String::new();
+        let mut tool_calls: Vec<ToolCall> = Vec::new();
+        let mut content_blocks: Vec<ContentBlock> = Vec::new();
+        let mut usage: Option<UsageData> = None;
+
+        let mut reader = BufReader::new(response);
+        let mut line = String::new();
+        while reader
+            .read_line(&mut line)
+            .map_err(|e| format!("Failed to read stream: {e}"))?
+            > 0
+        {
+            let data = line.trim();
+            line.clear();
+            let Some(data) = data.strip_prefix("data: ") else {
+                continue;
+            };
+            if data == "[DONE]" {
+                break;
+            }
+
+            let chunk: StreamChunk = match serde_json::from_str(data) {
+                Ok(c) => c,
+                Err(e) => {
+                    log::trace!("Skipping unparsable stream chunk: {e}");
+                    continue;
+                }
+            };
+
+            if let Some(usage_data) = chunk.usage {
+                usage = Some(UsageData {
+                    input_tokens: usage_data.prompt_tokens,
+                    output_tokens: usage_data.completion_tokens,
+                    cache_read_input_tokens: 0,
+                    cache_creation_input_tokens: 0,
+                });
+            }
+
+            for choice in chunk.choices {
+                if let Some(text) = choice.delta.content {
+                    if !text.is_empty() {
+                        full_content.push_str(&text);
+                        content_blocks.push(ContentBlock::Text { text: text.clone() });
+                        let _ = app.emit_all(
+                            "chat:chunk",
+                            &ChunkEvent {
+                                session_id: request.session_id.to_string(),
+                                worktree_id: request.worktree_id.to_string(),
+                                content: text,
+                            },
+                        );
+                    }
+                }
+
+                // Tool-call passthrough: forward whatever the server streamed, without
+                // attempting to map it onto Jean's own tool set (that mapping only
+                // makes sense for the Claude CLI's built-in tools).
+                for tool_call in choice.delta.tool_calls {
+                    let id = tool_call.id.unwrap_or_default();
+                    let name = tool_call.function.name.unwrap_or_default();
+                    let input = tool_call
+                        .function
+                        .arguments
+                        .and_then(|args| serde_json::from_str(&args).ok())
+                        .unwrap_or(serde_json::Value::Null);
+
+                    tool_calls.push(ToolCall {
+                        id: id.clone(),
+                        name: name.clone(),
+                        input: input.clone(),
+                        output: None,
+                        parent_tool_use_id: None,
+                    });
+                    content_blocks.push(ContentBlock::ToolUse {
+                        tool_call_id: id.clone(),
+                    });
+                    let _ = app.emit_all(
+                        "chat:tool_use",
+                        &ToolUseEvent {
+                            session_id: request.session_id.to_string(),
+                            worktree_id: request.worktree_id.to_string(),
+                            id,
+                            name,
+                            input,
+                            parent_tool_use_id: None,
+                        },
+                    );
+                }
+            }
+        }
+
+        let _ = app.emit_all(
+            "chat:done",
+            &DoneEvent {
+                session_id: request.session_id.to_string(),
+                worktree_id: request.worktree_id.to_string(),
+            },
+        );
+
+        Ok((
+            0, // No OS process backs this provider, so there's nothing to register for cancellation
+            ClaudeResponse {
+                content: full_content,
+                session_id: String::new(), // No server-side session concept to resume
+                tool_calls,
+                content_blocks,
+                cancelled: false,
+                usage,
+            },
+        ))
+    }
+
+    fn cancel(
+        &self,
+        _app: &AppHandle,
+        _session_id: &str,
+        _worktree_id: &str,
+        _force: bool,
+    ) -> Result<bool, String> {
+        // spawn() blocks for the duration of the HTTP request with no PID to kill;
+        // there is no in-flight request to cancel once spawn() has returned.
+        Ok(false)
+    }
+}
+
+fn emit_error(app: &AppHandle, session_id: &str, worktree_id: &str, error: &str) {
+    let _ = app.emit_all(
+        "chat:error",
+        &ErrorEvent {
+            session_id: session_id.to_string(),
+            worktree_id: worktree_id.to_string(),
+            error: error.to_string(),
+        },
+    );
+}
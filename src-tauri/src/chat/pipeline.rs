@@ -0,0 +1,438 @@
+//! Multi-step agent pipelines: an ordered list of steps (prompt / shell command / git
+//! operation), each gated by the outcome of the step before it, executed sequentially and
+//! persisted to disk so a pipeline survives an app restart mid-run.
+//!
+//! Modeled closely on `schedule.rs` (same persisted-JSON-file + lock + `*:updated` event
+//! shape) but for a sequence of heterogeneous steps instead of a single future chat message.
+//! "Conditional on previous result" is expressed per-step via `RunIf` rather than a nested
+//! branching tree - a pipeline is a straight line where individual steps can be skipped,
+//! which covers the "plan -> implement -> test -> commit -> open PR" flow (e.g. "only open
+//! the PR if tests passed") without needing a general graph executor.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use uuid::Uuid;
+
+use crate::http_server::EmitExt;
+
+use super::types::ThinkingLevel;
+
+/// Guards read-modify-write races on pipelines.json.
+static PIPELINE_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PipelineStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StepStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+    Skipped,
+}
+
+/// When a step is eligible to run, based on the step immediately before it. The first step
+/// in a pipeline always runs regardless of this field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunIf {
+    Always,
+    PreviousSucceeded,
+    PreviousFailed,
+}
+
+fn default_run_if() -> RunIf {
+    RunIf::Always
+}
+
+/// The action a step performs. `ShellCommand` and `GitOperation` are distinguished because
+/// a git operation is always run as `git <command> <args...>` from the worktree root,
+/// while a shell command runs through the user's configured shell, matching the existing
+/// `pre_run`/`post_run` project hooks in `projects::git::run_hook_script`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PipelineStepKind {
+    Prompt {
+        message: String,
+        model: Option<String>,
+        execution_mode: Option<String>,
+        thinking_level: Option<ThinkingLevel>,
+    },
+    ShellCommand {
+        command: String,
+    },
+    GitOperation {
+        command: String,
+        args: Vec<String>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineStep {
+    pub id: String,
+    pub kind: PipelineStepKind,
+    #[serde(default = "default_run_if")]
+    pub run_if: RunIf,
+    #[serde(default = "default_step_status")]
+    pub status: StepStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<u64>,
+}
+
+fn default_step_status() -> StepStatus {
+    StepStatus::Pending
+}
+
+/// One step's kind + conditional-run setting, as supplied when creating a pipeline. Kept
+/// separate from `PipelineStep` so callers don't have to fill in the runtime-only fields
+/// (`status`, `output`, timestamps).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineStepInput {
+    pub kind: PipelineStepKind,
+    #[serde(default = "default_run_if")]
+    pub run_if: RunIf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pipeline {
+    pub id: String,
+    pub worktree_id: String,
+    pub worktree_path: String,
+    pub session_id: String,
+    pub name: String,
+    pub steps: Vec<PipelineStep>,
+    pub status: PipelineStatus,
+    /// Index of the next step to execute. Steps before this index have already run (or
+    /// been skipped) and are not re-run when `run_pipeline` is called again, which is what
+    /// makes a pipeline resumable after an app restart mid-run.
+    pub current_step_index: usize,
+    pub created_at: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PipelinesFile {
+    pipelines: Vec<Pipeline>,
+}
+
+fn get_pipelines_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = crate::data_dir::resolve(app)?;
+
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data directory: {e}"))?;
+
+    Ok(app_data_dir.join("pipelines.json"))
+}
+
+fn load(app: &AppHandle) -> PipelinesFile {
+    let path = match get_pipelines_path(app) {
+        Ok(p) => p,
+        Err(_) => return PipelinesFile::default(),
+    };
+
+    if !path.exists() {
+        return PipelinesFile::default();
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => PipelinesFile::default(),
+    }
+}
+
+fn save(app: &AppHandle, file: &PipelinesFile) -> Result<(), String> {
+    let path = get_pipelines_path(app)?;
+    let temp_path = path.with_extension("tmp");
+
+    let json = serde_json::to_string_pretty(file)
+        .map_err(|e| format!("Failed to serialize pipelines: {e}"))?;
+
+    fs::write(&temp_path, &json).map_err(|e| format!("Failed to write pipelines file: {e}"))?;
+
+    fs::rename(&temp_path, &path).map_err(|e| format!("Failed to finalize pipelines file: {e}"))?;
+
+    Ok(())
+}
+
+/// Payload for the `pipeline:updated` event, emitted whenever a pipeline or any of its
+/// steps changes status.
+#[derive(Serialize, Clone)]
+struct PipelineUpdatedEvent {
+    pipeline: Pipeline,
+}
+
+fn emit_updated(app: &AppHandle, pipeline: &Pipeline) {
+    let _ = app.emit_all(
+        "pipeline:updated",
+        &PipelineUpdatedEvent {
+            pipeline: pipeline.clone(),
+        },
+    );
+}
+
+/// Create a pipeline from an ordered list of steps. Does not start execution - call
+/// `run_pipeline` to begin (or resume) it.
+#[tauri::command]
+pub async fn create_pipeline(
+    app: AppHandle,
+    worktree_id: String,
+    worktree_path: String,
+    session_id: String,
+    name: String,
+    steps: Vec<PipelineStepInput>,
+) -> Result<Pipeline, String> {
+    if steps.is_empty() {
+        return Err("A pipeline needs at least one step".to_string());
+    }
+
+    let _lock = PIPELINE_LOCK.lock().unwrap();
+
+    let pipeline = Pipeline {
+        id: Uuid::new_v4().to_string(),
+        worktree_id,
+        worktree_path,
+        session_id,
+        name,
+        steps: steps
+            .into_iter()
+            .map(|input| PipelineStep {
+                id: Uuid::new_v4().to_string(),
+                kind: input.kind,
+                run_if: input.run_if,
+                status: StepStatus::Pending,
+                output: None,
+                error: None,
+                started_at: None,
+                finished_at: None,
+            })
+            .collect(),
+        status: PipelineStatus::Pending,
+        current_step_index: 0,
+        created_at: super::run_log::now_timestamp(),
+    };
+
+    let mut file = load(&app);
+    file.pipelines.push(pipeline.clone());
+    save(&app, &file)?;
+
+    Ok(pipeline)
+}
+
+/// List all pipelines (any status), oldest first.
+#[tauri::command]
+pub async fn list_pipelines(app: AppHandle) -> Result<Vec<Pipeline>, String> {
+    Ok(load(&app).pipelines)
+}
+
+/// Cancel a pipeline. Has no effect on a pipeline that already finished.
+#[tauri::command]
+pub async fn cancel_pipeline(app: AppHandle, pipeline_id: String) -> Result<bool, String> {
+    let _lock = PIPELINE_LOCK.lock().unwrap();
+
+    let mut file = load(&app);
+    let Some(pipeline) = file.pipelines.iter_mut().find(|p| p.id == pipeline_id) else {
+        return Ok(false);
+    };
+    if matches!(
+        pipeline.status,
+        PipelineStatus::Completed | PipelineStatus::Failed | PipelineStatus::Cancelled
+    ) {
+        return Ok(false);
+    }
+    pipeline.status = PipelineStatus::Cancelled;
+    save(&app, &file)?;
+    emit_updated(&app, pipeline);
+
+    Ok(true)
+}
+
+/// Start (or resume) executing a pipeline from `current_step_index`. Returns once the
+/// pipeline finishes - callers that want progress as it happens should listen for
+/// `pipeline:updated` events instead of awaiting this to completion.
+#[tauri::command]
+pub async fn run_pipeline(app: AppHandle, pipeline_id: String) -> Result<Pipeline, String> {
+    let mut pipeline = {
+        let _lock = PIPELINE_LOCK.lock().unwrap();
+        let mut file = load(&app);
+        let Some(pipeline) = file.pipelines.iter_mut().find(|p| p.id == pipeline_id) else {
+            return Err(format!("Pipeline not found: {pipeline_id}"));
+        };
+        pipeline.status = PipelineStatus::Running;
+        let pipeline = pipeline.clone();
+        save(&app, &file)?;
+        emit_updated(&app, &pipeline);
+        pipeline
+    };
+
+    let mut previous_status: Option<StepStatus> = pipeline
+        .current_step_index
+        .checked_sub(1)
+        .and_then(|i| pipeline.steps.get(i))
+        .map(|s| s.status);
+    let mut any_failed = pipeline
+        .steps
+        .iter()
+        .any(|s| s.status == StepStatus::Failed);
+
+    while pipeline.current_step_index < pipeline.steps.len() {
+        {
+            let _lock = PIPELINE_LOCK.lock().unwrap();
+            let file = load(&app);
+            if let Some(p) = file.pipelines.iter().find(|p| p.id == pipeline_id) {
+                if p.status == PipelineStatus::Cancelled {
+                    return Ok(p.clone());
+                }
+            }
+        }
+
+        let index = pipeline.current_step_index;
+        let run_if = pipeline.steps[index].run_if;
+        let should_run = index == 0
+            || match (run_if, previous_status) {
+            (RunIf::Always, _) => true,
+            (RunIf::PreviousSucceeded, Some(StepStatus::Succeeded)) => true,
+            (RunIf::PreviousFailed, Some(StepStatus::Failed)) => true,
+            _ => false,
+        };
+
+        if !should_run {
+            pipeline.steps[index].status = StepStatus::Skipped;
+            previous_status = Some(StepStatus::Skipped);
+            pipeline.current_step_index += 1;
+            persist_and_emit(&app, &pipeline)?;
+            continue;
+        }
+
+        pipeline.steps[index].status = StepStatus::Running;
+        pipeline.steps[index].started_at = Some(super::run_log::now_timestamp());
+        persist_and_emit(&app, &pipeline)?;
+
+        let outcome = execute_step(&app, &pipeline, index).await;
+        pipeline.steps[index].finished_at = Some(super::run_log::now_timestamp());
+        match outcome {
+            Ok(output) => {
+                pipeline.steps[index].status = StepStatus::Succeeded;
+                pipeline.steps[index].output = Some(output);
+            }
+            Err(e) => {
+                pipeline.steps[index].status = StepStatus::Failed;
+                pipeline.steps[index].error = Some(e);
+                any_failed = true;
+            }
+        }
+
+        previous_status = Some(pipeline.steps[index].status);
+        pipeline.current_step_index += 1;
+        persist_and_emit(&app, &pipeline)?;
+    }
+
+    pipeline.status = if any_failed {
+        PipelineStatus::Failed
+    } else {
+        PipelineStatus::Completed
+    };
+    persist_and_emit(&app, &pipeline)?;
+
+    Ok(pipeline)
+}
+
+fn persist_and_emit(app: &AppHandle, pipeline: &Pipeline) -> Result<(), String> {
+    let _lock = PIPELINE_LOCK.lock().unwrap();
+    let mut file = load(app);
+    if let Some(existing) = file.pipelines.iter_mut().find(|p| p.id == pipeline.id) {
+        *existing = pipeline.clone();
+    } else {
+        file.pipelines.push(pipeline.clone());
+    }
+    save(app, &file)?;
+    emit_updated(app, pipeline);
+    Ok(())
+}
+
+/// Execute a single step and return its output text (for `Prompt`, the assistant's reply;
+/// for `ShellCommand`/`GitOperation`, combined stdout+stderr).
+async fn execute_step(
+    app: &AppHandle,
+    pipeline: &Pipeline,
+    index: usize,
+) -> Result<String, String> {
+    match &pipeline.steps[index].kind {
+        PipelineStepKind::Prompt {
+            message,
+            model,
+            execution_mode,
+            thinking_level,
+        } => {
+            let assistant_message = super::commands::send_chat_message(
+                app.clone(),
+                pipeline.session_id.clone(),
+                pipeline.worktree_id.clone(),
+                pipeline.worktree_path.clone(),
+                message.clone(),
+                model.clone(),
+                execution_mode.clone(),
+                thinking_level.clone(),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+            Ok(assistant_message.content)
+        }
+        PipelineStepKind::ShellCommand { command } => {
+            let branch = crate::projects::git::get_current_branch(&pipeline.worktree_path)
+                .unwrap_or_default();
+            crate::projects::git::run_hook_script(
+                &pipeline.worktree_path,
+                &pipeline.worktree_path,
+                &branch,
+                "pipeline_step",
+                command,
+                &super::env_vars::resolve_env_vars(
+                    app,
+                    &pipeline.worktree_id,
+                    &pipeline.session_id,
+                ),
+            )
+        }
+        PipelineStepKind::GitOperation { command, args } => {
+            let output = crate::platform::silent_command("git")
+                .arg(command)
+                .args(args)
+                .current_dir(&pipeline.worktree_path)
+                .output()
+                .map_err(|e| format!("Failed to run git {command}: {e}"))?;
+
+            let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            let combined = format!("{stdout}{stderr}").trim().to_string();
+
+            if output.status.success() {
+                Ok(combined)
+            } else {
+                Err(format!("git {command} failed:\n{combined}"))
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Computes a structured "blast radius" preview for a plan-mode message: which files it
+//! references, and whether those paths actually exist in the session's worktree. Meant for
+//! the approval UI to show alongside `mark_plan_approved`, not as a guarantee of what a plan
+//! will actually touch - it's a text-matching heuristic over the plan content, not a parse of
+//! Claude's intended tool calls.
+
+use regex::Regex;
+use tauri::AppHandle;
+
+use super::run_log::load_session_messages;
+use super::storage::load_metadata;
+use crate::projects::storage::load_projects_data;
+
+/// A single file path referenced by a plan, and whether it currently exists in the worktree.
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanImpactFile {
+    pub path: String,
+    pub exists: bool,
+}
+
+/// Structured impact preview for a plan message.
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanImpact {
+    pub message_id: String,
+    pub files: Vec<PlanImpactFile>,
+}
+
+/// Extract file-path-looking tokens from plan text: backtick-quoted spans containing a `/` or
+/// `.`, and bare tokens that contain a `/` and end in a file extension. Best-effort - plans
+/// are free-form prose, not a structured format.
+fn extract_referenced_paths(content: &str) -> Vec<String> {
+    let backtick_re = Regex::new(r"`([^`\s]+)`").expect("Invalid regex");
+    let bare_path_re =
+        Regex::new(r"\b[\w./-]*/[\w./-]+\.[A-Za-z0-9]{1,8}\b").expect("Invalid regex");
+
+    let looks_like_path = |s: &str| s.contains('/') || s.contains('.');
+
+    let mut paths: Vec<String> = backtick_re
+        .captures_iter(content)
+        .filter_map(|cap| cap.get(1).map(|m| m.as_str().to_string()))
+        .filter(|s| looks_like_path(s))
+        .collect();
+
+    paths.extend(
+        bare_path_re
+            .find_iter(content)
+            .map(|m| m.as_str().to_string()),
+    );
+
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// Parse the plan in `message_id` and resolve each referenced path against the session's
+/// worktree, so the caller can judge a plan's blast radius before `mark_plan_approved`.
+#[tauri::command]
+pub async fn get_plan_impact(
+    app: AppHandle,
+    session_id: String,
+    message_id: String,
+) -> Result<PlanImpact, String> {
+    log::trace!("Computing plan impact for message: {message_id}");
+
+    let messages = load_session_messages(&app, &session_id)?;
+    let message = messages
+        .iter()
+        .find(|m| m.id == message_id)
+        .ok_or_else(|| format!("Message not found: {message_id}"))?;
+
+    let worktree_path = load_metadata(&app, &session_id)?
+        .and_then(|metadata| {
+            let data = load_projects_data(&app).ok()?;
+            let worktree = data.find_worktree(&metadata.worktree_id)?;
+            Some(worktree.path.clone())
+        })
+        .unwrap_or_default();
+
+    let files = extract_referenced_paths(&message.content)
+        .into_iter()
+        .map(|path| {
+            let exists = if worktree_path.is_empty() {
+                false
+            } else {
+                std::path::Path::new(&worktree_path).join(&path).exists()
+            };
+            PlanImpactFile { path, exists }
+        })
+        .collect();
+
+    Ok(PlanImpact { message_id, files })
+}
@@ -0,0 +1,187 @@
+//! Per-session FIFO queue for chat messages sent while a run is already in progress.
+//!
+//! `commands::send_chat_message` enqueues instead of dispatching when
+//! `registry::is_process_running` reports the session busy, so the caller always gets an
+//! immediate response. Once the in-flight run finishes, `dispatch_next` pops the next
+//! queued message (if any) and resubmits it as a normal `send_chat_message` call.
+//!
+//! A session is busy while a process is registered via `registry::register_process`.
+//! `send_chat_message` runs every session through the Claude CLI, which registers its
+//! process, so queueing applies whatever the session's `selected_provider` is.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use tauri::AppHandle;
+use uuid::Uuid;
+
+use crate::http_server::EmitExt;
+
+use super::types::ThinkingLevel;
+
+/// A chat message waiting for the session's current run to finish.
+#[derive(Debug, Clone, Serialize)]
+pub struct QueuedMessage {
+    pub id: String,
+    pub session_id: String,
+    pub worktree_id: String,
+    pub worktree_path: String,
+    pub message: String,
+    pub model: Option<String>,
+    pub execution_mode: Option<String>,
+    pub thinking_level: Option<ThinkingLevel>,
+    pub disable_thinking_for_mode: Option<bool>,
+    pub parallel_execution_prompt_enabled: Option<bool>,
+    pub ai_language: Option<String>,
+    pub allowed_tools: Option<Vec<String>>,
+    pub queued_at: u64,
+}
+
+static QUEUES: Lazy<Mutex<HashMap<String, VecDeque<QueuedMessage>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Add a message to the back of `session_id`'s queue and emit `queue:updated`.
+#[allow(clippy::too_many_arguments)]
+pub fn enqueue(
+    app: &AppHandle,
+    session_id: String,
+    worktree_id: String,
+    worktree_path: String,
+    message: String,
+    model: Option<String>,
+    execution_mode: Option<String>,
+    thinking_level: Option<ThinkingLevel>,
+    disable_thinking_for_mode: Option<bool>,
+    parallel_execution_prompt_enabled: Option<bool>,
+    ai_language: Option<String>,
+    allowed_tools: Option<Vec<String>>,
+) -> QueuedMessage {
+    let queued = QueuedMessage {
+        id: Uuid::new_v4().to_string(),
+        session_id: session_id.clone(),
+        worktree_id,
+        worktree_path,
+        message,
+        model,
+        execution_mode,
+        thinking_level,
+        disable_thinking_for_mode,
+        parallel_execution_prompt_enabled,
+        ai_language,
+        allowed_tools,
+        queued_at: super::run_log::now_timestamp(),
+    };
+
+    QUEUES
+        .lock()
+        .unwrap()
+        .entry(session_id.clone())
+        .or_default()
+        .push_back(queued.clone());
+
+    emit_updated(app, &session_id);
+    queued
+}
+
+/// List messages currently queued for a session, oldest first.
+#[tauri::command]
+pub async fn list_queued_messages(session_id: String) -> Result<Vec<QueuedMessage>, String> {
+    Ok(queue_snapshot(&session_id))
+}
+
+fn queue_snapshot(session_id: &str) -> Vec<QueuedMessage> {
+    QUEUES
+        .lock()
+        .unwrap()
+        .get(session_id)
+        .map(|queue| queue.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Remove one queued message by ID. Returns `true` if it was found and removed.
+#[tauri::command]
+pub async fn cancel_queued_message(
+    app: AppHandle,
+    session_id: String,
+    message_id: String,
+) -> Result<bool, String> {
+    let removed = {
+        let mut queues = QUEUES.lock().unwrap();
+        match queues.get_mut(&session_id) {
+            Some(queue) => {
+                let before = queue.len();
+                queue.retain(|m| m.id != message_id);
+                before != queue.len()
+            }
+            None => false,
+        }
+    };
+
+    if removed {
+        emit_updated(&app, &session_id);
+    }
+    Ok(removed)
+}
+
+/// Pop the oldest queued message for a session, if any, and emit `queue:updated`.
+pub fn pop_next(app: &AppHandle, session_id: &str) -> Option<QueuedMessage> {
+    let next = QUEUES
+        .lock()
+        .unwrap()
+        .get_mut(session_id)
+        .and_then(|queue| queue.pop_front());
+
+    if next.is_some() {
+        emit_updated(app, session_id);
+    }
+    next
+}
+
+/// Payload for the `queue:updated` event, emitted whenever a session's queue changes.
+#[derive(Serialize, Clone)]
+struct QueueUpdatedEvent {
+    session_id: String,
+    messages: Vec<QueuedMessage>,
+}
+
+fn emit_updated(app: &AppHandle, session_id: &str) {
+    let _ = app.emit_all(
+        "queue:updated",
+        &QueueUpdatedEvent {
+            session_id: session_id.to_string(),
+            messages: queue_snapshot(session_id),
+        },
+    );
+}
+
+/// Dispatch the next queued message for a session, if any, as a fresh `send_chat_message`
+/// call. Runs on the async runtime so the caller whose run just finished isn't blocked.
+pub fn dispatch_next(app: AppHandle, session_id: String) {
+    let Some(queued) = pop_next(&app, &session_id) else {
+        return;
+    };
+
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = super::commands::send_chat_message(
+            app,
+            queued.session_id,
+            queued.worktree_id,
+            queued.worktree_path,
+            queued.message,
+            queued.model,
+            queued.execution_mode,
+            queued.thinking_level,
+            queued.disable_thinking_for_mode,
+            queued.parallel_execution_prompt_enabled,
+            queued.ai_language,
+            queued.allowed_tools,
+            None,
+        )
+        .await
+        {
+            log::warn!("Queued message failed to send: {e}");
+        }
+    });
+}
@@ -1,41 +1,97 @@
 use std::collections::HashMap;
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 use tauri::AppHandle;
 
 use super::claude::CancelledEvent;
 use super::run_log;
 use super::storage;
 use crate::http_server::EmitExt;
+use crate::platform::{sample_process_stats, ProcessStats};
 
-/// Global registry of running Claude process PIDs by session_id
-/// Allows cancellation of in-progress chat requests via SIGKILL
+/// A registered process's PID plus the OS-reported start time it had at registration, so a
+/// PID that's since been recycled by an unrelated process can be told apart from the one we
+/// actually spawned - see `cancel_process`. `started_at` is `None` if `sysinfo` couldn't read
+/// it (treated as "can't verify, trust the PID" rather than refusing to ever cancel).
+struct RegisteredProcess {
+    pid: u32,
+    started_at: Option<u64>,
+}
+
+/// Global registry of running Claude processes by session_id
+/// Allows cancellation of in-progress chat requests via a graceful signal ladder (see
+/// `cancel_process`)
 /// Key is session_id (not worktree_id) to support multiple concurrent sessions per worktree
-static PROCESS_REGISTRY: Lazy<Mutex<HashMap<String, u32>>> =
+static PROCESS_REGISTRY: Lazy<Mutex<HashMap<String, RegisteredProcess>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
-/// Register a running Claude process PID for a session
-pub fn register_process(session_id: String, pid: u32) {
+/// How long to wait for the process to exit on its own after each signal in the ladder
+/// before escalating to the next one.
+const SIGINT_GRACE_PERIOD: Duration = Duration::from_secs(3);
+const SIGTERM_GRACE_PERIOD: Duration = Duration::from_secs(2);
+const PROCESS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often `start_process_stats_sweep` samples and emits resource usage for registered
+/// processes. Short enough to feel live in a CPU/memory monitor UI, long enough that the
+/// `sysinfo` refresh it does each tick isn't a noticeable drain itself.
+const PROCESS_STATS_SWEEP_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Poll `is_process_alive` until it returns false or `timeout` elapses. Returns true if the
+/// process exited within the timeout.
+fn wait_for_exit(pid: u32, timeout: Duration) -> bool {
+    use crate::platform::is_process_alive;
+
+    let started = Instant::now();
+    while started.elapsed() < timeout {
+        if !is_process_alive(pid) {
+            return true;
+        }
+        std::thread::sleep(PROCESS_POLL_INTERVAL);
+    }
+    !is_process_alive(pid)
+}
+
+/// Register a running Claude process PID for a session, and persist it so it can be reaped
+/// as an orphan (see `process_reaper::reap_orphans`) if Jean crashes before it's unregistered.
+pub fn register_process(app: &AppHandle, session_id: String, pid: u32) {
+    let started_at = crate::platform::process_start_time(pid);
+    if started_at.is_none() {
+        log::warn!(
+            "Could not read start time for pid={pid}, session {session_id} - \
+             PID-reuse checks will be skipped for it"
+        );
+    }
+
     let mut registry = PROCESS_REGISTRY.lock().unwrap();
     log::trace!("Registering Claude process pid={pid} for session: {session_id}");
     log::trace!(
         "Registry state before insert: {:?}",
         registry.keys().collect::<Vec<_>>()
     );
-    registry.insert(session_id, pid);
+    registry.insert(session_id.clone(), RegisteredProcess { pid, started_at });
+    drop(registry);
+    crate::process_reaper::record_started(app, &session_id, ProcessKind::Chat, pid);
+    crate::power::job_started(app);
 }
 
 /// Remove a process from the registry (called after completion or cancellation)
-pub fn unregister_process(session_id: &str) {
+pub fn unregister_process(app: &AppHandle, session_id: &str) {
     let mut registry = PROCESS_REGISTRY.lock().unwrap();
-    if let Some(pid) = registry.remove(session_id) {
-        log::trace!("Unregistered Claude process {pid} for session: {session_id}");
+    if let Some(process) = registry.remove(session_id) {
+        log::trace!(
+            "Unregistered Claude process {} for session: {session_id}",
+            process.pid
+        );
     }
+    drop(registry);
+    crate::process_reaper::record_stopped(app, session_id);
+    crate::power::job_stopped();
 }
 
 /// Check if a session has a running process
-#[allow(dead_code)]
 pub fn is_process_running(session_id: &str) -> bool {
     PROCESS_REGISTRY.lock().unwrap().contains_key(session_id)
 }
@@ -45,56 +101,111 @@ pub fn get_running_sessions() -> Vec<String> {
     PROCESS_REGISTRY.lock().unwrap().keys().cloned().collect()
 }
 
-/// Cancel a running Claude process for a session by sending SIGKILL to the process group
-/// Returns true if a process was found and signal sent, false otherwise
+/// Cancel a running Claude process for a session.
+///
+/// By default, escalates through a graceful signal ladder - SIGINT, then (if it's still
+/// alive after a short grace period) SIGTERM, then (if it's *still* alive) SIGKILL - giving
+/// the CLI a chance to finish its current tool call and flush state instead of being killed
+/// mid-write. Pass `force: true` to skip straight to SIGKILL (the old behavior), e.g. when
+/// the caller doesn't want to block on the grace periods.
+///
+/// Returns true if a process was registered for the session (it is removed from the registry
+/// even when the PID-reuse check below skips the kill), false otherwise.
 ///
-/// SAFETY: We kill the entire process group (negative PID) to ensure all child processes
-/// spawned by Claude CLI are also terminated. This is safe because:
+/// `reason`, if given, is attached to the `chat:cancelled` event (e.g. `"timeout"` for an
+/// execution-timeout-triggered cancel) so the frontend can distinguish it from a
+/// user-initiated cancel. Pass `None` for the ordinary case.
+///
+/// SAFETY: We signal the entire process group (negative PID) to ensure all child processes
+/// spawned by Claude CLI are also reached. This is safe because:
 /// 1. Claude is spawned with process_group(0), creating a NEW group separate from Jean
 /// 2. We guard against dangerous PIDs (0, 1) that could affect system processes
 pub fn cancel_process(
     app: &AppHandle,
     session_id: &str,
     worktree_id: &str,
+    force: bool,
+    reason: Option<&str>,
 ) -> Result<bool, String> {
     let mut registry = PROCESS_REGISTRY.lock().unwrap();
-    log::trace!("cancel_process called for session: {session_id}");
+    log::trace!("cancel_process called for session: {session_id}, force: {force}");
     log::trace!("Registry state: {:?}", registry.iter().collect::<Vec<_>>());
 
-    if let Some(pid) = registry.remove(session_id) {
+    if let Some(process) = registry.remove(session_id) {
+        drop(registry);
+        crate::process_reaper::record_stopped(app, session_id);
+        crate::power::job_stopped();
+
+        let pid = process.pid;
+
         // SAFETY: Never kill PID 0 (would kill our own process group) or PID 1 (init/launchd)
         if pid == 0 || pid == 1 {
             log::error!("Refusing to kill dangerous PID: {pid}");
             return Err(format!("Invalid PID: {pid}"));
         }
 
-        log::trace!("Cancelling Claude process group {pid} for session: {session_id}");
-
-        // Kill the entire process tree to ensure child processes are also terminated
-        // Uses platform-specific implementation from the platform module
-        use crate::platform::{is_process_alive, kill_process, kill_process_tree};
+        use crate::platform::{
+            interrupt_process_tree, is_process_alive, kill_process, kill_process_tree,
+            process_start_time, terminate_process_tree,
+        };
 
-        log::trace!("Killing process tree for pid={pid}");
+        // Guard against PID reuse: on a busy system, by the time we get around to killing
+        // this PID it may no longer be the process we registered (ours exited and the OS
+        // recycled the PID for something unrelated). Re-check its start time before sending
+        // any signal. If we couldn't read a start time at registration, there's nothing to
+        // compare against, so fall back to trusting the PID as before.
+        let identity_matches = match process.started_at {
+            Some(expected) => process_start_time(pid) == Some(expected),
+            None => true,
+        };
 
-        // First, check if the process exists
-        if !is_process_alive(pid) {
-            log::warn!("Process {pid} check failed (may have exited)");
+        if !identity_matches {
+            log::warn!(
+                "PID {pid} for session {session_id} no longer matches the process we \
+                 registered (likely reused by an unrelated process) - skipping kill"
+            );
         } else {
-            log::trace!("Process {pid} exists, proceeding with kill");
-        }
+            log::trace!("Cancelling Claude process group {pid} for session: {session_id}");
 
-        // Kill the process tree (process group on Unix, taskkill /T on Windows)
-        if let Err(e) = kill_process_tree(pid) {
-            log::error!("Failed to kill process tree for pid={pid}: {e}");
-        } else {
-            log::trace!("Successfully sent kill to process tree pid={pid}");
-        }
+            if !is_process_alive(pid) {
+                log::warn!("Process {pid} check failed (may have exited)");
+            } else if force {
+                log::trace!("Force cancel requested, killing process tree for pid={pid} directly");
+            } else {
+                log::trace!("Sending SIGINT to process group {pid}");
+                if let Err(e) = interrupt_process_tree(pid) {
+                    log::warn!("Failed to interrupt process group {pid}: {e}");
+                } else if wait_for_exit(pid, SIGINT_GRACE_PERIOD) {
+                    log::trace!("Process {pid} exited after SIGINT");
+                } else {
+                    log::trace!("Process {pid} still alive after SIGINT, escalating to SIGTERM");
+                    if let Err(e) = terminate_process_tree(pid) {
+                        log::warn!("Failed to terminate process group {pid}: {e}");
+                    } else if wait_for_exit(pid, SIGTERM_GRACE_PERIOD) {
+                        log::trace!("Process {pid} exited after SIGTERM");
+                    } else {
+                        log::trace!(
+                            "Process {pid} still alive after SIGTERM, escalating to SIGKILL"
+                        );
+                    }
+                }
+            }
 
-        // Also try killing the process directly as fallback
-        if let Err(e) = kill_process(pid) {
-            log::trace!("Direct kill of pid={pid} failed (may be redundant): {e}");
-        } else {
-            log::trace!("Direct kill of pid={pid} succeeded");
+            // Kill the process tree (process group on Unix, taskkill /T on Windows). A no-op
+            // if the ladder above already got the process to exit, since `is_process_alive`
+            // would already be false - but cheap to call unconditionally as a final guarantee.
+            if let Err(e) = kill_process_tree(pid) {
+                log::error!("Failed to kill process tree for pid={pid}: {e}");
+            } else {
+                log::trace!("Successfully sent kill to process tree pid={pid}");
+            }
+
+            // Also try killing the process directly as fallback
+            if let Err(e) = kill_process(pid) {
+                log::trace!("Direct kill of pid={pid} failed (may be redundant): {e}");
+            } else {
+                log::trace!("Direct kill of pid={pid} succeeded");
+            }
         }
 
         // Update manifest SYNCHRONOUSLY before emitting event
@@ -108,6 +219,7 @@ pub fn cancel_process(
             session_id: session_id.to_string(),
             worktree_id: worktree_id.to_string(),
             undo_send: false, // Process was running, may have partial content
+            reason: reason.map(|r| r.to_string()),
         };
         if let Err(e) = app.emit_all("chat:cancelled", &event) {
             log::error!("Failed to emit chat:cancelled event: {e}");
@@ -121,7 +233,9 @@ pub fn cancel_process(
 }
 
 /// Cancel all running Claude processes for a given worktree
-/// Called before worktree deletion to clean up orphaned processes
+/// Called before worktree deletion to clean up orphaned processes - uses `force: true`
+/// since the worktree's files are about to be removed anyway, so there's nothing left to
+/// flush gracefully and no reason to block deletion on the signal ladder's grace periods.
 pub fn cancel_processes_for_worktree(app: &AppHandle, worktree_id: &str) {
     log::trace!("Cancelling all Claude processes for worktree: {worktree_id}");
 
@@ -130,7 +244,7 @@ pub fn cancel_processes_for_worktree(app: &AppHandle, worktree_id: &str) {
         Ok(sessions) => {
             let mut cancelled_count = 0;
             for session in &sessions.sessions {
-                if let Ok(true) = cancel_process(app, &session.id, worktree_id) {
+                if let Ok(true) = cancel_process(app, &session.id, worktree_id, true, None) {
                     cancelled_count += 1;
                 }
             }
@@ -146,3 +260,90 @@ pub fn cancel_processes_for_worktree(app: &AppHandle, worktree_id: &str) {
         }
     }
 }
+
+/// What kind of process a `TrackedProcessStats` entry is sampling, so the frontend can label
+/// it without guessing from the `id` shape. Also persisted by `process_reaper` so a reaped
+/// orphan can be reported with the same label.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessKind {
+    Chat,
+    Terminal,
+}
+
+/// Resource usage for one registered process, tagged with the session or terminal it belongs
+/// to. Emitted in bulk as `process:stats` and returned by `get_process_stats`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackedProcessStats {
+    /// `session_id` for `ProcessKind::Chat`, `terminal_id` for `ProcessKind::Terminal`.
+    pub id: String,
+    pub kind: ProcessKind,
+    pub stats: ProcessStats,
+}
+
+/// Sample CPU/memory/child-process-count for every currently registered Claude and terminal
+/// process. Processes that have exited since they were registered (but not yet unregistered)
+/// are silently dropped rather than erroring - see `sample_process_stats`.
+pub fn collect_process_stats() -> Vec<TrackedProcessStats> {
+    let chat_pids: Vec<(String, u32)> = {
+        let registry = PROCESS_REGISTRY.lock().unwrap();
+        registry
+            .iter()
+            .map(|(session_id, process)| (session_id.clone(), process.pid))
+            .collect()
+    };
+    let terminal_pids = crate::terminal::get_all_terminal_pids();
+
+    let all_pids: Vec<u32> = chat_pids
+        .iter()
+        .chain(terminal_pids.iter())
+        .map(|(_, pid)| *pid)
+        .collect();
+    let sampled_by_pid: HashMap<u32, ProcessStats> = sample_process_stats(&all_pids)
+        .into_iter()
+        .map(|stats| (stats.pid, stats))
+        .collect();
+
+    chat_pids
+        .into_iter()
+        .filter_map(|(id, pid)| {
+            sampled_by_pid
+                .get(&pid)
+                .cloned()
+                .map(|stats| TrackedProcessStats {
+                    id,
+                    kind: ProcessKind::Chat,
+                    stats,
+                })
+        })
+        .chain(terminal_pids.into_iter().filter_map(|(id, pid)| {
+            sampled_by_pid
+                .get(&pid)
+                .cloned()
+                .map(|stats| TrackedProcessStats {
+                    id,
+                    kind: ProcessKind::Terminal,
+                    stats,
+                })
+        }))
+        .collect()
+}
+
+/// Periodically sample and broadcast resource usage for every registered Claude and terminal
+/// process as a `process:stats` event, so a CPU/memory monitor UI doesn't need to poll
+/// `get_process_stats` itself.
+///
+/// Spawned once from `lib.rs::run()`, mirroring `activity::start_weekly_summary_sweep`'s shape:
+/// runs for the lifetime of the app regardless of window focus.
+pub fn start_process_stats_sweep(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(PROCESS_STATS_SWEEP_INTERVAL);
+        let stats = collect_process_stats();
+        if !stats.is_empty() {
+            if let Err(e) = app.emit_all("process:stats", &stats) {
+                log::warn!("Failed to emit process:stats: {e}");
+            }
+        }
+    });
+}
@@ -0,0 +1,212 @@
+//! Idle-session auto-archival and the background sweep that drives it.
+//!
+//! This is the missing piece between two things that already existed: sessions have had an
+//! `archived_at` timestamp and a manual "Archive" action for a while, and
+//! `projects::cleanup_old_archives`/`run_log_retention::compress_old_run_logs` already shrink
+//! and delete things *once archived*. Nothing previously archived a session just because it
+//! went quiet, and nothing ran the existing cleanup passes on a timer rather than once per
+//! app launch. This module adds both: `auto_archive_idle_sessions` stamps `archived_at` on
+//! sessions past `session_idle_archive_days` of inactivity, and `start_sweep` runs that plus
+//! the existing cleanup/compression passes on a recurring background timer, mirroring
+//! `schedule::start_poller`'s shape.
+
+use std::thread;
+use std::time::Duration;
+
+use tauri::AppHandle;
+
+use super::run_log::now_timestamp;
+use super::storage::{load_metadata, load_sessions, with_sessions_mut};
+use super::types::{RunStatus, SessionMetadata};
+use crate::projects::storage::load_projects_data;
+
+/// How often the background sweep checks for idle sessions and aging archives. Coarser than
+/// `schedule::POLL_INTERVAL_SECS` since retention thresholds are measured in days, not minutes.
+const SWEEP_INTERVAL_SECS: u64 = 6 * 60 * 60; // 6 hours
+
+/// Result of an `auto_archive_idle_sessions` pass.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct IdleArchivalSummary {
+    pub sessions_scanned: usize,
+    pub sessions_archived: usize,
+}
+
+/// A preview of what a retention sweep would do right now, without mutating anything - for a
+/// Settings UI "preview" action before the user commits to a policy.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct RetentionPreview {
+    pub sessions_to_archive: u32,
+    pub archived_worktrees_to_delete: u32,
+    pub archived_sessions_to_delete: u32,
+}
+
+/// A run in progress keeps a session from being auto-archived even if it's otherwise past
+/// the idle threshold - e.g. a long yolo-mode task left running overnight.
+fn has_running_run(metadata: &SessionMetadata) -> bool {
+    metadata.runs.iter().any(|r| r.status == RunStatus::Running)
+}
+
+/// Most recent activity in a session: its last run's start time, or its creation time if it
+/// has no runs yet.
+fn last_activity(metadata: &SessionMetadata) -> u64 {
+    metadata
+        .runs
+        .last()
+        .map(|r| r.started_at)
+        .unwrap_or(metadata.created_at)
+}
+
+/// Archive sessions that have had no activity for `idle_days`. `idle_days` of 0 disables this
+/// pass, matching `archive_retention_days`'s convention. Sessions in an already-archived
+/// worktree, already-archived sessions, and sessions with a run still in progress are skipped.
+pub fn auto_archive_idle_sessions(
+    app: &AppHandle,
+    idle_days: u32,
+) -> Result<IdleArchivalSummary, String> {
+    let mut summary = IdleArchivalSummary::default();
+    if idle_days == 0 {
+        return Ok(summary);
+    }
+
+    let cutoff = now_timestamp().saturating_sub(idle_days as u64 * 86400);
+    let data = load_projects_data(app)?;
+
+    for worktree in data.worktrees.iter().filter(|w| w.archived_at.is_none()) {
+        let sessions = match load_sessions(app, &worktree.path, &worktree.id) {
+            Ok(s) => s,
+            Err(e) => {
+                log::warn!("Failed to load sessions for worktree {}: {e}", worktree.id);
+                continue;
+            }
+        };
+
+        for session in sessions.sessions.iter().filter(|s| s.archived_at.is_none()) {
+            summary.sessions_scanned += 1;
+
+            let Ok(Some(metadata)) = load_metadata(app, &session.id) else {
+                continue;
+            };
+            if has_running_run(&metadata) || last_activity(&metadata) >= cutoff {
+                continue;
+            }
+
+            let session_id = session.id.clone();
+            let result = with_sessions_mut(app, &worktree.path, &worktree.id, |sessions| {
+                let session = sessions
+                    .find_session_mut(&session_id)
+                    .ok_or_else(|| format!("Session not found: {session_id}"))?;
+                session.archived_at = Some(now_timestamp());
+                Ok(())
+            });
+
+            match result {
+                Ok(()) => summary.sessions_archived += 1,
+                Err(e) => log::warn!("Failed to auto-archive session {session_id}: {e}"),
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Dry-run version of what a sweep would affect: idle sessions that would be archived under
+/// `idle_archive_days`, plus already-archived worktrees/sessions that would be deleted under
+/// `archive_retention_days` (mirroring `projects::cleanup_old_archives`'s cutoff, without
+/// performing the deletion or any git cleanup it also does).
+#[tauri::command]
+pub async fn preview_retention_policy(
+    app: AppHandle,
+    idle_archive_days: u32,
+    archive_retention_days: u32,
+) -> Result<RetentionPreview, String> {
+    let mut preview = RetentionPreview::default();
+    let data = load_projects_data(&app)?;
+
+    if idle_archive_days > 0 {
+        let idle_cutoff = now_timestamp().saturating_sub(idle_archive_days as u64 * 86400);
+        for worktree in data.worktrees.iter().filter(|w| w.archived_at.is_none()) {
+            let Ok(sessions) = load_sessions(&app, &worktree.path, &worktree.id) else {
+                continue;
+            };
+            for session in sessions.sessions.iter().filter(|s| s.archived_at.is_none()) {
+                if let Ok(Some(metadata)) = load_metadata(&app, &session.id) {
+                    if !has_running_run(&metadata) && last_activity(&metadata) < idle_cutoff {
+                        preview.sessions_to_archive += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    if archive_retention_days > 0 {
+        let delete_cutoff = now_timestamp().saturating_sub(archive_retention_days as u64 * 86400);
+        preview.archived_worktrees_to_delete = data
+            .worktrees
+            .iter()
+            .filter(|w| w.archived_at.is_some_and(|t| t < delete_cutoff))
+            .count() as u32;
+
+        for worktree in data.worktrees.iter().filter(|w| w.archived_at.is_none()) {
+            let Ok(sessions) = load_sessions(&app, &worktree.path, &worktree.id) else {
+                continue;
+            };
+            preview.archived_sessions_to_delete += sessions
+                .sessions
+                .iter()
+                .filter(|s| s.archived_at.is_some_and(|t| t < delete_cutoff))
+                .count() as u32;
+        }
+    }
+
+    Ok(preview)
+}
+
+/// Run one sweep: auto-archive idle sessions, then hand off to the existing
+/// archive-retention cleanup (deleting old archived items) and run-log compression passes.
+fn run_sweep_once(app: &AppHandle) {
+    let preferences = match crate::load_preferences_sync(app) {
+        Ok(p) => p,
+        Err(e) => {
+            log::warn!("Retention sweep skipped: failed to load preferences: {e}");
+            return;
+        }
+    };
+
+    match auto_archive_idle_sessions(app, preferences.session_idle_archive_days) {
+        Ok(summary) if summary.sessions_archived > 0 => {
+            log::info!(
+                "Retention sweep auto-archived {} of {} idle sessions",
+                summary.sessions_archived,
+                summary.sessions_scanned
+            );
+        }
+        Ok(_) => {}
+        Err(e) => log::warn!("Idle session auto-archival failed: {e}"),
+    }
+
+    let app = app.clone();
+    let archive_retention_days = preferences.archive_retention_days;
+    let run_log_retention_days = preferences.run_log_retention_days;
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) =
+            crate::projects::cleanup_old_archives(app.clone(), archive_retention_days).await
+        {
+            log::warn!("Archive cleanup failed during retention sweep: {e}");
+        }
+        if let Err(e) = crate::chat::compress_old_run_logs(app, run_log_retention_days).await {
+            log::warn!("Run log compression failed during retention sweep: {e}");
+        }
+    });
+}
+
+/// Start the background retention sweep.
+///
+/// Spawned once from `lib.rs::run()`, mirroring `schedule::start_poller`'s shape: runs for
+/// the lifetime of the app regardless of window focus, since idle sessions and aging
+/// archives accumulate whether or not anyone has the app open.
+pub fn start_sweep(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_secs(SWEEP_INTERVAL_SECS));
+        run_sweep_once(&app);
+    });
+}
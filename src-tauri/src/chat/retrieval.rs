@@ -0,0 +1,184 @@
+//! Lexical relevance retrieval over a worktree, to auto-attach the snippets most likely
+//! to matter for the current message instead of nothing (or everything).
+//!
+//! A real embedding index (local model or API) would need a vector store, an indexing
+//! pipeline kept in sync with the working tree, and - for an API-backed model - a per-call
+//! cost and network dependency that doesn't fit this app's "everything works offline"
+//! posture. This first slice instead scores files by plain term-frequency overlap with the
+//! query, which needs no index, no model, and no network call, and already beats attaching
+//! nothing on a large, unfamiliar repo. Swapping the scoring function for real embeddings
+//! later would not require changing `retrieve_relevant_context`'s signature or its caller
+//! in `claude::build_claude_args`.
+
+use ignore::WalkBuilder;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Files larger than this are skipped entirely - large generated/vendored files would
+/// dominate term-frequency scoring without being good handwritten context.
+const MAX_FILE_BYTES: u64 = 512 * 1024;
+
+/// Lines of context kept around the best-matching line in a file, on each side.
+const SNIPPET_CONTEXT_LINES: usize = 8;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelevantSnippet {
+    pub relative_path: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub snippet: String,
+    pub score: f64,
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|s| s.len() > 2)
+        .map(|s| s.to_lowercase())
+        .collect()
+}
+
+/// Score `content` against `query_terms` by raw term-frequency overlap, and return the
+/// 0-indexed line with the most term hits (for snippet extraction).
+fn score_content(content: &str, query_terms: &[String]) -> Option<(f64, usize)> {
+    if query_terms.is_empty() {
+        return None;
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut line_scores = vec![0.0_f64; lines.len()];
+    let mut total_score = 0.0_f64;
+
+    for (i, line) in lines.iter().enumerate() {
+        let line_lower = line.to_lowercase();
+        let mut hits = 0.0_f64;
+        for term in query_terms {
+            hits += line_lower.matches(term.as_str()).count() as f64;
+        }
+        line_scores[i] = hits;
+        total_score += hits;
+    }
+
+    if total_score == 0.0 {
+        return None;
+    }
+
+    let best_line = line_scores
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+
+    Some((total_score, best_line))
+}
+
+fn extract_snippet(content: &str, best_line: usize) -> (String, usize, usize) {
+    let lines: Vec<&str> = content.lines().collect();
+    let start = best_line.saturating_sub(SNIPPET_CONTEXT_LINES);
+    let end = (best_line + SNIPPET_CONTEXT_LINES + 1).min(lines.len());
+    let snippet = lines[start..end].join("\n");
+    (snippet, start + 1, end)
+}
+
+/// Find the `k` files in `worktree_path` most relevant to `query`, by term-frequency
+/// overlap, each represented as a snippet around its best-matching line rather than the
+/// whole file.
+pub fn retrieve_relevant_snippets(
+    worktree_path: &str,
+    query: &str,
+    k: usize,
+) -> Result<Vec<RelevantSnippet>, String> {
+    let query_terms = tokenize(query);
+    if query_terms.is_empty() || k == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Deduplicate query terms so a word repeated in the query (e.g. a repeated
+    // identifier) is scored once rather than linearly dominating the score.
+    let mut term_counts: HashMap<String, usize> = HashMap::new();
+    for term in &query_terms {
+        *term_counts.entry(term.clone()).or_insert(0) += 1;
+    }
+    let unique_terms: Vec<String> = term_counts.keys().cloned().collect();
+
+    let root = Path::new(worktree_path);
+    let walker = WalkBuilder::new(worktree_path)
+        .hidden(false)
+        .git_ignore(true)
+        .git_global(true)
+        .git_exclude(true)
+        .require_git(false)
+        .build();
+
+    let mut scored: Vec<(f64, usize, std::path::PathBuf)> = Vec::new();
+
+    for entry in walker {
+        let entry = match entry {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+        let path = entry.path();
+
+        if path == root || path.is_dir() {
+            continue;
+        }
+        if path.components().any(|c| c.as_os_str() == ".git") {
+            continue;
+        }
+        if let Ok(metadata) = entry.metadata() {
+            if metadata.len() > MAX_FILE_BYTES {
+                continue;
+            }
+        }
+
+        let content = match std::fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(_) => continue, // binary or unreadable
+        };
+
+        if let Some((score, best_line)) = score_content(&content, &unique_terms) {
+            scored.push((score, best_line, path.to_path_buf()));
+        }
+    }
+
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    scored.truncate(k);
+
+    let mut results = Vec::new();
+    for (score, best_line, path) in scored {
+        let content = match std::fs::read_to_string(&path) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+        let (snippet, start_line, end_line) = extract_snippet(&content, best_line);
+        let relative_path = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .to_string();
+
+        results.push(RelevantSnippet {
+            relative_path,
+            start_line,
+            end_line,
+            snippet,
+            score,
+        });
+    }
+
+    Ok(results)
+}
+
+/// Tauri command wrapper around [`retrieve_relevant_snippets`], for the frontend to call
+/// directly (e.g. to preview what would be auto-attached before sending a message).
+#[tauri::command]
+pub async fn retrieve_relevant_context(
+    worktree_path: String,
+    query: String,
+    k: Option<usize>,
+) -> Result<Vec<RelevantSnippet>, String> {
+    log::trace!("Retrieving relevant context for query in worktree: {worktree_path}");
+    retrieve_relevant_snippets(&worktree_path, &query, k.unwrap_or(5))
+}
@@ -14,7 +14,7 @@ use super::storage::{
     get_session_dir, list_all_session_ids, load_metadata, save_metadata, with_metadata_mut,
 };
 use super::types::{
-    ChatMessage, ContentBlock, MessageRole, RunEntry, RunStatus, ToolCall, UsageData,
+    ChatMessage, ContentBlock, MessagePage, MessageRole, RunEntry, RunStatus, ToolCall, UsageData,
 };
 
 // ============================================================================
@@ -68,6 +68,7 @@ impl RunLogWriter {
         let now = now_timestamp();
         let run_id = self.run_id.clone();
         let claude_sid = claude_session_id.map(|s| s.to_string());
+        let mut run_model: Option<String> = None;
 
         with_metadata_mut(
             &self.app,
@@ -82,6 +83,7 @@ impl RunLogWriter {
                     run.assistant_message_id = Some(assistant_message_id.to_string());
                     run.claude_session_id = claude_sid.clone();
                     run.usage = usage.clone();
+                    run_model = run.model.clone();
                 }
 
                 // Update metadata's claude_session_id for resumption
@@ -93,6 +95,23 @@ impl RunLogWriter {
             },
         )?;
 
+        if let Some(usage) = &usage {
+            super::usage::emit_usage_updated(
+                &self.app,
+                &self.session_id,
+                &self.worktree_id,
+                usage,
+                run_model.as_deref(),
+            );
+            super::budget::check_and_emit_warning(&self.app, &self.worktree_id);
+        }
+
+        super::context_usage::check_and_emit_warning(
+            &self.app,
+            &self.worktree_id,
+            &self.session_id,
+        );
+
         log::trace!("Run completed: {}", self.run_id);
         Ok(())
     }
@@ -172,6 +191,70 @@ impl RunLogWriter {
         Ok(())
     }
 
+    /// Record the output of the project's `pre_run` hook script for this run
+    pub fn set_pre_hook_output(&mut self, output: &str) -> Result<(), String> {
+        let run_id = self.run_id.clone();
+        let output = output.to_string();
+
+        with_metadata_mut(
+            &self.app,
+            &self.session_id,
+            &self.worktree_id,
+            &self.session_name,
+            self.order,
+            |metadata| {
+                if let Some(run) = metadata.find_run_mut(&run_id) {
+                    run.pre_hook_output = Some(output);
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// Record the ref name of the pre-run snapshot created for this run (see
+    /// `projects::git::create_snapshot`)
+    pub fn set_snapshot_ref(&mut self, snapshot_ref: &str) -> Result<(), String> {
+        let run_id = self.run_id.clone();
+        let snapshot_ref = snapshot_ref.to_string();
+
+        with_metadata_mut(
+            &self.app,
+            &self.session_id,
+            &self.worktree_id,
+            &self.session_name,
+            self.order,
+            |metadata| {
+                if let Some(run) = metadata.find_run_mut(&run_id) {
+                    run.snapshot_ref = Some(snapshot_ref);
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// Record the output of the project's `post_run` hook script for this run, or its
+    /// error if the hook failed (a post-run hook failure never affects run status).
+    pub fn set_post_hook_result(&mut self, result: Result<String, String>) -> Result<(), String> {
+        let run_id = self.run_id.clone();
+
+        with_metadata_mut(
+            &self.app,
+            &self.session_id,
+            &self.worktree_id,
+            &self.session_name,
+            self.order,
+            |metadata| {
+                if let Some(run) = metadata.find_run_mut(&run_id) {
+                    match &result {
+                        Ok(output) => run.post_hook_output = Some(output.clone()),
+                        Err(error) => run.hook_error = Some(error.clone()),
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+
     /// Get the path to the JSONL output file for this run
     pub fn output_file_path(&self) -> Result<PathBuf, String> {
         let session_dir = get_session_dir(&self.app, &self.session_id)?;
@@ -312,6 +395,10 @@ pub fn start_run(
         claude_session_id: None,
         pid: None,   // Set later via set_pid() after spawning detached process
         usage: None, // Set on completion via complete()
+        pre_hook_output: None,   // Set via set_pre_hook_output(), if a pre_run hook ran
+        post_hook_output: None,  // Set via set_post_hook_result(), after the run completes
+        hook_error: None,
+        snapshot_ref: None, // Set via set_snapshot_ref(), if pre-run snapshots are enabled
     };
 
     with_metadata_mut(
@@ -412,7 +499,11 @@ pub fn get_run_log_path(
     Ok(session_dir.join(format!("{run_id}.jsonl")))
 }
 
-/// Read all lines from a run's JSONL file
+/// Read all lines from a run's JSONL file.
+///
+/// Transparently falls back to `{run_id}.jsonl.gz` if the plain file is gone - retention
+/// rotation (see `run_log_retention`) compresses and removes the original once a run ages
+/// out, but readers shouldn't have to know which form is on disk.
 pub fn read_run_log(
     app: &tauri::AppHandle,
     session_id: &str,
@@ -420,16 +511,23 @@ pub fn read_run_log(
 ) -> Result<Vec<String>, String> {
     let path = get_run_log_path(app, session_id, run_id)?;
 
-    if !path.exists() {
-        return Ok(vec![]);
+    if path.exists() {
+        let file = File::open(&path).map_err(|e| format!("Failed to open run log: {e}"))?;
+        let reader = BufReader::new(file);
+        let lines: Result<Vec<_>, _> = reader.lines().collect();
+        return lines.map_err(|e| format!("Failed to read run log: {e}"));
     }
 
-    let file = File::open(&path).map_err(|e| format!("Failed to open run log: {e}"))?;
+    let gz_path = path.with_extension("jsonl.gz");
+    if !gz_path.exists() {
+        return Ok(vec![]);
+    }
 
-    let reader = BufReader::new(file);
+    let file = File::open(&gz_path).map_err(|e| format!("Failed to open compressed run log: {e}"))?;
+    let reader = BufReader::new(flate2::read::GzDecoder::new(file));
     let lines: Result<Vec<_>, _> = reader.lines().collect();
 
-    lines.map_err(|e| format!("Failed to read run log: {e}"))
+    lines.map_err(|e| format!("Failed to read compressed run log: {e}"))
 }
 
 /// Parse JSONL lines and build a ChatMessage
@@ -584,6 +682,9 @@ pub fn parse_run_to_message(lines: &[String], run: &RunEntry) -> Result<ChatMess
         thinking_level: None,
         recovered: run.recovered,
         usage: run.usage.clone(), // Token usage from metadata
+        queued: false,
+        offline_reason: None,
+        retry_count: None,
     })
 }
 
@@ -591,6 +692,65 @@ pub fn parse_run_to_message(lines: &[String], run: &RunEntry) -> Result<ChatMess
 // Message Loading
 // ============================================================================
 
+/// Whether a run is an instant-cancel with no visible history ("undo send"): these have
+/// `Cancelled` status but no `assistant_message_id`, and contribute no messages at all.
+fn is_undo_send(run: &RunEntry) -> bool {
+    run.status == RunStatus::Cancelled && run.assistant_message_id.is_none()
+}
+
+/// Build a run's user message from metadata alone - no disk I/O required.
+fn user_message_for_run(session_id: &str, run: &RunEntry) -> ChatMessage {
+    ChatMessage {
+        id: run.user_message_id.clone(),
+        session_id: session_id.to_string(),
+        role: MessageRole::User,
+        content: run.user_message.clone(),
+        timestamp: run.started_at,
+        tool_calls: vec![],
+        content_blocks: vec![],
+        cancelled: false,
+        plan_approved: false,
+        model: run.model.clone(),
+        execution_mode: run.execution_mode.clone(),
+        thinking_level: run.thinking_level.clone(),
+        recovered: false,
+        usage: None, // User messages don't have token usage
+        queued: false,
+        offline_reason: None,
+        retry_count: None,
+    }
+}
+
+/// Build a run's assistant message by reading and parsing its JSONL log file from disk.
+/// Returns `None` for runs that never produced an assistant message (still running, or
+/// an undo-send).
+fn assistant_message_for_run(
+    app: &tauri::AppHandle,
+    session_id: &str,
+    run: &RunEntry,
+) -> Result<Option<ChatMessage>, String> {
+    if run.status == RunStatus::Running || is_undo_send(run) {
+        return Ok(None);
+    }
+
+    let lines = read_run_log(app, session_id, &run.run_id)?;
+
+    // Parse JSONL content (may only have metadata header if crashed early)
+    let mut assistant_msg = parse_run_to_message(&lines, run)?;
+    assistant_msg.session_id = session_id.to_string();
+
+    // For crashed runs with no content (only metadata header), add placeholder
+    if run.status == RunStatus::Crashed
+        && assistant_msg.content.is_empty()
+        && assistant_msg.tool_calls.is_empty()
+    {
+        assistant_msg.content =
+            "*Response lost - Jean was closed before receiving a response.*".to_string();
+    }
+
+    Ok(Some(assistant_msg))
+}
+
 /// Load all messages for a session by parsing JSONL files
 /// Returns messages in chronological order (user message, then assistant response)
 pub fn load_session_messages(
@@ -605,52 +765,88 @@ pub fn load_session_messages(
     let mut messages = Vec::new();
 
     for run in &metadata.runs {
-        // Skip user message for instant-cancelled runs (undo_send)
-        // These have Cancelled status but no assistant_message_id
-        let is_undo_send = run.status == RunStatus::Cancelled && run.assistant_message_id.is_none();
-
-        if !is_undo_send {
-            // Add user message
-            messages.push(ChatMessage {
-                id: run.user_message_id.clone(),
-                session_id: session_id.to_string(),
-                role: MessageRole::User,
-                content: run.user_message.clone(),
-                timestamp: run.started_at,
-                tool_calls: vec![],
-                content_blocks: vec![],
-                cancelled: false,
-                plan_approved: false,
-                model: run.model.clone(),
-                execution_mode: run.execution_mode.clone(),
-                thinking_level: run.thinking_level.clone(),
-                recovered: false,
-                usage: None, // User messages don't have token usage
-            });
+        if !is_undo_send(run) {
+            messages.push(user_message_for_run(session_id, run));
+        }
+        if let Some(assistant_msg) = assistant_message_for_run(app, session_id, run)? {
+            messages.push(assistant_msg);
         }
+    }
+
+    Ok(messages)
+}
+
+/// Load one page of messages for a session, working backward from `before_message_id` (or
+/// from the end of the session if `before_message_id` is `None`) until `limit` messages have
+/// been collected, then returning them in chronological order.
+///
+/// Unlike `load_session_messages`, this never reads a run's log file from disk unless that
+/// run actually falls within the requested page: a run's "shape" (whether it has a user
+/// message, an assistant message, or both) is already known from `SessionMetadata` alone,
+/// so runs outside the page are skipped with no I/O at all. This keeps opening an old
+/// session over WebSocket fast regardless of how much history it has.
+pub fn load_session_messages_page(
+    app: &tauri::AppHandle,
+    session_id: &str,
+    limit: Option<usize>,
+    before_message_id: Option<&str>,
+) -> Result<MessagePage, String> {
+    let metadata = match load_metadata(app, session_id)? {
+        Some(m) => m,
+        None => return Ok(MessagePage { messages: vec![], has_more: false }),
+    };
 
-        // Add assistant message if run has completed/cancelled/crashed
-        if run.status != RunStatus::Running && !is_undo_send {
-            let lines = read_run_log(app, session_id, &run.run_id)?;
-
-            // Parse JSONL content (may only have metadata header if crashed early)
-            let mut assistant_msg = parse_run_to_message(&lines, run)?;
-            assistant_msg.session_id = session_id.to_string();
-
-            // For crashed runs with no content (only metadata header), add placeholder
-            if run.status == RunStatus::Crashed
-                && assistant_msg.content.is_empty()
-                && assistant_msg.tool_calls.is_empty()
-            {
-                assistant_msg.content =
-                    "*Response lost - Jean was closed before receiving a response.*".to_string();
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut skipping = before_message_id.is_some();
+    let mut page_runs: Vec<&RunEntry> = Vec::new();
+    let mut message_count = 0usize;
+    let mut has_more = false;
+
+    for run in metadata.runs.iter().rev() {
+        if skipping {
+            // `skipping` is only ever true when `before_message_id` is `Some`, so these
+            // comparisons can't spuriously match a run whose assistant message is also `None`.
+            let matches_cursor = before_message_id == Some(run.user_message_id.as_str())
+                || before_message_id == run.assistant_message_id.as_deref();
+            if matches_cursor {
+                skipping = false;
             }
+            continue;
+        }
+
+        let messages_in_run = if is_undo_send(run) {
+            0
+        } else if run.status == RunStatus::Running {
+            1
+        } else {
+            2
+        };
+        if messages_in_run == 0 {
+            continue;
+        }
 
+        if message_count >= limit {
+            has_more = true;
+            break;
+        }
+
+        page_runs.push(run);
+        message_count += messages_in_run;
+    }
+
+    page_runs.reverse();
+
+    let mut messages = Vec::new();
+    for run in page_runs {
+        if !is_undo_send(run) {
+            messages.push(user_message_for_run(session_id, run));
+        }
+        if let Some(assistant_msg) = assistant_message_for_run(app, session_id, run)? {
             messages.push(assistant_msg);
         }
     }
 
-    Ok(messages)
+    Ok(MessagePage { messages, has_more })
 }
 
 /// Mark any running run for this session as cancelled (called by cancel_process)
@@ -700,6 +896,21 @@ pub struct RecoveredRun {
     pub user_message: String,
     /// True if the process is still running and can be resumed
     pub resumable: bool,
+    /// Byte length of the run's JSONL log at the time this run was journaled for recovery -
+    /// how much of it was already written, in case a future reader wants to pick up tailing
+    /// from there instead of re-reading the whole file.
+    pub last_event_offset: u64,
+}
+
+/// Byte length of a run's JSONL output file, or 0 if it can't be read (deleted, never
+/// written, etc. - recovery should never fail just because this is unavailable).
+fn run_log_offset(app: &tauri::AppHandle, session_id: &str, run_id: &str) -> u64 {
+    get_session_dir(app, session_id)
+        .ok()
+        .map(|dir| dir.join(format!("{run_id}.jsonl")))
+        .and_then(|path| fs::metadata(path).ok())
+        .map(|m| m.len())
+        .unwrap_or(0)
 }
 
 /// Check for and recover incomplete runs across all sessions
@@ -734,6 +945,7 @@ pub fn recover_incomplete_runs(app: &tauri::AppHandle) -> Result<Vec<RecoveredRu
                         run_id: run.run_id.clone(),
                         user_message: run.user_message.clone(),
                         resumable: true,
+                        last_event_offset: run_log_offset(app, &session_id, &run.run_id),
                     });
 
                     log::trace!(
@@ -756,6 +968,7 @@ pub fn recover_incomplete_runs(app: &tauri::AppHandle) -> Result<Vec<RecoveredRu
                         run_id: run.run_id.clone(),
                         user_message: run.user_message.clone(),
                         resumable: false,
+                        last_event_offset: run_log_offset(app, &session_id, &run.run_id),
                     });
 
                     log::trace!(
@@ -783,6 +996,41 @@ pub fn recover_incomplete_runs(app: &tauri::AppHandle) -> Result<Vec<RecoveredRu
     Ok(recovered)
 }
 
+/// List runs already journaled as resumable or crashed (by a prior [`recover_incomplete_runs`]
+/// pass), without mutating anything. Unlike `recover_incomplete_runs`, safe to call repeatedly
+/// - e.g. from a "Recovery" settings pane the user can open any time - since it only reads
+/// whatever status startup recovery already settled on.
+pub fn list_recoverable_runs(app: &tauri::AppHandle) -> Result<Vec<RecoveredRun>, String> {
+    let session_ids = list_all_session_ids(app)?;
+    let mut recoverable = Vec::new();
+
+    for session_id in session_ids {
+        let metadata = match load_metadata(app, &session_id)? {
+            Some(m) => m,
+            None => continue,
+        };
+
+        for run in &metadata.runs {
+            let resumable = match run.status {
+                RunStatus::Resumable => true,
+                RunStatus::Crashed if run.recovered => false,
+                _ => continue,
+            };
+
+            recoverable.push(RecoveredRun {
+                session_id: session_id.clone(),
+                worktree_id: metadata.worktree_id.clone(),
+                run_id: run.run_id.clone(),
+                user_message: run.user_message.clone(),
+                resumable,
+                last_event_offset: run_log_offset(app, &session_id, &run.run_id),
+            });
+        }
+    }
+
+    Ok(recoverable)
+}
+
 /// Find all runs with status = Running (incomplete runs that need recovery)
 #[allow(dead_code)]
 pub fn find_incomplete_runs(
@@ -857,7 +1105,7 @@ pub fn delete_run_logs(app: &tauri::AppHandle, session_id: &str) -> Result<usize
 // Utility Functions
 // ============================================================================
 
-fn now_timestamp() -> u64 {
+pub(super) fn now_timestamp() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
@@ -0,0 +1,162 @@
+//! Size/age-based rotation and compression for per-run JSONL log files (see `run_log`).
+//!
+//! `run_log` gives every run its own `{run_id}.jsonl` file and never removes them, so a
+//! long-lived session's directory grows without bound. This module compresses completed
+//! runs' files in place once they're old enough or a session's total log size gets out of
+//! hand, and exposes `get_run_log`/`list_runs` so the frontend can still inspect old runs
+//! afterwards.
+//!
+//! This crate doesn't depend on zstd; `flate2` (gzip) is already a dependency used for gh
+//! CLI response decompression, so compression here reuses it rather than adding a new one.
+
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::Path;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use super::storage::{get_session_dir, list_all_session_ids, load_metadata};
+use super::types::{RunEntry, RunStatus};
+
+/// Combined size, in bytes, of a session's uncompressed run logs above which its oldest
+/// completed runs are compressed regardless of age. Protects against a single very chatty
+/// session ballooning disk usage before it ages past the retention window.
+const SIZE_ROTATION_THRESHOLD_BYTES: u64 = 50 * 1024 * 1024; // 50 MiB
+
+/// Result of a `compress_old_run_logs` pass.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RunLogCompactionSummary {
+    pub sessions_scanned: usize,
+    pub runs_compressed: usize,
+    pub bytes_reclaimed: u64,
+}
+
+/// Compress completed runs' JSONL files that are either older than `retention_days` or
+/// pushing their session's total log size over `SIZE_ROTATION_THRESHOLD_BYTES`.
+///
+/// Mirrors `projects::cleanup_old_archives`'s shape: `retention_days` of 0 disables the
+/// age-based pass, but the size-based pass still runs, since it guards against unbounded
+/// growth within the retention window rather than aging runs out. Compressed runs stay
+/// readable via `get_run_log`/`read_run_log` - nothing is deleted, only shrunk.
+#[tauri::command]
+pub async fn compress_old_run_logs(
+    app: tauri::AppHandle,
+    retention_days: u32,
+) -> Result<RunLogCompactionSummary, String> {
+    let cutoff = if retention_days == 0 {
+        None
+    } else {
+        Some(super::run_log::now_timestamp().saturating_sub(retention_days as u64 * 86400))
+    };
+
+    let mut summary = RunLogCompactionSummary {
+        sessions_scanned: 0,
+        runs_compressed: 0,
+        bytes_reclaimed: 0,
+    };
+
+    for session_id in list_all_session_ids(&app)? {
+        summary.sessions_scanned += 1;
+
+        let metadata = match load_metadata(&app, &session_id)? {
+            Some(m) => m,
+            None => continue,
+        };
+
+        let session_dir = get_session_dir(&app, &session_id)?;
+        if !session_dir.exists() {
+            continue;
+        }
+
+        // Oldest-first, so the size-based pass compresses the runs least likely to still
+        // be read before it ever reaches a recent one.
+        let mut completed: Vec<&RunEntry> = metadata
+            .runs
+            .iter()
+            .filter(|r| r.status != RunStatus::Running)
+            .collect();
+        completed.sort_by_key(|r| r.started_at);
+
+        let mut total_size: u64 = completed
+            .iter()
+            .filter_map(|r| session_dir.join(format!("{}.jsonl", r.run_id)).metadata().ok())
+            .map(|m| m.len())
+            .sum();
+
+        for run in completed {
+            let path = session_dir.join(format!("{}.jsonl", run.run_id));
+            if !path.exists() {
+                continue; // already compressed, or never written
+            }
+
+            let aged_out = cutoff.is_some_and(|c| run.ended_at.unwrap_or(run.started_at) < c);
+            let over_size_budget = total_size > SIZE_ROTATION_THRESHOLD_BYTES;
+            if !aged_out && !over_size_budget {
+                continue;
+            }
+
+            let original_size = path.metadata().map(|m| m.len()).unwrap_or(0);
+            match compress_run_log(&path) {
+                Ok(compressed_size) => {
+                    let reclaimed = original_size.saturating_sub(compressed_size);
+                    total_size = total_size.saturating_sub(reclaimed);
+                    summary.runs_compressed += 1;
+                    summary.bytes_reclaimed += reclaimed;
+                }
+                Err(e) => {
+                    log::warn!("Failed to compress run log {}: {e}", run.run_id);
+                }
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Gzip-compress `path` to `{path}.gz` and remove the original on success. Returns the
+/// compressed file's size in bytes.
+fn compress_run_log(path: &Path) -> Result<u64, String> {
+    let mut input = Vec::new();
+    File::open(path)
+        .and_then(|mut f| f.read_to_end(&mut input))
+        .map_err(|e| format!("Failed to read run log: {e}"))?;
+
+    let gz_path = path.with_extension("jsonl.gz");
+    let gz_file =
+        File::create(&gz_path).map_err(|e| format!("Failed to create compressed run log: {e}"))?;
+    let mut encoder = GzEncoder::new(gz_file, Compression::default());
+    encoder
+        .write_all(&input)
+        .map_err(|e| format!("Failed to write compressed run log: {e}"))?;
+    encoder
+        .finish()
+        .map_err(|e| format!("Failed to finalize compressed run log: {e}"))?;
+
+    let compressed_size = fs::metadata(&gz_path).map(|m| m.len()).unwrap_or(0);
+    fs::remove_file(path).map_err(|e| format!("Failed to remove uncompressed run log: {e}"))?;
+    Ok(compressed_size)
+}
+
+/// Get a single run's raw JSONL lines, transparently decompressing if it's been rotated.
+/// Thin wrapper around `run_log::read_run_log` for frontend consumption.
+#[tauri::command]
+pub async fn get_run_log(
+    app: tauri::AppHandle,
+    session_id: String,
+    run_id: String,
+) -> Result<Vec<String>, String> {
+    super::run_log::read_run_log(&app, &session_id, &run_id)
+}
+
+/// List a session's run metadata (without the JSONL content), so old runs stay inspectable
+/// - e.g. for a "load full transcript" action - without loading every run's content up
+/// front the way `load_session_messages` does.
+#[tauri::command]
+pub async fn list_runs(app: tauri::AppHandle, session_id: String) -> Result<Vec<RunEntry>, String> {
+    let metadata = match load_metadata(&app, &session_id)? {
+        Some(m) => m,
+        None => return Ok(vec![]),
+    };
+    Ok(metadata.runs)
+}
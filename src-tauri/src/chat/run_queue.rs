@@ -0,0 +1,220 @@
+//! Global cap on simultaneous AI runs across all worktrees, with a priority queue for
+//! runs that don't fit under the cap.
+//!
+//! Unlike `queue` (which only holds back a message sent to a session that's *already*
+//! busy), this queue holds back runs once `AppPreferences::max_concurrent_runs` active
+//! processes are already registered in `registry`, regardless of which session or
+//! worktree they belong to. `commands::send_chat_message` checks this queue after the
+//! per-session one, so a message only ever waits in one place at a time.
+//!
+//! Runs are dispatched highest `Project::run_priority` first, ties broken FIFO by
+//! `queued_at`. `dispatch_next` is called from `send_chat_message` whenever a run
+//! finishes, freeing up a slot for the next queued run.
+
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use tauri::AppHandle;
+use uuid::Uuid;
+
+use crate::http_server::EmitExt;
+use crate::projects::storage::load_projects_data;
+
+use super::types::ThinkingLevel;
+
+/// A run waiting for a free slot under `AppPreferences::max_concurrent_runs`.
+#[derive(Debug, Clone, Serialize)]
+pub struct QueuedRun {
+    pub id: String,
+    pub session_id: String,
+    pub worktree_id: String,
+    pub worktree_path: String,
+    pub message: String,
+    pub model: Option<String>,
+    pub execution_mode: Option<String>,
+    pub thinking_level: Option<ThinkingLevel>,
+    pub disable_thinking_for_mode: Option<bool>,
+    pub parallel_execution_prompt_enabled: Option<bool>,
+    pub ai_language: Option<String>,
+    pub allowed_tools: Option<Vec<String>>,
+    pub priority: i32,
+    pub queued_at: u64,
+}
+
+static RUN_QUEUE: Lazy<Mutex<Vec<QueuedRun>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Look up the `run_priority` of the project that owns `worktree_id`, defaulting to 0
+/// (normal priority) if the worktree or its project can't be resolved.
+fn priority_for_worktree(app: &AppHandle, worktree_id: &str) -> i32 {
+    let Ok(projects_data) = load_projects_data(app) else {
+        return 0;
+    };
+    let Some(worktree) = projects_data.find_worktree(worktree_id) else {
+        return 0;
+    };
+    projects_data
+        .find_project(&worktree.project_id)
+        .map(|p| p.run_priority)
+        .unwrap_or(0)
+}
+
+/// Add a run to the queue and emit `run_queue:updated`.
+#[allow(clippy::too_many_arguments)]
+pub fn enqueue(
+    app: &AppHandle,
+    session_id: String,
+    worktree_id: String,
+    worktree_path: String,
+    message: String,
+    model: Option<String>,
+    execution_mode: Option<String>,
+    thinking_level: Option<ThinkingLevel>,
+    disable_thinking_for_mode: Option<bool>,
+    parallel_execution_prompt_enabled: Option<bool>,
+    ai_language: Option<String>,
+    allowed_tools: Option<Vec<String>>,
+) -> QueuedRun {
+    let priority = priority_for_worktree(app, &worktree_id);
+    let queued = QueuedRun {
+        id: Uuid::new_v4().to_string(),
+        session_id,
+        worktree_id,
+        worktree_path,
+        message,
+        model,
+        execution_mode,
+        thinking_level,
+        disable_thinking_for_mode,
+        parallel_execution_prompt_enabled,
+        ai_language,
+        allowed_tools,
+        priority,
+        queued_at: super::run_log::now_timestamp(),
+    };
+
+    RUN_QUEUE.lock().unwrap().push(queued.clone());
+    emit_updated(app);
+    queued
+}
+
+/// List runs currently waiting, ordered highest priority first (FIFO within a priority).
+#[tauri::command]
+pub async fn list_queued_runs() -> Result<Vec<QueuedRun>, String> {
+    Ok(queue_snapshot())
+}
+
+fn queue_snapshot() -> Vec<QueuedRun> {
+    let mut runs = RUN_QUEUE.lock().unwrap().clone();
+    runs.sort_by(|a, b| b.priority.cmp(&a.priority).then(a.queued_at.cmp(&b.queued_at)));
+    runs
+}
+
+/// Remove one queued run by ID. Returns `true` if it was found and removed.
+#[tauri::command]
+pub async fn cancel_queued_run(app: AppHandle, run_id: String) -> Result<bool, String> {
+    let removed = {
+        let mut queue = RUN_QUEUE.lock().unwrap();
+        let before = queue.len();
+        queue.retain(|r| r.id != run_id);
+        before != queue.len()
+    };
+
+    if removed {
+        emit_updated(&app);
+    }
+    Ok(removed)
+}
+
+/// Remove and return the highest-priority queued run (FIFO within a priority), if any.
+fn pop_next(app: &AppHandle) -> Option<QueuedRun> {
+    let mut queue = RUN_QUEUE.lock().unwrap();
+    let next_index = queue
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.priority.cmp(&b.priority).then(b.queued_at.cmp(&a.queued_at)))
+        .map(|(index, _)| index)?;
+    let next = queue.remove(next_index);
+    drop(queue);
+    emit_updated(app);
+    Some(next)
+}
+
+/// Payload for the `run_queue:updated` event, emitted whenever the queue changes.
+#[derive(Serialize, Clone)]
+struct RunQueueUpdatedEvent {
+    runs: Vec<QueuedRun>,
+}
+
+/// Payload for the `run_queue:started` event, emitted when a queued run is dispatched.
+#[derive(Serialize, Clone)]
+struct RunQueueStartedEvent {
+    session_id: String,
+    worktree_id: String,
+}
+
+fn emit_updated(app: &AppHandle) {
+    let _ = app.emit_all(
+        "run_queue:updated",
+        &RunQueueUpdatedEvent {
+            runs: queue_snapshot(),
+        },
+    );
+}
+
+/// If a slot is free under `AppPreferences::max_concurrent_runs`, dispatch the next
+/// queued run (if any) as a fresh `send_chat_message` call. Called whenever a run
+/// finishes, so a freed slot doesn't sit idle. No-op if the queue is empty, no limit is
+/// configured, or the limit is already met (e.g. another session started a run in the
+/// same instant).
+pub fn dispatch_next(app: AppHandle) {
+    let max_concurrent_runs = match crate::load_preferences_sync(&app) {
+        Ok(prefs) => prefs.max_concurrent_runs,
+        Err(e) => {
+            log::warn!("Failed to load preferences for run queue dispatch: {e}");
+            return;
+        }
+    };
+
+    let Some(max_concurrent_runs) = max_concurrent_runs else {
+        return;
+    };
+
+    if super::registry::get_running_sessions().len() >= max_concurrent_runs as usize {
+        return;
+    }
+
+    let Some(queued) = pop_next(&app) else {
+        return;
+    };
+
+    let _ = app.emit_all(
+        "run_queue:started",
+        &RunQueueStartedEvent {
+            session_id: queued.session_id.clone(),
+            worktree_id: queued.worktree_id.clone(),
+        },
+    );
+
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = super::commands::send_chat_message(
+            app,
+            queued.session_id,
+            queued.worktree_id,
+            queued.worktree_path,
+            queued.message,
+            queued.model,
+            queued.execution_mode,
+            queued.thinking_level,
+            queued.disable_thinking_for_mode,
+            queued.parallel_execution_prompt_enabled,
+            queued.ai_language,
+            queued.allowed_tools,
+            None,
+        )
+        .await
+        {
+            log::warn!("Queued run failed to send: {e}");
+        }
+    });
+}
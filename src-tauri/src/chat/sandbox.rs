@@ -0,0 +1,150 @@
+//! Wraps the Claude CLI invocation with an OS-level sandbox when a project has opted in
+//! (see `Project::sandbox`), restricting filesystem writes to the run's worktree and
+//! optionally denying network access.
+//!
+//! Scope for this first pass: Linux (via `bwrap`/bubblewrap) and macOS (via the built-in
+//! `sandbox-exec`) are supported. Windows has no equivalent of either tool available by
+//! default - a real implementation would need an AppContainer, which is a much larger
+//! undertaking (manifest/capability setup, ACLs on the worktree) than fits here - so
+//! `wrap_command` on Windows logs a warning and runs the CLI unsandboxed rather than
+//! silently pretending to restrict it. If the platform's sandbox binary is missing (e.g.
+//! `bwrap` not installed), the same fallback applies: a best-effort feature shouldn't turn
+//! into a run that never starts.
+
+use std::path::{Path, PathBuf};
+
+use tauri::AppHandle;
+
+use crate::projects::storage::load_projects_data;
+use crate::projects::types::SandboxConfig;
+
+/// Resolve the sandbox settings to apply to `worktree_id`'s runs. Falls back to disabled if
+/// the worktree/project can't be looked up - a missing/unreadable config shouldn't block a
+/// run any more than a missing instructions document does (see
+/// `instructions::resolve_instructions`).
+pub fn resolve_sandbox_config(app: &AppHandle, worktree_id: &str) -> SandboxConfig {
+    load_projects_data(app)
+        .ok()
+        .and_then(|data| {
+            let worktree = data.find_worktree(worktree_id)?;
+            let project = data.find_project(&worktree.project_id)?;
+            Some(project.sandbox)
+        })
+        .unwrap_or_default()
+}
+
+/// Given the command that would normally be run (`cli_path` + `args`), return the command
+/// to actually run instead - either unchanged, or wrapped in a sandbox that confines
+/// filesystem writes to `worktree_path` (and denies network if configured). Never fails:
+/// any sandboxing problem (tool missing, unsupported platform) is logged and falls back to
+/// the unwrapped command.
+pub fn wrap_command(
+    cli_path: &Path,
+    args: &[String],
+    worktree_path: &Path,
+    config: &SandboxConfig,
+) -> (PathBuf, Vec<String>) {
+    if !config.enabled {
+        return (cli_path.to_path_buf(), args.to_vec());
+    }
+
+    match wrap_command_platform(cli_path, args, worktree_path, config) {
+        Some(wrapped) => wrapped,
+        None => (cli_path.to_path_buf(), args.to_vec()),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn wrap_command_platform(
+    cli_path: &Path,
+    args: &[String],
+    worktree_path: &Path,
+    config: &SandboxConfig,
+) -> Option<(PathBuf, Vec<String>)> {
+    if !crate::platform::executable_exists("bwrap") {
+        log::warn!("Sandbox requested but `bwrap` is not installed - running unsandboxed");
+        return None;
+    }
+
+    let mut bwrap_args = vec![
+        "--ro-bind".to_string(),
+        "/".to_string(),
+        "/".to_string(),
+        "--dev".to_string(),
+        "/dev".to_string(),
+        "--proc".to_string(),
+        "/proc".to_string(),
+        "--tmpfs".to_string(),
+        "/tmp".to_string(),
+        "--bind".to_string(),
+        worktree_path.to_string_lossy().to_string(),
+        worktree_path.to_string_lossy().to_string(),
+    ];
+    if config.disable_network {
+        bwrap_args.push("--unshare-net".to_string());
+    }
+    bwrap_args.push("--".to_string());
+    bwrap_args.push(cli_path.to_string_lossy().to_string());
+    bwrap_args.extend(args.iter().cloned());
+
+    Some((PathBuf::from("bwrap"), bwrap_args))
+}
+
+/// Escape `\` and `"` so a path can't break out of the quoted Scheme string literal it's
+/// interpolated into in the `sandbox-exec` profile below - a worktree path containing a `"`
+/// (legal on macOS) would otherwise close the literal early and let the rest of the path be
+/// parsed as additional profile directives, widening rather than narrowing the sandbox.
+#[cfg(target_os = "macos")]
+fn escape_profile_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(target_os = "macos")]
+fn wrap_command_platform(
+    cli_path: &Path,
+    args: &[String],
+    worktree_path: &Path,
+    config: &SandboxConfig,
+) -> Option<(PathBuf, Vec<String>)> {
+    if !crate::platform::executable_exists("sandbox-exec") {
+        log::warn!("Sandbox requested but `sandbox-exec` is not available - running unsandboxed");
+        return None;
+    }
+
+    let worktree = escape_profile_string(&worktree_path.to_string_lossy());
+    let network_clause = if config.disable_network {
+        "(deny network*)\n"
+    } else {
+        ""
+    };
+    let profile = format!(
+        "(version 1)\n\
+         (allow default)\n\
+         (deny file-write*)\n\
+         (allow file-write* (subpath \"{worktree}\"))\n\
+         {network_clause}"
+    );
+
+    let mut sandbox_args = vec![
+        "-p".to_string(),
+        profile,
+        cli_path.to_string_lossy().to_string(),
+    ];
+    sandbox_args.extend(args.iter().cloned());
+
+    Some((PathBuf::from("sandbox-exec"), sandbox_args))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn wrap_command_platform(
+    _cli_path: &Path,
+    _args: &[String],
+    _worktree_path: &Path,
+    _config: &SandboxConfig,
+) -> Option<(PathBuf, Vec<String>)> {
+    log::warn!(
+        "Sandbox mode was requested for this project, but sandboxing isn't supported on this \
+         platform yet - running unsandboxed"
+    );
+    None
+}
@@ -0,0 +1,280 @@
+//! Prompts scheduled to run at a future time, persisted across restarts and picked up by a
+//! dedicated background poller (started once from `lib.rs::run()`).
+//!
+//! Scope: one-shot schedules only, no recurring/cron expressions - a prompt fires once at
+//! `run_at` and moves from `Pending` to `Completed`/`Failed`. Recurring schedules would need
+//! a separate "next occurrence" computation and are left for a future request.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use uuid::Uuid;
+
+use crate::http_server::EmitExt;
+
+use super::types::ThinkingLevel;
+
+/// How often the background poller checks for due prompts.
+const POLL_INTERVAL_SECS: u64 = 15;
+
+/// Lifecycle of a scheduled prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduleStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+/// A chat message scheduled to be sent in a specific session at a future time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledPrompt {
+    pub id: String,
+    pub worktree_id: String,
+    pub worktree_path: String,
+    pub session_id: String,
+    pub message: String,
+    pub model: Option<String>,
+    pub execution_mode: Option<String>,
+    pub thinking_level: Option<ThinkingLevel>,
+    /// Unix timestamp (seconds) the prompt should fire at.
+    pub run_at: u64,
+    pub status: ScheduleStatus,
+    pub created_at: u64,
+    pub executed_at: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ScheduledPromptsFile {
+    prompts: Vec<ScheduledPrompt>,
+}
+
+/// Guards read-modify-write races on scheduled-prompts.json.
+static SCHEDULE_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+fn get_schedule_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = crate::data_dir::resolve(app)?;
+
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data directory: {e}"))?;
+
+    Ok(app_data_dir.join("scheduled-prompts.json"))
+}
+
+fn load(app: &AppHandle) -> ScheduledPromptsFile {
+    let path = match get_schedule_path(app) {
+        Ok(p) => p,
+        Err(_) => return ScheduledPromptsFile::default(),
+    };
+
+    if !path.exists() {
+        return ScheduledPromptsFile::default();
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => ScheduledPromptsFile::default(),
+    }
+}
+
+fn save(app: &AppHandle, file: &ScheduledPromptsFile) -> Result<(), String> {
+    let path = get_schedule_path(app)?;
+    let temp_path = path.with_extension("tmp");
+
+    let json = serde_json::to_string_pretty(file)
+        .map_err(|e| format!("Failed to serialize scheduled prompts: {e}"))?;
+
+    fs::write(&temp_path, &json)
+        .map_err(|e| format!("Failed to write scheduled prompts file: {e}"))?;
+
+    fs::rename(&temp_path, &path)
+        .map_err(|e| format!("Failed to finalize scheduled prompts file: {e}"))?;
+
+    Ok(())
+}
+
+/// Schedule `message` to be sent in `session_id` at `run_at` (unix seconds).
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn schedule_prompt(
+    app: AppHandle,
+    worktree_id: String,
+    worktree_path: String,
+    session_id: String,
+    message: String,
+    run_at: u64,
+    model: Option<String>,
+    execution_mode: Option<String>,
+    thinking_level: Option<ThinkingLevel>,
+) -> Result<ScheduledPrompt, String> {
+    let _lock = SCHEDULE_LOCK.lock().unwrap();
+
+    let prompt = ScheduledPrompt {
+        id: Uuid::new_v4().to_string(),
+        worktree_id,
+        worktree_path,
+        session_id,
+        message,
+        model,
+        execution_mode,
+        thinking_level,
+        run_at,
+        status: ScheduleStatus::Pending,
+        created_at: super::run_log::now_timestamp(),
+        executed_at: None,
+        error: None,
+    };
+
+    let mut file = load(&app);
+    file.prompts.push(prompt.clone());
+    save(&app, &file)?;
+    emit_updated(&app, &file);
+
+    Ok(prompt)
+}
+
+/// List all scheduled prompts (any status), oldest first.
+#[tauri::command]
+pub async fn list_scheduled_prompts(app: AppHandle) -> Result<Vec<ScheduledPrompt>, String> {
+    Ok(load(&app).prompts)
+}
+
+/// Cancel a pending scheduled prompt. Returns `false` if it was not found or already fired.
+#[tauri::command]
+pub async fn cancel_scheduled_prompt(app: AppHandle, id: String) -> Result<bool, String> {
+    let _lock = SCHEDULE_LOCK.lock().unwrap();
+
+    let mut file = load(&app);
+    let Some(prompt) = file.prompts.iter_mut().find(|p| p.id == id) else {
+        return Ok(false);
+    };
+    if prompt.status != ScheduleStatus::Pending {
+        return Ok(false);
+    }
+    prompt.status = ScheduleStatus::Cancelled;
+    save(&app, &file)?;
+    emit_updated(&app, &file);
+
+    Ok(true)
+}
+
+/// Payload for the `schedule:updated` event, emitted whenever a prompt is scheduled,
+/// cancelled, or finishes running.
+#[derive(Serialize, Clone)]
+struct ScheduledPromptsUpdatedEvent {
+    prompts: Vec<ScheduledPrompt>,
+}
+
+fn emit_updated(app: &AppHandle, file: &ScheduledPromptsFile) {
+    let _ = app.emit_all(
+        "schedule:updated",
+        &ScheduledPromptsUpdatedEvent {
+            prompts: file.prompts.clone(),
+        },
+    );
+}
+
+/// Start the background poller that fires due scheduled prompts.
+///
+/// Spawned once from `lib.rs::run()` and runs for the lifetime of the app regardless of
+/// window focus, since a scheduled prompt must fire whether or not the app is foregrounded.
+pub fn start_poller(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_secs(POLL_INTERVAL_SECS));
+
+        let now = super::run_log::now_timestamp();
+        let due: Vec<ScheduledPrompt> = {
+            let _lock = SCHEDULE_LOCK.lock().unwrap();
+            let mut file = load(&app);
+            let due: Vec<ScheduledPrompt> = file
+                .prompts
+                .iter()
+                .filter(|p| p.status == ScheduleStatus::Pending && p.run_at <= now)
+                .cloned()
+                .collect();
+
+            if due.is_empty() {
+                continue;
+            }
+
+            for prompt in &due {
+                if let Some(p) = file.prompts.iter_mut().find(|p| p.id == prompt.id) {
+                    p.status = ScheduleStatus::Running;
+                }
+            }
+            if let Err(e) = save(&app, &file) {
+                log::warn!("Failed to persist scheduled prompt status: {e}");
+            }
+            emit_updated(&app, &file);
+
+            due
+        };
+
+        for prompt in due {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                run_scheduled_prompt(app, prompt).await;
+            });
+        }
+    });
+}
+
+/// Execute one due prompt via the normal chat pipeline, persist its outcome, and notify.
+async fn run_scheduled_prompt(app: AppHandle, prompt: ScheduledPrompt) {
+    let result = super::commands::send_chat_message(
+        app.clone(),
+        prompt.session_id.clone(),
+        prompt.worktree_id.clone(),
+        prompt.worktree_path.clone(),
+        prompt.message.clone(),
+        prompt.model.clone(),
+        prompt.execution_mode.clone(),
+        prompt.thinking_level,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await;
+
+    {
+        let _lock = SCHEDULE_LOCK.lock().unwrap();
+        let mut file = load(&app);
+        if let Some(p) = file.prompts.iter_mut().find(|p| p.id == prompt.id) {
+            p.executed_at = Some(super::run_log::now_timestamp());
+            match &result {
+                Ok(_) => p.status = ScheduleStatus::Completed,
+                Err(e) => {
+                    p.status = ScheduleStatus::Failed;
+                    p.error = Some(e.clone());
+                }
+            }
+        }
+        if let Err(e) = save(&app, &file) {
+            log::warn!("Failed to persist scheduled prompt result: {e}");
+        }
+        emit_updated(&app, &file);
+    }
+
+    let (title, body) = match &result {
+        Ok(_) => (
+            "Scheduled prompt ran".to_string(),
+            Some(prompt.message.clone()),
+        ),
+        Err(e) => (
+            "Scheduled prompt failed".to_string(),
+            Some(format!("{}: {e}", prompt.message)),
+        ),
+    };
+    let _ = crate::send_native_notification(app, title, body).await;
+}
@@ -0,0 +1,139 @@
+//! Rollback for pre-run worktree snapshots (see `projects::git::create_snapshot`, created
+//! from `send_chat_message` when `AppPreferences::pre_run_snapshots_enabled` is on).
+
+use tauri::AppHandle;
+
+use super::storage::{get_session_dir, list_all_session_ids, load_metadata, with_metadata_mut};
+use crate::projects::storage::load_projects_data;
+
+/// A run's snapshot, for the "roll back to before this run" UI.
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotEntry {
+    pub run_id: String,
+    pub user_message: String,
+    pub started_at: u64,
+}
+
+/// List a session's runs that have a pre-run snapshot available to roll back to.
+#[tauri::command]
+pub async fn list_snapshots(
+    app: AppHandle,
+    session_id: String,
+) -> Result<Vec<SnapshotEntry>, String> {
+    let metadata = match load_metadata(&app, &session_id)? {
+        Some(m) => m,
+        None => return Ok(vec![]),
+    };
+
+    Ok(metadata
+        .runs
+        .iter()
+        .filter(|run| run.snapshot_ref.is_some())
+        .map(|run| SnapshotEntry {
+            run_id: run.run_id.clone(),
+            user_message: run.user_message.clone(),
+            started_at: run.started_at,
+        })
+        .collect())
+}
+
+/// Roll a session's worktree back to the state it was in right before `run_id` started,
+/// undoing everything that run (and anything after it) did - including uncommitted changes
+/// made since. Destructive and not itself undoable; the frontend should confirm first.
+#[tauri::command]
+pub async fn rollback_to_snapshot(
+    app: AppHandle,
+    session_id: String,
+    run_id: String,
+) -> Result<(), String> {
+    log::trace!("Rolling back session {session_id} to snapshot from run {run_id}");
+
+    let metadata = load_metadata(&app, &session_id)?
+        .ok_or_else(|| format!("Session not found: {session_id}"))?;
+
+    let run = metadata
+        .runs
+        .iter()
+        .find(|r| r.run_id == run_id)
+        .ok_or_else(|| format!("Run not found: {run_id}"))?;
+    let snapshot_ref = run
+        .snapshot_ref
+        .clone()
+        .ok_or_else(|| format!("Run {run_id} has no snapshot"))?;
+
+    let worktree_path = load_projects_data(&app)?
+        .find_worktree(&metadata.worktree_id)
+        .map(|w| w.path.clone())
+        .ok_or_else(|| format!("Worktree not found: {}", metadata.worktree_id))?;
+
+    crate::projects::git::rollback_to_snapshot(&worktree_path, &snapshot_ref)?;
+
+    log::trace!("Successfully rolled back session {session_id} to run {run_id}'s snapshot");
+    Ok(())
+}
+
+/// Garbage-collect snapshot refs for runs older than `retention_days` (0 = disabled), across
+/// every session. Mirrors `run_log_retention::compress_old_run_logs`'s age-cutoff shape.
+#[tauri::command]
+pub async fn gc_old_snapshots(app: AppHandle, retention_days: u32) -> Result<u32, String> {
+    if retention_days == 0 {
+        return Ok(0);
+    }
+    let cutoff = super::run_log::now_timestamp().saturating_sub(retention_days as u64 * 86400);
+
+    let mut deleted = 0u32;
+
+    for session_id in list_all_session_ids(&app)? {
+        let metadata = match load_metadata(&app, &session_id)? {
+            Some(m) => m,
+            None => continue,
+        };
+        if !get_session_dir(&app, &session_id)?.exists() {
+            continue;
+        }
+
+        let worktree_path = load_projects_data(&app)
+            .ok()
+            .and_then(|data| data.find_worktree(&metadata.worktree_id).map(|w| w.path.clone()));
+        let Some(worktree_path) = worktree_path else {
+            continue;
+        };
+
+        let stale_run_ids: Vec<String> = metadata
+            .runs
+            .iter()
+            .filter(|run| run.snapshot_ref.is_some() && run.started_at < cutoff)
+            .map(|run| run.run_id.clone())
+            .collect();
+
+        if stale_run_ids.is_empty() {
+            continue;
+        }
+
+        for run_id in &stale_run_ids {
+            match crate::projects::git::delete_snapshot_ref(&worktree_path, run_id) {
+                Ok(()) => deleted += 1,
+                Err(e) => log::warn!("Failed to delete snapshot ref for run {run_id}: {e}"),
+            }
+        }
+
+        with_metadata_mut(
+            &app,
+            &session_id,
+            &metadata.worktree_id,
+            &metadata.name,
+            metadata.order,
+            |metadata| {
+                for run in metadata.runs.iter_mut() {
+                    if stale_run_ids.contains(&run.run_id) {
+                        run.snapshot_ref = None;
+                    }
+                }
+                Ok(())
+            },
+        )?;
+    }
+
+    Ok(deleted)
+}
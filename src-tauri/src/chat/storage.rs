@@ -1,11 +1,11 @@
 use std::collections::HashMap;
-use std::fs::{self, File};
-use std::io::{BufReader, BufWriter};
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 use once_cell::sync::Lazy;
-use tauri::{AppHandle, Manager};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Emitter, Manager};
 
 use super::types::{
     SavedContextsMetadata, Session, SessionIndexEntry, SessionMetadata, WorktreeIndex,
@@ -38,8 +38,10 @@ fn get_index_lock(worktree_id: &str) -> Arc<Mutex<()>> {
         .clone()
 }
 
-/// Get or create a mutex for a specific session metadata
-fn get_metadata_lock(session_id: &str) -> Arc<Mutex<()>> {
+/// Get or create a mutex for a specific session metadata. `pub(crate)` so `sync.rs` can hold
+/// the same in-process lock while mirroring a session's `metadata.json` to/from the sync
+/// directory, preventing it from racing a concurrent `with_metadata_mut`/`save_metadata`.
+pub(crate) fn get_metadata_lock(session_id: &str) -> Arc<Mutex<()>> {
     let mut locks = METADATA_LOCKS.lock().unwrap();
     locks
         .entry(session_id.to_string())
@@ -64,6 +66,135 @@ pub fn sanitize_filename(name: &str) -> String {
         .collect()
 }
 
+// ============================================================================
+// Atomic Writes With Corruption Detection
+// ============================================================================
+//
+// Every document this module owns is written via temp-file + rename (so a crash mid-write
+// can never leave a half-written file at the real path) and gets a trailing length/checksum
+// footer, so a truncated or bit-flipped file can be told apart from a deliberately-empty or
+// merely-outdated one on load. When the footer doesn't check out, `read_checked` falls back
+// to `<path>.bak` - a copy of the last write that itself passed this same check - and emits
+// `storage:corruption-detected` so the frontend can surface that something was lost, either
+// way. Old files written before this existed have no footer and are treated as trusted,
+// since there's nothing to verify them against.
+
+const CHECKSUM_FOOTER_MARKER: &str = "\n--jean-checksum:v1:";
+
+/// Event emitted on `storage:corruption-detected` when `read_checked` has to fall back to
+/// (or fails to find) a backup.
+#[derive(Debug, Clone, serde::Serialize)]
+struct CorruptionDetectedEvent {
+    path: String,
+    recovered_from_backup: bool,
+}
+
+fn emit_corruption_detected(app: &AppHandle, path: &Path, recovered_from_backup: bool) {
+    let event = CorruptionDetectedEvent {
+        path: path.display().to_string(),
+        recovered_from_backup,
+    };
+    if let Err(e) = app.emit("storage:corruption-detected", &event) {
+        log::warn!("Failed to emit storage:corruption-detected event: {e}");
+    }
+}
+
+fn checksum_footer(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    format!(
+        "{CHECKSUM_FOOTER_MARKER}{}:{:x}--\n",
+        content.len(),
+        hasher.finalize()
+    )
+}
+
+/// Split a document's raw file contents into its body and, if a footer written by
+/// `checksum_footer` is present, the length/checksum it recorded.
+fn split_checksum_footer(raw: &str) -> (&str, Option<(usize, &str)>) {
+    let Some(marker_pos) = raw.rfind(CHECKSUM_FOOTER_MARKER) else {
+        return (raw, None);
+    };
+    let body = &raw[..marker_pos];
+    let footer = raw[marker_pos + CHECKSUM_FOOTER_MARKER.len()..]
+        .trim_end()
+        .trim_end_matches("--");
+    match footer.split_once(':') {
+        Some((len_str, checksum)) => match len_str.parse::<usize>() {
+            Ok(len) => (body, Some((len, checksum))),
+            Err(_) => (raw, None),
+        },
+        None => (raw, None),
+    }
+}
+
+/// Verify `body` against a length/checksum footer parsed by `split_checksum_footer`. A
+/// missing footer (file predates this check) is always treated as valid.
+fn verify_checksum_footer(body: &str, footer: Option<(usize, &str)>) -> bool {
+    let Some((len, checksum)) = footer else {
+        return true;
+    };
+    if body.len() != len {
+        return false;
+    }
+    let mut hasher = Sha256::new();
+    hasher.update(body.as_bytes());
+    format!("{:x}", hasher.finalize()) == checksum
+}
+
+/// Write `content` to `path` atomically (temp file + rename) with a trailing
+/// length/checksum footer. Before overwriting, backs up the current file to `path.bak` - but
+/// only if it still passes its own checksum, so a corrupt file is never allowed to clobber a
+/// good backup.
+fn write_checked_atomic(path: &Path, content: &str) -> Result<(), String> {
+    if let Ok(existing) = fs::read_to_string(path) {
+        let (body, footer) = split_checksum_footer(&existing);
+        if verify_checksum_footer(body, footer) {
+            let _ = fs::copy(path, path.with_extension("bak"));
+        }
+    }
+
+    let temp_path = path.with_extension("tmp");
+    let full_content = format!("{content}{}", checksum_footer(content));
+    fs::write(&temp_path, &full_content)
+        .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    fs::rename(&temp_path, path)
+        .map_err(|e| format!("Failed to finalize {}: {e}", path.display()))?;
+    Ok(())
+}
+
+/// Read `path`, verifying its checksum footer. Falls back to `path.bak` if the primary file
+/// is missing a valid footer (and that backup itself checks out), emitting
+/// `storage:corruption-detected` in either case so the frontend knows something was lost.
+/// Returns `Ok(None)` whenever the primary file does not exist - the normal "nothing saved
+/// yet" case; the backup is only consulted for a primary file that exists but fails its check.
+fn read_checked(app: &AppHandle, path: &Path) -> Result<Option<String>, String> {
+    if let Ok(raw) = fs::read_to_string(path) {
+        let (body, footer) = split_checksum_footer(&raw);
+        if verify_checksum_footer(body, footer) {
+            return Ok(Some(body.to_string()));
+        }
+        log::error!("Detected corrupted storage file: {}", path.display());
+    } else if !path.exists() {
+        return Ok(None);
+    }
+
+    let backup_path = path.with_extension("bak");
+    if let Ok(raw) = fs::read_to_string(&backup_path) {
+        let (body, footer) = split_checksum_footer(&raw);
+        if verify_checksum_footer(body, footer) {
+            emit_corruption_detected(app, path, true);
+            return Ok(Some(body.to_string()));
+        }
+    }
+
+    emit_corruption_detected(app, path, false);
+    Err(format!(
+        "{} is corrupted and no valid backup was found",
+        path.display()
+    ))
+}
+
 // ============================================================================
 // Directory Structure
 // ============================================================================
@@ -71,10 +202,7 @@ pub fn sanitize_filename(name: &str) -> String {
 /// Get the sessions base directory in app data (creates if not exists)
 /// Structure: sessions/
 pub fn get_sessions_dir(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
 
     let sessions_dir = app_data_dir.join("sessions");
 
@@ -149,12 +277,7 @@ pub fn get_base_index_path(app: &AppHandle, project_id: &str) -> Result<PathBuf,
 fn load_index_internal(app: &AppHandle, worktree_id: &str) -> Result<WorktreeIndex, String> {
     let path = get_index_path(app, worktree_id)?;
 
-    if path.exists() {
-        let contents = fs::read_to_string(&path).map_err(|e| {
-            log::error!("Failed to read index file: {e}");
-            format!("Failed to read index: {e}")
-        })?;
-
+    if let Some(contents) = read_checked(app, &path)? {
         let index: WorktreeIndex = serde_json::from_str(&contents).map_err(|e| {
             log::error!("Failed to parse index JSON: {e}");
             format!("Failed to parse index: {e}")
@@ -172,22 +295,13 @@ fn load_index_internal(app: &AppHandle, worktree_id: &str) -> Result<WorktreeInd
 fn save_index_internal(app: &AppHandle, index: &WorktreeIndex) -> Result<(), String> {
     log::trace!("Saving index for worktree: {}", index.worktree_id);
     let path = get_index_path(app, &index.worktree_id)?;
-    let temp_path = path.with_extension("tmp");
 
     let json_content = serde_json::to_string_pretty(index).map_err(|e| {
         log::error!("Failed to serialize index: {e}");
         format!("Failed to serialize index: {e}")
     })?;
 
-    fs::write(&temp_path, &json_content).map_err(|e| {
-        log::error!("Failed to write index file: {e}");
-        format!("Failed to write index: {e}")
-    })?;
-
-    fs::rename(&temp_path, &path).map_err(|e| {
-        log::error!("Failed to finalize index file: {e}");
-        format!("Failed to finalize index: {e}")
-    })?;
+    write_checked_atomic(&path, &json_content)?;
 
     log::trace!(
         "Saved {} sessions in index for worktree {}",
@@ -214,13 +328,17 @@ pub fn load_index(app: &AppHandle, worktree_id: &str) -> Result<WorktreeIndex, S
 }
 
 /// Atomically load, modify, and save a worktree index.
-/// This prevents race conditions by holding a lock for the entire operation.
+/// This prevents race conditions by holding a lock for the entire operation. The in-process
+/// mutex only serializes threads within this process, so a cross-process `FileLock` on the
+/// index file is also held for the same span, in case another Jean instance (native app +
+/// headless server, or two instances pointed at the same data directory) is touching it too.
 pub fn with_index_mut<F, T>(app: &AppHandle, worktree_id: &str, f: F) -> Result<T, String>
 where
     F: FnOnce(&mut WorktreeIndex) -> Result<T, String>,
 {
     let lock = get_index_lock(worktree_id);
     let _guard = lock.lock().unwrap();
+    let _file_lock = crate::platform::FileLock::acquire(&get_index_path(app, worktree_id)?)?;
 
     let mut index = load_index_internal(app, worktree_id)?;
     let result = f(&mut index)?;
@@ -240,15 +358,15 @@ fn load_metadata_internal(
 ) -> Result<Option<SessionMetadata>, String> {
     let path = get_metadata_path(app, session_id)?;
 
-    if !path.exists() {
+    let Some(contents) = read_checked(app, &path)? else {
         return Ok(None);
-    }
+    };
 
-    let file =
-        File::open(&path).map_err(|e| format!("Failed to open metadata file {path:?}: {e}"))?;
+    // `decrypt_string_if_encrypted` is a no-op on a plaintext file, so this is safe whether
+    // or not `AppPreferences::encryption_enabled` is currently set (see encryption.rs).
+    let contents = crate::encryption::decrypt_string_if_encrypted(&contents)?;
 
-    let reader = BufReader::new(file);
-    let metadata: SessionMetadata = serde_json::from_reader(reader)
+    let metadata: SessionMetadata = serde_json::from_str(&contents)
         .map_err(|e| format!("Failed to parse metadata file {path:?}: {e}"))?;
 
     Ok(Some(metadata))
@@ -257,16 +375,17 @@ fn load_metadata_internal(
 /// Save session metadata (internal, no locking - atomic write)
 fn save_metadata_internal(app: &AppHandle, metadata: &SessionMetadata) -> Result<(), String> {
     let path = get_metadata_path(app, &metadata.id)?;
-    let temp_path = path.with_extension("tmp");
 
-    let file = File::create(&temp_path)
-        .map_err(|e| format!("Failed to create temp metadata file: {e}"))?;
+    let json_content = serde_json::to_string_pretty(metadata)
+        .map_err(|e| format!("Failed to serialize metadata: {e}"))?;
 
-    let writer = BufWriter::new(file);
-    serde_json::to_writer_pretty(writer, metadata)
-        .map_err(|e| format!("Failed to write metadata: {e}"))?;
+    let encryption_enabled = crate::load_preferences_sync(app)
+        .map(|p| p.encryption_enabled)
+        .unwrap_or(false);
+    let json_content =
+        crate::encryption::encrypt_string_if_enabled(&json_content, encryption_enabled)?;
 
-    fs::rename(&temp_path, &path).map_err(|e| format!("Failed to rename metadata file: {e}"))?;
+    write_checked_atomic(&path, &json_content)?;
 
     log::trace!("Saved metadata for session: {}", metadata.id);
     Ok(())
@@ -301,6 +420,7 @@ where
 {
     let lock = get_metadata_lock(session_id);
     let _guard = lock.lock().unwrap();
+    let _file_lock = crate::platform::FileLock::acquire(&get_metadata_path(app, session_id)?)?;
 
     let mut metadata = load_metadata_internal(app, session_id)?.unwrap_or_else(|| {
         SessionMetadata::new(
@@ -386,9 +506,12 @@ pub fn load_sessions(
                     .as_secs(),
                 messages: vec![],
                 message_count: Some(entry.message_count),
+                has_more_messages: None,
                 claude_session_id: None,
                 selected_model: None,
+                selected_provider: None,
                 selected_thinking_level: None,
+                env_vars: vec![],
                 session_naming_completed: false,
                 archived_at: entry.archived_at,
                 answered_questions: vec![],
@@ -552,8 +675,8 @@ pub fn restore_base_sessions(
     }
 
     // Load the preserved index
-    let contents = fs::read_to_string(&preserved_path)
-        .map_err(|e| format!("Failed to read preserved index: {e}"))?;
+    let contents = read_checked(app, &preserved_path)?
+        .ok_or_else(|| "Preserved index file vanished before it could be read".to_string())?;
 
     let mut index: WorktreeIndex = serde_json::from_str(&contents)
         .map_err(|e| format!("Failed to parse preserved index: {e}"))?;
@@ -585,10 +708,7 @@ pub fn restore_base_sessions(
 /// Get the images directory path in app data directory (creates if not exists)
 /// Used for storing pasted images: ~/Library/Application Support/<app>/pasted-images/
 pub fn get_images_dir(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
 
     let path = app_data_dir.join("pasted-images");
 
@@ -600,10 +720,7 @@ pub fn get_images_dir(app: &AppHandle) -> Result<PathBuf, String> {
 /// Get the pastes directory path in app data directory (creates if not exists)
 /// Used for storing pasted text files: ~/Library/Application Support/<app>/pasted-texts/
 pub fn get_pastes_dir(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
 
     let path = app_data_dir.join("pasted-texts");
 
@@ -615,10 +732,7 @@ pub fn get_pastes_dir(app: &AppHandle) -> Result<PathBuf, String> {
 /// Get the saved contexts directory path in app data directory (creates if not exists)
 /// Used for storing conversation context summaries: ~/Library/Application Support/<app>/session-context/
 pub fn get_saved_contexts_dir(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
 
     let path = app_data_dir.join("session-context");
 
@@ -641,13 +755,13 @@ pub fn load_saved_contexts_metadata(app: &AppHandle) -> SavedContextsMetadata {
         Err(_) => return SavedContextsMetadata::default(),
     };
 
-    if !path.exists() {
-        return SavedContextsMetadata::default();
-    }
-
-    match fs::read_to_string(&path) {
-        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
-        Err(_) => SavedContextsMetadata::default(),
+    match read_checked(app, &path) {
+        Ok(Some(contents)) => serde_json::from_str(&contents).unwrap_or_default(),
+        Ok(None) => SavedContextsMetadata::default(),
+        Err(e) => {
+            log::error!("Failed to load saved contexts metadata: {e}");
+            SavedContextsMetadata::default()
+        }
     }
 }
 
@@ -659,14 +773,11 @@ pub fn save_saved_contexts_metadata(
     let _lock = SAVED_CONTEXTS_LOCK.lock().unwrap();
 
     let path = get_saved_contexts_metadata_path(app)?;
-    let temp_path = path.with_extension("tmp");
 
     let json = serde_json::to_string_pretty(metadata)
         .map_err(|e| format!("Failed to serialize metadata: {e}"))?;
 
-    fs::write(&temp_path, &json).map_err(|e| format!("Failed to write metadata file: {e}"))?;
-
-    fs::rename(&temp_path, &path).map_err(|e| format!("Failed to finalize metadata file: {e}"))?;
+    write_checked_atomic(&path, &json)?;
 
     Ok(())
 }
@@ -765,4 +876,56 @@ mod tests {
         assert!(metadata.runs.is_empty());
         assert_eq!(metadata.version, 1);
     }
+
+    #[test]
+    fn test_checksum_footer_roundtrip() {
+        let content = "{\"hello\":\"world\"}";
+        let footer = checksum_footer(content);
+        let full = format!("{content}{footer}");
+
+        let (body, parsed_footer) = split_checksum_footer(&full);
+        assert_eq!(body, content);
+        assert!(verify_checksum_footer(body, parsed_footer));
+    }
+
+    #[test]
+    fn test_verify_checksum_footer_detects_corruption() {
+        let content = "{\"hello\":\"world\"}";
+        let footer = checksum_footer(content);
+        let corrupted = format!("{{\"hello\":\"xxxxx\"}}{footer}");
+
+        let (body, parsed_footer) = split_checksum_footer(&corrupted);
+        assert!(!verify_checksum_footer(body, parsed_footer));
+    }
+
+    #[test]
+    fn test_verify_checksum_footer_missing_is_trusted() {
+        let (body, footer) = split_checksum_footer("no footer here");
+        assert_eq!(body, "no footer here");
+        assert!(footer.is_none());
+        assert!(verify_checksum_footer(body, footer));
+    }
+
+    #[test]
+    fn test_write_checked_atomic_creates_backup_on_rewrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.json");
+
+        write_checked_atomic(&path, "{\"v\":1}").unwrap();
+        let backup_path = path.with_extension("bak");
+        assert!(!backup_path.exists());
+
+        write_checked_atomic(&path, "{\"v\":2}").unwrap();
+        assert!(backup_path.exists());
+
+        let raw = fs::read_to_string(&path).unwrap();
+        let (body, footer) = split_checksum_footer(&raw);
+        assert_eq!(body, "{\"v\":2}");
+        assert!(verify_checksum_footer(body, footer));
+
+        let backup_raw = fs::read_to_string(&backup_path).unwrap();
+        let (backup_body, backup_footer) = split_checksum_footer(&backup_raw);
+        assert_eq!(backup_body, "{\"v\":1}");
+        assert!(verify_checksum_footer(backup_body, backup_footer));
+    }
 }
@@ -0,0 +1,110 @@
+//! Version-aware normalization of the Claude CLI's stream-json event types.
+//!
+//! `tail_claude_output` parses each NDJSON line's `"type"` field directly. CLI releases
+//! have occasionally renamed or restructured that field's values, and an unrecognized one
+//! used to fall through `tail_claude_output`'s catch-all match arm and silently drop
+//! whatever content it carried. `classify_event` gives that fallback a name, and
+//! `tail_claude_output` emits a `chat:unsupported_event` diagnostic (see
+//! `UnsupportedEventDiagnostic`) for anything it doesn't recognize, tagged with the
+//! detected CLI version, instead of dropping it.
+//!
+//! Only one wire format exists in the wild today, so `classify_event` has nothing to
+//! branch on yet beyond the type names `tail_claude_output` already understands - but the
+//! detected version is threaded through regardless, so a future CLI release that renames
+//! or adds an event type only needs a new arm here, not a hunt through the tailing loop.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+/// A Claude CLI version, as reported by `claude --version`, parsed into comparable parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CliVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl CliVersion {
+    /// Parse a version string like "1.0.28" or "1.0.28-beta.1". Returns `None` if it
+    /// doesn't have at least a major.minor.patch shape.
+    pub fn parse(version_str: &str) -> Option<Self> {
+        let mut parts = version_str.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        // The patch segment may carry a prerelease suffix (e.g. "28-beta.1") - only the
+        // leading digits are used.
+        let patch = parts
+            .next()?
+            .split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok()?;
+        Some(Self {
+            major,
+            minor,
+            patch,
+        })
+    }
+}
+
+impl std::fmt::Display for CliVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The detected CLI version, cached after the first spawn so later runs don't pay for an
+/// extra `--version` subprocess. `Some(None)` means detection was already attempted and
+/// failed (not installed, or `--version` didn't parse) - also cached, so we don't keep
+/// retrying a CLI that isn't there.
+static DETECTED_VERSION: Lazy<Mutex<Option<Option<CliVersion>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Detect (and cache) the installed Claude CLI's version for `binary_path`.
+pub fn detect_version(binary_path: &Path) -> Option<CliVersion> {
+    let mut cached = DETECTED_VERSION.lock().unwrap();
+    if let Some(version) = *cached {
+        return version;
+    }
+
+    let version =
+        crate::claude_cli::get_cli_version_sync(binary_path).and_then(|v| CliVersion::parse(&v));
+    *cached = Some(version);
+    version
+}
+
+/// The message `"type"` values `tail_claude_output` already knows how to handle, across
+/// every CLI version seen so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamEventKind {
+    Assistant,
+    User,
+    Result,
+    System,
+}
+
+/// Classify a raw NDJSON message's `"type"` field. Returns `None` for anything
+/// `tail_claude_output` doesn't have a handler for, in which case the caller should emit
+/// an `UnsupportedEventDiagnostic` instead of silently dropping the message.
+pub fn classify_event(msg_type: &str) -> Option<StreamEventKind> {
+    match msg_type {
+        "assistant" => Some(StreamEventKind::Assistant),
+        "user" => Some(StreamEventKind::User),
+        "result" => Some(StreamEventKind::Result),
+        "system" => Some(StreamEventKind::System),
+        _ => None,
+    }
+}
+
+/// Payload for the `chat:unsupported_event` diagnostic, emitted when a stream-json
+/// message's `"type"` isn't one `classify_event` recognizes - most likely because a CLI
+/// update renamed or introduced an event kind this version of Jean predates.
+#[derive(Debug, Clone, Serialize)]
+pub struct UnsupportedEventDiagnostic {
+    pub session_id: String,
+    pub worktree_id: String,
+    pub event_type: String,
+    pub cli_version: Option<String>,
+}
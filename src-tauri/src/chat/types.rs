@@ -161,6 +161,20 @@ pub struct ChatMessage {
     /// Token usage for this message (assistant messages only)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub usage: Option<UsageData>,
+    /// True if this message was queued behind an in-progress run rather than sent
+    /// immediately (see `queue::enqueue`); the queued user message, not a reply.
+    #[serde(default)]
+    pub queued: bool,
+    /// If `queued` because the Claude CLI backend was unavailable (not installed,
+    /// unauthenticated, or unreachable) rather than just busy, why - see
+    /// `offline_queue::enqueue`. `None` for messages queued for other reasons.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offline_reason: Option<String>,
+    /// Number of times the session was automatically resumed after a transient Claude CLI
+    /// error (API overloaded, network drop) before this message completed. `None` if it
+    /// succeeded on the first attempt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_count: Option<u32>,
 }
 
 impl Default for ChatMessage {
@@ -180,6 +194,9 @@ impl Default for ChatMessage {
             thinking_level: None,
             recovered: false,
             usage: None,
+            queued: false,
+            offline_reason: None,
+            retry_count: None,
         }
     }
 }
@@ -256,15 +273,27 @@ pub struct Session {
     /// Message count (populated separately for efficiency when full messages not needed)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub message_count: Option<u32>,
+    /// Whether older messages exist before the ones in `messages` (only set when `get_session`
+    /// was called with pagination parameters; `None` means the full history was loaded)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_more_messages: Option<bool>,
     /// Claude CLI session ID for resuming conversations
     #[serde(default)]
     pub claude_session_id: Option<String>,
     /// Selected model for this session
     #[serde(default)]
     pub selected_model: Option<String>,
+    /// Selected AI provider for this session (e.g. "claude"). Defaults to the Claude CLI
+    /// when unset, since that's the only provider that existed before this field was added.
+    #[serde(default)]
+    pub selected_provider: Option<String>,
     /// Selected thinking level for this session
     #[serde(default)]
     pub selected_thinking_level: Option<ThinkingLevel>,
+    /// Environment variables injected into the Claude CLI process for this session only.
+    /// Overrides a project-level entry of the same key (see `Project::env_vars`).
+    #[serde(default)]
+    pub env_vars: Vec<crate::projects::types::EnvVarEntry>,
     /// Whether session naming has been attempted for this session
     /// Prevents re-triggering on app restart
     #[serde(default)]
@@ -315,9 +344,12 @@ impl Session {
                 .as_secs(),
             messages: vec![],
             message_count: None,
+            has_more_messages: None,
             claude_session_id: None,
             selected_model: None,
+            selected_provider: None,
             selected_thinking_level: None,
+            env_vars: vec![],
             session_naming_completed: false,
             archived_at: None,
             // Session-specific UI state
@@ -447,9 +479,12 @@ impl SessionMetadata {
             created_at: self.created_at,
             messages: vec![], // Loaded separately from JSONL files
             message_count: Some(self.to_index_entry().message_count),
+            has_more_messages: None,
             claude_session_id: self.claude_session_id.clone(),
             selected_model: self.selected_model.clone(),
+            selected_provider: self.selected_provider.clone(),
             selected_thinking_level: self.selected_thinking_level.clone(),
+            env_vars: self.env_vars.clone(),
             session_naming_completed: self.session_naming_completed,
             archived_at: self.archived_at,
             answered_questions: self.answered_questions.clone(),
@@ -469,7 +504,9 @@ impl SessionMetadata {
         self.order = session.order;
         self.claude_session_id = session.claude_session_id.clone();
         self.selected_model = session.selected_model.clone();
+        self.selected_provider = session.selected_provider.clone();
         self.selected_thinking_level = session.selected_thinking_level.clone();
+        self.env_vars = session.env_vars.clone();
         self.session_naming_completed = session.session_naming_completed;
         self.archived_at = session.archived_at;
         self.answered_questions = session.answered_questions.clone();
@@ -615,6 +652,18 @@ pub struct AllSessionsResponse {
     pub entries: Vec<AllSessionsEntry>,
 }
 
+/// A page of messages for a session, for lazy-loading history instead of reading every
+/// run's log file from disk up front (see `run_log::load_session_messages_page`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessagePage {
+    /// Messages in chronological order, newest page boundary first in terms of age but
+    /// still chronologically ordered within the page
+    pub messages: Vec<ChatMessage>,
+    /// Whether older messages exist before this page (pass the oldest message's id as
+    /// `before_message_id` to fetch the next page)
+    pub has_more: bool,
+}
+
 // ============================================================================
 // Run Types (for NDJSON-based persistence)
 // ============================================================================
@@ -678,6 +727,22 @@ pub struct RunEntry {
     /// Token usage for this run (captured from Claude CLI result)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub usage: Option<UsageData>,
+    /// Output of the project's `pre_run` hook script (jean.json), if configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pre_hook_output: Option<String>,
+    /// Output of the project's `post_run` hook script (jean.json), if configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_hook_output: Option<String>,
+    /// Error from the most recent hook failure, if any (pre-run hook failures abort
+    /// the run before this is set; post-run hook failures are recorded here without
+    /// affecting the run's own status)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hook_error: Option<String>,
+    /// Git ref name for the pre-run worktree snapshot (see `projects::git::create_snapshot`),
+    /// if `AppPreferences::pre_run_snapshots_enabled` was on when this run started. Pass to
+    /// `rollback_to_snapshot` to fully undo everything this run did to the worktree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_ref: Option<String>,
 }
 
 /// Session metadata - single source of truth for session data and run history
@@ -702,9 +767,16 @@ pub struct SessionMetadata {
     /// Selected model for this session
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub selected_model: Option<String>,
+    /// Selected AI provider for this session (e.g. "claude")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_provider: Option<String>,
     /// Selected thinking level for this session
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub selected_thinking_level: Option<ThinkingLevel>,
+    /// Environment variables injected into the Claude CLI process for this session only.
+    /// Overrides a project-level entry of the same key (see `Project::env_vars`).
+    #[serde(default)]
+    pub env_vars: Vec<crate::projects::types::EnvVarEntry>,
     /// Whether session naming has been attempted
     #[serde(default)]
     pub session_naming_completed: bool,
@@ -742,6 +814,13 @@ pub struct SessionMetadata {
     #[serde(default)]
     pub runs: Vec<RunEntry>,
 
+    /// Digest text awaiting re-injection into the next message sent to the Claude CLI.
+    /// Set by `compaction::maybe_compact_session` when it clears `claude_session_id` to
+    /// start a fresh CLI conversation; consumed (and cleared) at the start of the next
+    /// `send_chat_message` call, since `--resume` is the only continuity the CLI has.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_compaction_digest: Option<String>,
+
     /// Storage format version for migrations
     #[serde(default = "default_manifest_version")]
     pub version: u32,
@@ -807,7 +886,9 @@ impl SessionMetadata {
                 .as_secs(),
             claude_session_id: None,
             selected_model: None,
+            selected_provider: None,
             selected_thinking_level: None,
+            env_vars: vec![],
             session_naming_completed: false,
             archived_at: None,
             answered_questions: vec![],
@@ -819,6 +900,7 @@ impl SessionMetadata {
             waiting_for_input: false,
             approved_plan_message_ids: vec![],
             runs: vec![],
+            pending_compaction_digest: None,
             version: 1,
         }
     }
@@ -843,11 +925,11 @@ impl SessionMetadata {
             .find_map(|r| r.claude_session_id.as_deref())
     }
 
-    /// Convert to a lightweight index entry for tab rendering
-    pub fn to_index_entry(&self) -> SessionIndexEntry {
-        // Count messages: each run has 1 user message, plus 1 assistant message if completed
-        let message_count: u32 = self
-            .runs
+    /// Count messages without reading any run log from disk: each run has 1 user message,
+    /// plus 1 assistant message if it produced one (a cancelled run with no reply counts as
+    /// 0), based purely on metadata already resident in memory.
+    pub fn count_messages(&self) -> u32 {
+        self.runs
             .iter()
             .map(|run| {
                 let is_undo_send =
@@ -860,18 +942,67 @@ impl SessionMetadata {
                     1 // just user (still running or cancelled without response)
                 }
             })
-            .sum();
+            .sum()
+    }
 
+    /// Convert to a lightweight index entry for tab rendering
+    pub fn to_index_entry(&self) -> SessionIndexEntry {
         SessionIndexEntry {
             id: self.id.clone(),
             name: self.name.clone(),
             order: self.order,
-            message_count,
+            message_count: self.count_messages(),
             archived_at: self.archived_at,
         }
     }
 }
 
+// ============================================================================
+// Model Comparison Types
+// ============================================================================
+
+/// Outcome of running the comparison prompt against one model, in its own temporary session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelCompareResult {
+    pub model: String,
+    /// The temporary session created to run this model's reply - left in place afterward so
+    /// the user can keep chatting with whichever model they preferred.
+    pub session_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<ChatMessage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Response from `compare_models`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompareModelsResponse {
+    pub results: Vec<ModelCompareResult>,
+}
+
+// ============================================================================
+// Broadcast Prompt Types
+// ============================================================================
+
+/// Outcome of running the broadcast prompt in one worktree's session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BroadcastPromptResult {
+    pub worktree_id: String,
+    /// The session created to run this worktree's reply - left in place afterward so the
+    /// user can review or continue the conversation there.
+    pub session_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<ChatMessage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Response from `broadcast_prompt`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BroadcastPromptResponse {
+    pub results: Vec<BroadcastPromptResult>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1178,6 +1309,10 @@ mod tests {
             claude_session_id: None,
             pid: Some(12345),
             usage: None,
+            pre_hook_output: None,
+            post_hook_output: None,
+            hook_error: None,
+            snapshot_ref: None,
         });
 
         assert!(metadata.find_run("run-1").is_some());
@@ -1213,6 +1348,10 @@ mod tests {
             claude_session_id: None,
             pid: None,
             usage: None,
+            pre_hook_output: None,
+            post_hook_output: None,
+            hook_error: None,
+            snapshot_ref: None,
         });
 
         assert!(metadata.latest_claude_session_id().is_none());
@@ -1234,6 +1373,10 @@ mod tests {
             claude_session_id: Some("claude-sess-abc".to_string()),
             pid: None,
             usage: None,
+            pre_hook_output: None,
+            post_hook_output: None,
+            hook_error: None,
+            snapshot_ref: None,
         });
 
         assert_eq!(metadata.latest_claude_session_id(), Some("claude-sess-abc"));
@@ -0,0 +1,275 @@
+//! Token usage and cost tracking reports.
+//!
+//! Per-run usage is already persisted via `RunEntry::usage` in each session's
+//! `metadata.json` (written by `run_log::RunLogWriter::complete`). This module
+//! turns that per-run data into an aggregated report across sessions, worktrees,
+//! projects, and models, and defines the `usage:updated` event emitted after each
+//! run completes so the UI can show burn rate without polling.
+//!
+//! Cost is estimated from a small built-in Claude pricing table (`pricing_for_model`).
+//! Other backends (`super::openai_compat`, `super::ollama`, `super::codex`) report real
+//! token counts but have no pricing data mapped yet, so their estimated cost is $0 —
+//! the alternative (guessing at a third-party provider's price) would be actively
+//! misleading.
+
+use serde::Serialize;
+use tauri::AppHandle;
+
+use super::storage::{list_all_session_ids, load_metadata};
+use super::types::UsageData;
+use crate::http_server::EmitExt;
+use crate::projects::storage::load_projects_data;
+
+/// Time window for a usage report.
+#[derive(Debug, Clone, Copy, Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageRange {
+    Today,
+    Last7Days,
+    Last30Days,
+    AllTime,
+}
+
+impl UsageRange {
+    /// Unix timestamp a run must have started at or after to be included. `None` for `AllTime`.
+    fn cutoff(self, now: u64) -> Option<u64> {
+        const DAY_SECS: u64 = 24 * 60 * 60;
+        match self {
+            UsageRange::Today => Some(now.saturating_sub(DAY_SECS)),
+            UsageRange::Last7Days => Some(now.saturating_sub(7 * DAY_SECS)),
+            UsageRange::Last30Days => Some(now.saturating_sub(30 * DAY_SECS)),
+            UsageRange::AllTime => None,
+        }
+    }
+}
+
+/// Dimension to aggregate a usage report by.
+#[derive(Debug, Clone, Copy, Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageGroupBy {
+    Session,
+    Worktree,
+    Project,
+    Model,
+}
+
+/// Aggregated usage and estimated cost for one group (or the report's overall total).
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct UsageReportEntry {
+    pub group_key: String,
+    pub group_label: String,
+    pub run_count: u64,
+    pub usage: UsageData,
+    pub estimated_cost_usd: f64,
+}
+
+impl UsageReportEntry {
+    fn add(&mut self, usage: &UsageData, model: Option<&str>) {
+        self.run_count += 1;
+        self.usage.input_tokens += usage.input_tokens;
+        self.usage.output_tokens += usage.output_tokens;
+        self.usage.cache_read_input_tokens += usage.cache_read_input_tokens;
+        self.usage.cache_creation_input_tokens += usage.cache_creation_input_tokens;
+        self.estimated_cost_usd += estimate_cost_usd(usage, model);
+    }
+}
+
+/// Full usage report returned by `get_usage_report`.
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageReport {
+    pub range: UsageRange,
+    pub group_by: UsageGroupBy,
+    pub entries: Vec<UsageReportEntry>,
+    pub total: UsageReportEntry,
+}
+
+/// USD price per million tokens for a model tier.
+struct ModelPricing {
+    input: f64,
+    output: f64,
+    cache_read: f64,
+    cache_write: f64,
+}
+
+/// Best-effort pricing lookup by substring match against Claude model names
+/// (e.g. "claude-opus-4-20250514"). Returns `None` for unrecognized models.
+fn pricing_for_model(model: &str) -> Option<ModelPricing> {
+    let model = model.to_lowercase();
+    if model.contains("opus") {
+        Some(ModelPricing {
+            input: 15.0,
+            output: 75.0,
+            cache_read: 1.50,
+            cache_write: 18.75,
+        })
+    } else if model.contains("sonnet") {
+        Some(ModelPricing {
+            input: 3.0,
+            output: 15.0,
+            cache_read: 0.30,
+            cache_write: 3.75,
+        })
+    } else if model.contains("haiku") {
+        Some(ModelPricing {
+            input: 0.80,
+            output: 4.0,
+            cache_read: 0.08,
+            cache_write: 1.0,
+        })
+    } else {
+        None
+    }
+}
+
+/// Estimate the USD cost of one run's usage. Unrecognized/missing models estimate to $0
+/// rather than guessing, since an overconfident wrong number is worse than an honest gap.
+fn estimate_cost_usd(usage: &UsageData, model: Option<&str>) -> f64 {
+    let Some(pricing) = model.and_then(pricing_for_model) else {
+        return 0.0;
+    };
+    let million = 1_000_000.0;
+    (usage.input_tokens as f64 / million) * pricing.input
+        + (usage.output_tokens as f64 / million) * pricing.output
+        + (usage.cache_read_input_tokens as f64 / million) * pricing.cache_read
+        + (usage.cache_creation_input_tokens as f64 / million) * pricing.cache_write
+}
+
+/// Build a token usage and cost report across all sessions, grouped and windowed as requested.
+#[tauri::command]
+pub async fn get_usage_report(
+    app: AppHandle,
+    range: UsageRange,
+    group_by: UsageGroupBy,
+) -> Result<UsageReport, String> {
+    let now = super::run_log::now_timestamp();
+    let cutoff = range.cutoff(now);
+
+    // Resolve worktree_id -> project label once, rather than per-run.
+    let projects_data = load_projects_data(&app)?;
+
+    let mut total = UsageReportEntry::default();
+    let mut entries: std::collections::HashMap<String, UsageReportEntry> =
+        std::collections::HashMap::new();
+
+    for session_id in list_all_session_ids(&app)? {
+        let Some(metadata) = load_metadata(&app, &session_id)? else {
+            continue;
+        };
+
+        for run in &metadata.runs {
+            let Some(usage) = &run.usage else { continue };
+            if let Some(cutoff) = cutoff {
+                if run.started_at < cutoff {
+                    continue;
+                }
+            }
+
+            let model = run.model.as_deref();
+            total.add(usage, model);
+
+            let (group_key, group_label) = match group_by {
+                UsageGroupBy::Session => (metadata.id.clone(), metadata.name.clone()),
+                UsageGroupBy::Worktree => (metadata.worktree_id.clone(), metadata.worktree_id.clone()),
+                UsageGroupBy::Project => {
+                    let project = projects_data
+                        .worktrees
+                        .iter()
+                        .find(|w| w.id == metadata.worktree_id)
+                        .and_then(|w| {
+                            projects_data
+                                .projects
+                                .iter()
+                                .find(|p| p.id == w.project_id)
+                        });
+                    match project {
+                        Some(p) => (p.id.clone(), p.name.clone()),
+                        None => ("unknown".to_string(), "Unknown project".to_string()),
+                    }
+                }
+                UsageGroupBy::Model => {
+                    let model = model.unwrap_or("unknown").to_string();
+                    (model.clone(), model)
+                }
+            };
+
+            entries
+                .entry(group_key.clone())
+                .or_insert_with(|| UsageReportEntry {
+                    group_key,
+                    group_label,
+                    ..Default::default()
+                })
+                .add(usage, model);
+        }
+    }
+
+    let mut entries: Vec<UsageReportEntry> = entries.into_values().collect();
+    entries.sort_by(|a, b| b.usage.output_tokens.cmp(&a.usage.output_tokens));
+
+    Ok(UsageReport {
+        range,
+        group_by,
+        entries,
+        total,
+    })
+}
+
+/// Sum estimated cost across all runs since `since`, optionally restricted to a set of
+/// worktree IDs (pass `None` for every worktree, used for a global budget scope).
+///
+/// Walks `list_all_session_ids` with its own per-session/per-run scan, reading the same
+/// run usage and pricing (`estimate_cost_usd`) as `get_usage_report`.
+pub(super) fn cost_since(
+    app: &AppHandle,
+    since: u64,
+    worktree_ids: Option<&std::collections::HashSet<String>>,
+) -> Result<f64, String> {
+    let mut total = 0.0;
+    for session_id in list_all_session_ids(app)? {
+        let Some(metadata) = load_metadata(app, &session_id)? else {
+            continue;
+        };
+        if let Some(worktree_ids) = worktree_ids {
+            if !worktree_ids.contains(&metadata.worktree_id) {
+                continue;
+            }
+        }
+        for run in &metadata.runs {
+            let Some(usage) = &run.usage else { continue };
+            if run.started_at < since {
+                continue;
+            }
+            total += estimate_cost_usd(usage, run.model.as_deref());
+        }
+    }
+    Ok(total)
+}
+
+/// Payload for the `usage:updated` event, emitted after each run completes.
+#[derive(Serialize, Clone)]
+pub struct UsageUpdatedEvent {
+    pub session_id: String,
+    pub worktree_id: String,
+    pub usage: UsageData,
+    pub estimated_cost_usd: f64,
+}
+
+/// Emit `usage:updated` for a just-completed run, so the UI can show burn rate
+/// without polling `get_usage_report` after every message.
+pub(super) fn emit_usage_updated(
+    app: &AppHandle,
+    session_id: &str,
+    worktree_id: &str,
+    usage: &UsageData,
+    model: Option<&str>,
+) {
+    let _ = app.emit_all(
+        "usage:updated",
+        &UsageUpdatedEvent {
+            session_id: session_id.to_string(),
+            worktree_id: worktree_id.to_string(),
+            usage: usage.clone(),
+            estimated_cost_usd: estimate_cost_usd(usage, model),
+        },
+    );
+}
@@ -69,25 +69,14 @@ pub struct InstallProgress {
     pub percent: u8,
 }
 
-/// Check if Claude CLI is installed and get its status
-#[tauri::command]
-pub async fn check_claude_cli_installed(app: AppHandle) -> Result<ClaudeCliStatus, String> {
-    log::trace!("Checking Claude CLI installation status");
-
-    let binary_path = get_cli_binary_path(&app)?;
-
-    if !binary_path.exists() {
-        log::trace!("Claude CLI not found at {:?}", binary_path);
-        return Ok(ClaudeCliStatus {
-            installed: false,
-            version: None,
-            path: None,
-        });
-    }
-
-    // Try to get the version by running claude --version
+/// Run `claude --version` against the binary at `binary_path` and parse its output.
+/// `None` if the binary can't be run or doesn't print a parseable version.
+///
+/// Synchronous so it can also be called from `chat::stream_format::detect_version` at
+/// spawn time, which runs outside the async Tauri command context.
+pub fn get_cli_version_sync(binary_path: &std::path::Path) -> Option<String> {
     // Use the binary directly - shell wrapper causes PowerShell parsing issues on Windows
-    let version = match silent_command(&binary_path).arg("--version").output() {
+    match silent_command(binary_path).arg("--version").output() {
         Ok(output) => {
             if output.status.success() {
                 let version_str = String::from_utf8_lossy(&output.stdout).trim().to_string();
@@ -106,7 +95,26 @@ pub async fn check_claude_cli_installed(app: AppHandle) -> Result<ClaudeCliStatu
             log::warn!("Failed to execute Claude CLI: {}", e);
             None
         }
-    };
+    }
+}
+
+/// Check if Claude CLI is installed and get its status
+#[tauri::command]
+pub async fn check_claude_cli_installed(app: AppHandle) -> Result<ClaudeCliStatus, String> {
+    log::trace!("Checking Claude CLI installation status");
+
+    let binary_path = get_cli_binary_path(&app)?;
+
+    if !binary_path.exists() {
+        log::trace!("Claude CLI not found at {:?}", binary_path);
+        return Ok(ClaudeCliStatus {
+            installed: false,
+            version: None,
+            path: None,
+        });
+    }
+
+    let version = get_cli_version_sync(&binary_path);
 
     Ok(ClaudeCliStatus {
         installed: true,
@@ -134,12 +142,31 @@ struct Manifest {
     platforms: std::collections::HashMap<String, PlatformInfo>,
 }
 
+/// Build an HTTP client for talking to the npm registry / Claude CLI distribution bucket,
+/// honoring `AppPreferences::cli_install_proxy` if the user has set one (e.g. because direct
+/// internet access is blocked on their network).
+fn build_http_client(app: &AppHandle) -> Result<reqwest::Client, String> {
+    let proxy_url = crate::load_preferences_sync(app)
+        .ok()
+        .and_then(|prefs| prefs.cli_install_proxy);
+
+    let mut builder = reqwest::Client::builder();
+    if let Some(proxy_url) = proxy_url {
+        let proxy = reqwest::Proxy::all(&proxy_url)
+            .map_err(|e| format!("Invalid CLI install proxy URL {proxy_url}: {e}"))?;
+        builder = builder.proxy(proxy);
+    }
+    builder
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))
+}
+
 /// Get available Claude CLI versions from npm registry
 #[tauri::command]
-pub async fn get_available_cli_versions() -> Result<Vec<ReleaseInfo>, String> {
+pub async fn get_available_cli_versions(app: AppHandle) -> Result<Vec<ReleaseInfo>, String> {
     log::trace!("Fetching available Claude CLI versions from npm registry");
 
-    let client = reqwest::Client::new();
+    let client = build_http_client(&app)?;
     let response = client
         .get("https://registry.npmjs.org/@anthropic-ai/claude-code")
         .send()
@@ -197,11 +224,11 @@ pub async fn get_available_cli_versions() -> Result<Vec<ReleaseInfo>, String> {
 }
 
 /// Fetch the latest version string from the distribution bucket
-async fn fetch_latest_version() -> Result<String, String> {
+async fn fetch_latest_version(app: &AppHandle) -> Result<String, String> {
     let url = format!("{CLAUDE_DIST_BUCKET}/latest");
     log::trace!("Fetching latest version from {url}");
 
-    let client = reqwest::Client::new();
+    let client = build_http_client(app)?;
     let response = client
         .get(&url)
         .send()
@@ -226,43 +253,51 @@ async fn fetch_latest_version() -> Result<String, String> {
     Ok(version)
 }
 
-/// Get the platform string for the current system
+/// Get the platform string for the current system. Uses the *runtime-detected* CPU
+/// architecture (see `platform::arch::host_arch`) rather than the architecture Jean was
+/// compiled for, so an x86_64 build running under Rosetta or Windows-on-ARM emulation still
+/// downloads the native binary.
 fn get_platform() -> Result<&'static str, String> {
-    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
-    {
-        return Ok("darwin-arm64");
-    }
+    use crate::platform::arch::{host_arch, HostArch};
 
-    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+    #[cfg(target_os = "linux")]
     {
-        return Ok("darwin-x64");
+        if crate::platform::arch::is_musl_libc() {
+            return Err(
+                "This host uses musl libc, but Claude CLI only publishes glibc binaries for Linux"
+                    .to_string(),
+            );
+        }
     }
 
-    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
-    {
-        return Ok("linux-x64");
-    }
+    #[cfg(target_os = "macos")]
+    return Ok(match host_arch() {
+        HostArch::Aarch64 => "darwin-arm64",
+        HostArch::X86_64 => "darwin-x64",
+    });
 
-    #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
-    {
-        return Ok("linux-arm64");
-    }
+    #[cfg(target_os = "linux")]
+    return Ok(match host_arch() {
+        HostArch::Aarch64 => "linux-arm64",
+        HostArch::X86_64 => "linux-x64",
+    });
 
-    #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
-    {
-        return Ok("win32-x64");
-    }
+    #[cfg(target_os = "windows")]
+    return Ok(match host_arch() {
+        HostArch::Aarch64 => "win32-arm64",
+        HostArch::X86_64 => "win32-x64",
+    });
 
     #[allow(unreachable_code)]
     Err("Unsupported platform".to_string())
 }
 
 /// Fetch the release manifest containing checksums for all platforms
-async fn fetch_manifest(version: &str) -> Result<Manifest, String> {
+async fn fetch_manifest(app: &AppHandle, version: &str) -> Result<Manifest, String> {
     let url = format!("{CLAUDE_DIST_BUCKET}/{version}/manifest.json");
     log::trace!("Fetching manifest from {url}");
 
-    let client = reqwest::Client::new();
+    let client = build_http_client(app)?;
     let response = client
         .get(&url)
         .send()
@@ -321,7 +356,7 @@ pub async fn install_claude_cli(app: AppHandle, version: Option<String>) -> Resu
     // Determine version (use provided or fetch stable)
     let version = match version {
         Some(v) => v,
-        None => fetch_latest_version().await?,
+        None => fetch_latest_version(&app).await?,
     };
 
     // Detect platform
@@ -335,7 +370,7 @@ pub async fn install_claude_cli(app: AppHandle, version: Option<String>) -> Resu
         "Fetching release manifest...",
         10,
     );
-    let manifest = fetch_manifest(&version).await?;
+    let manifest = fetch_manifest(&app, &version).await?;
     let expected_checksum = manifest
         .platforms
         .get(platform)
@@ -357,7 +392,7 @@ pub async fn install_claude_cli(app: AppHandle, version: Option<String>) -> Resu
     emit_progress(&app, "downloading", "Downloading Claude CLI...", 25);
 
     // Download the binary
-    let client = reqwest::Client::new();
+    let client = build_http_client(&app)?;
     let response = client
         .get(&download_url)
         .send()
@@ -436,6 +471,18 @@ pub async fn install_claude_cli(app: AppHandle, version: Option<String>) -> Resu
     Ok(())
 }
 
+/// How the Claude CLI is authenticated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaudeAuthMode {
+    /// Authenticated via an `ANTHROPIC_API_KEY` environment variable (pay-per-token billing).
+    ApiKey,
+    /// Authenticated via the CLI's own login flow (a Claude.ai Pro/Max subscription).
+    Subscription,
+    /// Not authenticated, or the mode couldn't be determined.
+    Unknown,
+}
+
 /// Result of checking Claude CLI authentication status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClaudeAuthStatus {
@@ -443,6 +490,35 @@ pub struct ClaudeAuthStatus {
     pub authenticated: bool,
     /// Error message if authentication check failed
     pub error: Option<String>,
+    /// How the CLI is authenticated, if known.
+    pub auth_mode: ClaudeAuthMode,
+    /// Current subscription plan name (e.g. "Pro", "Max"), if the CLI reported one.
+    /// Only ever set for `ClaudeAuthMode::Subscription` - API key billing has no "plan".
+    pub plan: Option<String>,
+    /// Remaining usage quota, in whatever form the CLI reported it (e.g. "80% remaining"),
+    /// if available. `None` doesn't mean quota is unlimited, just that nothing was reported.
+    pub quota_remaining: Option<String>,
+}
+
+/// Best-effort extraction of plan/quota info the CLI prints to stdout during an
+/// auth check, if any. The probe prompt here is intentionally trivial ("reply with OK")
+/// so there's rarely anything to find - this only catches it on builds that mention plan
+/// or quota status unprompted (e.g. a low-quota warning banner).
+fn extract_plan_and_quota(text: &str) -> (Option<String>, Option<String>) {
+    let plan_re = regex::Regex::new(r"(?i)plan:\s*([A-Za-z ]+)").expect("Invalid regex");
+    let quota_pattern = r"(?i)(\d+%\s*(?:remaining|used)|\d+\s*(?:requests?|tokens?)\s*remaining)";
+    let quota_re = regex::Regex::new(quota_pattern).expect("Invalid regex");
+
+    let plan = plan_re
+        .captures(text)
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().trim().to_string());
+    let quota_remaining = quota_re
+        .captures(text)
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().trim().to_string());
+
+    (plan, quota_remaining)
 }
 
 /// Check if Claude CLI is authenticated by running a simple query
@@ -456,9 +532,20 @@ pub async fn check_claude_cli_auth(app: AppHandle) -> Result<ClaudeAuthStatus, S
         return Ok(ClaudeAuthStatus {
             authenticated: false,
             error: Some("Claude CLI not installed".to_string()),
+            auth_mode: ClaudeAuthMode::Unknown,
+            plan: None,
+            quota_remaining: None,
         });
     }
 
+    // An API key takes precedence over a subscription login whenever the CLI is run - see
+    // `env_vars::resolve_env_vars` for how a project/session can also set one.
+    let auth_mode = if std::env::var("ANTHROPIC_API_KEY").is_ok() {
+        ClaudeAuthMode::ApiKey
+    } else {
+        ClaudeAuthMode::Subscription
+    };
+
     // Run a simple non-interactive query to check if authenticated
     // Use --print to avoid interactive mode, and a simple prompt
     log::trace!("Running auth check: {:?}", binary_path);
@@ -477,9 +564,22 @@ pub async fn check_claude_cli_auth(app: AppHandle) -> Result<ClaudeAuthStatus, S
     if output.status.success() {
         let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
         log::trace!("Claude CLI auth check successful, response: {}", stdout);
+
+        // The backend just confirmed it's usable - drain any messages that got queued
+        // while it wasn't (see `chat::offline_queue`).
+        crate::chat::dispatch_pending(app.clone());
+
+        let (plan, quota_remaining) = extract_plan_and_quota(&stdout);
         Ok(ClaudeAuthStatus {
             authenticated: true,
             error: None,
+            auth_mode,
+            plan: if auth_mode == ClaudeAuthMode::Subscription {
+                plan
+            } else {
+                None
+            },
+            quota_remaining,
         })
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
@@ -487,6 +587,9 @@ pub async fn check_claude_cli_auth(app: AppHandle) -> Result<ClaudeAuthStatus, S
         Ok(ClaudeAuthStatus {
             authenticated: false,
             error: Some(stderr),
+            auth_mode: ClaudeAuthMode::Unknown,
+            plan: None,
+            quota_remaining: None,
         })
     }
 }
@@ -1,7 +1,12 @@
 //! Configuration and path management for the embedded Claude CLI
+//!
+//! CLI resolution here is native on every platform - `CLI_BINARY_NAME`/`get_cli_binary_path`
+//! always point at a platform-native `claude`/`claude.exe` binary run directly via
+//! `std::process::Command`. There is no WSL shell-out anywhere in this codebase, so Windows
+//! users have never needed WSL installed to run Jean.
 
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use tauri::AppHandle;
 
 /// Directory name for storing the Claude CLI binary
 pub const CLI_DIR_NAME: &str = "claude-cli";
@@ -16,10 +21,7 @@ pub const CLI_BINARY_NAME: &str = "claude";
 ///
 /// Returns: `~/Library/Application Support/jean/claude-cli/`
 pub fn get_cli_dir(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
     Ok(app_data_dir.join(CLI_DIR_NAME))
 }
 
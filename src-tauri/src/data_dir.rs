@@ -0,0 +1,158 @@
+//! Lets Jean's data directory (sessions, archives, pasted images - everything normally
+//! written under the OS's per-app data directory) live somewhere other than the OS default,
+//! for users whose OS drive is small and whose sessions (especially with pasted images)
+//! grow fast.
+//!
+//! Two ways to override the OS default, checked in this order:
+//! 1. `--data-dir <path>` on the command line, captured once via [`set_cli_override_from_args`]
+//!    at startup - lasts for this process only, never written to disk. Mainly for
+//!    scripting/testing a location without committing to it.
+//! 2. A small `data-dir-override.txt` file naming the real data directory. It has to live at
+//!    the fixed OS-default location rather than inside the directory it points to, or it
+//!    would become unfindable the moment the data moved. [`migrate_data_dir`] is the only
+//!    thing that writes it, after copying existing data across.
+//!
+//! Every other module that persists something under the app data directory resolves it
+//! through [`resolve`] rather than calling `app.path().app_data_dir()` directly, so an
+//! override is honored uniformly everywhere instead of only for files this module knows
+//! about.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use tauri::{AppHandle, Manager};
+
+const OVERRIDE_MARKER_FILENAME: &str = "data-dir-override.txt";
+
+static CLI_OVERRIDE: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+/// Capture a `--data-dir <path>` argument from `args`, if present, for the lifetime of this
+/// process. Call once, early in `run()`, before anything resolves the data directory.
+pub fn set_cli_override_from_args(args: &[String]) {
+    let value = args
+        .iter()
+        .position(|a| a == "--data-dir")
+        .and_then(|i| args.get(i + 1))
+        .map(PathBuf::from);
+    let _ = CLI_OVERRIDE.set(value);
+}
+
+fn default_app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get OS-default app data directory: {e}"))
+}
+
+fn override_marker_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(default_app_data_dir(app)?.join(OVERRIDE_MARKER_FILENAME))
+}
+
+fn read_marker_override(app: &AppHandle) -> Option<PathBuf> {
+    let contents = std::fs::read_to_string(override_marker_path(app).ok()?).ok()?;
+    let trimmed = contents.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(PathBuf::from(trimmed))
+    }
+}
+
+/// Resolve the directory Jean should store its data in for `app`, honoring (in precedence
+/// order) a `--data-dir` CLI override, a persisted override written by `migrate_data_dir`,
+/// and finally the OS default.
+pub fn resolve(app: &AppHandle) -> Result<PathBuf, String> {
+    let resolved = if let Some(Some(dir)) = CLI_OVERRIDE.get() {
+        dir.clone()
+    } else if let Some(dir) = read_marker_override(app) {
+        dir
+    } else {
+        default_app_data_dir(app)?
+    };
+    Ok(crate::platform::paths::normalize(&resolved))
+}
+
+fn set_marker_override(app: &AppHandle, new_dir: &Path) -> Result<(), String> {
+    let marker_path = override_marker_path(app)?;
+    if let Some(parent) = marker_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {e}", parent.display()))?;
+    }
+    std::fs::write(&marker_path, new_dir.to_string_lossy().as_bytes())
+        .map_err(|e| format!("Failed to write {}: {e}", marker_path.display()))
+}
+
+fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(dst).map_err(|e| format!("Failed to create {}: {e}", dst.display()))?;
+    let entries =
+        std::fs::read_dir(src).map_err(|e| format!("Failed to read {}: {e}", src.display()))?;
+
+    for entry in entries.flatten() {
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+        if src_path.is_dir() {
+            copy_dir_recursive(&src_path, &dst_path)?;
+        } else {
+            std::fs::copy(&src_path, &dst_path)
+                .map_err(|e| format!("Failed to copy {}: {e}", src_path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Clear out everything under `dir` except the override marker itself, once its contents
+/// have already been copied elsewhere. Leaves `dir` in place (rather than removing it
+/// outright) since on the very first migration `dir` is the OS-default directory, which
+/// still needs to exist to hold the marker file pointing at the new location.
+fn clear_dir_contents_except_marker(dir: &Path) -> Result<(), String> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {e}", dir.display()))?;
+
+    for entry in entries.flatten() {
+        if entry.file_name() == OVERRIDE_MARKER_FILENAME {
+            continue;
+        }
+        let path = entry.path();
+        let result = if path.is_dir() {
+            std::fs::remove_dir_all(&path)
+        } else {
+            std::fs::remove_file(&path)
+        };
+        if let Err(e) = result {
+            log::warn!("Failed to remove {} while migrating data directory: {e}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Move Jean's entire data directory to `new_dir`: copies everything from the current
+/// location there, and only once that copy has fully succeeded does it persist `new_dir` as
+/// the override for all future launches and clear out the old location. A `--data-dir` CLI
+/// override (if set for this process) takes precedence over what this writes until the app
+/// is relaunched without it.
+#[tauri::command]
+pub async fn migrate_data_dir(app: AppHandle, new_dir: String) -> Result<(), String> {
+    let current_dir = resolve(&app)?;
+    let new_dir = PathBuf::from(new_dir);
+
+    if new_dir == current_dir {
+        return Err("New data directory is the same as the current one".to_string());
+    }
+    let new_dir_occupied = new_dir
+        .read_dir()
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+    if new_dir_occupied {
+        return Err("New data directory is not empty".to_string());
+    }
+
+    copy_dir_recursive(&current_dir, &new_dir)?;
+    set_marker_override(&app, &new_dir)?;
+    clear_dir_contents_except_marker(&current_dir)?;
+
+    log::info!(
+        "Migrated data directory from {} to {}",
+        current_dir.display(),
+        new_dir.display()
+    );
+    Ok(())
+}
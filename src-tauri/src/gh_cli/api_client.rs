@@ -0,0 +1,162 @@
+//! Direct REST client for the GitHub API, bypassing `gh` for hot call paths
+//!
+//! Most of Jean's GitHub integration shells out to the `gh` CLI, which is simple and reuses its
+//! auth and config, but spawning a process per call is slow - noticeable on paths like the
+//! background poller that run repeatedly. `GhApiClient` reads `gh`'s stored token once (via
+//! `gh auth token`) and makes REST calls directly with `reqwest`, using ETags so repeated polls
+//! of an unchanged resource cost a cheap 304 instead of a full payload. It falls back to `gh api`
+//! on any failure (offline `gh` auth helpers, corporate proxies, etc.), so callers get the same
+//! reliability as shelling out, just faster in the common case.
+//!
+//! This is additive: only `is_issue_closed` is wired up to it so far. Routing the rest of the
+//! `gh`-shelling call sites through this client is follow-up work.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::platform::silent_command;
+
+struct CacheEntry {
+    etag: String,
+    body: serde_json::Value,
+}
+
+/// Shared, long-lived client for direct GitHub REST API calls
+///
+/// Registered as managed Tauri state (see `lib.rs`) so the cached token and response cache
+/// persist across commands instead of being re-fetched on every call.
+pub struct GhApiClient {
+    http: reqwest::blocking::Client,
+    token: Mutex<Option<String>>,
+    cache: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl GhApiClient {
+    pub fn new() -> Self {
+        Self {
+            http: reqwest::blocking::Client::builder()
+                .user_agent("Jean-App/1.0")
+                .build()
+                .expect("failed to build reqwest client"),
+            token: Mutex::new(None),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Fetch (and cache for the life of the process) the token `gh` uses for API calls
+    fn token(&self, gh_binary: &Path) -> Result<String, String> {
+        if let Some(token) = self.token.lock().unwrap().as_ref() {
+            return Ok(token.clone());
+        }
+
+        let output = silent_command(gh_binary)
+            .args(["auth", "token"])
+            .output()
+            .map_err(|e| format!("Failed to run gh auth token: {e}"))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to get gh auth token: {stderr}"));
+        }
+
+        let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        *self.token.lock().unwrap() = Some(token.clone());
+        Ok(token)
+    }
+
+    /// GET a GitHub REST API path (e.g. `"repos/owner/repo/issues/1"`)
+    ///
+    /// Falls back to `gh api <path>` if the direct request fails for any reason.
+    pub fn get_json(&self, gh_binary: &Path, path: &str) -> Result<serde_json::Value, String> {
+        match self.get_json_direct(gh_binary, path) {
+            Ok(value) => Ok(value),
+            Err(e) => {
+                log::warn!("Direct GitHub API call to {path} failed, falling back to gh api: {e}");
+                self.get_json_via_cli(gh_binary, path)
+            }
+        }
+    }
+
+    fn get_json_direct(&self, gh_binary: &Path, path: &str) -> Result<serde_json::Value, String> {
+        let token = self.token(gh_binary)?;
+        let url = format!("https://api.github.com/{path}");
+
+        let cached_etag = self
+            .cache
+            .lock()
+            .unwrap()
+            .get(path)
+            .map(|entry| entry.etag.clone());
+
+        let mut request = self
+            .http
+            .get(&url)
+            .bearer_auth(&token)
+            .header("Accept", "application/vnd.github+json");
+        if let Some(etag) = &cached_etag {
+            request = request.header("If-None-Match", etag.clone());
+        }
+
+        let response = request
+            .send()
+            .map_err(|e| format!("Failed to reach GitHub API: {e}"))?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return self
+                .cache
+                .lock()
+                .unwrap()
+                .get(path)
+                .map(|entry| entry.body.clone())
+                .ok_or_else(|| "Received 304 Not Modified but have no cached body".to_string());
+        }
+
+        if !response.status().is_success() {
+            return Err(format!("GitHub API returned status: {}", response.status()));
+        }
+
+        let etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(|s| s.to_string());
+
+        let body: serde_json::Value = response
+            .json()
+            .map_err(|e| format!("Failed to parse GitHub API response: {e}"))?;
+
+        if let Some(etag) = etag {
+            self.cache.lock().unwrap().insert(
+                path.to_string(),
+                CacheEntry {
+                    etag,
+                    body: body.clone(),
+                },
+            );
+        }
+
+        Ok(body)
+    }
+
+    fn get_json_via_cli(&self, gh_binary: &Path, path: &str) -> Result<serde_json::Value, String> {
+        let output = silent_command(gh_binary)
+            .args(["api", path])
+            .output()
+            .map_err(|e| format!("Failed to run gh api: {e}"))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("gh api {path} failed: {stderr}"));
+        }
+
+        serde_json::from_slice(&output.stdout)
+            .map_err(|e| format!("Failed to parse gh api output: {e}"))
+    }
+}
+
+impl Default for GhApiClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
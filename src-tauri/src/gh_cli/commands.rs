@@ -2,6 +2,7 @@
 
 use crate::platform::silent_command;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tauri::AppHandle;
 
 use super::config::{ensure_gh_cli_dir, get_gh_cli_binary_path};
@@ -55,7 +56,6 @@ struct GitHubRelease {
 }
 
 #[derive(Debug, Deserialize)]
-#[allow(dead_code)]
 struct GitHubAsset {
     name: String,
     browser_download_url: String,
@@ -110,16 +110,31 @@ pub async fn check_gh_cli_installed(app: AppHandle) -> Result<GhCliStatus, Strin
     })
 }
 
+/// Build an HTTP client for talking to the GitHub releases API / CDN, honoring
+/// `AppPreferences::cli_install_proxy` if the user has set one (e.g. because direct internet
+/// access is blocked on their network).
+fn build_http_client(app: &AppHandle) -> Result<reqwest::Client, String> {
+    let proxy_url = crate::load_preferences_sync(app)
+        .ok()
+        .and_then(|prefs| prefs.cli_install_proxy);
+
+    let mut builder = reqwest::Client::builder().user_agent("Jean-App/1.0");
+    if let Some(proxy_url) = proxy_url {
+        let proxy = reqwest::Proxy::all(&proxy_url)
+            .map_err(|e| format!("Invalid CLI install proxy URL {proxy_url}: {e}"))?;
+        builder = builder.proxy(proxy);
+    }
+    builder
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))
+}
+
 /// Get available GitHub CLI versions from GitHub releases API
 #[tauri::command]
-pub async fn get_available_gh_versions() -> Result<Vec<GhReleaseInfo>, String> {
+pub async fn get_available_gh_versions(app: AppHandle) -> Result<Vec<GhReleaseInfo>, String> {
     log::trace!("Fetching available GitHub CLI versions from GitHub API");
 
-    let client = reqwest::Client::builder()
-        .user_agent("Jean-App/1.0")
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
-
+    let client = build_http_client(&app)?;
     let response = client
         .get(GITHUB_RELEASES_API)
         .send()
@@ -160,38 +175,42 @@ pub async fn get_available_gh_versions() -> Result<Vec<GhReleaseInfo>, String> {
     Ok(versions)
 }
 
-/// Get the platform string for the current system (for gh releases)
+/// Get the platform string for the current system (for gh releases). Uses the
+/// *runtime-detected* CPU architecture (see `platform::arch::host_arch`) rather than the
+/// architecture Jean was compiled for, so an x86_64 build running under Rosetta or
+/// Windows-on-ARM emulation still downloads the native binary.
 fn get_gh_platform() -> Result<(&'static str, &'static str), String> {
-    // Returns (platform_string, archive_extension)
-    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
-    {
-        return Ok(("macOS_arm64", "zip"));
-    }
-
-    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
-    {
-        return Ok(("macOS_amd64", "zip"));
-    }
+    use crate::platform::arch::{host_arch, HostArch};
 
-    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    // Returns (platform_string, archive_extension)
+    #[cfg(target_os = "linux")]
     {
-        return Ok(("linux_amd64", "tar.gz"));
+        if crate::platform::arch::is_musl_libc() {
+            return Err(
+                "This host uses musl libc, but GitHub CLI only publishes glibc binaries for \
+                 Linux"
+                    .to_string(),
+            );
+        }
     }
 
-    #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
-    {
-        return Ok(("linux_arm64", "tar.gz"));
-    }
+    #[cfg(target_os = "macos")]
+    return Ok(match host_arch() {
+        HostArch::Aarch64 => ("macOS_arm64", "zip"),
+        HostArch::X86_64 => ("macOS_amd64", "zip"),
+    });
 
-    #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
-    {
-        return Ok(("windows_amd64", "zip"));
-    }
+    #[cfg(target_os = "linux")]
+    return Ok(match host_arch() {
+        HostArch::Aarch64 => ("linux_arm64", "tar.gz"),
+        HostArch::X86_64 => ("linux_amd64", "tar.gz"),
+    });
 
-    #[cfg(all(target_os = "windows", target_arch = "aarch64"))]
-    {
-        return Ok(("windows_arm64", "zip"));
-    }
+    #[cfg(target_os = "windows")]
+    return Ok(match host_arch() {
+        HostArch::Aarch64 => ("windows_arm64", "zip"),
+        HostArch::X86_64 => ("windows_amd64", "zip"),
+    });
 
     #[allow(unreachable_code)]
     Err("Unsupported platform".to_string())
@@ -222,7 +241,7 @@ pub async fn install_gh_cli(app: AppHandle, version: Option<String>) -> Result<(
     // Determine version (use provided or fetch latest)
     let version = match version {
         Some(v) => v,
-        None => fetch_latest_gh_version().await?,
+        None => fetch_latest_gh_version(&app).await?,
     };
 
     // Detect platform
@@ -240,10 +259,7 @@ pub async fn install_gh_cli(app: AppHandle, version: Option<String>) -> Result<(
     emit_progress(&app, "downloading", "Downloading GitHub CLI...", 20);
 
     // Download the archive
-    let client = reqwest::Client::builder()
-        .user_agent("Jean-App/1.0")
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+    let client = build_http_client(&app)?;
 
     let response = client
         .get(&download_url)
@@ -265,6 +281,11 @@ pub async fn install_gh_cli(app: AppHandle, version: Option<String>) -> Result<(
 
     log::trace!("Downloaded {} bytes", archive_content.len());
 
+    // Emit progress: verifying checksum
+    emit_progress(&app, "verifying_checksum", "Verifying checksum...", 30);
+    verify_gh_checksum(&client, &version, &archive_name, &archive_content).await?;
+    log::trace!("Checksum verified successfully");
+
     // Emit progress: extracting
     emit_progress(&app, "extracting", "Extracting archive...", 40);
 
@@ -345,13 +366,10 @@ pub async fn install_gh_cli(app: AppHandle, version: Option<String>) -> Result<(
 }
 
 /// Fetch the latest GitHub CLI version from GitHub API
-async fn fetch_latest_gh_version() -> Result<String, String> {
+async fn fetch_latest_gh_version(app: &AppHandle) -> Result<String, String> {
     log::trace!("Fetching latest GitHub CLI version");
 
-    let client = reqwest::Client::builder()
-        .user_agent("Jean-App/1.0")
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+    let client = build_http_client(app)?;
 
     let response = client
         .get(format!("{GITHUB_RELEASES_API}/latest"))
@@ -380,6 +398,63 @@ async fn fetch_latest_gh_version() -> Result<String, String> {
     Ok(version)
 }
 
+/// Download `gh_{version}_checksums.txt` from the release's assets and verify that
+/// `archive_content` matches the expected SHA256 for `archive_name`. The `cli/cli` repo
+/// doesn't publish a separate checksums API, so this fetches the release by tag to find the
+/// checksums file's actual download URL rather than guessing it.
+async fn verify_gh_checksum(
+    client: &reqwest::Client,
+    version: &str,
+    archive_name: &str,
+    archive_content: &[u8],
+) -> Result<(), String> {
+    let release: GitHubRelease = client
+        .get(format!("{GITHUB_RELEASES_API}/tags/v{version}"))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch release info: {e}"))?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse release info: {e}"))?;
+
+    let checksums_name = format!("gh_{version}_checksums.txt");
+    let checksums_asset = release
+        .assets
+        .iter()
+        .find(|a| a.name == checksums_name)
+        .ok_or_else(|| format!("No checksums file found in release assets: {checksums_name}"))?;
+
+    let checksums_text = client
+        .get(&checksums_asset.browser_download_url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download checksums file: {e}"))?
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read checksums file: {e}"))?;
+
+    // Each line is "<sha256>  <filename>"
+    let expected_checksum = checksums_text
+        .lines()
+        .find_map(|line| {
+            let (checksum, name) = line.split_once(char::is_whitespace)?;
+            (name.trim() == archive_name).then(|| checksum.to_string())
+        })
+        .ok_or_else(|| format!("No checksum entry found for {archive_name}"))?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(archive_content);
+    let computed_checksum = format!("{:x}", hasher.finalize());
+
+    if computed_checksum != expected_checksum.to_lowercase() {
+        return Err(format!(
+            "Checksum mismatch for {archive_name}: expected {expected_checksum}, got {computed_checksum}"
+        ));
+    }
+
+    Ok(())
+}
+
 /// Extract gh binary from a zip archive (macOS, Windows)
 fn extract_zip(
     archive_content: &[u8],
@@ -532,6 +607,146 @@ pub async fn check_gh_cli_auth(app: AppHandle) -> Result<GhAuthStatus, String> {
     }
 }
 
+/// A GitHub account logged into the local `gh` CLI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GhAccount {
+    /// Hostname the account is logged into (e.g. "github.com")
+    pub hostname: String,
+    /// Account username
+    pub username: String,
+    /// Whether this is the currently active account for its hostname
+    pub active: bool,
+}
+
+/// List GitHub accounts logged into the local `gh` CLI, across all hosts
+///
+/// Parses the human-readable output of `gh auth status` since the CLI has no `--json` flag
+/// for this command.
+#[tauri::command]
+pub async fn list_gh_accounts(app: AppHandle) -> Result<Vec<GhAccount>, String> {
+    log::trace!("Listing gh accounts");
+
+    let binary_path = get_gh_cli_binary_path(&app)?;
+    let output = silent_command(&binary_path)
+        .args(["auth", "status"])
+        .output()
+        .map_err(|e| format!("Failed to execute GitHub CLI: {e}"))?;
+
+    // `gh auth status` writes its report to stderr and exits non-zero when no accounts are
+    // logged in anywhere, but still prints normally when at least one host is authenticated.
+    let text = format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let mut accounts = Vec::new();
+
+    for raw_line in text.lines() {
+        let line = raw_line.trim();
+        // e.g. "✓ Logged in to github.com account monalisa (keyring)"
+        if let Some(logged_in_at) = line.find("Logged in to ") {
+            let rest = &line[logged_in_at + "Logged in to ".len()..];
+            let hostname = rest.split_whitespace().next().unwrap_or("").to_string();
+            let username = rest
+                .find("account ")
+                .and_then(|idx| rest[idx + "account ".len()..].split_whitespace().next())
+                .unwrap_or("")
+                .to_string();
+            if !hostname.is_empty() && !username.is_empty() {
+                accounts.push(GhAccount {
+                    hostname,
+                    username,
+                    active: false,
+                });
+            }
+            continue;
+        }
+        if let Some(rest) = line.strip_prefix("- Active account: ") {
+            if let Some(last) = accounts.last_mut() {
+                last.active = rest.trim() == "true";
+            }
+        }
+    }
+
+    log::trace!("Found {} gh accounts", accounts.len());
+    Ok(accounts)
+}
+
+/// Remaining quota for one GitHub API resource (REST core or GraphQL)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GhRateLimitResource {
+    pub limit: u32,
+    pub remaining: u32,
+    /// Unix timestamp when the quota resets
+    pub reset_at: u64,
+}
+
+/// GitHub API rate limit status, as returned by `gh api rate_limit`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GhRateLimitStatus {
+    pub core: GhRateLimitResource,
+    pub graphql: GhRateLimitResource,
+}
+
+/// Fetch the current GitHub API rate limit status for the authenticated account
+///
+/// Used by the background poller to back off the remote polling interval when quota is
+/// running low, and can be surfaced in the UI to explain stale remote data.
+#[tauri::command]
+pub async fn get_gh_rate_limit(app: AppHandle) -> Result<GhRateLimitStatus, String> {
+    let binary_path = get_gh_cli_binary_path(&app)?;
+    fetch_gh_rate_limit(&binary_path)
+}
+
+/// Plain (non-Tauri) version of [`get_gh_rate_limit`], usable from background threads that
+/// already have a resolved `gh` binary path.
+pub fn fetch_gh_rate_limit(gh_binary: &std::path::Path) -> Result<GhRateLimitStatus, String> {
+    log::trace!("Fetching gh rate limit status");
+
+    let output = silent_command(gh_binary)
+        .args(["api", "rate_limit"])
+        .output()
+        .map_err(|e| format!("Failed to run gh api rate_limit: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("gh api rate_limit failed: {stderr}"));
+    }
+
+    #[derive(Deserialize)]
+    struct GhResource {
+        limit: u32,
+        remaining: u32,
+        reset: u64,
+    }
+    #[derive(Deserialize)]
+    struct GhResources {
+        core: GhResource,
+        graphql: GhResource,
+    }
+    #[derive(Deserialize)]
+    struct GhRateLimitResponse {
+        resources: GhResources,
+    }
+
+    let parsed: GhRateLimitResponse = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse rate limit response: {e}"))?;
+
+    Ok(GhRateLimitStatus {
+        core: GhRateLimitResource {
+            limit: parsed.resources.core.limit,
+            remaining: parsed.resources.core.remaining,
+            reset_at: parsed.resources.core.reset,
+        },
+        graphql: GhRateLimitResource {
+            limit: parsed.resources.graphql.limit,
+            remaining: parsed.resources.graphql.remaining,
+            reset_at: parsed.resources.graphql.reset,
+        },
+    })
+}
+
 /// Helper function to emit installation progress events
 fn emit_progress(app: &AppHandle, stage: &str, message: &str, percent: u8) {
     let progress = GhInstallProgress {
@@ -1,7 +1,7 @@
 //! Configuration and path management for the embedded GitHub CLI
 
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use tauri::AppHandle;
 
 /// Directory name for storing the GitHub CLI binary
 pub const GH_CLI_DIR_NAME: &str = "gh-cli";
@@ -19,10 +19,7 @@ pub const GH_CLI_BINARY_NAME: &str = "gh.exe";
 ///          `~/.local/share/jean/gh-cli/` (Linux)
 ///          `%APPDATA%/jean/gh-cli/` (Windows)
 pub fn get_gh_cli_dir(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
     Ok(app_data_dir.join(GH_CLI_DIR_NAME))
 }
 
@@ -47,6 +44,30 @@ pub fn resolve_gh_binary(app: &AppHandle) -> PathBuf {
     PathBuf::from("gh")
 }
 
+/// Switch the active `gh` account before running commands against a project that has a
+/// `gh_account` assigned (via `set_project_gh_account`). No-op when `account` is `None`.
+///
+/// This shells out to `gh auth switch`, which changes the account active for *all* `gh`
+/// invocations on the machine, not just this process — the best isolation the CLI supports
+/// short of running each project against a separate `GH_CONFIG_DIR`.
+pub fn ensure_gh_account(gh: &std::path::Path, account: Option<&str>) -> Result<(), String> {
+    let Some(account) = account else {
+        return Ok(());
+    };
+
+    let output = crate::platform::silent_command(gh)
+        .args(["auth", "switch", "--user", account])
+        .output()
+        .map_err(|e| format!("Failed to run gh auth switch: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to switch to gh account '{account}': {stderr}"));
+    }
+
+    Ok(())
+}
+
 /// Ensure the CLI directory exists, creating it if necessary
 pub fn ensure_gh_cli_dir(app: &AppHandle) -> Result<PathBuf, String> {
     let cli_dir = get_gh_cli_dir(app)?;
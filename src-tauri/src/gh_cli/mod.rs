@@ -3,6 +3,7 @@
 //! Handles downloading, installing, and managing the GitHub CLI (gh) binary
 //! embedded within the Jean application.
 
+pub mod api_client;
 mod commands;
 pub(crate) mod config;
 
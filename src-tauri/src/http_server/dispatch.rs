@@ -38,6 +38,89 @@ pub async fn dispatch_command(
             emit_cache_invalidation(app, &["ui-state"]);
             Ok(Value::Null)
         }
+        "get_storage_info" => {
+            let result = crate::storage_migrations::get_storage_info(app.clone()).await?;
+            to_value(result)
+        }
+        "create_backup" => {
+            let output_path: String = field(&args, "outputPath", "output_path")?;
+            let include_images: bool = field(&args, "includeImages", "include_images")?;
+            let result =
+                crate::backup::create_backup(app.clone(), output_path, include_images).await?;
+            to_value(result)
+        }
+        "restore_backup" => {
+            let input_path: String = field(&args, "inputPath", "input_path")?;
+            let mode = from_field(&args, "mode")?;
+            crate::backup::restore_backup(app.clone(), input_path, mode).await?;
+            Ok(Value::Null)
+        }
+        "sync_now" => {
+            let result = crate::sync::sync_now(app.clone()).await?;
+            to_value(result)
+        }
+        "migrate_data_dir" => {
+            let new_dir: String = field(&args, "newDir", "new_dir")?;
+            crate::data_dir::migrate_data_dir(app.clone(), new_dir).await?;
+            Ok(Value::Null)
+        }
+        "list_trash" => {
+            let result = crate::trash::list_trash(app.clone()).await?;
+            to_value(result)
+        }
+        "restore_from_trash" => {
+            let id: String = from_field(&args, "id")?;
+            crate::trash::restore_from_trash(app.clone(), id).await?;
+            Ok(Value::Null)
+        }
+        "empty_trash" => {
+            let result = crate::trash::empty_trash(app.clone()).await?;
+            to_value(result)
+        }
+        "save_preference_profile" => {
+            let name: String = from_field(&args, "name")?;
+            let preferences = from_field(&args, "preferences")?;
+            crate::preference_profiles::save_preference_profile(app.clone(), name, preferences)
+                .await?;
+            Ok(Value::Null)
+        }
+        "list_preference_profiles" => {
+            let result = crate::preference_profiles::list_preference_profiles(app.clone()).await?;
+            to_value(result)
+        }
+        "delete_preference_profile" => {
+            let name: String = from_field(&args, "name")?;
+            crate::preference_profiles::delete_preference_profile(app.clone(), name).await?;
+            Ok(Value::Null)
+        }
+        "switch_preference_profile" => {
+            let name: String = from_field(&args, "name")?;
+            let result =
+                crate::preference_profiles::switch_preference_profile(app.clone(), name).await?;
+            emit_cache_invalidation(app, &["preferences"]);
+            to_value(result)
+        }
+        "export_preferences" => {
+            let path: String = from_field(&args, "path")?;
+            crate::preference_profiles::export_preferences(app.clone(), path).await?;
+            Ok(Value::Null)
+        }
+        "import_preferences" => {
+            let path: String = from_field(&args, "path")?;
+            let result = crate::preference_profiles::import_preferences(app.clone(), path).await?;
+            emit_cache_invalidation(app, &["preferences"]);
+            to_value(result)
+        }
+        "get_storage_usage" => {
+            let result = crate::storage_usage::get_storage_usage(app.clone()).await?;
+            to_value(result)
+        }
+        "get_worktree_activity" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let range = from_field(&args, "range")?;
+            let result = crate::activity::get_worktree_activity(app.clone(), worktree_id, range).await?;
+            to_value(result)
+        }
 
         // =====================================================================
         // Projects
@@ -94,11 +177,24 @@ pub async fn dispatch_command(
         "update_project_settings" => {
             let project_id: String = field(&args, "projectId", "project_id")?;
             let default_branch: Option<String> = field_opt(&args, "defaultBranch", "default_branch")?;
+            let shell: Option<String> = field_opt(&args, "shell", "shell")?;
+            let shell_startup_command: Option<String> =
+                field_opt(&args, "shellStartupCommand", "shell_startup_command")?;
             let result = crate::projects::update_project_settings(
-                app.clone(), project_id, default_branch,
+                app.clone(), project_id, default_branch, shell, shell_startup_command,
             ).await?;
             to_value(result)
         }
+        "validate_shell_path" => {
+            let shell_path: String = field(&args, "shellPath", "shell_path")?;
+            let result = crate::projects::validate_shell_path(shell_path).await;
+            to_value(result)
+        }
+        "get_effective_env" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let result = crate::projects::get_effective_env(app.clone(), worktree_id).await?;
+            to_value(result)
+        }
         "reorder_projects" => {
             let project_ids: Vec<String> = field(&args, "projectIds", "project_ids")?;
             crate::projects::reorder_projects(app.clone(), project_ids).await?;
@@ -117,6 +213,11 @@ pub async fn dispatch_command(
             let result = crate::projects::fetch_worktrees_status(app.clone(), project_id).await?;
             to_value(result)
         }
+        "fetch_worktrees_pr_status" => {
+            let project_id: String = field(&args, "projectId", "project_id")?;
+            let result = crate::projects::fetch_worktrees_pr_status(app.clone(), project_id).await?;
+            to_value(result)
+        }
         "archive_worktree" => {
             let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
             crate::projects::archive_worktree(app.clone(), worktree_id).await?;
@@ -193,7 +294,7 @@ pub async fn dispatch_command(
             let push: bool = from_field_opt(&args, "push")?.unwrap_or(false);
             let model: Option<String> = from_field_opt(&args, "model")?;
             let result = crate::projects::create_commit_with_ai(
-                app.clone(), worktree_path, custom_prompt, push, model,
+                app.clone(), worktree_path, custom_prompt, push, model, None,
             ).await?;
             to_value(result)
         }
@@ -235,6 +336,14 @@ pub async fn dispatch_command(
             let result = crate::projects::list_worktree_files(worktree_path, max_files).await?;
             to_value(result)
         }
+        "generate_repo_map" => {
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let depth: Option<usize> = from_field_opt(&args, "depth")?;
+            let max_entries: Option<usize> = field_opt(&args, "maxEntries", "max_entries")?;
+            let result =
+                crate::projects::generate_repo_map(worktree_path, depth, max_entries).await?;
+            to_value(result)
+        }
 
         // =====================================================================
         // GitHub Issues & PRs
@@ -263,6 +372,15 @@ pub async fn dispatch_command(
             let result = crate::projects::get_github_pr(app.clone(), project_path, pr_number).await?;
             to_value(result)
         }
+        "list_prs_awaiting_my_review" => {
+            let result = crate::projects::list_prs_awaiting_my_review(app.clone()).await?;
+            to_value(result)
+        }
+        "list_open_change_requests" => {
+            let project_id: String = field(&args, "projectId", "project_id")?;
+            let result = crate::projects::list_open_change_requests(app.clone(), project_id).await?;
+            to_value(result)
+        }
         "load_issue_context" => {
             let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
             let issue_number: u32 = field(&args, "issueNumber", "issue_number")?;
@@ -391,11 +509,18 @@ pub async fn dispatch_command(
             let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
             let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
             let session_id: String = field(&args, "sessionId", "session_id")?;
+            let limit: Option<u32> = from_field_opt(&args, "limit")?;
+            let before_message_id: Option<String> = field_opt(&args, "beforeMessageId", "before_message_id")?;
             let result = crate::chat::get_session(
-                app.clone(), worktree_id, worktree_path, session_id,
+                app.clone(), worktree_id, worktree_path, session_id, limit, before_message_id,
             ).await?;
             to_value(result)
         }
+        "get_message_count" => {
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let result = crate::chat::get_message_count(app.clone(), session_id).await?;
+            to_value(result)
+        }
         "create_session" => {
             let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
             let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
@@ -403,6 +528,54 @@ pub async fn dispatch_command(
             let result = crate::chat::create_session(app.clone(), worktree_id, worktree_path, name).await?;
             to_value(result)
         }
+        "fork_session" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let at_message_id: String = field(&args, "atMessageId", "at_message_id")?;
+            let new_name: Option<String> = field_opt(&args, "newName", "new_name")?;
+            let result = crate::chat::fork_session(
+                app.clone(),
+                worktree_id,
+                worktree_path,
+                session_id,
+                at_message_id,
+                new_name,
+            )
+            .await?;
+            to_value(result)
+        }
+        "compare_models" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let message: String = from_field(&args, "message")?;
+            let models: Vec<String> = from_field(&args, "models")?;
+            let execution_mode: Option<String> =
+                field_opt(&args, "executionMode", "execution_mode")?;
+            let thinking_level: Option<crate::chat::types::ThinkingLevel> =
+                field_opt(&args, "thinkingLevel", "thinking_level")?;
+            let result = crate::chat::compare_models(
+                app.clone(),
+                worktree_id,
+                worktree_path,
+                session_id,
+                message,
+                models,
+                execution_mode,
+                thinking_level,
+            )
+            .await?;
+            to_value(result)
+        }
+        "broadcast_prompt" => {
+            let worktree_ids: Vec<String> = from_field(&args, "worktreeIds")?;
+            let message: String = from_field(&args, "message")?;
+            let model: Option<String> = from_field_opt(&args, "model")?;
+            let result =
+                crate::chat::broadcast_prompt(app.clone(), worktree_ids, message, model).await?;
+            to_value(result)
+        }
         "rename_session" => {
             let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
             let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
@@ -460,17 +633,19 @@ pub async fn dispatch_command(
             let parallel_execution_prompt_enabled: Option<bool> = field_opt(&args, "parallelExecutionPromptEnabled", "parallel_execution_prompt_enabled")?;
             let ai_language: Option<String> = field_opt(&args, "aiLanguage", "ai_language")?;
             let allowed_tools: Option<Vec<String>> = field_opt(&args, "allowedTools", "allowed_tools")?;
+            let override_budget: Option<bool> = field_opt(&args, "overrideBudget", "override_budget")?;
             let result = crate::chat::send_chat_message(
                 app.clone(), session_id, worktree_id, worktree_path, message,
                 model, execution_mode, thinking_level, disable_thinking_for_mode,
-                parallel_execution_prompt_enabled, ai_language, allowed_tools,
+                parallel_execution_prompt_enabled, ai_language, allowed_tools, override_budget,
             ).await?;
             to_value(result)
         }
         "cancel_chat_message" => {
             let session_id: String = field(&args, "sessionId", "session_id")?;
             let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
-            crate::chat::cancel_chat_message(app.clone(), session_id, worktree_id).await?;
+            let force: Option<bool> = field_opt(&args, "force", "force")?;
+            crate::chat::cancel_chat_message(app.clone(), session_id, worktree_id, force).await?;
             Ok(Value::Null)
         }
         "clear_session_history" => {
@@ -493,6 +668,16 @@ pub async fn dispatch_command(
             ).await?;
             Ok(Value::Null)
         }
+        "set_session_provider" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let provider: String = from_field(&args, "provider")?;
+            crate::chat::set_session_provider(
+                app.clone(), worktree_id, worktree_path, session_id, provider,
+            ).await?;
+            Ok(Value::Null)
+        }
         "set_session_thinking_level" => {
             let session_id: String = field(&args, "sessionId", "session_id")?;
             let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
@@ -503,6 +688,200 @@ pub async fn dispatch_command(
             ).await?;
             Ok(Value::Null)
         }
+        "set_session_env_vars" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let env_vars: Vec<crate::projects::types::EnvVarEntry> =
+                field(&args, "envVars", "env_vars")?;
+            crate::chat::set_session_env_vars(
+                app.clone(), worktree_id, worktree_path, session_id, env_vars,
+            ).await?;
+            Ok(Value::Null)
+        }
+        "list_ollama_models" => {
+            let result = crate::chat::list_ollama_models(app.clone()).await?;
+            to_value(result)
+        }
+        "get_usage_report" => {
+            let range: crate::chat::usage::UsageRange = from_field(&args, "range")?;
+            let group_by: crate::chat::usage::UsageGroupBy = field(&args, "groupBy", "group_by")?;
+            let result = crate::chat::get_usage_report(app.clone(), range, group_by).await?;
+            to_value(result)
+        }
+        "get_budget_status" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let result = crate::chat::get_budget_status(app.clone(), worktree_id).await?;
+            to_value(result)
+        }
+        "get_session_context_usage" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let result =
+                crate::chat::get_session_context_usage(app.clone(), worktree_id, session_id)
+                    .await?;
+            to_value(result)
+        }
+        "export_session" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let format: crate::chat::export::ExportFormat = from_field(&args, "format")?;
+            let include_tool_calls: bool =
+                field(&args, "includeToolCalls", "include_tool_calls")?;
+            let output_path: String = field(&args, "outputPath", "output_path")?;
+            crate::chat::export_session(
+                app.clone(),
+                worktree_id,
+                session_id,
+                format,
+                include_tool_calls,
+                output_path,
+            )
+            .await?;
+            Ok(Value::Null)
+        }
+        "import_session" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let path: String = from_field(&args, "path")?;
+            let result = crate::chat::import_session(app.clone(), worktree_id, path).await?;
+            to_value(result)
+        }
+        "list_queued_messages" => {
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let result = crate::chat::list_queued_messages(session_id).await?;
+            to_value(result)
+        }
+        "cancel_queued_message" => {
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let message_id: String = field(&args, "messageId", "message_id")?;
+            let result =
+                crate::chat::cancel_queued_message(app.clone(), session_id, message_id).await?;
+            to_value(result)
+        }
+        "list_offline_queue" => {
+            let result = crate::chat::list_offline_queue(app.clone()).await?;
+            to_value(result)
+        }
+        "cancel_offline_queued_message" => {
+            let message_id: String = field(&args, "messageId", "message_id")?;
+            let result =
+                crate::chat::cancel_offline_queued_message(app.clone(), message_id).await?;
+            to_value(result)
+        }
+        "list_queued_runs" => {
+            let result = crate::chat::list_queued_runs().await?;
+            to_value(result)
+        }
+        "cancel_queued_run" => {
+            let run_id: String = field(&args, "runId", "run_id")?;
+            let result = crate::chat::cancel_queued_run(app.clone(), run_id).await?;
+            to_value(result)
+        }
+        "get_run_log" => {
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let run_id: String = field(&args, "runId", "run_id")?;
+            let result = crate::chat::get_run_log(app.clone(), session_id, run_id).await?;
+            to_value(result)
+        }
+        "list_runs" => {
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let result = crate::chat::list_runs(app.clone(), session_id).await?;
+            to_value(result)
+        }
+        "compress_old_run_logs" => {
+            let retention_days: u32 = field(&args, "retentionDays", "retention_days")?;
+            let result = crate::chat::compress_old_run_logs(app.clone(), retention_days).await?;
+            to_value(result)
+        }
+        "preview_retention_policy" => {
+            let idle_archive_days: u32 = field(&args, "idleArchiveDays", "idle_archive_days")?;
+            let archive_retention_days: u32 = field(&args, "archiveRetentionDays", "archive_retention_days")?;
+            let result = crate::chat::preview_retention_policy(
+                app.clone(), idle_archive_days, archive_retention_days,
+            ).await?;
+            to_value(result)
+        }
+        "list_snapshots" => {
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let result = crate::chat::list_snapshots(app.clone(), session_id).await?;
+            to_value(result)
+        }
+        "rollback_to_snapshot" => {
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let run_id: String = field(&args, "runId", "run_id")?;
+            crate::chat::rollback_to_snapshot(app.clone(), session_id, run_id).await?;
+            Ok(Value::Null)
+        }
+        "gc_old_snapshots" => {
+            let retention_days: u32 = field(&args, "retentionDays", "retention_days")?;
+            let result = crate::chat::gc_old_snapshots(app.clone(), retention_days).await?;
+            to_value(result)
+        }
+        "schedule_prompt" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let message: String = from_field(&args, "message")?;
+            let run_at: u64 = field(&args, "runAt", "run_at")?;
+            let model: Option<String> = field_opt(&args, "model", "model")?;
+            let execution_mode: Option<String> =
+                field_opt(&args, "executionMode", "execution_mode")?;
+            let thinking_level: Option<crate::chat::types::ThinkingLevel> =
+                field_opt(&args, "thinkingLevel", "thinking_level")?;
+            let result = crate::chat::schedule_prompt(
+                app.clone(),
+                worktree_id,
+                worktree_path,
+                session_id,
+                message,
+                run_at,
+                model,
+                execution_mode,
+                thinking_level,
+            )
+            .await?;
+            to_value(result)
+        }
+        "list_scheduled_prompts" => {
+            let result = crate::chat::list_scheduled_prompts(app.clone()).await?;
+            to_value(result)
+        }
+        "cancel_scheduled_prompt" => {
+            let id: String = from_field(&args, "id")?;
+            let result = crate::chat::cancel_scheduled_prompt(app.clone(), id).await?;
+            to_value(result)
+        }
+        "create_pipeline" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let name: String = from_field(&args, "name")?;
+            let steps: Vec<crate::chat::PipelineStepInput> = from_field(&args, "steps")?;
+            let result = crate::chat::create_pipeline(
+                app.clone(),
+                worktree_id,
+                worktree_path,
+                session_id,
+                name,
+                steps,
+            )
+            .await?;
+            to_value(result)
+        }
+        "run_pipeline" => {
+            let pipeline_id: String = field(&args, "pipelineId", "pipeline_id")?;
+            let result = crate::chat::run_pipeline(app.clone(), pipeline_id).await?;
+            to_value(result)
+        }
+        "list_pipelines" => {
+            let result = crate::chat::list_pipelines(app.clone()).await?;
+            to_value(result)
+        }
+        "cancel_pipeline" => {
+            let pipeline_id: String = field(&args, "pipelineId", "pipeline_id")?;
+            let result = crate::chat::cancel_pipeline(app.clone(), pipeline_id).await?;
+            to_value(result)
+        }
         "mark_plan_approved" => {
             let session_id: String = field(&args, "sessionId", "session_id")?;
             let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
@@ -513,6 +892,12 @@ pub async fn dispatch_command(
             ).await?;
             Ok(Value::Null)
         }
+        "get_plan_impact" => {
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let message_id: String = field(&args, "messageId", "message_id")?;
+            let result = crate::chat::get_plan_impact(app.clone(), session_id, message_id).await?;
+            to_value(result)
+        }
         "save_cancelled_message" => {
             let session_id: String = field(&args, "sessionId", "session_id")?;
             let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
@@ -530,6 +915,10 @@ pub async fn dispatch_command(
             let result = crate::chat::has_running_sessions();
             to_value(result)
         }
+        "get_process_stats" => {
+            let result = crate::chat::get_process_stats();
+            to_value(result)
+        }
 
         // =====================================================================
         // Chat - Saved Contexts
@@ -570,6 +959,65 @@ pub async fn dispatch_command(
             to_value(result)
         }
 
+        // =====================================================================
+        // Chat - File/directory context attachments
+        // =====================================================================
+        "attach_file_context" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let paths: Vec<String> = from_field(&args, "paths")?;
+            let result =
+                crate::chat::attach_file_context(app.clone(), worktree_id, session_id, paths)
+                    .await?;
+            to_value(result)
+        }
+        "list_file_context" => {
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let result = crate::chat::list_file_context(app.clone(), session_id).await?;
+            to_value(result)
+        }
+        "remove_file_context" => {
+            let session_id: String = field(&args, "sessionId", "session_id")?;
+            let attachment_id: String = field(&args, "attachmentId", "attachment_id")?;
+            crate::chat::remove_file_context(app.clone(), session_id, attachment_id).await?;
+            Ok(Value::Null)
+        }
+        "list_followups" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let result = crate::chat::list_followups(app.clone(), worktree_id).await?;
+            to_value(result)
+        }
+        "set_followup_completed" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let followup_id: String = field(&args, "followupId", "followup_id")?;
+            let completed: bool = from_field(&args, "completed")?;
+            let result = crate::chat::set_followup_completed(
+                app.clone(),
+                worktree_id,
+                followup_id,
+                completed,
+            )
+            .await?;
+            to_value(result)
+        }
+        "retrieve_relevant_context" => {
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let query: String = from_field(&args, "query")?;
+            let k: Option<usize> = from_field_opt(&args, "k")?;
+            let result = crate::chat::retrieve_relevant_context(worktree_path, query, k).await?;
+            to_value(result)
+        }
+        "rebuild_search_index" => {
+            let result = crate::chat::rebuild_search_index(app.clone()).await?;
+            to_value(result)
+        }
+        "search_messages" => {
+            let query: String = from_field(&args, "query")?;
+            let worktree_id: Option<String> = field_opt(&args, "worktreeId", "worktree_id")?;
+            let result = crate::chat::search_messages(app.clone(), query, worktree_id).await?;
+            to_value(result)
+        }
+
         // =====================================================================
         // Chat - File operations
         // =====================================================================
@@ -657,6 +1105,10 @@ pub async fn dispatch_command(
             let result = crate::chat::check_resumable_sessions(app.clone()).await?;
             to_value(result)
         }
+        "list_recoverable_runs" => {
+            let result = crate::chat::list_recoverable_runs(app.clone()).await?;
+            to_value(result)
+        }
         "cleanup_old_archives" => {
             let retention_days: u32 = field(&args, "retentionDays", "retention_days")?;
             let result = crate::projects::cleanup_old_archives(app.clone(), retention_days).await?;
@@ -790,6 +1242,15 @@ pub async fn dispatch_command(
             ).await?;
             to_value(result)
         }
+        "merge_pr" => {
+            let project_path: String = field(&args, "projectPath", "project_path")?;
+            let pr_number: u32 = field(&args, "prNumber", "pr_number")?;
+            let method: crate::projects::types::MergeType = field(&args, "method", "method")?;
+            let delete_branch: bool = field(&args, "deleteBranch", "delete_branch")?;
+            crate::projects::merge_pr(app.clone(), project_path, pr_number, method, delete_branch)
+                .await?;
+            Ok(Value::Null)
+        }
         "open_project_on_github" => {
             let project_id: String = field(&args, "projectId", "project_id")?;
             crate::projects::open_project_on_github(app.clone(), project_id).await?;
@@ -797,7 +1258,14 @@ pub async fn dispatch_command(
         }
         "get_pr_prompt" => {
             let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
-            let result = crate::projects::get_pr_prompt(app.clone(), worktree_path).await?;
+            let template_name: Option<String> = field_opt(&args, "templateName", "template_name")?;
+            let result =
+                crate::projects::get_pr_prompt(app.clone(), worktree_path, template_name).await?;
+            to_value(result)
+        }
+        "list_pr_templates" => {
+            let project_path: String = field(&args, "projectPath", "project_path")?;
+            let result = crate::projects::list_pr_templates(project_path).await?;
             to_value(result)
         }
         "get_review_prompt" => {
@@ -909,41 +1377,167 @@ pub async fn dispatch_command(
             let result = crate::projects::remove_project_avatar(app.clone(), project_id).await?;
             to_value(result)
         }
+        "set_project_gh_account" => {
+            let project_id: String = field(&args, "projectId", "project_id")?;
+            let account: Option<String> = from_field_opt(&args, "account")?;
+            let result =
+                crate::projects::set_project_gh_account(app.clone(), project_id, account).await?;
+            to_value(result)
+        }
+        "set_project_gitea_config" => {
+            let project_id: String = field(&args, "projectId", "project_id")?;
+            let host: Option<String> = from_field_opt(&args, "host")?;
+            let token: Option<String> = from_field_opt(&args, "token")?;
+            let result =
+                crate::projects::set_project_gitea_config(app.clone(), project_id, host, token)
+                    .await?;
+            to_value(result)
+        }
+        "set_project_budget" => {
+            let project_id: String = field(&args, "projectId", "project_id")?;
+            let monthly_budget_usd: Option<f64> = field_opt(&args, "monthlyBudgetUsd", "monthly_budget_usd")?;
+            let result =
+                crate::projects::set_project_budget(app.clone(), project_id, monthly_budget_usd)
+                    .await?;
+            to_value(result)
+        }
+        "set_project_env_vars" => {
+            let project_id: String = field(&args, "projectId", "project_id")?;
+            let env_vars: Vec<crate::projects::types::EnvVarEntry> =
+                field(&args, "envVars", "env_vars")?;
+            let result =
+                crate::projects::set_project_env_vars(app.clone(), project_id, env_vars).await?;
+            to_value(result)
+        }
+        "set_project_instructions" => {
+            let project_id: String = field(&args, "projectId", "project_id")?;
+            let instructions: Option<String> = field_opt(&args, "instructions", "instructions")?;
+            let result =
+                crate::projects::set_project_instructions(app.clone(), project_id, instructions)
+                    .await?;
+            to_value(result)
+        }
+        "set_project_auto_commit_after_run" => {
+            let project_id: String = field(&args, "projectId", "project_id")?;
+            let enabled: bool = from_field(&args, "enabled")?;
+            let result = crate::projects::set_project_auto_commit_after_run(
+                app.clone(),
+                project_id,
+                enabled,
+            )
+            .await?;
+            to_value(result)
+        }
+        "set_project_sandbox_config" => {
+            let project_id: String = field(&args, "projectId", "project_id")?;
+            let sandbox: crate::projects::types::SandboxConfig =
+                field(&args, "sandbox", "sandbox")?;
+            let result =
+                crate::projects::set_project_sandbox_config(app.clone(), project_id, sandbox)
+                    .await?;
+            to_value(result)
+        }
+        "set_worktree_instructions" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let instructions: Option<String> = field_opt(&args, "instructions", "instructions")?;
+            let result = crate::projects::set_worktree_instructions(
+                app.clone(),
+                worktree_id,
+                instructions,
+            )
+            .await?;
+            to_value(result)
+        }
         "get_app_data_dir" => {
             let result = crate::projects::get_app_data_dir(app.clone()).await?;
             to_value(result)
         }
 
         // =====================================================================
-        // Terminal (NATIVE ONLY — return empty/null in browser mode)
+        // Terminal — PTYs are spawned on the server, output streams to browser
+        // clients as `terminal:output` WebSocket events (see `terminal::pty`).
         // =====================================================================
         "start_terminal" => {
-            // NATIVE ONLY: Terminals don't work in browser mode
+            let terminal_id: String = field(&args, "terminalId", "terminal_id")?;
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let name: String = from_field(&args, "name")?;
+            let profile = field_opt(&args, "profile", "profile")?;
+            let cols: u16 = from_field(&args, "cols")?;
+            let rows: u16 = from_field(&args, "rows")?;
+            let command: Option<String> = field_opt(&args, "command", "command")?;
+            crate::terminal::start_terminal(
+                app.clone(),
+                terminal_id,
+                worktree_id,
+                worktree_path,
+                name,
+                profile,
+                cols,
+                rows,
+                command,
+            )
+            .await?;
             Ok(Value::Null)
         }
         "terminal_write" => {
-            // NATIVE ONLY: Terminals don't work in browser mode
+            let terminal_id: String = field(&args, "terminalId", "terminal_id")?;
+            let data: String = from_field(&args, "data")?;
+            crate::terminal::terminal_write(terminal_id, data).await?;
             Ok(Value::Null)
         }
         "terminal_resize" => {
-            // NATIVE ONLY: Terminals don't work in browser mode
+            let terminal_id: String = field(&args, "terminalId", "terminal_id")?;
+            let cols: u16 = from_field(&args, "cols")?;
+            let rows: u16 = from_field(&args, "rows")?;
+            crate::terminal::terminal_resize(terminal_id, cols, rows).await?;
             Ok(Value::Null)
         }
         "stop_terminal" => {
-            // NATIVE ONLY: Terminals don't work in browser mode
-            Ok(Value::Null)
+            let terminal_id: String = field(&args, "terminalId", "terminal_id")?;
+            let result = crate::terminal::stop_terminal(app.clone(), terminal_id).await?;
+            to_value(result)
         }
         "get_active_terminals" => {
-            // NATIVE ONLY: Return empty array
-            Ok(Value::Array(vec![]))
+            let result = crate::terminal::get_active_terminals().await;
+            to_value(result)
         }
         "has_active_terminal" => {
-            // NATIVE ONLY: No terminals in browser mode
-            to_value(false)
+            let terminal_id: String = field(&args, "terminalId", "terminal_id")?;
+            let result = crate::terminal::has_active_terminal(terminal_id).await;
+            to_value(result)
+        }
+        "reattach_terminal" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let terminal_id: String = field(&args, "terminalId", "terminal_id")?;
+            let result = crate::terminal::reattach_terminal(worktree_id, terminal_id).await;
+            to_value(result)
+        }
+        "list_terminals" => {
+            let worktree_id: String = field(&args, "worktreeId", "worktree_id")?;
+            let result = crate::terminal::list_terminals(worktree_id).await;
+            to_value(result)
         }
         "get_run_script" => {
-            // NATIVE ONLY: Terminals don't work in browser mode
-            Ok(Value::Null)
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let result = crate::terminal::get_run_script(worktree_path).await;
+            to_value(result)
+        }
+        "run_project_script" => {
+            let worktree_path: String = field(&args, "worktreePath", "worktree_path")?;
+            let kind: crate::scripts::ScriptKind = from_field(&args, "kind")?;
+            let result = crate::scripts::run_project_script(app.clone(), worktree_path, kind).await?;
+            to_value(result)
+        }
+        "get_remote_git_status" => {
+            let remote: crate::projects::types::RemoteConfig = from_field(&args, "remote")?;
+            let result = crate::remote::get_remote_git_status(remote).await?;
+            to_value(result)
+        }
+        "get_remote_git_diff" => {
+            let remote: crate::projects::types::RemoteConfig = from_field(&args, "remote")?;
+            let result = crate::remote::get_remote_git_diff(remote).await?;
+            to_value(result)
         }
 
         // =====================================================================
@@ -1121,7 +1715,7 @@ pub async fn dispatch_command(
             to_value(result)
         }
         "get_available_cli_versions" => {
-            let result = crate::claude_cli::get_available_cli_versions().await?;
+            let result = crate::claude_cli::get_available_cli_versions(app.clone()).await?;
             to_value(result)
         }
         "install_claude_cli" => {
@@ -1137,8 +1731,16 @@ pub async fn dispatch_command(
             let result = crate::gh_cli::check_gh_cli_auth(app.clone()).await?;
             to_value(result)
         }
+        "list_gh_accounts" => {
+            let result = crate::gh_cli::list_gh_accounts(app.clone()).await?;
+            to_value(result)
+        }
+        "get_gh_rate_limit" => {
+            let result = crate::gh_cli::get_gh_rate_limit(app.clone()).await?;
+            to_value(result)
+        }
         "get_available_gh_versions" => {
-            let result = crate::gh_cli::get_available_gh_versions().await?;
+            let result = crate::gh_cli::get_available_gh_versions(app.clone()).await?;
             to_value(result)
         }
         "install_gh_cli" => {
@@ -53,13 +53,20 @@ impl EmitExt for AppHandle {
         self.emit(event, payload.clone())
             .map_err(|e| format!("Tauri emit failed: {e}"))?;
 
+        let value = serde_json::to_value(payload)
+            .map_err(|e| format!("Failed to serialize {event} payload: {e}"))?;
+
         // Broadcast to WebSocket clients (if server is running)
         if let Some(ws) = self.try_state::<WsBroadcaster>() {
-            let value = serde_json::to_value(payload)
-                .map_err(|e| format!("Failed to serialize for WS broadcast: {e}"))?;
             ws.broadcast(event, &value);
         }
 
+        // Central notification pipeline (rules engine, etc.) - see `notifications::on_event`.
+        crate::notifications::on_event(self, event, &value);
+
+        // Tray/dock attention badge - see `tray::on_event`.
+        crate::tray::on_event(self, event, &value);
+
         Ok(())
     }
 }
@@ -42,6 +42,11 @@ pub struct ServerStatus {
     pub token: Option<String>,
     pub port: Option<u16>,
     pub localhost_only: Option<bool>,
+    /// Whether another Jean instance (native app or headless server) was already using this
+    /// data directory at startup - see `instance_lock.rs`. Independent of `running`: this
+    /// process's own HTTP server can be stopped while another instance is still live.
+    pub other_instance_running: bool,
+    pub other_instance_pid: Option<u32>,
 }
 
 #[derive(Deserialize)]
@@ -399,6 +404,9 @@ fn get_local_ip() -> Option<String> {
 
 /// Get current server status. Called from dispatch.
 pub async fn get_server_status(app: AppHandle) -> ServerStatus {
+    let instance_lock_status = crate::instance_lock::current(&app);
+    let other_instance_running = instance_lock_status.other_instance_running;
+    let other_instance_pid = instance_lock_status.other_instance_pid;
     match app.try_state::<Arc<Mutex<Option<HttpServerHandle>>>>() {
         Some(handle_state) => {
             let handle = handle_state.lock().await;
@@ -409,6 +417,8 @@ pub async fn get_server_status(app: AppHandle) -> ServerStatus {
                     token: Some(h.token.clone()),
                     port: Some(h.port),
                     localhost_only: Some(h.localhost_only),
+                    other_instance_running,
+                    other_instance_pid,
                 },
                 None => ServerStatus {
                     running: false,
@@ -416,6 +426,8 @@ pub async fn get_server_status(app: AppHandle) -> ServerStatus {
                     token: None,
                     port: None,
                     localhost_only: None,
+                    other_instance_running,
+                    other_instance_pid,
                 },
             }
         }
@@ -425,6 +437,8 @@ pub async fn get_server_status(app: AppHandle) -> ServerStatus {
             token: None,
             port: None,
             localhost_only: None,
+            other_instance_running,
+            other_instance_pid,
         },
     }
 }
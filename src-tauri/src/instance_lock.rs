@@ -0,0 +1,109 @@
+//! Detects whether another Jean process (the native app, a `--headless` server, or a second
+//! copy of either) is already pointed at the same data directory. Two instances sharing a data
+//! directory without this would silently race on `chat::storage`'s and `projects::storage`'s
+//! JSON files - the per-document [`crate::platform::FileLock`]s now stop them from corrupting
+//! each other mid-write, but a user launching a second instance by accident still deserves a
+//! clear warning instead of two copies quietly fighting over the same sessions.
+//!
+//! [`acquire`] is called once, early in `run()`'s setup, and its result is stored as managed
+//! state for [`crate::http_server::server::get_server_status`] to read. It never blocks and
+//! never refuses to start a second instance - Jean has no business stopping a user who, say,
+//! wants a second window - it only records what it found.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+const LOCK_FILENAME: &str = "instance.lock";
+
+#[derive(Serialize, Deserialize)]
+struct LockFileContents {
+    pid: u32,
+    headless: bool,
+}
+
+/// Whether another live Jean instance was found sharing this data directory at startup.
+#[derive(Clone, Copy, Serialize)]
+pub struct InstanceLockStatus {
+    pub other_instance_running: bool,
+    pub other_instance_pid: Option<u32>,
+    pub other_instance_headless: Option<bool>,
+}
+
+impl InstanceLockStatus {
+    fn none() -> Self {
+        InstanceLockStatus {
+            other_instance_running: false,
+            other_instance_pid: None,
+            other_instance_headless: None,
+        }
+    }
+}
+
+fn lock_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::data_dir::resolve(app)?.join(LOCK_FILENAME))
+}
+
+/// Read back the status recorded by [`acquire`] at startup, for anything (e.g.
+/// `get_http_server_status`) that wants to surface it later.
+pub fn current(app: &AppHandle) -> InstanceLockStatus {
+    app.try_state::<InstanceLockStatus>()
+        .map(|s| *s.inner())
+        .unwrap_or_else(InstanceLockStatus::none)
+}
+
+/// Check for a live instance already holding the lock file, then stamp it with this process's
+/// PID. A lock file left behind by a crashed process (its PID no longer alive) is treated as
+/// stale and overwritten rather than blocking startup.
+pub fn acquire(app: &AppHandle, headless: bool) -> InstanceLockStatus {
+    let path = match lock_path(app) {
+        Ok(p) => p,
+        Err(e) => {
+            log::warn!("Failed to resolve instance lock path: {e}");
+            return InstanceLockStatus::none();
+        }
+    };
+
+    let existing = fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<LockFileContents>(&contents).ok());
+
+    let status = match existing {
+        Some(other) if crate::platform::is_process_alive(other.pid) => {
+            log::warn!(
+                "Another Jean instance (pid {}, headless: {}) is already using this data directory",
+                other.pid,
+                other.headless
+            );
+            InstanceLockStatus {
+                other_instance_running: true,
+                other_instance_pid: Some(other.pid),
+                other_instance_headless: Some(other.headless),
+            }
+        }
+        Some(_) => {
+            log::trace!("Found stale instance lock file, overwriting");
+            InstanceLockStatus::none()
+        }
+        None => InstanceLockStatus::none(),
+    };
+
+    if !status.other_instance_running {
+        let contents = LockFileContents {
+            pid: std::process::id(),
+            headless,
+        };
+        match serde_json::to_string(&contents) {
+            Ok(json) => {
+                if let Err(e) = fs::write(&path, json) {
+                    log::warn!("Failed to write instance lock file: {e}");
+                }
+            }
+            Err(e) => log::warn!("Failed to serialize instance lock contents: {e}"),
+        }
+    }
+
+    status
+}
@@ -8,14 +8,30 @@ use tauri::{AppHandle, Emitter, Manager};
 #[cfg(target_os = "macos")]
 use tauri::menu::{MenuBuilder, MenuItemBuilder, PredefinedMenuItem, SubmenuBuilder};
 
+mod activity;
 mod background_tasks;
+mod backup;
 mod chat;
 mod claude_cli;
+mod data_dir;
+mod encryption;
 mod gh_cli;
 pub mod http_server;
+mod instance_lock;
+mod notifications;
+mod preference_profiles;
 mod platform;
+mod power;
+mod process_reaper;
 mod projects;
+mod remote;
+mod scripts;
+mod storage_migrations;
+mod storage_usage;
+mod sync;
 mod terminal;
+mod trash;
+mod tray;
 
 // Validation functions
 fn validate_filename(filename: &str) -> Result<(), String> {
@@ -105,6 +121,8 @@ pub struct AppPreferences {
     pub keybindings: std::collections::HashMap<String, String>, // User-configurable keyboard shortcuts
     #[serde(default = "default_archive_retention_days")]
     pub archive_retention_days: u32, // Days to keep archived items before auto-cleanup (0 = disabled)
+    #[serde(default)]
+    pub session_idle_archive_days: u32, // Auto-archive a session after this many idle days (0 = disabled)
     #[serde(default = "default_session_grouping_enabled")]
     pub session_grouping_enabled: bool, // Group session tabs by status when >3 sessions
     #[serde(default = "default_syntax_theme_dark")]
@@ -133,6 +151,10 @@ pub struct AppPreferences {
     pub waiting_sound: String, // Sound when session is waiting for input: none, ding, chime, pop, choochoo
     #[serde(default = "default_review_sound")]
     pub review_sound: String, // Sound when session finishes reviewing: none, ding, chime, pop, choochoo
+    #[serde(default = "default_error_sound")]
+    pub error_sound: String, // Sound when a run errors (see notifications::sounds): none, ding, chime, pop, choochoo, or a custom audio file path
+    #[serde(default = "default_pr_merged_sound")]
+    pub pr_merged_sound: String, // Sound when a worktree's PR is merged (see notifications::sounds): none, ding, chime, pop, choochoo, or a custom audio file path
     #[serde(default)]
     pub http_server_auto_start: bool, // Auto-start HTTP server on app launch
     #[serde(default = "default_http_server_port")]
@@ -141,6 +163,68 @@ pub struct AppPreferences {
     pub http_server_token: Option<String>, // Persisted auth token (generated once)
     #[serde(default)]
     pub http_server_localhost_only: bool, // Bind to localhost only (more secure)
+    #[serde(default)]
+    pub openai_compat_base_url: Option<String>, // Base URL for the OpenAI-compatible provider (e.g. https://openrouter.ai/api/v1)
+    #[serde(default)]
+    pub openai_compat_api_key: Option<String>, // API key for the OpenAI-compatible provider
+    #[serde(default)]
+    pub openai_compat_model: Option<String>, // Model name to request from the OpenAI-compatible provider
+    #[serde(default = "default_ollama_base_url")]
+    pub ollama_base_url: String, // Base URL for the local Ollama daemon
+    #[serde(default)]
+    pub ollama_model: Option<String>, // Model name to request from Ollama
+    #[serde(default)]
+    pub codex_cli_path: Option<String>, // Path to the Codex CLI binary (defaults to "codex" on PATH)
+    #[serde(default)]
+    pub global_monthly_budget_usd: Option<f64>, // Fallback monthly AI usage budget for projects with no budget of their own
+    #[serde(default)]
+    pub max_concurrent_runs: Option<u32>, // Cap on simultaneous AI runs across all worktrees (None = unlimited); excess runs wait in chat::run_queue
+    #[serde(default = "default_run_log_retention_days")]
+    pub run_log_retention_days: u32, // Days before a completed run's JSONL log is gzip-compressed (0 = disabled)
+    #[serde(default)]
+    pub pre_run_snapshots_enabled: bool, // Record a rollback-able git snapshot before each AI run (off by default - mutates the worktree's git state)
+    #[serde(default = "default_snapshot_retention_days")]
+    pub snapshot_retention_days: u32, // Days before an old run's snapshot ref is garbage-collected (0 = disabled)
+    #[serde(default)]
+    pub sync_enabled: bool, // Opt-in cross-machine sync (see sync.rs) - off by default
+    #[serde(default)]
+    pub sync_dir: Option<String>, // Directory synced by Dropbox/Syncthing/etc, or a git repo checkout
+    #[serde(default)]
+    pub encryption_enabled: bool, // Encrypt http_server_token and session metadata at rest (see encryption.rs) - off by default
+    #[serde(default)]
+    pub low_priority_background_runs: bool, // Run Claude CLI with reduced CPU/IO priority when another run is active or the app is unfocused (see platform::priority) - off by default
+    #[serde(default = "default_sleep_inhibition_enabled")]
+    pub sleep_inhibition_enabled: bool, // Prevent the OS from sleeping while a Claude process or terminal job is active (see power.rs) - on by default
+    #[serde(default)]
+    pub cli_install_proxy: Option<String>, // HTTP(S) proxy URL used when downloading the Claude/GitHub CLI binaries (see claude_cli/gh_cli commands.rs)
+    #[serde(default)]
+    pub dnd_enabled: bool, // Do-not-disturb quiet hours enabled (see notifications::dnd) - off by default
+    #[serde(default = "default_dnd_start_hour")]
+    pub dnd_start_hour: u8, // Local hour (0-23) quiet hours start
+    #[serde(default = "default_dnd_end_hour")]
+    pub dnd_end_hour: u8, // Local hour (0-23) quiet hours end; may be less than dnd_start_hour (wraps past midnight)
+    #[serde(default = "default_preferences_schema_version")]
+    pub schema_version: u32, // See storage_migrations.rs
+}
+
+fn default_sleep_inhibition_enabled() -> bool {
+    true // Enabled by default - a run dying because the laptop slept is worse than a blocked sleep
+}
+
+fn default_dnd_start_hour() -> u8 {
+    22 // 10pm
+}
+
+fn default_dnd_end_hour() -> u8 {
+    8 // 8am
+}
+
+fn default_preferences_schema_version() -> u32 {
+    crate::storage_migrations::PREFERENCES_SCHEMA_VERSION
+}
+
+fn default_ollama_base_url() -> String {
+    "http://localhost:11434".to_string()
 }
 
 fn default_auto_branch_naming() -> bool {
@@ -222,6 +306,14 @@ fn default_archive_retention_days() -> u32 {
     30 // Keep archived items for 30 days by default
 }
 
+fn default_run_log_retention_days() -> u32 {
+    14 // Compress run logs older than 2 weeks by default
+}
+
+fn default_snapshot_retention_days() -> u32 {
+    14 // Garbage-collect snapshot refs older than 2 weeks by default
+}
+
 fn default_syntax_theme_dark() -> String {
     "vitesse-black".to_string()
 }
@@ -262,6 +354,14 @@ fn default_review_sound() -> String {
     "none".to_string()
 }
 
+fn default_error_sound() -> String {
+    "none".to_string()
+}
+
+fn default_pr_merged_sound() -> String {
+    "none".to_string()
+}
+
 fn default_http_server_port() -> u16 {
     3456
 }
@@ -525,6 +625,10 @@ impl Default for AppPreferences {
             remote_poll_interval: default_remote_poll_interval(),
             keybindings: default_keybindings(),
             archive_retention_days: default_archive_retention_days(),
+            session_idle_archive_days: 0,
+            run_log_retention_days: default_run_log_retention_days(),
+            pre_run_snapshots_enabled: false,
+            snapshot_retention_days: default_snapshot_retention_days(),
             session_grouping_enabled: default_session_grouping_enabled(),
             syntax_theme_dark: default_syntax_theme_dark(),
             syntax_theme_light: default_syntax_theme_light(),
@@ -539,10 +643,30 @@ impl Default for AppPreferences {
             allow_web_tools_in_plan_mode: default_allow_web_tools_in_plan_mode(),
             waiting_sound: default_waiting_sound(),
             review_sound: default_review_sound(),
+            error_sound: default_error_sound(),
+            pr_merged_sound: default_pr_merged_sound(),
             http_server_auto_start: false,
             http_server_port: default_http_server_port(),
             http_server_token: None,
             http_server_localhost_only: true, // Default to localhost-only for security
+            openai_compat_base_url: None,
+            openai_compat_api_key: None,
+            openai_compat_model: None,
+            ollama_base_url: default_ollama_base_url(),
+            ollama_model: None,
+            codex_cli_path: None,
+            global_monthly_budget_usd: None,
+            max_concurrent_runs: None,
+            sync_enabled: false,
+            sync_dir: None,
+            encryption_enabled: false,
+            low_priority_background_runs: false,
+            sleep_inhibition_enabled: default_sleep_inhibition_enabled(),
+            cli_install_proxy: None,
+            dnd_enabled: false,
+            dnd_start_hour: default_dnd_start_hour(),
+            dnd_end_hour: default_dnd_end_hour(),
+            schema_version: default_preferences_schema_version(),
         }
     }
 }
@@ -633,10 +757,7 @@ impl Default for UIState {
 }
 
 fn get_preferences_path(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
 
     // Ensure the directory exists
     std::fs::create_dir_all(&app_data_dir)
@@ -645,10 +766,10 @@ fn get_preferences_path(app: &AppHandle) -> Result<PathBuf, String> {
     Ok(app_data_dir.join("preferences.json"))
 }
 
-#[tauri::command]
-async fn load_preferences(app: AppHandle) -> Result<AppPreferences, String> {
-    log::trace!("Loading preferences from disk");
-    let prefs_path = get_preferences_path(&app)?;
+/// Plain (non-async) preferences load, usable from contexts without a tauri command
+/// runtime (e.g. a synchronous `AiProvider::spawn` implementation).
+pub(crate) fn load_preferences_sync(app: &AppHandle) -> Result<AppPreferences, String> {
+    let prefs_path = get_preferences_path(app)?;
 
     if !prefs_path.exists() {
         log::trace!("Preferences file not found, using defaults");
@@ -660,11 +781,25 @@ async fn load_preferences(app: AppHandle) -> Result<AppPreferences, String> {
         format!("Failed to read preferences file: {e}")
     })?;
 
-    let preferences: AppPreferences = serde_json::from_str(&contents).map_err(|e| {
+    let mut preferences: AppPreferences = serde_json::from_str(&contents).map_err(|e| {
         log::error!("Failed to parse preferences JSON: {e}");
         format!("Failed to parse preferences: {e}")
     })?;
 
+    // Transparently decrypt the token if it was written encrypted (see encryption.rs).
+    // `decrypt_string_if_encrypted` is a no-op on a plaintext token, so this is safe to run
+    // regardless of `encryption_enabled`'s current value.
+    if let Some(token) = &preferences.http_server_token {
+        preferences.http_server_token = Some(encryption::decrypt_string_if_encrypted(token)?);
+    }
+
+    Ok(preferences)
+}
+
+#[tauri::command]
+async fn load_preferences(app: AppHandle) -> Result<AppPreferences, String> {
+    log::trace!("Loading preferences from disk");
+    let preferences = load_preferences_sync(&app)?;
     log::trace!("Successfully loaded preferences");
     Ok(preferences)
 }
@@ -677,7 +812,17 @@ async fn save_preferences(app: AppHandle, preferences: AppPreferences) -> Result
     log::trace!("Saving preferences to disk: {preferences:?}");
     let prefs_path = get_preferences_path(&app)?;
 
-    let json_content = serde_json::to_string_pretty(&preferences).map_err(|e| {
+    // Encrypt the token on disk when enabled; `preferences` itself keeps the plaintext value
+    // so the caller's in-memory copy (and this function's return to it) is unaffected.
+    let mut on_disk = preferences.clone();
+    if let Some(token) = &preferences.http_server_token {
+        on_disk.http_server_token = Some(encryption::encrypt_string_if_enabled(
+            token,
+            preferences.encryption_enabled,
+        )?);
+    }
+
+    let json_content = serde_json::to_string_pretty(&on_disk).map_err(|e| {
         log::error!("Failed to serialize preferences: {e}");
         format!("Failed to serialize preferences: {e}")
     })?;
@@ -703,10 +848,7 @@ async fn save_preferences(app: AppHandle, preferences: AppPreferences) -> Result
 }
 
 fn get_ui_state_path(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
 
     // Ensure the directory exists
     std::fs::create_dir_all(&app_data_dir)
@@ -774,6 +916,20 @@ async fn send_native_notification(
     app: AppHandle,
     title: String,
     body: Option<String>,
+) -> Result<(), String> {
+    if notifications::dnd::maybe_queue(&app, &title, body.as_deref(), None) {
+        return Ok(());
+    }
+    show_native_notification(&app, &title, body.as_deref())
+}
+
+/// Show a native OS notification. Shared by the `send_native_notification` command and
+/// `notifications::rules`, which fires native notifications from the rule engine without
+/// going through the command/IPC layer.
+pub(crate) fn show_native_notification(
+    app: &AppHandle,
+    title: &str,
+    body: Option<&str>,
 ) -> Result<(), String> {
     log::trace!("Sending native notification: {title}");
 
@@ -808,10 +964,7 @@ async fn send_native_notification(
 
 // Recovery functions - simple pattern for saving JSON data to disk
 fn get_recovery_dir(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
 
     let recovery_dir = app_data_dir.join("recovery");
 
@@ -1011,12 +1164,15 @@ async fn start_http_server(
 
     // Start the server
     let handle = http_server::server::start_server(app.clone(), actual_port, token, localhost_only).await?;
+    let instance_lock_status = instance_lock::current(&app);
     let status = http_server::server::ServerStatus {
         running: true,
         url: Some(handle.url.clone()),
         token: Some(handle.token.clone()),
         port: Some(handle.port),
         localhost_only: Some(handle.localhost_only),
+        other_instance_running: instance_lock_status.other_instance_running,
+        other_instance_pid: instance_lock_status.other_instance_pid,
     };
 
     // Store the handle
@@ -1092,12 +1248,15 @@ async fn start_http_server_headless(
     // Start the server
     let handle =
         http_server::server::start_server(app.clone(), port, token, localhost_only).await?;
+    let instance_lock_status = instance_lock::current(&app);
     let status = http_server::server::ServerStatus {
         running: true,
         url: Some(handle.url.clone()),
         token: Some(handle.token.clone()),
         port: Some(handle.port),
         localhost_only: Some(handle.localhost_only),
+        other_instance_running: instance_lock_status.other_instance_running,
+        other_instance_pid: instance_lock_status.other_instance_pid,
     };
 
     // Store the handle
@@ -1233,6 +1392,7 @@ pub fn run() {
     // Parse CLI arguments for headless mode
     let args: Vec<String> = std::env::args().collect();
     let headless = args.iter().any(|a| a == "--headless");
+    data_dir::set_cli_override_from_args(&args);
 
     // Fix PATH environment for macOS GUI applications
     // GUI apps don't inherit shell PATH - spawns login shell to get PATH from profiles
@@ -1333,6 +1493,17 @@ pub fn run() {
                 }
             }
 
+            // Stamp/upgrade schema versions on persisted documents before anything else
+            // reads them (see storage_migrations.rs).
+            if let Err(e) = storage_migrations::run_startup_migrations(&app.handle().clone()) {
+                log::warn!("Failed to run storage migrations: {e}");
+            }
+
+            // Detect whether another Jean instance is already using this data directory, so
+            // get_http_server_status can surface it to the frontend.
+            let instance_lock_status = instance_lock::acquire(&app.handle().clone(), headless);
+            app.manage(instance_lock_status);
+
             // Recover any incomplete runs from previous session (crash recovery)
             let app_handle = app.handle().clone();
             match chat::run_log::recover_incomplete_runs(&app_handle) {
@@ -1455,6 +1626,58 @@ pub fn run() {
             app.manage(task_manager);
             log::trace!("Background task manager initialized");
 
+            // Start the scheduled-prompts poller (independent of window focus)
+            chat::schedule::start_poller(app.handle().clone());
+
+            // Start the background retention sweep (idle-session auto-archival + archive cleanup)
+            chat::retention::start_sweep(app.handle().clone());
+
+            // Start the trash expiry sweep (purges items deleted via the trash layer once old)
+            trash::start_expiry_sweep(app.handle().clone());
+
+            // Start the weekly per-worktree activity summary sweep
+            activity::start_weekly_summary_sweep(app.handle().clone());
+
+            // Start the resource-usage sweep (broadcasts `process:stats` for Claude/terminal processes)
+            chat::registry::start_process_stats_sweep(app.handle().clone());
+
+            // Start the do-not-disturb digest sweep (delivers queued notifications once quiet hours end)
+            notifications::dnd::start_digest_sweep(app.handle().clone());
+
+            // Tray icon + dock/taskbar attention badge (sessions awaiting input, failed runs,
+            // PRs with requested changes)
+            app.manage(tray::AttentionTracker::default());
+            if let Err(e) = tray::create(app) {
+                log::error!("Failed to create tray icon: {e}");
+            }
+
+            // Terminals from the previous run didn't survive the restart - log and drop them
+            // rather than leaving stale entries in the persisted index forever.
+            let orphaned = terminal::take_orphaned_terminals(app.handle());
+            if !orphaned.is_empty() {
+                log::info!(
+                    "{} terminal(s) did not survive the last restart: {:?}",
+                    orphaned.len(),
+                    orphaned.iter().map(|t| &t.worktree_id).collect::<Vec<_>>()
+                );
+            }
+
+            // Kill any Claude CLI / terminal processes recorded by a previous run that are still
+            // running, and report what was cleaned via `process:orphans-reaped`. A clean exit
+            // kills terminals synchronously, but detached Claude CLI runs outlive any exit, so
+            // one still running from before a clean quit is reaped here too.
+            let reaped = process_reaper::reap_orphans(app.handle());
+            if !reaped.is_empty() {
+                log::info!(
+                    "Reaped {} orphaned process(es) from a previous run: {:?}",
+                    reaped.len(),
+                    reaped.iter().map(|p| &p.id).collect::<Vec<_>>()
+                );
+            }
+
+            // Initialize direct GitHub API client (used alongside the `gh` CLI for hot paths)
+            app.manage(gh_cli::api_client::GhApiClient::new());
+
             // Initialize HTTP server infrastructure
             let (broadcaster, _) = http_server::WsBroadcaster::new();
             app.manage(broadcaster);
@@ -1523,7 +1746,31 @@ pub fn run() {
             save_preferences,
             load_ui_state,
             save_ui_state,
+            storage_migrations::get_storage_info,
+            backup::create_backup,
+            backup::restore_backup,
+            sync::sync_now,
+            data_dir::migrate_data_dir,
+            trash::list_trash,
+            trash::restore_from_trash,
+            trash::empty_trash,
+            preference_profiles::save_preference_profile,
+            preference_profiles::list_preference_profiles,
+            preference_profiles::delete_preference_profile,
+            preference_profiles::switch_preference_profile,
+            preference_profiles::export_preferences,
+            preference_profiles::import_preferences,
+            storage_usage::get_storage_usage,
+            activity::get_worktree_activity,
             send_native_notification,
+            notifications::list_notification_rules,
+            notifications::create_notification_rule,
+            notifications::update_notification_rule,
+            notifications::delete_notification_rule,
+            notifications::list_notifications,
+            notifications::mark_notification_read,
+            notifications::mark_all_notifications_read,
+            notifications::validate_sound_path,
             save_emergency_data,
             load_emergency_data,
             cleanup_old_recovery_files,
@@ -1557,16 +1804,58 @@ pub fn run() {
             projects::open_worktree_in_terminal,
             projects::open_worktree_in_editor,
             projects::open_pull_request,
+            projects::merge_pr,
             projects::create_pr_with_ai_content,
             projects::create_commit_with_ai,
             projects::run_review_with_ai,
             projects::commit_changes,
+            projects::commit_patch_hunks,
+            projects::get_pr_review_comments,
+            projects::add_pr_comment,
+            projects::reply_to_review_thread,
+            projects::add_issue_comment,
+            projects::submit_pr_review,
+            projects::link_pr_to_issue,
+            projects::get_branch_protection,
+            projects::detect_repo_info,
+            projects::list_dependency_update_prs,
+            projects::checkout_dependency_prs_combined,
+            projects::batch_merge_dependency_prs,
+            projects::list_open_change_requests,
+            projects::pr_status::get_pr_checks,
+            projects::pr_status::rerun_failed_checks,
+            projects::pr_status::get_remote_pr_diff,
+            projects::list_workflow_runs,
+            projects::get_workflow_run_jobs,
+            projects::rerun_workflow_run,
+            projects::cancel_workflow_run,
+            projects::watch_workflow_run,
+            projects::stream_workflow_logs,
+            projects::set_pr_auto_merge,
+            projects::set_pr_ready,
+            projects::request_pr_reviewers,
+            projects::remove_pr_reviewers,
+            projects::set_pr_assignees,
+            projects::suggest_pr_reviewers,
+            projects::list_labels,
+            projects::set_issue_labels,
+            projects::set_pr_labels,
+            projects::list_milestones,
+            projects::set_milestone,
+            projects::create_github_issue,
+            projects::list_issue_templates,
+            projects::update_github_issue,
+            projects::draft_github_issue_with_ai,
             projects::open_project_on_github,
             projects::open_branch_on_github,
             projects::list_worktree_files,
+            projects::generate_repo_map,
             projects::get_project_branches,
             projects::update_project_settings,
+            projects::validate_shell_path,
+            projects::get_effective_env,
             projects::get_pr_prompt,
+            projects::list_pr_templates,
             projects::get_review_prompt,
             projects::save_worktree_pr,
             projects::clear_worktree_pr,
@@ -1582,6 +1871,7 @@ pub fn run() {
             projects::reorder_projects,
             projects::reorder_worktrees,
             projects::fetch_worktrees_status,
+            projects::fetch_worktrees_pr_status,
             // Claude CLI skills & commands
             projects::list_claude_skills,
             projects::list_claude_commands,
@@ -1596,6 +1886,7 @@ pub fn run() {
             projects::list_github_prs,
             projects::search_github_prs,
             projects::get_github_pr,
+            projects::list_prs_awaiting_my_review,
             projects::load_pr_context,
             projects::list_loaded_pr_contexts,
             projects::remove_pr_context,
@@ -1615,6 +1906,16 @@ pub fn run() {
             // Avatar commands
             projects::set_project_avatar,
             projects::remove_project_avatar,
+            projects::set_project_gh_account,
+            projects::set_project_gitea_config,
+            projects::set_project_budget,
+            projects::set_project_env_vars,
+            projects::set_project_notification_webhooks,
+            projects::set_project_instructions,
+            projects::set_project_auto_commit_after_run,
+            projects::set_project_muted,
+            projects::set_project_sandbox_config,
+            projects::set_worktree_instructions,
             projects::get_app_data_dir,
             // Terminal commands
             terminal::start_terminal,
@@ -1623,13 +1924,22 @@ pub fn run() {
             terminal::stop_terminal,
             terminal::get_active_terminals,
             terminal::has_active_terminal,
+            terminal::reattach_terminal,
+            terminal::list_terminals,
             terminal::get_run_script,
             terminal::kill_all_terminals,
+            scripts::run_project_script,
+            remote::get_remote_git_status,
+            remote::get_remote_git_diff,
             // Chat commands - Session management
             chat::get_sessions,
             chat::list_all_sessions,
             chat::get_session,
+            chat::get_message_count,
             chat::create_session,
+            chat::fork_session,
+            chat::compare_models,
+            chat::broadcast_prompt,
             chat::rename_session,
             chat::update_session_state,
             chat::close_session,
@@ -1645,11 +1955,41 @@ pub fn run() {
             chat::send_chat_message,
             chat::clear_session_history,
             chat::set_session_model,
+            chat::set_session_provider,
             chat::set_session_thinking_level,
+            chat::set_session_env_vars,
+            chat::list_ollama_models,
+            chat::get_usage_report,
+            chat::get_budget_status,
+            chat::get_session_context_usage,
+            chat::export_session,
+            chat::import_session,
+            chat::list_queued_messages,
+            chat::cancel_queued_message,
+            chat::list_offline_queue,
+            chat::cancel_offline_queued_message,
+            chat::list_queued_runs,
+            chat::cancel_queued_run,
+            chat::get_run_log,
+            chat::list_runs,
+            chat::compress_old_run_logs,
+            chat::preview_retention_policy,
+            chat::list_snapshots,
+            chat::rollback_to_snapshot,
+            chat::gc_old_snapshots,
+            chat::schedule_prompt,
+            chat::list_scheduled_prompts,
+            chat::cancel_scheduled_prompt,
+            chat::create_pipeline,
+            chat::run_pipeline,
+            chat::list_pipelines,
+            chat::cancel_pipeline,
             chat::cancel_chat_message,
             chat::has_running_sessions,
+            chat::get_process_stats,
             chat::save_cancelled_message,
             chat::mark_plan_approved,
+            chat::get_plan_impact,
             // Chat commands - Image handling
             chat::save_pasted_image,
             chat::save_dropped_image,
@@ -1671,6 +2011,16 @@ pub fn run() {
             chat::delete_context_file,
             chat::rename_saved_context,
             chat::generate_context_from_session,
+            // Chat commands - File/directory context attachments
+            chat::attach_file_context,
+            chat::list_file_context,
+            chat::remove_file_context,
+            chat::list_followups,
+            chat::set_followup_completed,
+            chat::retrieve_relevant_context,
+            // Chat commands - Search index
+            chat::rebuild_search_index,
+            chat::search_messages,
             // Chat commands - Session digest (context recall)
             chat::generate_session_digest,
             // Chat commands - Real-time setting sync
@@ -1680,6 +2030,7 @@ pub fn run() {
             // Chat commands - Session resume (detached process recovery)
             chat::resume_session,
             chat::check_resumable_sessions,
+            chat::list_recoverable_runs,
             // Claude CLI management commands
             claude_cli::check_claude_cli_installed,
             claude_cli::check_claude_cli_auth,
@@ -1688,6 +2039,8 @@ pub fn run() {
             // GitHub CLI management commands
             gh_cli::check_gh_cli_installed,
             gh_cli::check_gh_cli_auth,
+            gh_cli::list_gh_accounts,
+            gh_cli::get_gh_rate_limit,
             gh_cli::get_available_gh_versions,
             gh_cli::install_gh_cli,
             // Background task commands
@@ -1712,6 +2065,7 @@ pub fn run() {
                 eprintln!("[TERMINAL CLEANUP] RunEvent::Exit received");
                 let killed = terminal::cleanup_all_terminals();
                 eprintln!("[TERMINAL CLEANUP] Killed {killed} terminal(s)");
+                power::release_all();
             }
             tauri::RunEvent::ExitRequested { api, .. } => {
                 // In headless mode, prevent exit when window closes
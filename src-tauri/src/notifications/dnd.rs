@@ -0,0 +1,208 @@
+//! Do-not-disturb: a global quiet-hours schedule (`AppPreferences::dnd_enabled` /
+//! `dnd_start_hour` / `dnd_end_hour`) plus per-project muting (`Project::muted`).
+//!
+//! Unlike `rules.rs`/`integrations.rs`, DND doesn't hook [`super::on_event`] - it needs to
+//! suppress a notification *before* it fires rather than react after, so it's called
+//! directly from `send_native_notification` and `rules::run_action`'s `Native` branch via
+//! [`maybe_queue`]. A notification suppressed this way is queued to `dnd-queue.json` and
+//! delivered as a single digest notification once quiet hours end, via
+//! [`start_digest_sweep`].
+
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::Timelike;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+/// How often the digest sweep checks whether quiet hours have ended.
+const SWEEP_INTERVAL_SECS: u64 = 60;
+
+/// A notification suppressed by do-not-disturb, held until the digest sweep delivers it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct QueuedNotification {
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct QueueFile {
+    #[serde(default)]
+    queued: Vec<QueuedNotification>,
+}
+
+fn queue_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::data_dir::resolve(app)?.join("dnd-queue.json"))
+}
+
+fn load_queue(app: &AppHandle) -> Result<QueueFile, String> {
+    let path = queue_path(app)?;
+    if !path.exists() {
+        return Ok(QueueFile::default());
+    }
+    let contents =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read DND queue: {e}"))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse DND queue: {e}"))
+}
+
+fn save_queue(app: &AppHandle, queue: &QueueFile) -> Result<(), String> {
+    let path = queue_path(app)?;
+    let json = serde_json::to_string_pretty(queue)
+        .map_err(|e| format!("Failed to serialize DND queue: {e}"))?;
+
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, json).map_err(|e| format!("Failed to write DND queue: {e}"))?;
+    fs::rename(&temp_path, &path).map_err(|e| format!("Failed to finalize DND queue: {e}"))?;
+    Ok(())
+}
+
+/// Whether the current local time falls within the configured quiet hours.
+fn is_quiet_hours(start_hour: u8, end_hour: u8) -> bool {
+    let hour = chrono::Local::now().hour() as u8;
+    hour_is_within_quiet_hours(hour, start_hour, end_hour)
+}
+
+/// Whether `hour` falls within `[start_hour, end_hour)`. Handles a window that wraps past
+/// midnight (e.g. 22 -> 8) as well as one that doesn't (e.g. 1 -> 5). Split out from
+/// [`is_quiet_hours`] so the wrapping logic can be unit tested without mocking the clock.
+fn hour_is_within_quiet_hours(hour: u8, start_hour: u8, end_hour: u8) -> bool {
+    if start_hour == end_hour {
+        return false;
+    }
+    if start_hour < end_hour {
+        hour >= start_hour && hour < end_hour
+    } else {
+        hour >= start_hour || hour < end_hour
+    }
+}
+
+/// Whether the project that owns `worktree_id` has muted notifications, via `Project::muted`.
+fn is_project_muted(app: &AppHandle, worktree_id: &str) -> bool {
+    let Ok(data) = crate::projects::storage::load_projects_data(app) else {
+        return false;
+    };
+    let Some(worktree) = data.find_worktree(worktree_id) else {
+        return false;
+    };
+    data.find_project(&worktree.project_id)
+        .is_some_and(|p| p.muted)
+}
+
+/// Check whether `title`/`body` should be suppressed by do-not-disturb - either the global
+/// quiet-hours schedule or, if `worktree_id` is known, the worktree's project being muted.
+/// If suppressed, `true` is returned so the caller skips showing it: a quiet-hours
+/// notification is appended to the digest queue, while a muted project's notification is
+/// dropped. Otherwise returns `false` and the caller proceeds normally.
+pub fn maybe_queue(
+    app: &AppHandle,
+    title: &str,
+    body: Option<&str>,
+    worktree_id: Option<&str>,
+) -> bool {
+    if let Some(worktree_id) = worktree_id {
+        if is_project_muted(app, worktree_id) {
+            return true; // Muted notifications are dropped, not queued for a digest.
+        }
+    }
+
+    let prefs = crate::load_preferences_sync(app).unwrap_or_default();
+    if !prefs.dnd_enabled || !is_quiet_hours(prefs.dnd_start_hour, prefs.dnd_end_hour) {
+        return false;
+    }
+
+    let mut queue = match load_queue(app) {
+        Ok(queue) => queue,
+        Err(e) => {
+            log::warn!("Failed to load DND queue, dropping notification: {e}");
+            return true;
+        }
+    };
+    queue.queued.push(QueuedNotification {
+        title: title.to_string(),
+        body: body.map(str::to_string),
+    });
+    if let Err(e) = save_queue(app, &queue) {
+        log::warn!("Failed to save DND queue: {e}");
+    }
+    true
+}
+
+/// If quiet hours have ended and notifications are queued, deliver them as a single digest
+/// native notification and clear the queue.
+fn maybe_flush_digest(app: &AppHandle) {
+    let prefs = crate::load_preferences_sync(app).unwrap_or_default();
+    if prefs.dnd_enabled && is_quiet_hours(prefs.dnd_start_hour, prefs.dnd_end_hour) {
+        return;
+    }
+
+    let queue = match load_queue(app) {
+        Ok(queue) => queue,
+        Err(e) => {
+            log::warn!("Failed to load DND queue: {e}");
+            return;
+        }
+    };
+    if queue.queued.is_empty() {
+        return;
+    }
+
+    let body = queue
+        .queued
+        .iter()
+        .map(|n| n.title.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let title = format!("{} notifications while you were away", queue.queued.len());
+    if let Err(e) = crate::show_native_notification(app, &title, Some(&body)) {
+        log::warn!("Failed to show DND digest notification: {e}");
+        return;
+    }
+
+    if let Err(e) = save_queue(app, &QueueFile::default()) {
+        log::warn!("Failed to clear DND queue after digest: {e}");
+    }
+}
+
+/// Spawn a background thread that periodically checks whether quiet hours have ended and,
+/// if so, delivers any queued notifications as a digest. Mirrors
+/// `activity::start_weekly_summary_sweep`/`trash::start_expiry_sweep`.
+pub fn start_digest_sweep(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(std::time::Duration::from_secs(SWEEP_INTERVAL_SECS));
+        maybe_flush_digest(&app);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hour_is_within_quiet_hours_non_wrapping() {
+        // 1 -> 5: quiet between 1am (inclusive) and 5am (exclusive)
+        assert!(!hour_is_within_quiet_hours(0, 1, 5));
+        assert!(hour_is_within_quiet_hours(1, 1, 5));
+        assert!(hour_is_within_quiet_hours(4, 1, 5));
+        assert!(!hour_is_within_quiet_hours(5, 1, 5));
+    }
+
+    #[test]
+    fn test_hour_is_within_quiet_hours_wrapping_past_midnight() {
+        // 22 -> 8: quiet from 10pm through 8am, wrapping past midnight
+        assert!(hour_is_within_quiet_hours(22, 22, 8));
+        assert!(hour_is_within_quiet_hours(23, 22, 8));
+        assert!(hour_is_within_quiet_hours(0, 22, 8));
+        assert!(hour_is_within_quiet_hours(7, 22, 8));
+        assert!(!hour_is_within_quiet_hours(8, 22, 8));
+        assert!(!hour_is_within_quiet_hours(12, 22, 8));
+    }
+
+    #[test]
+    fn test_hour_is_within_quiet_hours_equal_bounds_disabled() {
+        // start == end means no quiet hours window at all, regardless of hour.
+        assert!(!hour_is_within_quiet_hours(0, 9, 9));
+        assert!(!hour_is_within_quiet_hours(9, 9, 9));
+        assert!(!hour_is_within_quiet_hours(23, 9, 9));
+    }
+}
@@ -0,0 +1,199 @@
+//! Persists notifications and other important events (failed runs, suspended background
+//! tasks) so they don't vanish the moment a native notification's banner disappears.
+//!
+//! Hooked into the central pipeline from `super::on_event`: any event carrying an `error`
+//! field, plus a small set of named "important" events, is recorded here automatically.
+//! `rules::run_action`'s `Native` action also records its title/body directly, since a
+//! rule-fired notification is exactly the kind of thing a user would want to find again
+//! later.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::AppHandle;
+
+use crate::http_server::EmitExt;
+
+/// Oldest entries beyond this count are dropped on write, so the history file can't grow
+/// without bound.
+const MAX_ENTRIES: usize = 500;
+
+/// Guards read-modify-write races on notification-history.json.
+static HISTORY_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// Severity of a recorded notification, used by the frontend to pick an icon.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationLevel {
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+/// A single persisted notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationEntry {
+    pub id: String,
+    pub created_at: u64,
+    pub title: String,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub level: NotificationLevel,
+    /// The app event this was recorded from, if any (e.g. `"chat:error"`).
+    #[serde(default)]
+    pub source_event: Option<String>,
+    #[serde(default)]
+    pub read: bool,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn history_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::data_dir::resolve(app)?.join("notification-history.json"))
+}
+
+fn load_entries(app: &AppHandle) -> Result<Vec<NotificationEntry>, String> {
+    let path = history_path(app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read notification history: {e}"))?;
+    serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse notification history: {e}"))
+}
+
+fn save_entries(app: &AppHandle, entries: &[NotificationEntry]) -> Result<(), String> {
+    let path = history_path(app)?;
+    let json = serde_json::to_string_pretty(entries)
+        .map_err(|e| format!("Failed to serialize notification history: {e}"))?;
+
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, json)
+        .map_err(|e| format!("Failed to write notification history: {e}"))?;
+    fs::rename(&temp_path, &path)
+        .map_err(|e| format!("Failed to finalize notification history: {e}"))?;
+    Ok(())
+}
+
+/// Number of unread entries, broadcast to the frontend as `notification:unread-count`
+/// after every change so a badge can stay in sync.
+fn emit_unread_count(app: &AppHandle, entries: &[NotificationEntry]) {
+    let unread = entries.iter().filter(|e| !e.read).count();
+    if let Err(e) = app.emit_all(
+        "notification:unread-count",
+        &serde_json::json!({ "count": unread }),
+    ) {
+        log::warn!("Failed to emit notification unread count: {e}");
+    }
+}
+
+/// Append a notification to the history, trimming to `MAX_ENTRIES` and emitting the
+/// updated unread count. Best-effort: a failure is logged and swallowed, the same as
+/// `activity::record`, so a full disk never blocks whatever triggered the notification.
+pub fn record(app: &AppHandle, title: &str, body: Option<&str>, level: NotificationLevel, source_event: Option<&str>) {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+
+    let mut entries = match load_entries(app) {
+        Ok(entries) => entries,
+        Err(e) => {
+            log::warn!("Failed to load notification history: {e}");
+            return;
+        }
+    };
+
+    entries.push(NotificationEntry {
+        id: uuid::Uuid::new_v4().to_string(),
+        created_at: now(),
+        title: title.to_string(),
+        body: body.map(str::to_string),
+        level,
+        source_event: source_event.map(str::to_string),
+        read: false,
+    });
+
+    if entries.len() > MAX_ENTRIES {
+        let overflow = entries.len() - MAX_ENTRIES;
+        entries.drain(0..overflow);
+    }
+
+    if let Err(e) = save_entries(app, &entries) {
+        log::warn!("Failed to save notification history: {e}");
+        return;
+    }
+    emit_unread_count(app, &entries);
+}
+
+/// Record an "important event" straight from the central notification pipeline: any event
+/// carrying a non-empty `error` field, or a known terminal/suspension event, without
+/// requiring a notification rule to be configured for it.
+pub fn on_event(app: &AppHandle, event: &str, payload: &Value) {
+    if let Some(error) = payload.get("error").and_then(Value::as_str) {
+        if !error.is_empty() {
+            record(app, "Run failed", Some(error), NotificationLevel::Error, Some(event));
+        }
+        return;
+    }
+
+    if event == "process:orphans-reaped" {
+        let count = payload.as_array().map_or(0, Vec::len);
+        if count > 0 {
+            record(
+                app,
+                "Suspended background task recovered",
+                Some(&format!("Reaped {count} orphaned process(es) from a previous crash")),
+                NotificationLevel::Warning,
+                Some(event),
+            );
+        }
+    }
+}
+
+/// List every persisted notification, most recent first.
+#[tauri::command]
+pub async fn list_notifications(app: AppHandle) -> Result<Vec<NotificationEntry>, String> {
+    let mut entries = load_entries(&app)?;
+    entries.reverse();
+    Ok(entries)
+}
+
+/// Mark a single notification as read.
+#[tauri::command]
+pub async fn mark_notification_read(app: AppHandle, id: String) -> Result<(), String> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+
+    let mut entries = load_entries(&app)?;
+    let Some(entry) = entries.iter_mut().find(|e| e.id == id) else {
+        return Err(format!("No notification with id '{id}'"));
+    };
+    entry.read = true;
+    save_entries(&app, &entries)?;
+    emit_unread_count(&app, &entries);
+    Ok(())
+}
+
+/// Mark every notification as read in one call, e.g. when the notification center is opened.
+#[tauri::command]
+pub async fn mark_all_notifications_read(app: AppHandle) -> Result<(), String> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+
+    let mut entries = load_entries(&app)?;
+    for entry in &mut entries {
+        entry.read = true;
+    }
+    save_entries(&app, &entries)?;
+    emit_unread_count(&app, &entries);
+    Ok(())
+}
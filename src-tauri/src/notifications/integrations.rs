@@ -0,0 +1,103 @@
+//! Posts selected events to per-project Slack/Discord webhooks (see
+//! [`crate::projects::types::NotificationWebhook`]) so a long agent run, a merged PR, or a
+//! requested review can show up in a team channel without anyone watching Jean directly.
+//!
+//! Hooked into the central notification pipeline from [`super::on_event`], the same as
+//! `rules.rs`: a raw app event is classified into one of the three [`IntegrationEvent`]s a
+//! webhook can subscribe to, the worktree named in the payload is resolved to its project,
+//! and a provider-formatted message is posted to every webhook subscribed to that event.
+
+use serde_json::Value;
+use tauri::AppHandle;
+
+use crate::projects::storage::load_projects_data;
+use crate::projects::types::{IntegrationEvent, NotificationWebhook, WebhookProvider};
+
+/// Map a raw app event name + payload to the [`IntegrationEvent`] it represents, if any.
+fn classify(event: &str, payload: &Value) -> Option<IntegrationEvent> {
+    match event {
+        "chat:done" => Some(IntegrationEvent::RunCompleted),
+        "pr:status-update" => {
+            if payload.get("state").and_then(Value::as_str) == Some("merged") {
+                Some(IntegrationEvent::PrMerged)
+            } else if payload.get("review_decision").and_then(Value::as_str) == Some("review_required") {
+                Some(IntegrationEvent::ReviewRequested)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn webhook_message(kind: IntegrationEvent, project_name: &str, worktree_name: &str) -> String {
+    match kind {
+        IntegrationEvent::RunCompleted => {
+            format!(":white_check_mark: Run completed — *{project_name}* / `{worktree_name}`")
+        }
+        IntegrationEvent::PrMerged => {
+            format!(":twisted_rightwards_arrows: PR merged — *{project_name}* / `{worktree_name}`")
+        }
+        IntegrationEvent::ReviewRequested => {
+            format!(":eyes: Review requested — *{project_name}* / `{worktree_name}`")
+        }
+    }
+}
+
+/// POST `text` to `webhook` in its provider's body shape, fire-and-forget on a background
+/// thread so a slow/unreachable webhook never blocks the caller.
+fn post_webhook(webhook: &NotificationWebhook, text: String) {
+    let body = match webhook.provider {
+        WebhookProvider::Slack => serde_json::json!({ "text": text }),
+        WebhookProvider::Discord => serde_json::json!({ "content": text }),
+    };
+    let url = webhook.url.clone();
+
+    std::thread::spawn(move || {
+        let client = match reqwest::blocking::Client::builder().build() {
+            Ok(client) => client,
+            Err(e) => {
+                log::warn!("Failed to build HTTP client for notification webhook: {e}");
+                return;
+            }
+        };
+        if let Err(e) = client.post(&url).json(&body).send() {
+            log::warn!("Notification webhook {url} failed: {e}");
+        }
+    });
+}
+
+/// Evaluate `event`/`payload` against every project's configured webhooks, posting to any
+/// that subscribe to the matching [`IntegrationEvent`]. Best-effort: a missing worktree,
+/// missing project, or failed POST is logged and swallowed rather than surfaced, the same
+/// as `rules.rs`.
+pub fn evaluate(app: &AppHandle, event: &str, payload: &Value) {
+    let Some(kind) = classify(event, payload) else {
+        return;
+    };
+    let Some(worktree_id) = payload.get("worktree_id").and_then(Value::as_str) else {
+        return;
+    };
+
+    let data = match load_projects_data(app) {
+        Ok(data) => data,
+        Err(e) => {
+            log::warn!("Failed to load projects for notification webhooks: {e}");
+            return;
+        }
+    };
+
+    let Some(worktree) = data.find_worktree(worktree_id) else {
+        return;
+    };
+    let Some(project) = data.find_project(&worktree.project_id) else {
+        return;
+    };
+
+    let text = webhook_message(kind, &project.name, &worktree.name);
+    for webhook in &project.notification_webhooks {
+        if webhook.events.contains(&kind) {
+            post_webhook(webhook, text.clone());
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Central notification pipeline.
+//!
+//! `http_server::EmitExt::emit_all` calls [`on_event`] after every app event, so a
+//! notification feature only has to hook into [`on_event`] once rather than every
+//! individual `emit_all` call site across the codebase.
+
+pub mod dnd;
+pub mod history;
+pub mod integrations;
+pub mod ntfy;
+pub mod rules;
+pub mod sounds;
+
+use serde_json::Value;
+use tauri::AppHandle;
+
+pub use history::{list_notifications, mark_all_notifications_read, mark_notification_read};
+pub use rules::{
+    create_notification_rule, delete_notification_rule, list_notification_rules,
+    update_notification_rule,
+};
+pub use sounds::validate_sound_path;
+
+/// Run every notification feature against an emitted event.
+pub fn on_event(app: &AppHandle, event: &str, payload: &Value) {
+    rules::evaluate(app, event, payload);
+    integrations::evaluate(app, event, payload);
+    history::on_event(app, event, payload);
+
+    if event == "chat:error" {
+        sounds::play(app, sounds::SoundCategory::Error);
+    } else if event == "pr:status-update" && payload.get("state").and_then(Value::as_str) == Some("merged") {
+        sounds::play(app, sounds::SoundCategory::PrMerged);
+    }
+}
@@ -0,0 +1,28 @@
+//! Publishes to [ntfy](https://ntfy.sh) topics so an alert can reach a phone rather than
+//! only the desktop - e.g. "notify my phone when an hour-long agent run finishes while I'm
+//! away from my desk". Fired from a rule's `ntfy` action (see `rules::RuleAction::Ntfy`);
+//! ntfy's HTTP API takes the message as a plain-text POST body with the title in a header,
+//! not a JSON envelope like `integrations::post_webhook`'s Slack/Discord webhooks.
+
+/// POST `title`/`body` to an ntfy topic URL, fire-and-forget on a background thread so a
+/// slow/unreachable ntfy server never blocks the caller. `rule_name` is only used for log
+/// messages on failure.
+pub fn publish(url: String, title: String, body: Option<String>, rule_name: String) {
+    std::thread::spawn(move || {
+        let client = match reqwest::blocking::Client::builder().build() {
+            Ok(client) => client,
+            Err(e) => {
+                log::warn!("Notification rule '{rule_name}': failed to build HTTP client: {e}");
+                return;
+            }
+        };
+        let result = client
+            .post(&url)
+            .header("Title", title)
+            .body(body.unwrap_or_default())
+            .send();
+        if let Err(e) = result {
+            log::warn!("Notification rule '{rule_name}': ntfy publish to {url} failed: {e}");
+        }
+    });
+}
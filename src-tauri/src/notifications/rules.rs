@@ -0,0 +1,415 @@
+//! User-configurable rules that react to app events with a notification, a sound, or a
+//! webhook call - e.g. "notify when a run in an unfocused worktree completes" or "notify
+//! when PR checks fail". Evaluated from [`super::on_event`], which is called for every
+//! event the app emits (see `http_server::EmitExt::emit_all`), so a rule can react to any
+//! event in the system without the emitting code knowing rules exist.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::background_tasks::BackgroundTaskManager;
+use crate::http_server::EmitExt;
+
+fn default_true() -> bool {
+    true
+}
+
+/// How a [`RuleCondition`] compares the field at `field` against `value`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionOperator {
+    Equals,
+    NotEquals,
+    Exists,
+}
+
+/// A single condition checked against the event payload. A rule fires only if all of its
+/// conditions match (AND semantics).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleCondition {
+    /// Dot-separated path into the event payload, e.g. `"check_status"` or
+    /// `"totals.commit_count"`.
+    pub field: String,
+    pub operator: ConditionOperator,
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
+/// What happens when a rule fires.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RuleAction {
+    /// Show a native OS notification.
+    Native {
+        title: String,
+        #[serde(default)]
+        body: Option<String>,
+    },
+    /// Ask the frontend to play a named sound (see `src/lib/notifications.ts`) - actual
+    /// playback stays on the frontend, same as the existing `waiting_sound`/`review_sound`
+    /// preferences.
+    Sound { sound: String },
+    /// POST the event name and its payload as JSON (`{"event": ..., "payload": ...}`) to an
+    /// arbitrary webhook URL.
+    Webhook { url: String },
+    /// Publish a push notification to an ntfy topic URL (e.g. `https://ntfy.sh/my-topic`)
+    /// or a self-hosted ntfy server, for alerts that should reach a phone rather than the
+    /// desktop (see `notifications::ntfy`).
+    Ntfy {
+        url: String,
+        title: String,
+        #[serde(default)]
+        body: Option<String>,
+    },
+}
+
+/// A configurable rule: fire `actions` when `event` is emitted and `conditions` match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationRule {
+    pub id: String,
+    pub name: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Event name this rule reacts to, e.g. `"chat:done"` or `"pr:status-update"`.
+    pub event: String,
+    #[serde(default)]
+    pub conditions: Vec<RuleCondition>,
+    /// Only fire when the payload's `worktree_id` field (if present) isn't the worktree
+    /// currently focused in the UI, e.g. "notify when a run in an unfocused worktree
+    /// completes".
+    #[serde(default)]
+    pub only_when_unfocused: bool,
+    pub actions: Vec<RuleAction>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct RulesFile {
+    #[serde(default)]
+    rules: Vec<NotificationRule>,
+}
+
+fn rules_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::data_dir::resolve(app)?.join("notification-rules.json"))
+}
+
+fn load_rules(app: &AppHandle) -> Result<RulesFile, String> {
+    let path = rules_path(app)?;
+    if !path.exists() {
+        return Ok(RulesFile::default());
+    }
+    let contents =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read notification rules: {e}"))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse notification rules: {e}"))
+}
+
+fn save_rules(app: &AppHandle, rules: &RulesFile) -> Result<(), String> {
+    let path = rules_path(app)?;
+    let json = serde_json::to_string_pretty(rules)
+        .map_err(|e| format!("Failed to serialize notification rules: {e}"))?;
+
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, json).map_err(|e| format!("Failed to write notification rules: {e}"))?;
+    fs::rename(&temp_path, &path)
+        .map_err(|e| format!("Failed to finalize notification rules: {e}"))?;
+    Ok(())
+}
+
+/// List all configured notification rules.
+#[tauri::command]
+pub async fn list_notification_rules(app: AppHandle) -> Result<Vec<NotificationRule>, String> {
+    Ok(load_rules(&app)?.rules)
+}
+
+/// Create a new notification rule, assigning it a fresh id.
+#[tauri::command]
+pub async fn create_notification_rule(
+    app: AppHandle,
+    name: String,
+    event: String,
+    conditions: Vec<RuleCondition>,
+    only_when_unfocused: bool,
+    actions: Vec<RuleAction>,
+) -> Result<NotificationRule, String> {
+    let rule = NotificationRule {
+        id: uuid::Uuid::new_v4().to_string(),
+        name,
+        enabled: true,
+        event,
+        conditions,
+        only_when_unfocused,
+        actions,
+    };
+
+    let mut rules = load_rules(&app)?;
+    rules.rules.push(rule.clone());
+    save_rules(&app, &rules)?;
+    Ok(rule)
+}
+
+/// Replace an existing notification rule in place, keyed by `rule.id`.
+#[tauri::command]
+pub async fn update_notification_rule(
+    app: AppHandle,
+    rule: NotificationRule,
+) -> Result<(), String> {
+    let mut rules = load_rules(&app)?;
+    let Some(existing) = rules.rules.iter_mut().find(|r| r.id == rule.id) else {
+        return Err(format!("No notification rule with id '{}'", rule.id));
+    };
+    *existing = rule;
+    save_rules(&app, &rules)
+}
+
+/// Delete a notification rule by id.
+#[tauri::command]
+pub async fn delete_notification_rule(app: AppHandle, id: String) -> Result<(), String> {
+    let mut rules = load_rules(&app)?;
+    rules.rules.retain(|r| r.id != id);
+    save_rules(&app, &rules)
+}
+
+/// Read the value at `field` (dot-separated path) out of `payload`.
+fn resolve_field<'a>(payload: &'a serde_json::Value, field: &str) -> Option<&'a serde_json::Value> {
+    let mut current = payload;
+    for segment in field.split('.') {
+        current = current.get(segment)?;
+    }
+    Some(current)
+}
+
+fn condition_matches(payload: &serde_json::Value, condition: &RuleCondition) -> bool {
+    let found = resolve_field(payload, &condition.field);
+    match condition.operator {
+        ConditionOperator::Exists => found.is_some(),
+        ConditionOperator::Equals => found == Some(&condition.value),
+        ConditionOperator::NotEquals => found != Some(&condition.value),
+    }
+}
+
+fn rule_matches(
+    app: &AppHandle,
+    rule: &NotificationRule,
+    event: &str,
+    payload: &serde_json::Value,
+) -> bool {
+    if !rule.enabled || rule.event != event {
+        return false;
+    }
+
+    if rule.only_when_unfocused {
+        let app_focused = app
+            .try_state::<BackgroundTaskManager>()
+            .is_some_and(|state| state.is_focused());
+        let active_worktree = app
+            .try_state::<BackgroundTaskManager>()
+            .and_then(|state| state.active_worktree_id());
+        let payload_worktree = payload.get("worktree_id").and_then(|v| v.as_str());
+
+        let is_focused_worktree = app_focused
+            && payload_worktree.is_some()
+            && payload_worktree == active_worktree.as_deref();
+        if is_focused_worktree {
+            return false;
+        }
+    }
+
+    rule.conditions
+        .iter()
+        .all(|c| condition_matches(payload, c))
+}
+
+fn run_action(
+    app: &AppHandle,
+    rule: &NotificationRule,
+    action: &RuleAction,
+    payload: &serde_json::Value,
+) {
+    match action {
+        RuleAction::Native { title, body } => {
+            let worktree_id = payload.get("worktree_id").and_then(|v| v.as_str());
+            if !super::dnd::maybe_queue(app, title, body.as_deref(), worktree_id) {
+                if let Err(e) = crate::show_native_notification(app, title, body.as_deref()) {
+                    log::warn!(
+                        "Notification rule '{}': native notification failed: {e}",
+                        rule.name
+                    );
+                }
+            }
+            super::history::record(
+                app,
+                title,
+                body.as_deref(),
+                super::history::NotificationLevel::Info,
+                Some(rule.event.as_str()),
+            );
+        }
+        RuleAction::Sound { sound } => {
+            if let Err(e) = app.emit_all(
+                "notification:play-sound",
+                &serde_json::json!({ "sound": sound }),
+            ) {
+                log::warn!(
+                    "Notification rule '{}': failed to emit sound event: {e}",
+                    rule.name
+                );
+            }
+        }
+        RuleAction::Webhook { url } => {
+            let url = url.clone();
+            let rule_name = rule.name.clone();
+            let event = rule.event.clone();
+            let payload = payload.clone();
+            std::thread::spawn(move || {
+                let client = match reqwest::blocking::Client::builder().build() {
+                    Ok(client) => client,
+                    Err(e) => {
+                        log::warn!(
+                            "Notification rule '{rule_name}': failed to build HTTP client: {e}"
+                        );
+                        return;
+                    }
+                };
+                let result = client
+                    .post(&url)
+                    .json(&serde_json::json!({ "event": event, "payload": payload }))
+                    .send();
+                if let Err(e) = result {
+                    log::warn!(
+                        "Notification rule '{rule_name}': webhook call to {url} failed: {e}"
+                    );
+                }
+            });
+        }
+        RuleAction::Ntfy { url, title, body } => {
+            super::ntfy::publish(url.clone(), title.clone(), body.clone(), rule.name.clone());
+        }
+    }
+}
+
+/// Evaluate every enabled rule against `event`/`payload`, firing the actions of any rule
+/// that matches. Best-effort: a failure in one rule's action is logged and doesn't stop
+/// the rest from running.
+pub fn evaluate(app: &AppHandle, event: &str, payload: &serde_json::Value) {
+    let rules = match load_rules(app) {
+        Ok(rules) => rules.rules,
+        Err(e) => {
+            log::warn!("Failed to load notification rules: {e}");
+            return;
+        }
+    };
+
+    for rule in &rules {
+        if rule_matches(app, rule, event, payload) {
+            for action in &rule.actions {
+                run_action(app, rule, action, payload);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition(
+        field: &str,
+        operator: ConditionOperator,
+        value: serde_json::Value,
+    ) -> RuleCondition {
+        RuleCondition {
+            field: field.to_string(),
+            operator,
+            value,
+        }
+    }
+
+    #[test]
+    fn test_resolve_field_top_level() {
+        let payload = serde_json::json!({ "check_status": "failed" });
+        assert_eq!(
+            resolve_field(&payload, "check_status"),
+            Some(&serde_json::json!("failed"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_field_nested_path() {
+        let payload = serde_json::json!({ "totals": { "commit_count": 3 } });
+        assert_eq!(
+            resolve_field(&payload, "totals.commit_count"),
+            Some(&serde_json::json!(3))
+        );
+    }
+
+    #[test]
+    fn test_resolve_field_missing() {
+        let payload = serde_json::json!({ "check_status": "failed" });
+        assert_eq!(resolve_field(&payload, "totals.commit_count"), None);
+    }
+
+    #[test]
+    fn test_condition_matches_equals() {
+        let payload = serde_json::json!({ "check_status": "failed" });
+        let matching = condition(
+            "check_status",
+            ConditionOperator::Equals,
+            serde_json::json!("failed"),
+        );
+        let non_matching = condition(
+            "check_status",
+            ConditionOperator::Equals,
+            serde_json::json!("passed"),
+        );
+        assert!(condition_matches(&payload, &matching));
+        assert!(!condition_matches(&payload, &non_matching));
+    }
+
+    #[test]
+    fn test_condition_matches_not_equals() {
+        let payload = serde_json::json!({ "check_status": "failed" });
+        let matching = condition(
+            "check_status",
+            ConditionOperator::NotEquals,
+            serde_json::json!("passed"),
+        );
+        let non_matching = condition(
+            "check_status",
+            ConditionOperator::NotEquals,
+            serde_json::json!("failed"),
+        );
+        assert!(condition_matches(&payload, &matching));
+        assert!(!condition_matches(&payload, &non_matching));
+    }
+
+    #[test]
+    fn test_condition_matches_exists() {
+        let payload = serde_json::json!({ "check_status": "failed" });
+        let present = condition(
+            "check_status",
+            ConditionOperator::Exists,
+            serde_json::Value::Null,
+        );
+        let absent = condition(
+            "missing_field",
+            ConditionOperator::Exists,
+            serde_json::Value::Null,
+        );
+        assert!(condition_matches(&payload, &present));
+        assert!(!condition_matches(&payload, &absent));
+    }
+
+    #[test]
+    fn test_condition_matches_not_equals_when_field_missing() {
+        // A missing field is never equal to any concrete value, so `NotEquals` matches.
+        let payload = serde_json::json!({});
+        let condition = condition(
+            "check_status",
+            ConditionOperator::NotEquals,
+            serde_json::json!("failed"),
+        );
+        assert!(condition_matches(&payload, &condition));
+    }
+}
@@ -0,0 +1,71 @@
+//! Per-event-category sound theme: which sound plays for a run erroring or a worktree's PR
+//! getting merged (see `AppPreferences::error_sound`/`pr_merged_sound`; the longer-standing
+//! `waiting_sound`/`review_sound` categories are played directly by the frontend in
+//! `useStreamingEvents.ts` since they depend on UI-only state like "is this session currently
+//! being viewed"). Each preference is either a built-in name (see `notificationSoundOptions`
+//! in `src/lib/sounds.ts`) or an absolute path to a user-provided audio file, validated to
+//! exist before it's played or persisted.
+
+use tauri::AppHandle;
+
+use crate::http_server::EmitExt;
+
+const BUILTIN_SOUNDS: &[&str] = &["none", "ding", "chime", "pop", "choochoo"];
+
+/// Which event category a sound is assigned to.
+#[derive(Debug, Clone, Copy)]
+pub enum SoundCategory {
+    Error,
+    PrMerged,
+}
+
+/// Validate a sound preference value before it's saved: a built-in name always passes, a
+/// custom path must point to an existing file. Exposed so the frontend can check a
+/// user-picked file before persisting it to preferences.
+#[tauri::command]
+pub async fn validate_sound_path(sound: String) -> Result<(), String> {
+    if BUILTIN_SOUNDS.contains(&sound.as_str()) {
+        return Ok(());
+    }
+    if std::path::Path::new(&sound).is_file() {
+        return Ok(());
+    }
+    Err(format!("Sound file not found: {sound}"))
+}
+
+fn sound_for(prefs: &crate::AppPreferences, category: SoundCategory) -> &str {
+    match category {
+        SoundCategory::Error => &prefs.error_sound,
+        SoundCategory::PrMerged => &prefs.pr_merged_sound,
+    }
+}
+
+/// Play the sound assigned to `category` via the same `notification:play-sound` event a
+/// rule's `sound` action emits (see `rules::RuleAction::Sound`) - actual playback stays on
+/// the frontend. A no-op if the category is set to `none` or its custom file has gone missing
+/// since it was configured.
+pub fn play(app: &AppHandle, category: SoundCategory) {
+    let prefs = match crate::load_preferences_sync(app) {
+        Ok(prefs) => prefs,
+        Err(e) => {
+            log::warn!("Sound theme: failed to load preferences: {e}");
+            return;
+        }
+    };
+
+    let sound = sound_for(&prefs, category);
+    if sound == "none" {
+        return;
+    }
+    if !BUILTIN_SOUNDS.contains(&sound) && !std::path::Path::new(sound).is_file() {
+        log::warn!("Sound theme: configured sound file no longer exists: {sound}");
+        return;
+    }
+
+    if let Err(e) = app.emit_all(
+        "notification:play-sound",
+        &serde_json::json!({ "sound": sound }),
+    ) {
+        log::warn!("Sound theme: failed to emit notification:play-sound for {category:?}: {e}");
+    }
+}
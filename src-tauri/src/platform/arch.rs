@@ -0,0 +1,72 @@
+//! Detects the CPU architecture (and, on Linux, libc) Jean is actually running on, since
+//! `target_arch`/`target_os` at compile time reflect how *Jean* was built, not necessarily
+//! the host - an x86_64 build of Jean runs fine under Rosetta on Apple Silicon and under
+//! WOW64/ARM64EC on ARM Windows, which left `claude_cli`/`gh_cli` downloading the wrong CLI
+//! binary architecture for those hosts even though a native one was available.
+
+/// CPU architectures Jean's CLI installers know how to map to a download artifact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostArch {
+    X86_64,
+    Aarch64,
+}
+
+/// Detect the actual host CPU architecture, seeing through Rosetta (macOS) and WOW64/ARM64EC
+/// (Windows) emulation layers. Falls back to the architecture Jean itself was compiled for
+/// when no emulation layer is present (the common case) or detection fails.
+pub fn host_arch() -> HostArch {
+    #[cfg(target_os = "macos")]
+    {
+        // Set to "1" for an Intel build of Jean running under Rosetta on Apple Silicon.
+        use crate::platform::silent_command;
+        if let Ok(output) = silent_command("sysctl")
+            .args(["-n", "sysctl.proc_translated"])
+            .output()
+        {
+            if String::from_utf8_lossy(&output.stdout).trim() == "1" {
+                return HostArch::Aarch64;
+            }
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // Windows sets PROCESSOR_ARCHITEW6432 to the *native* architecture when the current
+        // process is running under WOW64/ARM64EC emulation; it's absent when running natively.
+        if let Ok(native) = std::env::var("PROCESSOR_ARCHITEW6432") {
+            if native.eq_ignore_ascii_case("ARM64") {
+                return HostArch::Aarch64;
+            }
+            if native.eq_ignore_ascii_case("AMD64") {
+                return HostArch::X86_64;
+            }
+        }
+    }
+
+    if cfg!(target_arch = "aarch64") {
+        HostArch::Aarch64
+    } else {
+        HostArch::X86_64
+    }
+}
+
+/// Whether this Linux host links against musl libc rather than glibc. The Claude/GitHub CLI
+/// releases are glibc-only, so a musl host (e.g. Alpine-based containers/VMs, common for
+/// lightweight Linux VMs on Apple Silicon hosts) needs a clear error up front rather than a
+/// binary that downloads fine and then fails to execute with a cryptic dynamic loader error.
+#[cfg(target_os = "linux")]
+pub fn is_musl_libc() -> bool {
+    use crate::platform::silent_command;
+
+    std::path::Path::new("/lib/ld-musl-x86_64.so.1").exists()
+        || std::path::Path::new("/lib/ld-musl-aarch64.so.1").exists()
+        || silent_command("ldd")
+            .arg("--version")
+            .output()
+            .map(|o| {
+                String::from_utf8_lossy(&o.stdout)
+                    .to_lowercase()
+                    .contains("musl")
+            })
+            .unwrap_or(false)
+}
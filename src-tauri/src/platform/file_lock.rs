@@ -0,0 +1,75 @@
+// Cross-process advisory locking for JSON documents shared between multiple Jean instances
+// (the native app and a `--headless` server, or two copies, pointed at the same data
+// directory). Complements the in-process `Mutex`es already guarding `chat::storage`'s and
+// `projects::storage`'s read-modify-write cycles - those only serialize threads within one
+// process, so a second process could still race in between a read and its matching write,
+// which is how concurrent instances silently clobber each other's session JSON today.
+//
+// Each lock is a `.lock` sibling file next to the document it protects, held via the OS's
+// advisory file locking (`flock` on Unix, `LockFileEx` on Windows) for as long as the
+// returned `FileLock` stays alive.
+
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+
+/// A held advisory lock on a document's `.lock` sibling file. Released when dropped.
+pub struct FileLock {
+    _file: File,
+}
+
+impl FileLock {
+    /// Block until an exclusive lock on `path`'s `.lock` sibling file is acquired.
+    pub fn acquire(path: &Path) -> Result<FileLock, String> {
+        let lock_path = path.with_extension("lock");
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&lock_path)
+            .map_err(|e| format!("Failed to open lock file {}: {e}", lock_path.display()))?;
+
+        lock_exclusive(&file)
+            .map_err(|e| format!("Failed to lock {}: {e}", lock_path.display()))?;
+
+        Ok(FileLock { _file: file })
+    }
+}
+
+#[cfg(unix)]
+fn lock_exclusive(file: &File) -> std::io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let result = unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(windows)]
+fn lock_exclusive(file: &File) -> std::io::Result<()> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Storage::FileSystem::{LockFileEx, LOCKFILE_EXCLUSIVE_LOCK};
+    use windows_sys::Win32::System::IO::OVERLAPPED;
+
+    let mut overlapped: OVERLAPPED = unsafe { std::mem::zeroed() };
+    let result = unsafe {
+        LockFileEx(
+            file.as_raw_handle() as _,
+            LOCKFILE_EXCLUSIVE_LOCK,
+            0,
+            u32::MAX,
+            u32::MAX,
+            &mut overlapped,
+        )
+    };
+
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+// The OS releases the lock automatically once `_file`'s handle closes on drop - no explicit
+// unlock needed on either platform.
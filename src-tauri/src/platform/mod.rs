@@ -1,7 +1,12 @@
 // Cross-platform abstractions for shell execution and process management
 
+pub mod arch;
+pub mod file_lock;
+pub mod paths;
+pub mod priority;
 pub mod process;
 pub mod shell;
 
+pub use file_lock::FileLock;
 pub use process::*;
 pub use shell::*;
@@ -0,0 +1,47 @@
+//! Normalizes paths for safe use with the filesystem and `Command::current_dir`, centralizing
+//! two Windows-only quirks that bite once worktrees nest a few directories deep:
+//! - The classic 260-character `MAX_PATH` limit, which git and plain file reads both hit
+//!   silently (as a "file not found" rather than a clear "path too long").
+//! - Mixed separators, when a path is built by joining `/`-separated segments (as all of our
+//!   path-joining code does, since `Path::join` accepts either) onto a Windows root - some
+//!   external tools are stricter about native `\` separators than the Win32 API itself.
+//!
+//! [`normalize`] is not applied at every existing `current_dir`/filesystem call site in one
+//! pass (186+ of them as of this writing) - like `silent_command`, it's meant to be adopted
+//! at call sites as they're touched, starting with the ones most likely to see deep nesting:
+//! worktree creation, the app data directory, and terminal spawn cwd.
+
+use std::path::{Path, PathBuf};
+
+/// Normalize `path` for OS-level use. On Windows: rewrites `/` separators to `\`, then - for
+/// absolute, non-UNC paths at or beyond the 260-character `MAX_PATH` limit - prefixes with
+/// `\\?\` (the extended-length prefix), which tells the Win32 API to skip `MAX_PATH` and
+/// `.`/`..` resolution entirely. Short paths are left unprefixed, since `\\?\` also disables
+/// useful relative-lookup behavior some tools rely on. On every other OS this is a no-op:
+/// there's no `MAX_PATH` equivalent and `/` is already the canonical separator.
+#[cfg(windows)]
+pub fn normalize(path: &Path) -> PathBuf {
+    let as_str = path.to_string_lossy();
+    if as_str.starts_with(r"\\?\") {
+        return path.to_path_buf();
+    }
+    let rewritten = PathBuf::from(as_str.replace('/', "\\"));
+    let rewritten_str = rewritten.to_string_lossy();
+    if rewritten.is_absolute() && rewritten_str.len() >= 260 && !rewritten_str.starts_with(r"\\") {
+        PathBuf::from(format!(r"\\?\{rewritten_str}"))
+    } else {
+        rewritten
+    }
+}
+
+#[cfg(not(windows))]
+pub fn normalize(path: &Path) -> PathBuf {
+    path.to_path_buf()
+}
+
+/// Strip the `\\?\` extended-length prefix added by [`normalize`], for showing a path to the
+/// user or writing it to a log line. A no-op if `path` doesn't have the prefix.
+pub fn display(path: &Path) -> String {
+    let as_str = path.to_string_lossy();
+    as_str.strip_prefix(r"\\?\").unwrap_or(&as_str).to_string()
+}
@@ -0,0 +1,21 @@
+// Cross-platform process priority adjustment
+//
+// Used by `chat::claude::execute_claude_detached` to de-prioritize a Claude CLI run when
+// `AppPreferences::low_priority_background_runs` is on and another run is already active or
+// the app is unfocused, so a long agent session doesn't make the rest of the machine feel
+// sluggish.
+
+/// Wrap a Unix shell command so the process it ultimately `exec`s runs at reduced CPU
+/// (`nice`) and I/O (`ionice`, best-effort) priority. Both tools `exec` their argument in
+/// place rather than forking, so this doesn't change the PID the caller already captured
+/// via `echo $!`.
+#[cfg(unix)]
+pub fn nice_prefix() -> &'static str {
+    "nice -n 10 ionice -c3"
+}
+
+/// Windows process creation flag for `BELOW_NORMAL_PRIORITY_CLASS`, OR'd into
+/// `spawn_detached_claude`'s `creation_flags()` call alongside `CREATE_NO_WINDOW` and
+/// `CREATE_NEW_PROCESS_GROUP`.
+#[cfg(windows)]
+pub const BELOW_NORMAL_PRIORITY_CLASS: u32 = 0x00004000;
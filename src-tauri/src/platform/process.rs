@@ -1,6 +1,11 @@
 // Cross-platform process management
 
 use std::process::Command;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use sysinfo::{Pid, System};
 
 /// Creates a Command that won't open a console window on Windows.
 /// Use for all background operations (git, gh, claude CLI, etc.).
@@ -154,3 +159,98 @@ pub fn terminate_process(pid: u32) -> Result<(), String> {
     // Windows doesn't have SIGTERM, use TerminateProcess
     kill_process(pid)
 }
+
+/// Send SIGINT to an entire process group (Unix only), asking it to stop the way Ctrl+C
+/// would - the gentlest of the three signals, giving the CLI the best chance to finish its
+/// current tool call and flush state before exiting.
+/// On Windows there's no equivalent signal for an arbitrary (non-console) process group, so
+/// this falls back to `kill_process_tree` (same as the other two rungs of the ladder).
+#[cfg(unix)]
+pub fn interrupt_process_tree(pid: u32) -> Result<(), String> {
+    let result = unsafe { libc::kill(-(pid as i32), libc::SIGINT) };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(format!(
+            "Failed to interrupt process group {}: {}",
+            pid,
+            std::io::Error::last_os_error()
+        ))
+    }
+}
+
+#[cfg(windows)]
+pub fn interrupt_process_tree(pid: u32) -> Result<(), String> {
+    kill_process_tree(pid)
+}
+
+/// Send SIGTERM to an entire process group (Unix only) - the second rung of the cancellation
+/// ladder, used if SIGINT didn't get the process to exit in time.
+/// On Windows there's no staged graceful-tree-terminate API, so this falls back to
+/// `kill_process_tree` like `interrupt_process_tree` does.
+#[cfg(unix)]
+pub fn terminate_process_tree(pid: u32) -> Result<(), String> {
+    let result = unsafe { libc::kill(-(pid as i32), libc::SIGTERM) };
+    if result == 0 {
+        Ok(())
+    } else {
+        // If process group signal fails, try terminating just the process
+        terminate_process(pid)
+    }
+}
+
+#[cfg(windows)]
+pub fn terminate_process_tree(pid: u32) -> Result<(), String> {
+    kill_process_tree(pid)
+}
+
+/// Shared `sysinfo` handle, kept alive across calls so `Process::cpu_usage()` (a delta since
+/// the previous refresh) reports something meaningful instead of always 0 on the first sample.
+static SYSTEM: Lazy<Mutex<System>> = Lazy::new(|| Mutex::new(System::new_all()));
+
+/// Live resource usage for a single process, sampled via `sysinfo`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessStats {
+    pub pid: u32,
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+    pub child_count: usize,
+}
+
+/// OS-reported start time (seconds since the epoch) of `pid`, or `None` if it's not
+/// currently running. Comparing this against a previously recorded value is how
+/// `process_reaper` tells "this is still the process we registered" apart from "this PID
+/// has since been reused by an unrelated process".
+pub fn process_start_time(pid: u32) -> Option<u64> {
+    let mut system = SYSTEM.lock().unwrap();
+    system.refresh_all();
+    system.process(Pid::from_u32(pid)).map(|p| p.start_time())
+}
+
+/// Sample CPU%, memory, and child-process count for each of `pids` that's still alive.
+/// PIDs that have already exited are silently omitted rather than erroring, since callers
+/// (e.g. `chat::registry::collect_process_stats`) poll a registry that can go stale between
+/// a process exiting and its owner unregistering it.
+pub fn sample_process_stats(pids: &[u32]) -> Vec<ProcessStats> {
+    let mut system = SYSTEM.lock().unwrap();
+    system.refresh_all();
+
+    pids.iter()
+        .filter_map(|&pid| {
+            let sys_pid = Pid::from_u32(pid);
+            let process = system.process(sys_pid)?;
+            let child_count = system
+                .processes()
+                .values()
+                .filter(|candidate| candidate.parent() == Some(sys_pid))
+                .count();
+            Some(ProcessStats {
+                pid,
+                cpu_percent: process.cpu_usage(),
+                memory_bytes: process.memory(),
+                child_count,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,173 @@
+//! Prevents the OS from sleeping while a Claude CLI run or terminal job is active (see
+//! `chat::registry::register_process`/`unregister_process` and `terminal::pty`), since a
+//! long agent run dies outright if the laptop sleeps mid-run.
+//!
+//! Tracks a simple count of active jobs and acquires a platform sleep-inhibition handle
+//! when it goes from 0 to 1, releasing it when it drops back to 0:
+//! - macOS: spawns `caffeinate -s` and kills it on release.
+//! - Linux: spawns `systemd-inhibit --what=sleep:idle ... sleep infinity` and kills it on
+//!   release (falls back to not inhibiting if `systemd-inhibit` isn't installed).
+//! - Windows: `SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED)`.
+//!
+//! Controlled by `AppPreferences::sleep_inhibition_enabled` (on by default) - checked once
+//! per 0-to-1 transition, so toggling it mid-run only takes effect for the next run.
+
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use tauri::AppHandle;
+
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+struct SleepInhibitor(std::process::Child);
+
+#[cfg(windows)]
+struct SleepInhibitor;
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+struct SleepInhibitor;
+
+static ACTIVE_JOBS: Mutex<u32> = Mutex::new(0);
+static INHIBITOR: Lazy<Mutex<Option<SleepInhibitor>>> = Lazy::new(|| Mutex::new(None));
+
+/// Record that a Claude process or terminal job started, acquiring the sleep-inhibition
+/// assertion if this is the first active job.
+pub fn job_started(app: &AppHandle) {
+    let mut count = ACTIVE_JOBS.lock().unwrap();
+    *count += 1;
+    if *count == 1 {
+        drop(count);
+        acquire(app);
+    }
+}
+
+/// Record that a Claude process or terminal job stopped, releasing the sleep-inhibition
+/// assertion once no jobs remain active.
+pub fn job_stopped() {
+    let mut count = ACTIVE_JOBS.lock().unwrap();
+    if *count == 0 {
+        return;
+    }
+    *count -= 1;
+    if *count == 0 {
+        drop(count);
+        release();
+    }
+}
+
+/// Force-release the sleep-inhibition assertion and reset the job count, regardless of how
+/// many jobs are still registered. Called on app exit, since an inhibitor child process
+/// (`caffeinate`/`systemd-inhibit`) doesn't necessarily die with Jean - if Jean is killed
+/// outright rather than exiting cleanly, an un-reaped one would keep the machine awake
+/// indefinitely.
+pub fn release_all() {
+    *ACTIVE_JOBS.lock().unwrap() = 0;
+    release();
+}
+
+fn acquire(app: &AppHandle) {
+    let enabled = crate::load_preferences_sync(app)
+        .map(|prefs| prefs.sleep_inhibition_enabled)
+        .unwrap_or(true);
+    if !enabled {
+        log::trace!("Sleep inhibition disabled in preferences, not acquiring");
+        return;
+    }
+
+    let Some(inhibitor) = acquire_platform() else {
+        return;
+    };
+    *INHIBITOR.lock().unwrap() = Some(inhibitor);
+    log::trace!("Acquired sleep-inhibition assertion");
+}
+
+fn release() {
+    if let Some(inhibitor) = INHIBITOR.lock().unwrap().take() {
+        release_platform(inhibitor);
+        log::trace!("Released sleep-inhibition assertion");
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn acquire_platform() -> Option<SleepInhibitor> {
+    use std::process::Stdio;
+
+    crate::platform::silent_command("caffeinate")
+        .arg("-s")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(SleepInhibitor)
+        .map_err(|e| log::warn!("Failed to spawn caffeinate: {e}"))
+        .ok()
+}
+
+#[cfg(target_os = "macos")]
+fn release_platform(inhibitor: SleepInhibitor) {
+    let mut child = inhibitor.0;
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[cfg(target_os = "linux")]
+fn acquire_platform() -> Option<SleepInhibitor> {
+    use std::process::Stdio;
+
+    if !crate::platform::executable_exists("systemd-inhibit") {
+        log::warn!(
+            "Sleep inhibition requested but `systemd-inhibit` is not installed - the system \
+             may sleep during long runs"
+        );
+        return None;
+    }
+
+    crate::platform::silent_command("systemd-inhibit")
+        .args([
+            "--what=sleep:idle",
+            "--why=Jean is running an AI agent",
+            "--mode=block",
+            "sleep",
+            "infinity",
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(SleepInhibitor)
+        .map_err(|e| log::warn!("Failed to spawn systemd-inhibit: {e}"))
+        .ok()
+}
+
+#[cfg(target_os = "linux")]
+fn release_platform(inhibitor: SleepInhibitor) {
+    let mut child = inhibitor.0;
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[cfg(windows)]
+fn acquire_platform() -> Option<SleepInhibitor> {
+    use windows_sys::Win32::System::Power::{
+        SetThreadExecutionState, ES_CONTINUOUS, ES_SYSTEM_REQUIRED,
+    };
+    unsafe {
+        SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED);
+    }
+    Some(SleepInhibitor)
+}
+
+#[cfg(windows)]
+fn release_platform(_inhibitor: SleepInhibitor) {
+    use windows_sys::Win32::System::Power::{SetThreadExecutionState, ES_CONTINUOUS};
+    unsafe {
+        SetThreadExecutionState(ES_CONTINUOUS);
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+fn acquire_platform() -> Option<SleepInhibitor> {
+    None
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+fn release_platform(_inhibitor: SleepInhibitor) {}
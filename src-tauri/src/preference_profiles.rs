@@ -0,0 +1,116 @@
+//! Named snapshots of `AppPreferences` ("work"/"personal"/"demo" style profiles) a user can
+//! switch between in one action, plus `export_preferences`/`import_preferences` for handing a
+//! single preferences file to another machine or a screen-sharing session. Complements
+//! `backup.rs`'s full data-directory backup - this module only ever touches
+//! `preferences.json`, never sessions or projects.
+//!
+//! Switching profiles works by saving/loading the *entire* `AppPreferences` struct under a
+//! name, so it naturally carries everything that struct covers - default model, thinking
+//! level, poll intervals, HTTP server settings, and so on - rather than a hand-picked subset
+//! that would need updating every time a new preference is added.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::AppPreferences;
+
+fn get_profiles_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::data_dir::resolve(app)?.join("preference-profiles.json"))
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct ProfilesFile {
+    #[serde(default)]
+    profiles: HashMap<String, AppPreferences>,
+}
+
+fn load_profiles(app: &AppHandle) -> Result<ProfilesFile, String> {
+    let path = get_profiles_path(app)?;
+    if !path.exists() {
+        return Ok(ProfilesFile::default());
+    }
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read preference profiles: {e}"))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse preference profiles: {e}"))
+}
+
+fn save_profiles(app: &AppHandle, profiles: &ProfilesFile) -> Result<(), String> {
+    let path = get_profiles_path(app)?;
+    let json = serde_json::to_string_pretty(profiles)
+        .map_err(|e| format!("Failed to serialize preference profiles: {e}"))?;
+
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, json)
+        .map_err(|e| format!("Failed to write preference profiles: {e}"))?;
+    fs::rename(&temp_path, &path)
+        .map_err(|e| format!("Failed to finalize preference profiles: {e}"))?;
+    Ok(())
+}
+
+/// Save `preferences` as a named profile, overwriting any existing profile with the same name.
+#[tauri::command]
+pub async fn save_preference_profile(
+    app: AppHandle,
+    name: String,
+    preferences: AppPreferences,
+) -> Result<(), String> {
+    let mut profiles = load_profiles(&app)?;
+    profiles.profiles.insert(name, preferences);
+    save_profiles(&app, &profiles)
+}
+
+/// List the names of saved preference profiles.
+#[tauri::command]
+pub async fn list_preference_profiles(app: AppHandle) -> Result<Vec<String>, String> {
+    let profiles = load_profiles(&app)?;
+    let mut names: Vec<String> = profiles.profiles.into_keys().collect();
+    names.sort();
+    Ok(names)
+}
+
+/// Delete a saved preference profile, if one exists with that name.
+#[tauri::command]
+pub async fn delete_preference_profile(app: AppHandle, name: String) -> Result<(), String> {
+    let mut profiles = load_profiles(&app)?;
+    profiles.profiles.remove(&name);
+    save_profiles(&app, &profiles)
+}
+
+/// Switch to a saved preference profile, making it the active preferences.
+#[tauri::command]
+pub async fn switch_preference_profile(app: AppHandle, name: String) -> Result<AppPreferences, String> {
+    let profiles = load_profiles(&app)?;
+    let preferences = profiles
+        .profiles
+        .get(&name)
+        .cloned()
+        .ok_or_else(|| format!("No preference profile named '{name}'"))?;
+    crate::save_preferences(app, preferences.clone()).await?;
+    Ok(preferences)
+}
+
+/// Export the current active preferences to a standalone JSON file at `path`.
+#[tauri::command]
+pub async fn export_preferences(app: AppHandle, path: String) -> Result<(), String> {
+    let preferences = crate::load_preferences_sync(&app)?;
+    let json = serde_json::to_string_pretty(&preferences)
+        .map_err(|e| format!("Failed to serialize preferences: {e}"))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write {path}: {e}"))?;
+    Ok(())
+}
+
+/// Import preferences from a standalone JSON file at `path`, making them the active
+/// preferences.
+#[tauri::command]
+pub async fn import_preferences(app: AppHandle, path: String) -> Result<AppPreferences, String> {
+    let contents =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read {path}: {e}"))?;
+    let preferences: AppPreferences = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse preferences from {path}: {e}"))?;
+    crate::save_preferences(app, preferences.clone()).await?;
+    Ok(preferences)
+}
@@ -0,0 +1,152 @@
+//! Detects and cleans up processes left running by a previous crash.
+//!
+//! `chat::registry` and `terminal::pty` track running Claude CLI and terminal child PIDs in
+//! memory, but that registry doesn't survive a restart. Claude CLI is deliberately spawned
+//! detached in its own process group (see `chat::registry::cancel_process`), so a run keeps
+//! going whether Jean exits cleanly or is killed outright; terminal shells are killed on a
+//! clean exit, but if Jean is killed outright they aren't tied to this process's lifetime
+//! either, so they keep running as orphans.
+//!
+//! This module persists `(kind, pid, started_at)` for every registered process to disk, the
+//! same way `terminal::persistence` tracks which terminals were open. On startup,
+//! `reap_orphans` reads that file and, for every entry whose PID is still alive *and* whose
+//! OS-reported start time still matches the one recorded (guarding against the PID having
+//! been reused by an unrelated process since), kills it and reports what was cleaned via a
+//! `process:orphans-reaped` event.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::chat::registry::ProcessKind;
+use crate::http_server::EmitExt;
+use crate::platform::{kill_process_tree, process_start_time};
+
+/// Persisted record of a process registered with `chat::registry` or `terminal::pty`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OrphanRecord {
+    kind: ProcessKind,
+    pid: u32,
+    /// OS-reported process start time (seconds since the epoch) at the time it was
+    /// registered - see the module doc for why this matters.
+    started_at: u64,
+}
+
+fn index_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::data_dir::resolve(app)?.join("process_registry.json"))
+}
+
+fn load_index(app: &AppHandle) -> HashMap<String, OrphanRecord> {
+    let Ok(path) = index_path(app) else {
+        return HashMap::new();
+    };
+    std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_index(app: &AppHandle, index: &HashMap<String, OrphanRecord>) -> Result<(), String> {
+    let path = index_path(app)?;
+    let json = serde_json::to_string_pretty(index)
+        .map_err(|e| format!("Failed to serialize process registry: {e}"))?;
+
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, json)
+        .map_err(|e| format!("Failed to write process registry: {e}"))?;
+    std::fs::rename(&temp_path, &path)
+        .map_err(|e| format!("Failed to finalize process registry: {e}"))?;
+    Ok(())
+}
+
+/// Record that a process started, so it can be reaped if Jean crashes before it's stopped.
+/// Best-effort: a failure to persist (or to read the PID's start time) never blocks the
+/// process itself from starting.
+pub fn record_started(app: &AppHandle, id: &str, kind: ProcessKind, pid: u32) {
+    let Some(started_at) = process_start_time(pid) else {
+        log::warn!("Could not read start time for pid={pid}, skipping orphan tracking for {id}");
+        return;
+    };
+    let mut index = load_index(app);
+    index.insert(
+        id.to_string(),
+        OrphanRecord {
+            kind,
+            pid,
+            started_at,
+        },
+    );
+    if let Err(e) = save_index(app, &index) {
+        log::warn!("Failed to persist process registry entry for {id}: {e}");
+    }
+}
+
+/// Record that a process stopped, removing it from the persisted index.
+pub fn record_stopped(app: &AppHandle, id: &str) {
+    let mut index = load_index(app);
+    if index.remove(id).is_none() {
+        return;
+    }
+    if let Err(e) = save_index(app, &index) {
+        log::warn!("Failed to remove process registry entry for {id}: {e}");
+    }
+}
+
+/// One process the reaper found still running from a previous crash and killed, for the
+/// `process:orphans-reaped` event.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReapedProcess {
+    pub id: String,
+    pub kind: ProcessKind,
+    pub pid: u32,
+}
+
+/// Check the persisted process index left by the previous run and kill anything still alive
+/// that's genuinely a leftover (same PID *and* same OS start time as when it was registered -
+/// see `OrphanRecord::started_at`). Meant to be called once at startup, after which the index
+/// is cleared so later calls don't keep re-reporting the same orphans.
+pub fn reap_orphans(app: &AppHandle) -> Vec<ReapedProcess> {
+    let index = load_index(app);
+    if index.is_empty() {
+        return Vec::new();
+    }
+    if let Err(e) = save_index(app, &HashMap::new()) {
+        log::warn!("Failed to clear process registry after restart: {e}");
+    }
+
+    let mut reaped = Vec::new();
+    for (id, record) in index {
+        if process_start_time(record.pid) != Some(record.started_at) {
+            // Already exited on its own, or the PID has since been reused by something else.
+            continue;
+        }
+        if let Err(e) = kill_process_tree(record.pid) {
+            log::warn!(
+                "Failed to kill orphaned {:?} process {id} (pid={}): {e}",
+                record.kind,
+                record.pid
+            );
+            continue;
+        }
+        log::info!(
+            "Reaped orphaned {:?} process {id} (pid={}) left over from a previous run",
+            record.kind,
+            record.pid
+        );
+        reaped.push(ReapedProcess {
+            id,
+            kind: record.kind,
+            pid: record.pid,
+        });
+    }
+
+    if !reaped.is_empty() {
+        if let Err(e) = app.emit_all("process:orphans-reaped", &reaped) {
+            log::warn!("Failed to emit process:orphans-reaped: {e}");
+        }
+    }
+    reaped
+}
@@ -0,0 +1,182 @@
+//! Branch protection rule lookup via the GitHub GraphQL API (through `gh api graphql`)
+//!
+//! Uses GraphQL rather than the REST branch-protection endpoint because the REST endpoint
+//! requires admin/push access to the repo, while `branchProtectionRules` is readable by any
+//! collaborator with read access.
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use super::git::get_repo_identifier;
+use crate::gh_cli::config::resolve_gh_binary;
+use crate::platform::silent_command;
+
+/// Branch protection rules that apply to a given branch, if any are configured
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchProtectionInfo {
+    pub pattern: String,
+    pub requires_approving_reviews: bool,
+    pub required_approving_review_count: Option<u32>,
+    pub requires_status_checks: bool,
+    pub required_status_check_contexts: Vec<String>,
+    pub requires_linear_history: bool,
+    pub restricts_pushes: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse {
+    data: Option<GraphQlData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlData {
+    repository: GraphQlRepository,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlRepository {
+    #[serde(rename = "branchProtectionRules")]
+    branch_protection_rules: GraphQlRuleConnection,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlRuleConnection {
+    nodes: Vec<GraphQlRule>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlRule {
+    pattern: String,
+    #[serde(rename = "requiresApprovingReviews")]
+    requires_approving_reviews: bool,
+    #[serde(rename = "requiredApprovingReviewCount")]
+    required_approving_review_count: Option<u32>,
+    #[serde(rename = "requiresStatusChecks")]
+    requires_status_checks: bool,
+    #[serde(rename = "requiredStatusCheckContexts")]
+    required_status_check_contexts: Vec<String>,
+    #[serde(rename = "requiresLinearHistory")]
+    requires_linear_history: bool,
+    #[serde(rename = "restrictsPushes")]
+    restricts_pushes: bool,
+}
+
+const BRANCH_PROTECTION_QUERY: &str = r#"
+query($owner: String!, $repo: String!) {
+  repository(owner: $owner, name: $repo) {
+    branchProtectionRules(first: 100) {
+      nodes {
+        pattern
+        requiresApprovingReviews
+        requiredApprovingReviewCount
+        requiresStatusChecks
+        requiredStatusCheckContexts
+        requiresLinearHistory
+        restrictsPushes
+      }
+    }
+  }
+}
+"#;
+
+/// Minimal branch-name-pattern matcher, adapted from the CODEOWNERS matcher in `pr_reviews.rs`:
+/// supports `*` as a wildcard and treats a pattern with no wildcard as an exact match.
+fn branch_pattern_matches(pattern: &str, branch: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+    if pattern.contains('*') {
+        let prefix = pattern.split('*').next().unwrap_or("");
+        return branch.starts_with(prefix);
+    }
+    pattern == branch
+}
+
+/// Fetch the branch protection rule that applies to `branch`, if the repo has one configured
+#[tauri::command]
+pub async fn get_branch_protection(
+    app: AppHandle,
+    project_path: String,
+    branch: String,
+) -> Result<Option<BranchProtectionInfo>, String> {
+    log::trace!("Fetching branch protection for {branch} in {project_path}");
+
+    let repo = get_repo_identifier(&project_path)?;
+    let gh = resolve_gh_binary(&app);
+
+    let output = silent_command(&gh)
+        .args([
+            "api",
+            "graphql",
+            "-f",
+            &format!("query={BRANCH_PROTECTION_QUERY}"),
+            "-f",
+            &format!("owner={}", repo.owner),
+            "-f",
+            &format!("repo={}", repo.repo),
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh api graphql: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to fetch branch protection rules: {stderr}"));
+    }
+
+    let parsed: GraphQlResponse = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse branch protection response: {e}"))?;
+
+    let rules = parsed
+        .data
+        .map(|d| d.repository.branch_protection_rules.nodes)
+        .unwrap_or_default();
+
+    let matched = rules
+        .into_iter()
+        .find(|r| branch_pattern_matches(&r.pattern, &branch))
+        .map(|r| BranchProtectionInfo {
+            pattern: r.pattern,
+            requires_approving_reviews: r.requires_approving_reviews,
+            required_approving_review_count: r.required_approving_review_count,
+            requires_status_checks: r.requires_status_checks,
+            required_status_check_contexts: r.required_status_check_contexts,
+            requires_linear_history: r.requires_linear_history,
+            restricts_pushes: r.restricts_pushes,
+        });
+
+    Ok(matched)
+}
+
+/// Build a human-readable warning describing why pushing or merging directly to a protected
+/// branch may be rejected upstream, or `None` if the rule wouldn't block a direct push.
+pub fn describe_protection_risk(info: &BranchProtectionInfo) -> Option<String> {
+    let mut reasons = Vec::new();
+
+    if info.requires_approving_reviews {
+        reasons.push(match info.required_approving_review_count {
+            Some(n) if n > 0 => format!("requires {n} approving review(s)"),
+            _ => "requires approving reviews".to_string(),
+        });
+    }
+    if info.requires_status_checks {
+        reasons.push("requires status checks to pass".to_string());
+    }
+    if info.requires_linear_history {
+        reasons.push("requires a linear history".to_string());
+    }
+    if info.restricts_pushes {
+        reasons.push("restricts who can push directly".to_string());
+    }
+
+    if reasons.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "Branch '{}' is protected and {} — this change may be rejected if pushed directly",
+        info.pattern,
+        reasons.join(", ")
+    ))
+}
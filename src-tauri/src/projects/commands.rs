@@ -9,6 +9,7 @@ use tauri::{AppHandle, Manager};
 use tauri_plugin_dialog::DialogExt;
 use uuid::Uuid;
 
+use super::branch_protection;
 use super::git;
 use super::git::get_repo_identifier;
 use super::github_issues::{
@@ -141,6 +142,21 @@ pub async fn add_project(
         parent_id,
         is_folder: false,
         avatar_path: None,
+        gh_account: None,
+        gitea_host: None,
+        gitea_token: None,
+        monthly_budget_usd: None,
+        run_priority: 0,
+        env_vars: Vec::new(),
+        dotenv_allowlist: Vec::new(),
+        instructions: None,
+        auto_commit_after_run: false,
+        remote: None,
+        shell: None,
+        shell_startup_command: None,
+        sandbox: crate::projects::types::SandboxConfig::default(),
+        notification_webhooks: Vec::new(),
+        muted: false,
     };
 
     data.add_project(project.clone());
@@ -291,6 +307,21 @@ pub async fn init_project(
         parent_id,
         is_folder: false,
         avatar_path: None,
+        gh_account: None,
+        gitea_host: None,
+        gitea_token: None,
+        monthly_budget_usd: None,
+        run_priority: 0,
+        env_vars: Vec::new(),
+        dotenv_allowlist: Vec::new(),
+        instructions: None,
+        auto_commit_after_run: false,
+        remote: None,
+        shell: None,
+        shell_startup_command: None,
+        sandbox: crate::projects::types::SandboxConfig::default(),
+        notification_webhooks: Vec::new(),
+        muted: false,
     };
 
     data.add_project(project.clone());
@@ -518,6 +549,7 @@ pub async fn create_worktree(
         cached_unpushed_count: None,
         order: 0, // Placeholder, actual order is set in background thread
         archived_at: None,
+        instructions_override: None,
     };
 
     // Clone values for the background thread
@@ -903,6 +935,7 @@ pub async fn create_worktree(
                 cached_unpushed_count: None,
                 order: max_order + 1,
                 archived_at: None,
+                instructions_override: None,
             };
 
             data.add_worktree(worktree.clone());
@@ -1023,6 +1056,7 @@ pub async fn create_worktree_from_existing_branch(
         cached_unpushed_count: None,
         order: 0, // Placeholder, actual order is set in background thread
         archived_at: None,
+        instructions_override: None,
     };
 
     // Clone values for the background thread
@@ -1237,6 +1271,7 @@ pub async fn create_worktree_from_existing_branch(
                 cached_unpushed_count: None,
                 order: max_order + 1,
                 archived_at: None,
+                instructions_override: None,
             };
 
             data.add_worktree(worktree.clone());
@@ -1315,6 +1350,10 @@ pub async fn checkout_pr(
         return unarchive_worktree(app, worktree_id).await;
     }
 
+    // Switch to the project's assigned gh account (if any) before any gh calls below
+    let gh = resolve_gh_binary(&app);
+    crate::gh_cli::config::ensure_gh_account(&gh, project.gh_account.as_deref())?;
+
     // Fetch PR details from GitHub (for context and worktree naming)
     let pr_detail = get_github_pr(app.clone(), project.path.clone(), pr_number).await?;
 
@@ -1402,6 +1441,7 @@ pub async fn checkout_pr(
         cached_unpushed_count: None,
         order: 0, // Will be updated in background thread
         archived_at: None,
+        instructions_override: None,
     };
 
     // Clone values for background thread
@@ -1628,6 +1668,7 @@ pub async fn checkout_pr(
                 cached_unpushed_count: None,
                 order: max_order + 1,
                 archived_at: None,
+                instructions_override: None,
             };
 
             data.add_worktree(worktree.clone());
@@ -1675,6 +1716,27 @@ pub async fn checkout_pr(
     Ok(pending_worktree)
 }
 
+/// List open PRs/MRs for a project, using whichever `GitProvider` matches its remote
+///
+/// Supports GitHub (via `gh`), GitLab (via `glab`), and Gitea/Forgejo (via REST, when the
+/// project has been configured with `set_project_gitea_config`).
+#[tauri::command]
+pub async fn list_open_change_requests(
+    app: AppHandle,
+    project_id: String,
+) -> Result<Vec<super::provider::ChangeRequestSummary>, String> {
+    log::trace!("Listing open PRs/MRs for project {project_id}");
+
+    let data = load_projects_data(&app)?;
+    let project = data
+        .find_project(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?;
+
+    let gh = resolve_gh_binary(&app);
+    let provider = super::provider::provider_for_project(project, gh)?;
+    provider.list_open_change_requests(&project.path)
+}
+
 /// Delete a worktree (runs in background)
 ///
 /// This command returns immediately after emitting a deleting event.
@@ -1853,6 +1915,7 @@ pub async fn create_base_session(app: AppHandle, project_id: String) -> Result<W
         cached_unpushed_count: None,
         order: 0, // Base sessions are always first
         archived_at: None,
+        instructions_override: None,
     };
 
     data.add_worktree(session.clone());
@@ -2164,6 +2227,7 @@ pub async fn import_worktree(
         cached_unpushed_count: None,
         order: max_order + 1,
         archived_at: None,
+        instructions_override: None,
     };
 
     data.add_worktree(worktree.clone());
@@ -2181,9 +2245,11 @@ pub async fn import_worktree(
     Ok(worktree)
 }
 
-/// Permanently delete an archived worktree (removes git worktree/branch from disk)
+/// Permanently delete an archived worktree (removes the git worktree from disk)
 ///
-/// This is the "true delete" that removes the worktree from disk.
+/// This is the "true delete" that removes the worktree's working directory from disk and its
+/// record from storage. Routes through the trash (see `crate::trash`) first: the branch is kept
+/// alive until the trash entry is restored or purged, so this can still be undone for a while.
 /// Only works on archived worktrees to prevent accidental deletion.
 #[tauri::command]
 pub async fn permanently_delete_worktree(
@@ -2211,6 +2277,20 @@ pub async fn permanently_delete_worktree(
         .ok_or_else(|| format!("Project not found: {}", worktree.project_id))?
         .clone();
 
+    // Read the sessions file before anything deletes it, so the trash entry can restore it
+    let sessions_file_contents = crate::data_dir::resolve(&app).ok().and_then(|dir| {
+        std::fs::read_to_string(dir.join("sessions").join(format!("{worktree_id}.json"))).ok()
+    });
+
+    // Move to the trash (see `crate::trash`) before removing from storage: its branch is kept
+    // until the trash entry is purged, so this can still be undone.
+    crate::trash::trash_worktree(
+        &app,
+        worktree.clone(),
+        project.path.clone(),
+        sessions_file_contents,
+    )?;
+
     // Remove from storage SYNCHRONOUSLY to avoid race conditions with other operations
     // (e.g., archive/unarchive could be overwritten if we save in background thread)
     let mut data = load_projects_data(&app)?;
@@ -2224,7 +2304,6 @@ pub async fn permanently_delete_worktree(
     let project_id_clone = worktree.project_id.clone();
     let project_path = project.path.clone();
     let worktree_path = worktree.path.clone();
-    let worktree_branch = worktree.branch.clone();
     let worktree_name = worktree.name.clone();
     let is_base_session = worktree.session_type == SessionType::Base;
 
@@ -2247,7 +2326,9 @@ pub async fn permanently_delete_worktree(
             log::warn!("Failed to cleanup PR contexts: {e}");
         }
 
-        // Only remove git worktree/branch for non-base sessions
+        // Only remove the git worktree for non-base sessions. The branch itself is left alone -
+        // it's kept alive by the trash entry created above and only deleted once that entry is
+        // restored or purged (see `crate::trash`).
         if !is_base_session {
             log::trace!("Background: Removing git worktree at {worktree_path}");
 
@@ -2255,17 +2336,10 @@ pub async fn permanently_delete_worktree(
             if let Err(e) = git::remove_worktree(&project_path, &worktree_path) {
                 log::warn!("Background: Failed to remove worktree (may already be deleted): {e}");
             }
-
-            log::trace!("Background: Deleting branch {worktree_branch}");
-
-            // Delete the branch (ignore errors if already gone)
-            if let Err(e) = git::delete_branch(&project_path, &worktree_branch) {
-                log::warn!("Background: Failed to delete branch (may already be deleted): {e}");
-            }
         }
 
         // Delete the sessions file for this worktree
-        if let Ok(app_data_dir) = app_clone.path().app_data_dir() {
+        if let Ok(app_data_dir) = crate::data_dir::resolve(&app_clone) {
             let sessions_file = app_data_dir
                 .join("sessions")
                 .join(format!("{worktree_id_clone}.json"));
@@ -2749,6 +2823,8 @@ pub async fn commit_changes(
 
     let result = git::commit_changes(&worktree.path, &message, stage_all.unwrap_or(false))?;
 
+    crate::activity::record(&app, &worktree_id, crate::activity::ActivityKind::Commit, now(), 0);
+
     log::trace!(
         "Successfully committed changes in worktree: {} ({})",
         worktree.name,
@@ -2757,6 +2833,38 @@ pub async fn commit_changes(
     Ok(result)
 }
 
+/// Commit only a selected set of hunks (e.g. the subset of an AI review's suggested fixes
+/// the user has accepted) without touching other working tree changes.
+///
+/// `patch` must be a unified diff containing only the hunks to commit, such as one assembled
+/// client-side from the hunks in the diff shown for a `run_review_with_ai` finding.
+#[tauri::command]
+pub async fn commit_patch_hunks(
+    app: AppHandle,
+    worktree_id: String,
+    patch: String,
+    message: String,
+) -> Result<String, String> {
+    log::trace!("Committing selected hunks in worktree: {worktree_id}");
+
+    let data = load_projects_data(&app)?;
+
+    let worktree = data
+        .find_worktree(&worktree_id)
+        .ok_or_else(|| format!("Worktree not found: {worktree_id}"))?;
+
+    let result = git::commit_patch_hunks(&worktree.path, &patch, &message)?;
+
+    crate::activity::record(&app, &worktree_id, crate::activity::ActivityKind::Commit, now(), 0);
+
+    log::trace!(
+        "Successfully committed selected hunks in worktree: {} ({})",
+        worktree.name,
+        result
+    );
+    Ok(result)
+}
+
 /// Open a pull request for a worktree using the GitHub CLI
 #[tauri::command]
 pub async fn open_pull_request(
@@ -2791,6 +2899,78 @@ pub async fn open_pull_request(
     Ok(result)
 }
 
+/// Merge an open pull request, with a pre-check against its cached mergeability status
+///
+/// On success, archives the worktree linked to this PR (if any) and refreshes the project's
+/// base branch so the merge is reflected locally without waiting for the next remote poll.
+#[tauri::command]
+pub async fn merge_pr(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+    method: MergeType,
+    delete_branch: bool,
+) -> Result<(), String> {
+    log::trace!("Merging PR #{pr_number} in {project_path} (method={method:?})");
+
+    let gh = resolve_gh_binary(&app);
+
+    // Pre-check mergeability so we can give a clearer error than gh's raw failure message.
+    // Not a hard gate: GitHub sometimes hasn't finished computing mergeability yet.
+    match super::pr_status::get_pr_status(&project_path, pr_number, "", "", &gh) {
+        Ok(status) if status.mergeable == Some(super::pr_status::MergeableStatus::Conflicting) => {
+            return Err(
+                "Pull request has conflicts with the base branch and can't be merged".to_string(),
+            );
+        }
+        Ok(_) => {}
+        Err(e) => {
+            log::warn!("Could not pre-check mergeability for PR #{pr_number}: {e}");
+        }
+    }
+
+    git::merge_pull_request(&project_path, pr_number, &method, delete_branch, &gh)?;
+
+    // Archive the worktree tied to this PR, if any
+    let data = load_projects_data(&app)?;
+    let linked_worktree_id = data
+        .projects
+        .iter()
+        .find(|p| p.path == project_path)
+        .and_then(|p| {
+            data.worktrees_for_project(&p.id)
+                .into_iter()
+                .find(|w| w.pr_number == Some(pr_number))
+                .map(|w| w.id.clone())
+        });
+
+    if let Some(worktree_id) = linked_worktree_id {
+        if let Err(e) = archive_worktree(app.clone(), worktree_id).await {
+            log::warn!("Merged PR #{pr_number} but failed to archive its worktree: {e}");
+        }
+    }
+
+    // Refresh the base branch so the merge shows up locally right away
+    let fetch_output = silent_command("git")
+        .args(["fetch", "origin"])
+        .current_dir(&project_path)
+        .output();
+    if let Ok(output) = fetch_output {
+        if output.status.success() {
+            if let Err(e) = silent_command("git")
+                .args(["pull"])
+                .current_dir(&project_path)
+                .output()
+            {
+                log::warn!("Merged PR #{pr_number} but failed to pull base branch: {e}");
+            }
+        }
+    }
+
+    log::trace!("Successfully merged pull request #{pr_number}");
+    Ok(())
+}
+
 /// Response structure for file listing
 #[derive(Debug, Clone, Serialize)]
 pub struct WorktreeFile {
@@ -2932,15 +3112,30 @@ pub async fn get_project_branches(
     Ok(branches)
 }
 
-/// Update project settings (currently just default_branch)
+/// Check whether a shell binary is resolvable (on PATH or an absolute path that exists),
+/// used by the settings UI to validate `Project::shell` before saving.
+#[tauri::command]
+pub async fn validate_shell_path(shell_path: String) -> bool {
+    crate::platform::executable_exists(&shell_path)
+}
+
+/// Update project settings (default_branch, shell, shell_startup_command)
 #[tauri::command]
 pub async fn update_project_settings(
     app: AppHandle,
     project_id: String,
     default_branch: Option<String>,
+    shell: Option<String>,
+    shell_startup_command: Option<String>,
 ) -> Result<Project, String> {
     log::trace!("Updating settings for project: {project_id}");
 
+    if let Some(ref shell_path) = shell {
+        if !crate::platform::executable_exists(shell_path) {
+            return Err(format!("Shell not found: {shell_path}"));
+        }
+    }
+
     let mut data = load_projects_data(&app)?;
 
     let project = data
@@ -2956,6 +3151,15 @@ pub async fn update_project_settings(
         project.default_branch = branch;
     }
 
+    if let Some(shell_path) = shell {
+        log::trace!("Updating shell for project '{}' to '{shell_path}'", project.name);
+        project.shell = Some(shell_path);
+    }
+
+    if let Some(startup_command) = shell_startup_command {
+        project.shell_startup_command = Some(startup_command);
+    }
+
     let updated_project = project.clone();
     save_projects_data(&app, &data)?;
 
@@ -3012,12 +3216,26 @@ pub async fn has_uncommitted_changes(app: AppHandle, worktree_id: String) -> Res
     Ok(git::has_uncommitted_changes(&worktree.path))
 }
 
+/// List PR templates available in a project's repository
+///
+/// Empty when the repo has no `.github/pull_request_template.md` and no
+/// `.github/PULL_REQUEST_TEMPLATE/` directory. Pass the chosen template's `name` as
+/// `template_name` to `get_pr_prompt` to use it instead of the default.
+#[tauri::command]
+pub async fn list_pr_templates(project_path: String) -> Result<Vec<git::TemplateInfo>, String> {
+    Ok(git::list_pr_templates(&project_path))
+}
+
 /// Generate a PR prompt with dynamic context for the AI assistant
 ///
 /// Gathers git state (uncommitted changes, current branch, upstream status)
 /// and includes the PR template if available.
 #[tauri::command]
-pub async fn get_pr_prompt(app: AppHandle, worktree_path: String) -> Result<String, String> {
+pub async fn get_pr_prompt(
+    app: AppHandle,
+    worktree_path: String,
+    template_name: Option<String>,
+) -> Result<String, String> {
     log::trace!("Generating PR prompt for worktree: {worktree_path}");
 
     // Load projects data to find the target branch
@@ -3036,7 +3254,8 @@ pub async fn get_pr_prompt(app: AppHandle, worktree_path: String) -> Result<Stri
         .ok_or_else(|| format!("Project not found: {}", worktree.project_id))?;
 
     let target_branch = &project.default_branch;
-    let context = git::generate_pr_context(&worktree_path, target_branch)?;
+    let context =
+        git::generate_pr_context(&worktree_path, target_branch, template_name.as_deref())?;
 
     let mut prompt = format!(
         r#"The user likes the state of the code and wants to open a PR.
@@ -3590,7 +3809,7 @@ pub struct CreatePrResponse {
 
 /// Extract structured output from Claude CLI stream-json response
 /// Handles the StructuredOutput tool call pattern used with --json-schema
-fn extract_structured_output(output: &str) -> Result<String, String> {
+pub(crate) fn extract_structured_output(output: &str) -> Result<String, String> {
     for line in output.lines() {
         let line = line.trim();
         if line.is_empty() {
@@ -4181,6 +4400,7 @@ pub async fn create_commit_with_ai(
     custom_prompt: Option<String>,
     push: bool,
     model: Option<String>,
+    tag: Option<String>,
 ) -> Result<CreateCommitResponse, String> {
     log::trace!("Creating commit for: {worktree_path}");
 
@@ -4224,8 +4444,15 @@ pub async fn create_commit_with_ai(
         response.message.lines().next().unwrap_or("")
     );
 
+    // 6b. Append a trailer identifying which run produced this commit, if tagged (used by
+    // automatic post-run commits; see `Project::auto_commit_after_run`).
+    let message = match &tag {
+        Some(tag) => format!("{}\n\nJean-Run: {tag}", response.message),
+        None => response.message,
+    };
+
     // 7. Create the commit
-    let commit_hash = create_git_commit(&worktree_path, &response.message)?;
+    let commit_hash = create_git_commit(&worktree_path, &message)?;
 
     log::trace!("Created commit: {commit_hash}");
 
@@ -4240,7 +4467,7 @@ pub async fn create_commit_with_ai(
 
     Ok(CreateCommitResponse {
         commit_hash,
-        message: response.message,
+        message,
         pushed,
     })
 }
@@ -4473,15 +4700,56 @@ pub async fn git_pull(worktree_path: String, base_branch: String) -> Result<Stri
 
 /// Push current branch to remote. If pr_number is provided, uses PR-aware push
 /// that handles fork remotes and uses --force-with-lease.
+///
+/// Before pushing, checks whether the branch being pushed has branch protection rules
+/// configured upstream; if so, prepends a warning to the result rather than blocking the
+/// push, since `git push`/`gh` will surface GitHub's own rejection if it actually applies.
 #[tauri::command]
 pub async fn git_push(app: tauri::AppHandle, worktree_path: String, pr_number: Option<u32>) -> Result<String, String> {
     log::trace!("Pushing changes for worktree: {worktree_path}, pr_number: {pr_number:?}");
-    match pr_number {
+
+    let protection_warning = match git::get_current_branch(&worktree_path) {
+        Ok(branch) => branch_protection_warning(&app, &worktree_path, &branch).await,
+        Err(e) => {
+            log::warn!("Could not determine current branch before push: {e}");
+            None
+        }
+    };
+
+    let result = match pr_number {
         Some(pr) => git::git_push_to_pr(&worktree_path, pr, &resolve_gh_binary(&app)),
         None => git::git_push(&worktree_path),
+    }?;
+
+    Ok(match protection_warning {
+        Some(warning) => format!("{warning}\n{result}"),
+        None => result,
+    })
+}
+
+/// Look up branch protection for `branch` and render a warning string if a rule would make a
+/// direct push risky. Errors are swallowed (logged only) since this is advisory, not a gate.
+async fn branch_protection_warning(
+    app: &tauri::AppHandle,
+    project_path: &str,
+    branch: &str,
+) -> Option<String> {
+    match branch_protection::get_branch_protection(app.clone(), project_path.to_string(), branch.to_string()).await {
+        Ok(Some(info)) => branch_protection::describe_protection_risk(&info),
+        Ok(None) => None,
+        Err(e) => {
+            log::trace!("Could not check branch protection for {branch}: {e}");
+            None
+        }
     }
 }
 
+/// Detect the hosting provider, host, owner, and repo for a project's origin remote
+#[tauri::command]
+pub async fn detect_repo_info(project_path: String) -> Result<git::RepoInfo, String> {
+    git::detect_repo_info(&project_path)
+}
+
 // =============================================================================
 // Local Merge
 // =============================================================================
@@ -4499,6 +4767,9 @@ pub struct MergeWorktreeResponse {
     pub conflict_diff: Option<String>,
     /// Whether worktree was cleaned up
     pub cleaned_up: bool,
+    /// Warning if the base branch has protection rules that pushing this merge commit
+    /// upstream might violate (e.g. required reviews/checks that a local merge bypasses)
+    pub protection_warning: Option<String>,
 }
 
 /// Merge worktree branch into base branch locally and clean up
@@ -4593,6 +4864,11 @@ pub async fn merge_worktree_to_base(
         }
     }
 
+    // Check upfront whether the base branch is protected, so we can warn that a local merge
+    // bypasses any required reviews/checks once the base branch is eventually pushed.
+    let protection_warning =
+        branch_protection_warning(&app, &project.path, &project.default_branch).await;
+
     // Perform the merge in main repo
     let merge_result = git::merge_branch_to_base(
         &project.path,
@@ -4652,6 +4928,7 @@ pub async fn merge_worktree_to_base(
                 conflicts: None,
                 conflict_diff: None,
                 cleaned_up: true,
+                protection_warning,
             })
         }
         git::MergeResult::Conflict {
@@ -4670,6 +4947,7 @@ pub async fn merge_worktree_to_base(
                 conflicts: Some(conflicting_files),
                 conflict_diff: Some(conflict_diff),
                 cleaned_up: false,
+                protection_warning: None,
             })
         }
         git::MergeResult::Error { message } => {
@@ -4910,7 +5188,7 @@ pub async fn cleanup_old_archives(
         }
 
         // Delete the sessions file
-        if let Ok(app_data_dir) = app.path().app_data_dir() {
+        if let Ok(app_data_dir) = crate::data_dir::resolve(&app) {
             let sessions_file = app_data_dir
                 .join("sessions")
                 .join(format!("{}.json", worktree.id));
@@ -5038,7 +5316,7 @@ pub async fn delete_all_archives(app: AppHandle) -> Result<CleanupResult, String
         }
 
         // Delete the sessions file
-        if let Ok(app_data_dir) = app.path().app_data_dir() {
+        if let Ok(app_data_dir) = crate::data_dir::resolve(&app) {
             let sessions_file = app_data_dir
                 .join("sessions")
                 .join(format!("{}.json", worktree.id));
@@ -5166,6 +5444,21 @@ pub async fn create_folder(
         parent_id,
         is_folder: true,
         avatar_path: None,
+        gh_account: None,
+        gitea_host: None,
+        gitea_token: None,
+        monthly_budget_usd: None,
+        run_priority: 0,
+        env_vars: Vec::new(),
+        dotenv_allowlist: Vec::new(),
+        instructions: None,
+        auto_commit_after_run: false,
+        remote: None,
+        shell: None,
+        shell_startup_command: None,
+        sandbox: crate::projects::types::SandboxConfig::default(),
+        notification_webhooks: Vec::new(),
+        muted: false,
     };
 
     data.add_project(folder.clone());
@@ -5491,6 +5784,61 @@ pub async fn fetch_worktrees_status(app: AppHandle, project_id: String) -> Resul
     Ok(())
 }
 
+/// Fetch PR status for every worktree in a project with an open PR, using a single batched
+/// GraphQL request instead of one `gh pr view` per worktree
+///
+/// Unlike `fetch_worktrees_status` above, this doesn't spawn a thread per worktree - the whole
+/// point is to make one `gh api graphql` call for the project's repository. Status is emitted
+/// per-PR via the existing `pr:status-update` event channel so the frontend doesn't need to
+/// distinguish this from the background poller's single-PR updates.
+#[tauri::command]
+pub async fn fetch_worktrees_pr_status(app: AppHandle, project_id: String) -> Result<(), String> {
+    use super::pr_status::get_pr_statuses_batch;
+
+    log::trace!("[fetch_worktrees_pr_status] Fetching PR status for project: {project_id}");
+
+    let data = load_projects_data(&app)?;
+    let project = data
+        .find_project(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?
+        .clone();
+
+    let prs: Vec<(String, u32, String)> = data
+        .worktrees_for_project(&project_id)
+        .into_iter()
+        .filter(|w| w.archived_at.is_none())
+        .filter_map(|w| {
+            let pr_number = w.pr_number?;
+            let pr_url = w.pr_url.clone()?;
+            Some((w.id.clone(), pr_number, pr_url))
+        })
+        .collect();
+
+    if prs.is_empty() {
+        log::trace!("[fetch_worktrees_pr_status] No open PRs to check for project: {project_id}");
+        return Ok(());
+    }
+
+    let repo_id = get_repo_identifier(&project.path)?;
+    let gh = resolve_gh_binary(&app);
+
+    let statuses = thread::spawn(move || {
+        get_pr_statuses_batch(&repo_id.owner, &repo_id.repo, &prs, &gh)
+    })
+    .join()
+    .map_err(|_| "PR status fetch thread panicked".to_string())??;
+
+    for status in statuses {
+        let worktree_id = status.worktree_id.clone();
+        if let Err(e) = app.emit_all("pr:status-update", &status) {
+            log::warn!("Failed to emit pr:status-update for worktree {worktree_id}: {e}");
+        }
+    }
+
+    log::trace!("[fetch_worktrees_pr_status] Done fetching PR status for project: {project_id}");
+    Ok(())
+}
+
 // =============================================================================
 // Claude CLI Skills & Commands
 // =============================================================================
@@ -5661,10 +6009,7 @@ pub async fn list_claude_commands() -> Result<Vec<ClaudeCommand>, String> {
 
 /// Get the avatars directory, creating it if needed
 fn get_avatars_dir(app: &AppHandle) -> Result<std::path::PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
 
     let avatars_dir = app_data_dir.join("avatars");
     std::fs::create_dir_all(&avatars_dir)
@@ -5750,10 +6095,7 @@ pub async fn remove_project_avatar(app: AppHandle, project_id: String) -> Result
 
     // Delete avatar file if it exists
     if let Some(ref avatar_path) = project.avatar_path {
-        let app_data_dir = app
-            .path()
-            .app_data_dir()
-            .map_err(|e| format!("Failed to get app data dir: {e}"))?;
+        let app_data_dir = crate::data_dir::resolve(&app)?;
 
         let full_path = app_data_dir.join(avatar_path);
         if full_path.exists() {
@@ -5774,14 +6116,296 @@ pub async fn remove_project_avatar(app: AppHandle, project_id: String) -> Result
     Ok(updated_project)
 }
 
+/// Assign the GitHub account to use for `gh` commands against a project
+///
+/// Pass `None` to fall back to whichever account is currently active in `gh auth status`.
+/// The account must already be logged in locally (see `list_gh_accounts`).
+#[tauri::command]
+pub async fn set_project_gh_account(
+    app: AppHandle,
+    project_id: String,
+    account: Option<String>,
+) -> Result<Project, String> {
+    log::trace!("Setting gh account for project {project_id}: {account:?}");
+
+    let mut data = load_projects_data(&app)?;
+    let project = data
+        .find_project_mut(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?;
+
+    project.gh_account = account;
+    let updated_project = project.clone();
+
+    save_projects_data(&app, &data)?;
+
+    log::trace!(
+        "Successfully set gh account for project: {}",
+        updated_project.name
+    );
+    Ok(updated_project)
+}
+
+/// Configure a project as pointing to a self-hosted Gitea/Forgejo instance
+///
+/// Pass `host: None` to clear the configuration and treat the project as a GitHub project again.
+#[tauri::command]
+pub async fn set_project_gitea_config(
+    app: AppHandle,
+    project_id: String,
+    host: Option<String>,
+    token: Option<String>,
+) -> Result<Project, String> {
+    log::trace!("Setting Gitea config for project {project_id}: host={host:?}");
+
+    let mut data = load_projects_data(&app)?;
+    let project = data
+        .find_project_mut(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?;
+
+    project.gitea_host = host;
+    project.gitea_token = token;
+    let updated_project = project.clone();
+
+    save_projects_data(&app, &data)?;
+
+    log::trace!(
+        "Successfully set Gitea config for project: {}",
+        updated_project.name
+    );
+    Ok(updated_project)
+}
+
+/// Set a project's monthly AI usage budget in USD
+///
+/// Pass `None` to clear the per-project budget and fall back to
+/// `AppPreferences::global_monthly_budget_usd`, if any.
+#[tauri::command]
+pub async fn set_project_budget(
+    app: AppHandle,
+    project_id: String,
+    monthly_budget_usd: Option<f64>,
+) -> Result<Project, String> {
+    log::trace!("Setting monthly budget for project {project_id}: {monthly_budget_usd:?}");
+
+    let mut data = load_projects_data(&app)?;
+    let project = data
+        .find_project_mut(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?;
+
+    project.monthly_budget_usd = monthly_budget_usd;
+    let updated_project = project.clone();
+
+    save_projects_data(&app, &data)?;
+
+    log::trace!(
+        "Successfully set monthly budget for project: {}",
+        updated_project.name
+    );
+    Ok(updated_project)
+}
+
+/// Set a project's environment variables, injected into the Claude CLI process and
+/// `jean.json` hook scripts for every session under this project (see
+/// `chat::env_vars::resolve_env_vars` for how these merge with session-level overrides).
+#[tauri::command]
+pub async fn set_project_env_vars(
+    app: AppHandle,
+    project_id: String,
+    env_vars: Vec<crate::projects::types::EnvVarEntry>,
+) -> Result<Project, String> {
+    log::trace!(
+        "Setting {} env var(s) for project {project_id}",
+        env_vars.len()
+    );
+
+    let mut data = load_projects_data(&app)?;
+    let project = data
+        .find_project_mut(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?;
+
+    project.env_vars = env_vars;
+    let updated_project = project.clone();
+
+    save_projects_data(&app, &data)?;
+
+    log::trace!(
+        "Successfully set env vars for project: {}",
+        updated_project.name
+    );
+    Ok(updated_project)
+}
+
+/// Set a project's Slack/Discord notification webhooks (see
+/// `notifications::integrations::evaluate` for how/when these fire).
+#[tauri::command]
+pub async fn set_project_notification_webhooks(
+    app: AppHandle,
+    project_id: String,
+    notification_webhooks: Vec<crate::projects::types::NotificationWebhook>,
+) -> Result<Project, String> {
+    log::trace!(
+        "Setting {} notification webhook(s) for project {project_id}",
+        notification_webhooks.len()
+    );
+
+    let mut data = load_projects_data(&app)?;
+    let project = data
+        .find_project_mut(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?;
+
+    project.notification_webhooks = notification_webhooks;
+    let updated_project = project.clone();
+
+    save_projects_data(&app, &data)?;
+
+    log::trace!(
+        "Successfully set notification webhooks for project: {}",
+        updated_project.name
+    );
+    Ok(updated_project)
+}
+
+/// Mute/unmute native notifications for a project's worktrees (see `notifications::dnd`).
+/// Rule/webhook actions other than `native` still fire - this only suppresses the OS-level
+/// popup.
+#[tauri::command]
+pub async fn set_project_muted(
+    app: AppHandle,
+    project_id: String,
+    muted: bool,
+) -> Result<Project, String> {
+    log::trace!("Setting muted={muted} for project {project_id}");
+
+    let mut data = load_projects_data(&app)?;
+    let project = data
+        .find_project_mut(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?;
+
+    project.muted = muted;
+    let updated_project = project.clone();
+
+    save_projects_data(&app, &data)?;
+
+    log::trace!(
+        "Successfully set muted for project: {}",
+        updated_project.name
+    );
+    Ok(updated_project)
+}
+
+/// Set a project's managed system-prompt instructions document, appended to every session's
+/// system prompt under this project (see `chat::instructions::resolve_instructions`).
+///
+/// Pass `None` to clear it. A worktree with `Worktree::instructions_override` set ignores
+/// this value.
+#[tauri::command]
+pub async fn set_project_instructions(
+    app: AppHandle,
+    project_id: String,
+    instructions: Option<String>,
+) -> Result<Project, String> {
+    log::trace!("Setting instructions for project {project_id}");
+
+    let mut data = load_projects_data(&app)?;
+    let project = data
+        .find_project_mut(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?;
+
+    project.instructions = instructions;
+    let updated_project = project.clone();
+
+    save_projects_data(&app, &data)?;
+
+    log::trace!(
+        "Successfully set instructions for project: {}",
+        updated_project.name
+    );
+    Ok(updated_project)
+}
+
+/// Toggle a project's `auto_commit_after_run` setting (see that field's doc comment).
+#[tauri::command]
+pub async fn set_project_auto_commit_after_run(
+    app: AppHandle,
+    project_id: String,
+    enabled: bool,
+) -> Result<Project, String> {
+    log::trace!("Setting auto_commit_after_run={enabled} for project {project_id}");
+
+    let mut data = load_projects_data(&app)?;
+    let project = data
+        .find_project_mut(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?;
+
+    project.auto_commit_after_run = enabled;
+    let updated_project = project.clone();
+
+    save_projects_data(&app, &data)?;
+
+    log::trace!(
+        "Successfully set auto_commit_after_run for project: {}",
+        updated_project.name
+    );
+    Ok(updated_project)
+}
+
+/// Set a project's opt-in Claude CLI sandbox settings (see `chat::sandbox`).
+#[tauri::command]
+pub async fn set_project_sandbox_config(
+    app: AppHandle,
+    project_id: String,
+    sandbox: crate::projects::types::SandboxConfig,
+) -> Result<Project, String> {
+    log::trace!("Setting sandbox config for project {project_id}: {sandbox:?}");
+
+    let mut data = load_projects_data(&app)?;
+    let project = data
+        .find_project_mut(&project_id)
+        .ok_or_else(|| format!("Project not found: {project_id}"))?;
+
+    project.sandbox = sandbox;
+    let updated_project = project.clone();
+
+    save_projects_data(&app, &data)?;
+
+    log::trace!(
+        "Successfully set sandbox config for project: {}",
+        updated_project.name
+    );
+    Ok(updated_project)
+}
+
+/// Set a worktree's instructions override, taking precedence over its project's
+/// `Project::instructions` for this worktree's sessions only.
+///
+/// Pass `None` to clear the override and inherit the project's instructions again.
+#[tauri::command]
+pub async fn set_worktree_instructions(
+    app: AppHandle,
+    worktree_id: String,
+    instructions: Option<String>,
+) -> Result<Worktree, String> {
+    log::trace!("Setting instructions override for worktree {worktree_id}");
+
+    let mut data = load_projects_data(&app)?;
+    let worktree = data
+        .find_worktree_mut(&worktree_id)
+        .ok_or_else(|| format!("Worktree not found: {worktree_id}"))?;
+
+    worktree.instructions_override = instructions;
+    let updated_worktree = worktree.clone();
+
+    save_projects_data(&app, &data)?;
+
+    log::trace!("Successfully set instructions override for worktree: {worktree_id}");
+    Ok(updated_worktree)
+}
+
 /// Get the app data directory path
 /// Used by frontend to resolve relative avatar paths to absolute file:// URLs
 #[tauri::command]
 pub async fn get_app_data_dir(app: AppHandle) -> Result<String, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(&app)?;
 
     Ok(app_data_dir.to_string_lossy().to_string())
 }
@@ -0,0 +1,221 @@
+//! Batch review workflow for dependency-update PRs (Dependabot/Renovate)
+//!
+//! These bots tend to open many low-risk PRs in quick succession, so reviewing them one at a
+//! time in the normal worktree flow is mostly overhead. This module adds a way to list them,
+//! combine several into one worktree to sanity-check them together, and merge a batch in one
+//! go. This module does not run the project's test suite against the combined worktree; that
+//! is done through `scripts::run_project_script` or the user's own terminal.
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use super::git;
+use super::types::MergeType;
+use crate::gh_cli::config::resolve_gh_binary;
+use crate::platform::silent_command;
+
+/// Login names (case-insensitive, with or without the `[bot]` suffix GitHub appends) recognized
+/// as automated dependency-update authors
+const DEPENDENCY_BOT_LOGINS: &[&str] = &["dependabot", "renovate"];
+
+fn is_dependency_bot(login: &str) -> bool {
+    let login = login.to_lowercase();
+    DEPENDENCY_BOT_LOGINS
+        .iter()
+        .any(|bot| login.starts_with(bot))
+}
+
+/// An open dependency-update PR, as surfaced to the batch review UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyPrSummary {
+    pub number: u32,
+    pub title: String,
+    pub url: String,
+    pub author: String,
+    pub head_ref_name: String,
+}
+
+/// The outcome of one PR within a batch checkout/merge operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchPrOutcome {
+    pub number: u32,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// List open PRs authored by a recognized dependency-update bot (Dependabot, Renovate)
+#[tauri::command]
+pub async fn list_dependency_update_prs(
+    app: AppHandle,
+    project_path: String,
+) -> Result<Vec<DependencyPrSummary>, String> {
+    log::trace!("Listing dependency-update PRs in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args([
+            "pr",
+            "list",
+            "--json",
+            "number,title,url,author,headRefName",
+            "--state",
+            "open",
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr list: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("gh pr list failed: {stderr}"));
+    }
+
+    #[derive(Deserialize)]
+    struct GhAuthor {
+        login: String,
+    }
+    #[derive(Deserialize)]
+    struct GhPr {
+        number: u32,
+        title: String,
+        url: String,
+        author: GhAuthor,
+        #[serde(rename = "headRefName")]
+        head_ref_name: String,
+    }
+
+    let prs: Vec<GhPr> = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse gh pr list output: {e}"))?;
+
+    Ok(prs
+        .into_iter()
+        .filter(|pr| is_dependency_bot(&pr.author.login))
+        .map(|pr| DependencyPrSummary {
+            number: pr.number,
+            title: pr.title,
+            url: pr.url,
+            author: pr.author.login,
+            head_ref_name: pr.head_ref_name,
+        })
+        .collect())
+}
+
+/// Merge a PR's remote head ref into the current branch of a local worktree, without touching
+/// GitHub. Used to combine several dependency-update PRs into one worktree for a joint review.
+///
+/// Leaves a failed merge in a clean state (aborts it) rather than leaving conflict markers, so
+/// a batch checkout can keep going with the next PR.
+fn merge_pr_ref_locally(worktree_path: &str, pr_number: u32) -> Result<(), String> {
+    let fetch_ref = format!("pull/{pr_number}/head:dep-pr-{pr_number}");
+
+    let fetch_output = silent_command("git")
+        .args(["fetch", "origin", &fetch_ref])
+        .current_dir(worktree_path)
+        .output()
+        .map_err(|e| format!("Failed to fetch PR #{pr_number}: {e}"))?;
+
+    if !fetch_output.status.success() {
+        let stderr = String::from_utf8_lossy(&fetch_output.stderr);
+        return Err(format!("Failed to fetch PR #{pr_number}: {stderr}"));
+    }
+
+    let local_ref = format!("dep-pr-{pr_number}");
+    let merge_output = silent_command("git")
+        .args(["merge", "--no-edit", &local_ref])
+        .current_dir(worktree_path)
+        .output()
+        .map_err(|e| format!("Failed to merge PR #{pr_number}: {e}"))?;
+
+    if !merge_output.status.success() {
+        silent_command("git")
+            .args(["merge", "--abort"])
+            .current_dir(worktree_path)
+            .output()
+            .ok();
+        let stderr = String::from_utf8_lossy(&merge_output.stderr);
+        return Err(format!("PR #{pr_number} has conflicts: {stderr}"));
+    }
+
+    silent_command("git")
+        .args(["branch", "-D", &local_ref])
+        .current_dir(worktree_path)
+        .output()
+        .ok();
+
+    Ok(())
+}
+
+/// Combine several dependency-update PRs into a single worktree by merging each PR's head ref
+/// into it in turn, so they can be reviewed and tested together.
+///
+/// `worktree_path` must already exist (e.g. a fresh worktree created off the base branch
+/// through the normal "new worktree" flow) — this command only handles merging the PR branches
+/// into it, not worktree creation. PRs that fail to merge cleanly are skipped so the rest of
+/// the batch can still be combined; check each outcome's `error` field.
+#[tauri::command]
+pub async fn checkout_dependency_prs_combined(
+    worktree_path: String,
+    pr_numbers: Vec<u32>,
+) -> Result<Vec<BatchPrOutcome>, String> {
+    log::trace!("Combining {} dependency PRs into {worktree_path}", pr_numbers.len());
+
+    let mut outcomes = Vec::with_capacity(pr_numbers.len());
+    for number in pr_numbers {
+        match merge_pr_ref_locally(&worktree_path, number) {
+            Ok(()) => outcomes.push(BatchPrOutcome {
+                number,
+                success: true,
+                error: None,
+            }),
+            Err(e) => {
+                log::warn!("Failed to combine PR #{number} into {worktree_path}: {e}");
+                outcomes.push(BatchPrOutcome {
+                    number,
+                    success: false,
+                    error: Some(e),
+                });
+            }
+        }
+    }
+
+    Ok(outcomes)
+}
+
+/// Merge a batch of dependency-update PRs on GitHub, one after another. A failure merging one
+/// PR (e.g. conflicts, required checks not passed) doesn't stop the rest of the batch.
+#[tauri::command]
+pub async fn batch_merge_dependency_prs(
+    app: AppHandle,
+    project_path: String,
+    pr_numbers: Vec<u32>,
+    method: MergeType,
+    delete_branch: bool,
+) -> Result<Vec<BatchPrOutcome>, String> {
+    log::trace!("Batch-merging {} dependency PRs in {project_path}", pr_numbers.len());
+
+    let gh = resolve_gh_binary(&app);
+    let mut outcomes = Vec::with_capacity(pr_numbers.len());
+
+    for number in pr_numbers {
+        let result = git::merge_pull_request(&project_path, number, &method, delete_branch, &gh);
+        match result {
+            Ok(()) => outcomes.push(BatchPrOutcome {
+                number,
+                success: true,
+                error: None,
+            }),
+            Err(e) => {
+                log::warn!("Failed to merge dependency PR #{number}: {e}");
+                outcomes.push(BatchPrOutcome {
+                    number,
+                    success: false,
+                    error: Some(e),
+                });
+            }
+        }
+    }
+
+    Ok(outcomes)
+}
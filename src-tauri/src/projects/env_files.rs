@@ -0,0 +1,117 @@
+//! Loads a worktree's `.env`/`.env.local` into the env vars injected into terminals and
+//! run scripts, filtered by `Project::dotenv_allowlist` so secrets in a `.env` aren't
+//! pulled in just because a file happens to exist.
+//!
+//! This is a separate, much smaller layer than `chat::env_vars::resolve_env_vars` (which
+//! only handles the explicit `Project::env_vars`/`SessionMetadata::env_vars` config) -
+//! `get_effective_env` below merges both so the merged result can be inspected in one place.
+
+use std::path::Path;
+use tauri::AppHandle;
+
+use crate::projects::storage::load_projects_data;
+use crate::projects::types::EnvVarEntry;
+
+/// Parse a `.env`-style file into `(key, value)` pairs. Lines that are blank, start with
+/// `#`, or don't contain `=` are skipped. Values may be wrapped in matching single or
+/// double quotes, which are stripped.
+fn parse_dotenv(contents: &str) -> Vec<(String, String)> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                return None;
+            }
+            let (key, value) = line.split_once('=')?;
+            let key = key.trim();
+            let mut value = value.trim();
+            if (value.starts_with('"') && value.ends_with('"') && value.len() >= 2)
+                || (value.starts_with('\'') && value.ends_with('\'') && value.len() >= 2)
+            {
+                value = &value[1..value.len() - 1];
+            }
+            if key.is_empty() {
+                None
+            } else {
+                Some((key.to_string(), value.to_string()))
+            }
+        })
+        .collect()
+}
+
+/// Match a variable name against a simple glob pattern supporting a single trailing `*`
+/// (e.g. `"VITE_*"`), or an exact match when the pattern has no `*`.
+fn matches_pattern(name: &str, pattern: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => name.starts_with(prefix),
+        None => name == pattern,
+    }
+}
+
+/// Load `.env` then `.env.local` (which overrides keys from `.env`, matching the usual
+/// dotenv convention) from `worktree_path`, keeping only names matched by `allowlist`.
+pub fn load_dotenv_vars(worktree_path: &str, allowlist: &[String]) -> Vec<(String, String)> {
+    if allowlist.is_empty() {
+        return Vec::new();
+    }
+
+    let mut vars: Vec<(String, String)> = Vec::new();
+    for filename in [".env", ".env.local"] {
+        let path = Path::new(worktree_path).join(filename);
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        for (key, value) in parse_dotenv(&contents) {
+            if !allowlist
+                .iter()
+                .any(|pattern| matches_pattern(&key, pattern))
+            {
+                continue;
+            }
+            if let Some(existing) = vars.iter_mut().find(|(k, _)| *k == key) {
+                existing.1 = value;
+            } else {
+                vars.push((key, value));
+            }
+        }
+    }
+    vars
+}
+
+/// Resolve the full merged env for a worktree - its project's `.env`-allowlisted
+/// variables, overridden by `Project::env_vars` - for display in the settings UI. Chat
+/// runs additionally layer `SessionMetadata::env_vars` on top via
+/// `chat::env_vars::resolve_env_vars`, which this does not duplicate.
+#[tauri::command]
+pub async fn get_effective_env(
+    app: AppHandle,
+    worktree_id: String,
+) -> Result<Vec<EnvVarEntry>, String> {
+    let data = load_projects_data(&app)?;
+    let worktree = data
+        .find_worktree(&worktree_id)
+        .ok_or_else(|| format!("Worktree not found: {worktree_id}"))?;
+    let project = data
+        .find_project(&worktree.project_id)
+        .ok_or_else(|| format!("Project not found: {}", worktree.project_id))?;
+
+    let mut merged: Vec<EnvVarEntry> = load_dotenv_vars(&worktree.path, &project.dotenv_allowlist)
+        .into_iter()
+        .map(|(key, value)| EnvVarEntry {
+            key,
+            value,
+            sensitive: false,
+        })
+        .collect();
+
+    for entry in &project.env_vars {
+        if let Some(existing) = merged.iter_mut().find(|e| e.key == entry.key) {
+            *existing = entry.clone();
+        } else {
+            merged.push(entry.clone());
+        }
+    }
+
+    Ok(merged)
+}
@@ -10,6 +10,14 @@ use super::types::{JeanConfig, MergeType};
 pub struct RepoIdentifier {
     pub owner: String,
     pub repo: String,
+    /// Hostname the repository is hosted on (e.g. "github.com", or a GitHub Enterprise
+    /// Server hostname like "github.mycompany.com")
+    #[serde(default = "default_github_host")]
+    pub host: String,
+}
+
+fn default_github_host() -> String {
+    "github.com".to_string()
 }
 
 impl RepoIdentifier {
@@ -19,32 +27,115 @@ impl RepoIdentifier {
     }
 }
 
-/// Extract repository owner and name from a git repository's GitHub remote
+/// Extract repository owner, name, and host from a git repository's GitHub remote
 ///
 /// Returns an error if:
 /// - The repository has no origin remote
-/// - The remote URL is not a GitHub URL
+/// - The remote URL is not a GitHub-style URL
+///
+/// Works against GitHub Enterprise Server remotes as well as github.com, since both use
+/// the same `https://host/owner/repo` shape.
 pub fn get_repo_identifier(repo_path: &str) -> Result<RepoIdentifier, String> {
-    let github_url = get_github_url(repo_path)?;
+    let remote_url = get_remote_origin_url(repo_path)?;
+    let (host, owner, repo) = parse_remote_url(&remote_url)?;
+    Ok(RepoIdentifier { host, owner, repo })
+}
 
-    // Parse owner/repo from URL: https://github.com/owner/repo
-    let url_without_prefix = github_url
-        .strip_prefix("https://github.com/")
-        .ok_or_else(|| format!("Invalid GitHub URL format: {github_url}"))?;
+/// Parse `host`, `owner`, and `repo` out of a git remote URL, regardless of shape:
+/// - HTTPS: `https://host/owner/repo(.git)?`
+/// - scp-style SSH: `git@host:owner/repo(.git)?`
+/// - SSH URL: `ssh://git@host[:port]/owner/repo(.git)?`
+///
+/// Handles owners/repos containing dots (e.g. `my.org/repo.name`) since splitting happens on
+/// `/`, not `.`; only a literal trailing `.git` is stripped.
+fn parse_remote_url(remote_url: &str) -> Result<(String, String, String), String> {
+    let remote_url = remote_url.trim();
+
+    let path_part = if let Some(rest) = remote_url.strip_prefix("ssh://") {
+        // ssh://[user@]host[:port]/owner/repo
+        let rest = rest.split_once('@').map_or(rest, |(_, r)| r);
+        let (host_and_port, path) = rest
+            .split_once('/')
+            .ok_or_else(|| format!("Could not parse owner/repo from SSH URL: {remote_url}"))?;
+        let host = host_and_port.split(':').next().unwrap_or(host_and_port);
+        format!("{host}/{path}")
+    } else if let Some(rest) = remote_url.strip_prefix("https://") {
+        rest.to_string()
+    } else if let Some(rest) = remote_url.strip_prefix("http://") {
+        rest.to_string()
+    } else if let Some(rest) = remote_url.strip_prefix("git@") {
+        // scp-style: git@host:owner/repo(.git)?
+        rest.replacen(':', "/", 1)
+    } else {
+        return Err(format!(
+            "Remote URL is not a recognized git hosting URL: {remote_url}"
+        ));
+    };
 
-    let parts: Vec<&str> = url_without_prefix.split('/').collect();
-    if parts.len() < 2 {
+    let path_part = path_part.trim_end_matches('/').trim_end_matches(".git");
+    let parts: Vec<&str> = path_part.split('/').collect();
+    if parts.len() < 3 {
         return Err(format!(
-            "Could not parse owner/repo from GitHub URL: {github_url}"
+            "Could not parse host/owner/repo from remote URL: {remote_url}"
         ));
     }
 
-    Ok(RepoIdentifier {
-        owner: parts[0].to_string(),
-        repo: parts[1].to_string(),
+    // Some hosting shapes (e.g. GitLab subgroups) nest extra path segments between owner and
+    // repo; we only support the common `host/owner/repo` shape, taking the last segment as the
+    // repo name and the first as owner.
+    let host = parts[0].to_string();
+    let owner = parts[1].to_string();
+    let repo = parts[parts.len() - 1].to_string();
+
+    Ok((host, owner, repo))
+}
+
+/// Repository identity together with the hosting provider it was detected on
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoInfo {
+    pub provider: super::provider::GitHostKind,
+    pub host: String,
+    pub owner: String,
+    pub repo: String,
+}
+
+/// Parse the repository's origin remote into a provider-aware `RepoInfo`, handling HTTPS, SSH,
+/// and scp-style remote URLs.
+///
+/// Provider detection is best-effort: self-hosted Gitea instances can't be distinguished from
+/// GitHub Enterprise Server by URL alone (see `detect_host_kind`), so this only recognizes
+/// gitlab.com remotes as GitLab; everything else is reported as GitHub. Code paths that already
+/// know a project is Gitea-backed (via its configured `gitea_host`) should prefer
+/// `provider::provider_for_project` instead.
+pub fn detect_repo_info(project_path: &str) -> Result<RepoInfo, String> {
+    let remote_url = get_remote_origin_url(project_path)?;
+    let (host, owner, repo) = parse_remote_url(&remote_url)?;
+    let provider = super::provider::detect_host_kind(&remote_url);
+    Ok(RepoInfo {
+        provider,
+        host,
+        owner,
+        repo,
     })
 }
 
+/// Read the `origin` remote URL for a repository, without any normalization
+fn get_remote_origin_url(repo_path: &str) -> Result<String, String> {
+    let output = silent_command("git")
+        .args(["remote", "get-url", "origin"])
+        .current_dir(repo_path)
+        .output()
+        .map_err(|e| format!("Failed to get remote URL: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to get remote URL: {stderr}"));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 /// Detect user's default shell and determine if it supports login mode.
 ///
 /// On macOS/Linux, GUI apps don't inherit the user's shell PATH. Using a login shell
@@ -180,38 +271,14 @@ pub fn get_repo_name(path: &str) -> Result<String, String> {
 
 /// Get the GitHub URL for a repository
 ///
-/// Converts git remote URLs to HTTPS GitHub URLs
+/// Converts git remote URLs (HTTPS, scp-style SSH, or `ssh://` URLs) to an HTTPS URL. Works for
+/// github.com as well as GitHub Enterprise Server remotes, since both use the same
+/// `host/owner/repo` URL shape.
 pub fn get_github_url(repo_path: &str) -> Result<String, String> {
-    let output = silent_command("git")
-        .args(["remote", "get-url", "origin"])
-        .current_dir(repo_path)
-        .output()
-        .map_err(|e| format!("Failed to get remote URL: {e}"))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("Failed to get remote URL: {stderr}"));
-    }
-
-    let remote_url = String::from_utf8_lossy(&output.stdout).trim().to_string();
-
-    // Convert SSH URL to HTTPS URL if needed
-    // git@github.com:user/repo.git -> https://github.com/user/repo
-    // https://github.com/user/repo.git -> https://github.com/user/repo
-    let github_url = if remote_url.starts_with("git@github.com:") {
-        remote_url
-            .replace("git@github.com:", "https://github.com/")
-            .trim_end_matches(".git")
-            .to_string()
-    } else if remote_url.starts_with("https://github.com/") {
-        remote_url.trim_end_matches(".git").to_string()
-    } else {
-        return Err(format!(
-            "Remote URL is not a GitHub repository: {remote_url}"
-        ));
-    };
-
-    Ok(github_url)
+    let remote_url = get_remote_origin_url(repo_path)?;
+    let (host, owner, repo) = parse_remote_url(&remote_url)
+        .map_err(|_| format!("Remote URL is not a GitHub-style repository: {remote_url}"))?;
+    Ok(format!("https://{host}/{owner}/{repo}"))
 }
 
 /// Get the current branch name (HEAD) for a repository
@@ -747,15 +814,11 @@ pub fn create_worktree(
     }
 
     // git worktree add -b <new_branch> <path> <base_branch>
+    let normalized_worktree_path = crate::platform::paths::normalize(worktree_path_obj);
     let output = silent_command("git")
-        .args([
-            "worktree",
-            "add",
-            "-b",
-            new_branch_name,
-            worktree_path,
-            base_branch,
-        ])
+        .args(["worktree", "add", "-b", new_branch_name])
+        .arg(&normalized_worktree_path)
+        .arg(base_branch)
         .current_dir(repo_path)
         .output()
         .map_err(|e| format!("Failed to run git worktree add: {e}"))?;
@@ -790,8 +853,11 @@ pub fn create_worktree_from_existing_branch(
     }
 
     // git worktree add <path> <existing_branch> (no -b flag)
+    let normalized_worktree_path = crate::platform::paths::normalize(worktree_path_obj);
     let output = silent_command("git")
-        .args(["worktree", "add", worktree_path, existing_branch])
+        .args(["worktree", "add"])
+        .arg(&normalized_worktree_path)
+        .arg(existing_branch)
         .current_dir(repo_path)
         .output()
         .map_err(|e| format!("Failed to run git worktree add: {e}"))?;
@@ -1060,6 +1126,95 @@ pub fn commit_changes(repo_path: &str, message: &str, stage_all: bool) -> Result
     Ok(hash)
 }
 
+/// Commit only the given unified-diff patch against the working tree, leaving the rest
+/// of the working tree changes untouched.
+///
+/// # Arguments
+/// * `repo_path` - Path to the repository
+/// * `patch` - A unified diff (as produced by `git diff`) containing only the hunks to commit
+/// * `message` - Commit message
+///
+/// Applies the patch to the index with `git apply --cached` and commits the index, without
+/// touching the working tree. `git commit` with no path arguments commits the *whole* index,
+/// not just what this call staged, so if anything else is already staged (plausible since
+/// `commit_changes` supports `stage_all: false`) this refuses rather than silently folding
+/// those unrelated staged changes into this commit. Useful for "commit only the AI review
+/// fixes" flows where the full diff also contains unrelated in-progress edits.
+pub fn commit_patch_hunks(repo_path: &str, patch: &str, message: &str) -> Result<String, String> {
+    log::trace!("Committing selected hunks in {repo_path}");
+
+    if patch.trim().is_empty() {
+        return Err("No hunks selected to commit".to_string());
+    }
+
+    if has_staged_changes(repo_path) {
+        return Err(
+            "Cannot commit selected hunks: the index already has other staged changes. \
+             Unstage them first so this commit contains only the selected hunks."
+                .to_string(),
+        );
+    }
+
+    // Apply the patch to the index only, leaving the working tree alone.
+    let mut apply_cmd = silent_command("git")
+        .args(["apply", "--cached", "--whitespace=nowarn", "-"])
+        .current_dir(repo_path)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run git apply: {e}"))?;
+
+    {
+        use std::io::Write;
+        let stdin = apply_cmd
+            .stdin
+            .as_mut()
+            .ok_or_else(|| "Failed to open git apply stdin".to_string())?;
+        stdin
+            .write_all(patch.as_bytes())
+            .map_err(|e| format!("Failed to write patch to git apply: {e}"))?;
+    }
+
+    let apply_output = apply_cmd
+        .wait_with_output()
+        .map_err(|e| format!("Failed to wait on git apply: {e}"))?;
+
+    if !apply_output.status.success() {
+        let stderr = String::from_utf8_lossy(&apply_output.stderr)
+            .trim()
+            .to_string();
+        return Err(format!("Failed to apply selected hunks: {stderr}"));
+    }
+
+    // Commit just what we staged above.
+    let commit_output = silent_command("git")
+        .args(["commit", "-m", message])
+        .current_dir(repo_path)
+        .output()
+        .map_err(|e| format!("Failed to run git commit: {e}"))?;
+
+    if !commit_output.status.success() {
+        let stderr = String::from_utf8_lossy(&commit_output.stderr)
+            .trim()
+            .to_string();
+        return Err(format!("Failed to commit selected hunks: {stderr}"));
+    }
+
+    let hash_output = silent_command("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(repo_path)
+        .output()
+        .map_err(|e| format!("Failed to get commit hash: {e}"))?;
+
+    let hash = String::from_utf8_lossy(&hash_output.stdout)
+        .trim()
+        .to_string();
+    log::trace!("Successfully committed selected hunks: {hash}");
+
+    Ok(hash)
+}
+
 /// Open a pull request using the GitHub CLI (gh)
 ///
 /// # Arguments
@@ -1190,6 +1345,50 @@ pub fn open_pull_request(
     Ok(stdout)
 }
 
+/// Merge a pull request via `gh pr merge`
+pub fn merge_pull_request(
+    repo_path: &str,
+    pr_number: u32,
+    method: &super::types::MergeType,
+    delete_branch: bool,
+    gh_binary: &std::path::Path,
+) -> Result<(), String> {
+    log::trace!("Merging pull request #{pr_number} in {repo_path}");
+
+    let mut args = vec!["pr".to_string(), "merge".to_string(), pr_number.to_string()];
+    args.push(
+        match method {
+            super::types::MergeType::Merge => "--merge",
+            super::types::MergeType::Squash => "--squash",
+            super::types::MergeType::Rebase => "--rebase",
+        }
+        .to_string(),
+    );
+    if delete_branch {
+        args.push("--delete-branch".to_string());
+    }
+
+    let output = silent_command(gh_binary)
+        .args(&args)
+        .current_dir(repo_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr merge: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("not mergeable") || stderr.contains("Merge conflict") {
+            return Err("Pull request is not mergeable - it likely has conflicts with the base branch".to_string());
+        }
+        if stderr.contains("review") {
+            return Err(format!("Pull request cannot be merged yet: {stderr}"));
+        }
+        return Err(format!("Failed to merge pull request: {stderr}"));
+    }
+
+    log::trace!("Successfully merged pull request #{pr_number}");
+    Ok(())
+}
+
 // =============================================================================
 // PR Context Generation
 // =============================================================================
@@ -1227,20 +1426,92 @@ pub fn has_upstream_branch(repo_path: &str) -> bool {
         .unwrap_or(false)
 }
 
-/// Read PR template if it exists
-pub fn get_pr_template(repo_path: &str) -> Option<String> {
-    let template_path = Path::new(repo_path).join(".github/pull_request_template.md");
-    std::fs::read_to_string(template_path).ok()
+/// A named PR or issue template discovered in a repository
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateInfo {
+    /// Display name (filename without extension, e.g. "bug_report", or "default" for a
+    /// repo's single unnamed template)
+    pub name: String,
+    /// Path relative to the repo root
+    pub path: String,
+}
+
+/// List available pull request templates
+///
+/// GitHub recognizes either a single template (`.github/pull_request_template.md`, or the same
+/// filename at the repo root or under `docs/`), or multiple named templates under
+/// `.github/PULL_REQUEST_TEMPLATE/`. Both forms are supported here.
+pub fn list_pr_templates(repo_path: &str) -> Vec<TemplateInfo> {
+    let repo = Path::new(repo_path);
+    let mut templates = Vec::new();
+
+    if let Ok(entries) = std::fs::read_dir(repo.join(".github/PULL_REQUEST_TEMPLATE")) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                templates.push(TemplateInfo {
+                    name: name.to_string(),
+                    path: path
+                        .strip_prefix(repo)
+                        .unwrap_or(&path)
+                        .to_string_lossy()
+                        .to_string(),
+                });
+            }
+        }
+    }
+
+    if templates.is_empty() {
+        for candidate in [
+            ".github/pull_request_template.md",
+            ".github/PULL_REQUEST_TEMPLATE.md",
+            "docs/pull_request_template.md",
+            "pull_request_template.md",
+        ] {
+            if repo.join(candidate).is_file() {
+                templates.push(TemplateInfo {
+                    name: "default".to_string(),
+                    path: candidate.to_string(),
+                });
+                break;
+            }
+        }
+    }
+
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+/// Read a pull request template's contents, optionally selecting one by name
+///
+/// With no name given, falls back to the single default template when there's exactly one.
+pub fn get_pr_template(repo_path: &str, template_name: Option<&str>) -> Option<String> {
+    let templates = list_pr_templates(repo_path);
+
+    let selected = match template_name {
+        Some(name) => templates.iter().find(|t| t.name == name),
+        None if templates.len() == 1 => templates.first(),
+        None => None,
+    }?;
+
+    std::fs::read_to_string(Path::new(repo_path).join(&selected.path)).ok()
 }
 
 /// Generate the full PR context for the prompt
-pub fn generate_pr_context(repo_path: &str, target_branch: &str) -> Result<PrContext, String> {
+pub fn generate_pr_context(
+    repo_path: &str,
+    target_branch: &str,
+    template_name: Option<&str>,
+) -> Result<PrContext, String> {
     Ok(PrContext {
         uncommitted_count: get_uncommitted_count(repo_path)?,
         current_branch: get_current_branch(repo_path)?,
         target_branch: target_branch.to_string(),
         has_upstream: has_upstream_branch(repo_path),
-        pr_template: get_pr_template(repo_path),
+        pr_template: get_pr_template(repo_path, template_name),
     })
 }
 
@@ -1319,6 +1590,206 @@ pub fn run_setup_script(
     Ok(combined)
 }
 
+/// Run a project's `pre_run`/`post_run` chat hook script (from jean.json) in a worktree.
+///
+/// Same execution model as `run_setup_script` (user's login shell, same `JEAN_*` env
+/// vars), but named generically since it's invoked around chat runs rather than
+/// worktree creation. `hook_name` is only used to label errors (e.g. "pre-run").
+/// `extra_env` carries the project/session-configured env vars from
+/// `chat::env_vars::resolve_env_vars`, applied after the `JEAN_*` vars so a user-configured
+/// key can override them if it collides.
+pub fn run_hook_script(
+    worktree_path: &str,
+    root_path: &str,
+    branch: &str,
+    hook_name: &str,
+    script: &str,
+    extra_env: &[(String, String)],
+) -> Result<String, String> {
+    log::trace!("Running {hook_name} hook in {worktree_path}: {script}");
+
+    let (shell, supports_login) = get_user_shell();
+
+    let mut cmd = silent_command(&shell);
+    if supports_login {
+        cmd.args(["-l", "-c", script]);
+    } else {
+        cmd.args(["-c", script]);
+    }
+
+    cmd.current_dir(worktree_path)
+        .env("JEAN_WORKSPACE_PATH", worktree_path)
+        .env("JEAN_ROOT_PATH", root_path)
+        .env("JEAN_BRANCH", branch);
+    for (key, value) in extra_env {
+        cmd.env(key, value);
+    }
+
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run {hook_name} hook: {e}"))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let combined = format!("{stdout}{stderr}").trim().to_string();
+
+    if !output.status.success() {
+        return Err(format!("{hook_name} hook failed:\n{combined}"));
+    }
+
+    log::trace!("{hook_name} hook completed successfully");
+    Ok(combined)
+}
+
+/// Namespace for refs that keep pre-run snapshot commits alive and out of the branch list.
+const SNAPSHOT_REF_PREFIX: &str = "refs/jean/snapshots";
+
+/// Record a rollback-able snapshot of `worktree_path`'s current state (HEAD + working tree +
+/// untracked files) and return the ref name it was stored under.
+///
+/// If the worktree is clean, the snapshot is just the current HEAD commit - nothing to stash.
+/// If it's dirty, this stages everything (`git add -A`), commits it, then resets the branch
+/// back to where it was with a mixed reset - the working tree is left exactly as it was, but
+/// the commit object survives, kept alive by the ref. `rollback_to_snapshot` can later `git
+/// reset --hard` to this ref to fully restore this state, including files the run added,
+/// modified, or deleted afterward.
+pub fn create_snapshot(worktree_path: &str, run_id: &str) -> Result<String, String> {
+    let snapshot_ref = format!("{SNAPSHOT_REF_PREFIX}/{run_id}");
+
+    if !has_uncommitted_changes(worktree_path) {
+        let head = silent_command("git")
+            .args(["rev-parse", "HEAD"])
+            .current_dir(worktree_path)
+            .output()
+            .map_err(|e| format!("Failed to read HEAD for snapshot: {e}"))?;
+        if !head.status.success() {
+            return Err(format!(
+                "Failed to read HEAD for snapshot: {}",
+                String::from_utf8_lossy(&head.stderr)
+            ));
+        }
+        let head_sha = String::from_utf8_lossy(&head.stdout).trim().to_string();
+        update_snapshot_ref(worktree_path, &snapshot_ref, &head_sha)?;
+        return Ok(snapshot_ref);
+    }
+
+    let add = silent_command("git")
+        .args(["add", "-A"])
+        .current_dir(worktree_path)
+        .output()
+        .map_err(|e| format!("Failed to stage changes for snapshot: {e}"))?;
+    if !add.status.success() {
+        return Err(format!(
+            "Failed to stage changes for snapshot: {}",
+            String::from_utf8_lossy(&add.stderr)
+        ));
+    }
+
+    let commit = silent_command("git")
+        .args([
+            "commit",
+            "--no-verify",
+            "-m",
+            &format!("Jean pre-run snapshot ({run_id})"),
+        ])
+        .current_dir(worktree_path)
+        .output()
+        .map_err(|e| format!("Failed to commit snapshot: {e}"))?;
+    if !commit.status.success() {
+        return Err(format!(
+            "Failed to commit snapshot: {}",
+            String::from_utf8_lossy(&commit.stderr)
+        ));
+    }
+
+    let head = silent_command("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(worktree_path)
+        .output()
+        .map_err(|e| format!("Failed to read snapshot commit: {e}"))?;
+    let snapshot_sha = String::from_utf8_lossy(&head.stdout).trim().to_string();
+
+    update_snapshot_ref(worktree_path, &snapshot_ref, &snapshot_sha)?;
+
+    // Move the branch back to before the snapshot commit; mixed reset (the default) leaves
+    // the working tree and index untouched, so the worktree looks exactly as it did before.
+    let reset = silent_command("git")
+        .args(["reset", "HEAD~1"])
+        .current_dir(worktree_path)
+        .output()
+        .map_err(|e| format!("Failed to undo snapshot commit from branch: {e}"))?;
+    if !reset.status.success() {
+        return Err(format!(
+            "Failed to undo snapshot commit from branch: {}",
+            String::from_utf8_lossy(&reset.stderr)
+        ));
+    }
+
+    Ok(snapshot_ref)
+}
+
+fn update_snapshot_ref(worktree_path: &str, snapshot_ref: &str, sha: &str) -> Result<(), String> {
+    let output = silent_command("git")
+        .args(["update-ref", snapshot_ref, sha])
+        .current_dir(worktree_path)
+        .output()
+        .map_err(|e| format!("Failed to create snapshot ref: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to create snapshot ref: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(())
+}
+
+/// Hard-reset `worktree_path` to a snapshot created by `create_snapshot`, fully undoing
+/// anything done to the worktree since - including uncommitted changes made after the
+/// snapshot. Destructive: any uncommitted work present right now is lost.
+pub fn rollback_to_snapshot(worktree_path: &str, snapshot_ref: &str) -> Result<(), String> {
+    let output = silent_command("git")
+        .args(["reset", "--hard", snapshot_ref])
+        .current_dir(worktree_path)
+        .output()
+        .map_err(|e| format!("Failed to roll back to snapshot: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to roll back to snapshot: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    // Clean up untracked files/directories left over from after the snapshot, so the
+    // rollback matches the snapshot exactly, not just its tracked content.
+    let _ = silent_command("git")
+        .args(["clean", "-fd"])
+        .current_dir(worktree_path)
+        .output();
+
+    Ok(())
+}
+
+/// Delete a run's snapshot ref, allowing its commit object to be garbage-collected by git.
+pub fn delete_snapshot_ref(worktree_path: &str, run_id: &str) -> Result<(), String> {
+    let snapshot_ref = format!("{SNAPSHOT_REF_PREFIX}/{run_id}");
+
+    let output = silent_command("git")
+        .args(["update-ref", "-d", &snapshot_ref])
+        .current_dir(worktree_path)
+        .output()
+        .map_err(|e| format!("Failed to delete snapshot ref: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to delete snapshot ref: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(())
+}
+
 /// Check if there are uncommitted changes (staged or unstaged)
 pub fn has_uncommitted_changes(repo_path: &str) -> bool {
     silent_command("git")
@@ -1335,6 +1806,17 @@ pub fn has_uncommitted_changes(repo_path: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// True if the index already has staged changes (see `commit_patch_hunks`, which refuses to
+/// run rather than fold these into a commit meant to hold only the selected hunks).
+fn has_staged_changes(repo_path: &str) -> bool {
+    silent_command("git")
+        .args(["diff", "--cached", "--name-only"])
+        .current_dir(repo_path)
+        .output()
+        .map(|o| o.status.success() && !String::from_utf8_lossy(&o.stdout).trim().is_empty())
+        .unwrap_or(false)
+}
+
 /// Rebase the current branch onto a base branch from origin
 ///
 /// This performs:
@@ -1909,6 +2391,7 @@ mod tests {
         let id = RepoIdentifier {
             owner: "heyandras".to_string(),
             repo: "jean".to_string(),
+            host: "github.com".to_string(),
         };
         assert_eq!(id.to_key(), "heyandras-jean");
     }
@@ -1918,7 +2401,50 @@ mod tests {
         let id = RepoIdentifier {
             owner: "my-org".to_string(),
             repo: "my-project".to_string(),
+            host: "github.com".to_string(),
         };
         assert_eq!(id.to_key(), "my-org-my-project");
     }
+
+    // ========================================================================
+    // parse_remote_url tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_remote_url_https() {
+        let (host, owner, repo) = parse_remote_url("https://github.com/owner/repo.git").unwrap();
+        assert_eq!(host, "github.com");
+        assert_eq!(owner, "owner");
+        assert_eq!(repo, "repo");
+    }
+
+    #[test]
+    fn test_parse_remote_url_scp_style() {
+        let (host, owner, repo) = parse_remote_url("git@github.com:owner/repo.git").unwrap();
+        assert_eq!(host, "github.com");
+        assert_eq!(owner, "owner");
+        assert_eq!(repo, "repo");
+    }
+
+    #[test]
+    fn test_parse_remote_url_ssh_scheme_with_port() {
+        let (host, owner, repo) =
+            parse_remote_url("ssh://git@github.mycompany.com:22/owner/repo.git").unwrap();
+        assert_eq!(host, "github.mycompany.com");
+        assert_eq!(owner, "owner");
+        assert_eq!(repo, "repo");
+    }
+
+    #[test]
+    fn test_parse_remote_url_owner_with_dots() {
+        let (host, owner, repo) = parse_remote_url("git@host.com:my.org/repo.name.git").unwrap();
+        assert_eq!(host, "host.com");
+        assert_eq!(owner, "my.org");
+        assert_eq!(repo, "repo.name");
+    }
+
+    #[test]
+    fn test_parse_remote_url_rejects_unrecognized_scheme() {
+        assert!(parse_remote_url("ftp://host/owner/repo").is_err());
+    }
 }
@@ -3,7 +3,10 @@ use std::path::PathBuf;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Manager};
 
+use super::commands::extract_structured_output;
 use super::git::get_repo_identifier;
+use super::storage::load_projects_data;
+use crate::claude_cli::get_cli_binary_path;
 use crate::gh_cli::config::resolve_gh_binary;
 use crate::platform::silent_command;
 
@@ -328,10 +331,7 @@ pub struct ContextReferences {
 
 /// Get the directory for shared GitHub contexts
 pub fn get_github_contexts_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
     Ok(app_data_dir.join("git-context"))
 }
 
@@ -539,7 +539,7 @@ pub fn remove_all_worktree_references(
 
 /// Parse a context key into (repo_owner, repo_name, number)
 /// Key format: "{owner}-{repo}-{number}"
-fn parse_context_key(key: &str) -> Option<(String, String, u32)> {
+pub(crate) fn parse_context_key(key: &str) -> Option<(String, String, u32)> {
     // Split from the right to get the number first
     let (repo_key, number_str) = key.rsplit_once('-')?;
     let number = number_str.parse::<u32>().ok()?;
@@ -550,6 +550,29 @@ fn parse_context_key(key: &str) -> Option<(String, String, u32)> {
     Some((owner.to_string(), repo.to_string(), number))
 }
 
+/// Check whether an issue has been closed on GitHub
+///
+/// Synchronous so it can be called from the background poller thread.
+pub(crate) fn is_issue_closed(
+    app: &tauri::AppHandle,
+    gh: &std::path::Path,
+    owner: &str,
+    repo: &str,
+    issue_number: u32,
+) -> Result<bool, String> {
+    use tauri::Manager;
+
+    let client = app.state::<crate::gh_cli::api_client::GhApiClient>();
+    let body = client.get_json(gh, &format!("repos/{owner}/{repo}/issues/{issue_number}"))?;
+
+    let state = body
+        .get("state")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "Issue response missing 'state' field".to_string())?;
+
+    Ok(state.eq_ignore_ascii_case("closed"))
+}
+
 /// Clean up orphaned context files older than retention_days
 /// Returns the number of files deleted
 pub fn cleanup_orphaned_contexts(
@@ -807,6 +830,38 @@ pub async fn remove_issue_context(
     Ok(())
 }
 
+/// Post a new comment on a GitHub issue
+#[tauri::command]
+pub async fn add_issue_comment(
+    app: tauri::AppHandle,
+    project_path: String,
+    issue_number: u32,
+    body: String,
+) -> Result<(), String> {
+    log::trace!("Adding comment to issue #{issue_number} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args([
+            "issue",
+            "comment",
+            &issue_number.to_string(),
+            "--body",
+            &body,
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh issue comment: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to add issue comment: {stderr}"));
+    }
+
+    log::trace!("Comment added to issue #{issue_number}");
+    Ok(())
+}
+
 // =============================================================================
 // GitHub Pull Request Types and Commands
 // =============================================================================
@@ -1035,6 +1090,97 @@ pub async fn get_github_pr(
     Ok(pr)
 }
 
+/// An open PR awaiting the current user's review, aggregated across all registered projects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrAwaitingReview {
+    pub project_id: String,
+    pub project_name: String,
+    pub number: u32,
+    pub title: String,
+    pub url: String,
+    pub author: GitHubAuthor,
+    pub is_draft: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReviewRequestedPr {
+    number: u32,
+    title: String,
+    url: String,
+    author: GitHubAuthor,
+    #[serde(rename = "isDraft")]
+    is_draft: bool,
+    #[serde(rename = "createdAt")]
+    created_at: String,
+}
+
+/// List open PRs across all registered projects where the current user is a requested reviewer
+///
+/// Projects that are folders, aren't git repositories, or fail to query (e.g. `gh` not
+/// authenticated for that host) are skipped rather than failing the whole aggregation.
+#[tauri::command]
+pub async fn list_prs_awaiting_my_review(app: AppHandle) -> Result<Vec<PrAwaitingReview>, String> {
+    log::trace!("Listing PRs awaiting review across all projects");
+
+    let data = load_projects_data(&app)?;
+    let gh = resolve_gh_binary(&app);
+
+    let mut awaiting = Vec::new();
+    for project in data.projects.iter().filter(|p| !p.is_folder) {
+        let output = match silent_command(&gh)
+            .args([
+                "pr",
+                "list",
+                "--search",
+                "is:open review-requested:@me",
+                "--json",
+                "number,title,url,author,isDraft,createdAt",
+            ])
+            .current_dir(&project.path)
+            .output()
+        {
+            Ok(output) => output,
+            Err(e) => {
+                log::warn!("Failed to run gh pr list for project {}: {e}", project.name);
+                continue;
+            }
+        };
+
+        if !output.status.success() {
+            log::warn!(
+                "gh pr list --search failed for project {}: {}",
+                project.name,
+                String::from_utf8_lossy(&output.stderr)
+            );
+            continue;
+        }
+
+        let prs: Vec<ReviewRequestedPr> = match serde_json::from_slice(&output.stdout) {
+            Ok(prs) => prs,
+            Err(e) => {
+                log::warn!("Failed to parse gh pr list output for project {}: {e}", project.name);
+                continue;
+            }
+        };
+
+        awaiting.extend(prs.into_iter().map(|pr| PrAwaitingReview {
+            project_id: project.id.clone(),
+            project_name: project.name.clone(),
+            number: pr.number,
+            title: pr.title,
+            url: pr.url,
+            author: pr.author,
+            is_draft: pr.is_draft,
+            created_at: pr.created_at,
+        }));
+    }
+
+    log::trace!("Found {} PRs awaiting review", awaiting.len());
+    Ok(awaiting)
+}
+
 /// Generate a branch name from a PR
 /// e.g., PR #123 "Fix the login bug" -> "pr-123-fix-the-login-bug"
 pub fn generate_branch_name_from_pr(pr_number: u32, title: &str) -> String {
@@ -1410,6 +1556,256 @@ pub async fn get_pr_context_content(
         .map_err(|e| format!("Failed to read PR context file: {e}"))
 }
 
+/// List issue templates available in a project's repository
+///
+/// Covers both legacy Markdown templates and the newer YAML issue forms under
+/// `.github/ISSUE_TEMPLATE/`. Pass the chosen template's `name` as `template_name` to
+/// `create_github_issue` to seed the body with it.
+#[tauri::command]
+pub async fn list_issue_templates(project_path: String) -> Result<Vec<super::git::TemplateInfo>, String> {
+    Ok(get_issue_templates(&project_path))
+}
+
+fn get_issue_templates(repo_path: &str) -> Vec<super::git::TemplateInfo> {
+    use super::git::TemplateInfo;
+    use std::path::Path;
+
+    let repo = Path::new(repo_path);
+    let Ok(entries) = std::fs::read_dir(repo.join(".github/ISSUE_TEMPLATE")) else {
+        return Vec::new();
+    };
+
+    let mut templates: Vec<TemplateInfo> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if !path.is_file() {
+                return None;
+            }
+            let ext = path.extension().and_then(|e| e.to_str())?;
+            if !matches!(ext, "md" | "yml" | "yaml") {
+                return None;
+            }
+            Some(TemplateInfo {
+                name: path.file_stem()?.to_str()?.to_string(),
+                path: path
+                    .strip_prefix(repo)
+                    .unwrap_or(&path)
+                    .to_string_lossy()
+                    .to_string(),
+            })
+        })
+        .collect();
+
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+/// Read an issue template's contents, optionally selecting one by name
+///
+/// With no name given, falls back to the sole template when there's exactly one.
+fn get_issue_template(repo_path: &str, template_name: Option<&str>) -> Option<String> {
+    let templates = get_issue_templates(repo_path);
+
+    let selected = match template_name {
+        Some(name) => templates.iter().find(|t| t.name == name),
+        None if templates.len() == 1 => templates.first(),
+        None => None,
+    }?;
+
+    std::fs::read_to_string(std::path::Path::new(repo_path).join(&selected.path)).ok()
+}
+
+/// Create a new GitHub issue
+#[tauri::command]
+pub async fn create_github_issue(
+    app: AppHandle,
+    project_path: String,
+    title: String,
+    body: Option<String>,
+    template_name: Option<String>,
+    labels: Option<Vec<String>>,
+    assignees: Option<Vec<String>>,
+) -> Result<u32, String> {
+    log::trace!("Creating GitHub issue '{title}' in {project_path}");
+
+    // Prefer an explicit body; otherwise seed from the chosen (or sole) issue template so the
+    // AI-generated content (or the user, editing afterward) has the repo's expected structure
+    // to fill in rather than a blank issue.
+    let body = body.or_else(|| get_issue_template(&project_path, template_name.as_deref()));
+
+    let gh = resolve_gh_binary(&app);
+    let mut args = vec!["issue".to_string(), "create".to_string(), "--title".to_string(), title];
+    args.push("--body".to_string());
+    args.push(body.unwrap_or_default());
+
+    if let Some(labels) = labels.filter(|l| !l.is_empty()) {
+        args.push("--label".to_string());
+        args.push(labels.join(","));
+    }
+    if let Some(assignees) = assignees.filter(|a| !a.is_empty()) {
+        args.push("--assignee".to_string());
+        args.push(assignees.join(","));
+    }
+
+    let output = silent_command(&gh)
+        .args(&args)
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh issue create: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to create issue: {stderr}"));
+    }
+
+    let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    url.split('/')
+        .next_back()
+        .and_then(|s| s.parse::<u32>().ok())
+        .ok_or_else(|| format!("Failed to parse issue number from: {url}"))
+}
+
+/// Update an existing GitHub issue's title/body/state
+#[tauri::command]
+pub async fn update_github_issue(
+    app: AppHandle,
+    project_path: String,
+    issue_number: u32,
+    title: Option<String>,
+    body: Option<String>,
+    close: Option<bool>,
+) -> Result<(), String> {
+    log::trace!("Updating GitHub issue #{issue_number} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+
+    if title.is_some() || body.is_some() {
+        let mut args = vec!["issue".to_string(), "edit".to_string(), issue_number.to_string()];
+        if let Some(title) = title {
+            args.push("--title".to_string());
+            args.push(title);
+        }
+        if let Some(body) = body {
+            args.push("--body".to_string());
+            args.push(body);
+        }
+
+        let output = silent_command(&gh)
+            .args(&args)
+            .current_dir(&project_path)
+            .output()
+            .map_err(|e| format!("Failed to run gh issue edit: {e}"))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to update issue: {stderr}"));
+        }
+    }
+
+    if let Some(close) = close {
+        let sub_command = if close { "close" } else { "reopen" };
+        let output = silent_command(&gh)
+            .args([sub_command, &issue_number.to_string()])
+            .arg("--comment")
+            .arg("")
+            .current_dir(&project_path)
+            .output();
+        // Closing/reopening failures shouldn't mask a successful title/body edit above,
+        // but still surface them.
+        if let Ok(output) = output {
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                if !stderr.contains("already closed") && !stderr.contains("already open") {
+                    return Err(format!("Failed to {sub_command} issue: {stderr}"));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// AI-drafted issue content, generated from a chat session transcript or diff
+#[derive(Debug, Deserialize, Serialize)]
+struct IssueContentResponse {
+    title: String,
+    body: String,
+}
+
+const ISSUE_CONTENT_SCHEMA: &str = r#"{"type":"object","properties":{"title":{"type":"string","description":"A concise issue title under 72 characters"},"body":{"type":"string","description":"Issue body in markdown, describing the problem/request, context, and (if applicable) reproduction steps or acceptance criteria"}},"required":["title","body"]}"#;
+
+/// Draft an issue title/body from freeform context (a chat transcript excerpt, a diff, or
+/// notes) using the Claude CLI, for the user to review before calling `create_github_issue`.
+#[tauri::command]
+pub async fn draft_github_issue_with_ai(
+    app: AppHandle,
+    context: String,
+    model: Option<String>,
+) -> Result<IssueContentResponse, String> {
+    log::trace!("Drafting GitHub issue content from context via Claude CLI");
+
+    let cli_path = get_cli_binary_path(&app)?;
+    if !cli_path.exists() {
+        return Err("Claude CLI not installed".to_string());
+    }
+
+    let prompt = format!(
+        "Based on the following context, draft a GitHub issue. Context:\n\n{context}"
+    );
+
+    let mut cmd = silent_command(&cli_path);
+    cmd.args([
+        "--print",
+        "--verbose",
+        "--input-format",
+        "stream-json",
+        "--output-format",
+        "stream-json",
+        "--model",
+        model.as_deref().unwrap_or("haiku"),
+        "--no-session-persistence",
+        "--tools",
+        "",
+        "--max-turns",
+        "1",
+        "--json-schema",
+        ISSUE_CONTENT_SCHEMA,
+    ]);
+    cmd.stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped());
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to spawn Claude CLI: {e}"))?;
+
+    {
+        use std::io::Write;
+        let stdin = child.stdin.as_mut().ok_or("Failed to open stdin")?;
+        let input_message = serde_json::json!({
+            "type": "user",
+            "message": { "role": "user", "content": prompt }
+        });
+        writeln!(stdin, "{input_message}").map_err(|e| format!("Failed to write to stdin: {e}"))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to wait for Claude CLI: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Claude CLI failed: {stderr}"));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json_content = extract_structured_output(&stdout)?;
+
+    serde_json::from_str(&json_content)
+        .map_err(|e| format!("Failed to parse issue content: {e}"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,203 @@
+//! Label and milestone management for GitHub issues and pull requests
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::gh_cli::config::resolve_gh_binary;
+use crate::platform::silent_command;
+
+/// A label available in the repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoLabel {
+    pub name: String,
+    pub color: String,
+    pub description: Option<String>,
+}
+
+/// A milestone available in the repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Milestone {
+    pub number: u32,
+    pub title: String,
+    pub state: String,
+    pub due_on: Option<String>,
+}
+
+/// List all labels defined on the repository
+#[tauri::command]
+pub async fn list_labels(app: AppHandle, project_path: String) -> Result<Vec<RepoLabel>, String> {
+    log::trace!("Listing labels for {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args(["label", "list", "--json", "name,color,description", "-L", "100"])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh label list: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to list labels: {stderr}"));
+    }
+
+    let labels: Vec<RepoLabel> = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse gh label list output: {e}"))?;
+    Ok(labels)
+}
+
+/// Replace the full set of labels on an issue
+#[tauri::command]
+pub async fn set_issue_labels(
+    app: AppHandle,
+    project_path: String,
+    issue_number: u32,
+    labels: Vec<String>,
+) -> Result<(), String> {
+    set_entity_labels(&app, &project_path, "issue", issue_number, labels).await
+}
+
+/// Replace the full set of labels on a pull request
+#[tauri::command]
+pub async fn set_pr_labels(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+    labels: Vec<String>,
+) -> Result<(), String> {
+    set_entity_labels(&app, &project_path, "pr", pr_number, labels).await
+}
+
+async fn set_entity_labels(
+    app: &AppHandle,
+    project_path: &str,
+    entity: &str,
+    number: u32,
+    labels: Vec<String>,
+) -> Result<(), String> {
+    log::trace!("Setting labels {labels:?} on {entity} #{number} in {project_path}");
+
+    let gh = resolve_gh_binary(app);
+
+    // `gh issue/pr edit` only adds/removes labels, so diff against the current set first.
+    let current_output = silent_command(&gh)
+        .args([entity, "view", &number.to_string(), "--json", "labels"])
+        .current_dir(project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh {entity} view: {e}"))?;
+
+    if !current_output.status.success() {
+        let stderr = String::from_utf8_lossy(&current_output.stderr);
+        return Err(format!("Failed to look up current labels: {stderr}"));
+    }
+
+    #[derive(Deserialize)]
+    struct LabelsWrapper {
+        labels: Vec<LabelName>,
+    }
+    #[derive(Deserialize)]
+    struct LabelName {
+        name: String,
+    }
+    let current: LabelsWrapper = serde_json::from_slice(&current_output.stdout)
+        .map_err(|e| format!("Failed to parse current labels: {e}"))?;
+    let current_names: Vec<String> = current.labels.into_iter().map(|l| l.name).collect();
+
+    let to_add: Vec<&String> = labels.iter().filter(|l| !current_names.contains(l)).collect();
+    let to_remove: Vec<&String> = current_names.iter().filter(|l| !labels.contains(l)).collect();
+
+    if to_add.is_empty() && to_remove.is_empty() {
+        return Ok(());
+    }
+
+    let mut args = vec![entity.to_string(), "edit".to_string(), number.to_string()];
+    if !to_add.is_empty() {
+        args.push("--add-label".to_string());
+        args.push(to_add.into_iter().cloned().collect::<Vec<_>>().join(","));
+    }
+    if !to_remove.is_empty() {
+        args.push("--remove-label".to_string());
+        args.push(to_remove.into_iter().cloned().collect::<Vec<_>>().join(","));
+    }
+
+    let output = silent_command(&gh)
+        .args(&args)
+        .current_dir(project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh {entity} edit: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to update labels: {stderr}"));
+    }
+
+    Ok(())
+}
+
+/// List milestones defined on the repository
+#[tauri::command]
+pub async fn list_milestones(app: AppHandle, project_path: String) -> Result<Vec<Milestone>, String> {
+    log::trace!("Listing milestones for {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args([
+            "api",
+            "repos/{owner}/{repo}/milestones",
+            "--jq",
+            ".[] | {number, title, state, due_on}",
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh api milestones: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to list milestones: {stderr}"));
+    }
+
+    // `--jq` streams one JSON object per line rather than a JSON array.
+    let milestones = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str::<Milestone>(line)
+                .map_err(|e| format!("Failed to parse milestone: {e}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(milestones)
+}
+
+/// Set (or clear, with `None`) the milestone on an issue or pull request
+#[tauri::command]
+pub async fn set_milestone(
+    app: AppHandle,
+    project_path: String,
+    entity: String,
+    number: u32,
+    milestone_title: Option<String>,
+) -> Result<(), String> {
+    log::trace!("Setting milestone={milestone_title:?} on {entity} #{number} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let milestone_value = milestone_title.unwrap_or_default();
+    let output = silent_command(&gh)
+        .args([
+            &entity,
+            "edit",
+            &number.to_string(),
+            "--milestone",
+            &milestone_value,
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh {entity} edit: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to set milestone: {stderr}"));
+    }
+
+    Ok(())
+}
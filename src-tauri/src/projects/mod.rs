@@ -1,14 +1,29 @@
+pub mod branch_protection;
 mod commands;
+pub mod dependency_prs;
+pub mod env_files;
 pub mod git;
 pub mod git_status;
 pub mod github_issues;
+pub mod labels;
 mod names;
+pub mod pr_reviews;
 pub mod pr_status;
+pub mod provider;
+pub mod repo_map;
 pub mod saved_contexts;
 pub mod storage;
 pub mod types;
+pub mod workflow_runs;
 
 // Re-export commands for registration in lib.rs
+pub use branch_protection::*;
 pub use commands::*;
+pub use dependency_prs::*;
+pub use env_files::*;
 pub use github_issues::*;
+pub use labels::*;
+pub use pr_reviews::*;
+pub use repo_map::*;
 pub use saved_contexts::*;
+pub use workflow_runs::*;
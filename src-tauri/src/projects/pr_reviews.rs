@@ -0,0 +1,728 @@
+//! PR review thread fetching via the GitHub GraphQL API (through `gh api graphql`)
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use super::git::get_repo_identifier;
+use crate::gh_cli::config::resolve_gh_binary;
+use crate::platform::silent_command;
+
+/// A single comment within a review thread
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewComment {
+    pub author: String,
+    pub body: String,
+    pub created_at: String,
+}
+
+/// A review thread anchored to a file/line, as shown in the PR "Files changed" view
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewThread {
+    pub id: String,
+    pub path: String,
+    pub line: Option<u32>,
+    pub is_resolved: bool,
+    pub is_outdated: bool,
+    pub comments: Vec<ReviewComment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse {
+    data: Option<GraphQlData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlData {
+    repository: GraphQlRepository,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlRepository {
+    #[serde(rename = "pullRequest")]
+    pull_request: GraphQlPullRequest,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlPullRequest {
+    #[serde(rename = "reviewThreads")]
+    review_threads: GraphQlThreadConnection,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlThreadConnection {
+    nodes: Vec<GraphQlThread>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlThread {
+    id: String,
+    path: String,
+    line: Option<u32>,
+    #[serde(rename = "isResolved")]
+    is_resolved: bool,
+    #[serde(rename = "isOutdated")]
+    is_outdated: bool,
+    comments: GraphQlCommentConnection,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlCommentConnection {
+    nodes: Vec<GraphQlComment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlComment {
+    body: String,
+    author: Option<GraphQlAuthor>,
+    #[serde(rename = "createdAt")]
+    created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlAuthor {
+    login: String,
+}
+
+const REVIEW_THREADS_QUERY: &str = r#"
+query($owner: String!, $repo: String!, $number: Int!) {
+  repository(owner: $owner, name: $repo) {
+    pullRequest(number: $number) {
+      reviewThreads(first: 100) {
+        nodes {
+          id
+          path
+          line
+          isResolved
+          isOutdated
+          comments(first: 50) {
+            nodes {
+              body
+              author { login }
+              createdAt
+            }
+          }
+        }
+      }
+    }
+  }
+}
+"#;
+
+/// Fetch review threads (file/line anchored comment chains) for a pull request
+#[tauri::command]
+pub async fn get_pr_review_comments(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+) -> Result<Vec<ReviewThread>, String> {
+    log::trace!("Fetching PR review threads for #{pr_number} in {project_path}");
+
+    let repo = get_repo_identifier(&project_path)?;
+    let gh = resolve_gh_binary(&app);
+
+    let output = silent_command(&gh)
+        .args([
+            "api",
+            "graphql",
+            "-f",
+            &format!("query={REVIEW_THREADS_QUERY}"),
+            "-f",
+            &format!("owner={}", repo.owner),
+            "-f",
+            &format!("repo={}", repo.repo),
+            "-F",
+            &format!("number={pr_number}"),
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh api graphql: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to fetch review threads: {stderr}"));
+    }
+
+    let parsed: GraphQlResponse = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse review threads response: {e}"))?;
+
+    let nodes = parsed
+        .data
+        .map(|d| d.repository.pull_request.review_threads.nodes)
+        .unwrap_or_default();
+
+    let threads = nodes
+        .into_iter()
+        .map(|t| ReviewThread {
+            id: t.id,
+            path: t.path,
+            line: t.line,
+            is_resolved: t.is_resolved,
+            is_outdated: t.is_outdated,
+            comments: t
+                .comments
+                .nodes
+                .into_iter()
+                .map(|c| ReviewComment {
+                    author: c.author.map(|a| a.login).unwrap_or_default(),
+                    body: c.body,
+                    created_at: c.created_at,
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+
+    log::trace!("Fetched {} review threads for #{pr_number}", threads.len());
+    Ok(threads)
+}
+
+/// Count unresolved review threads for a pull request, used to enrich `PrStatus`
+pub fn count_unresolved_threads(threads: &[ReviewThread]) -> u32 {
+    threads.iter().filter(|t| !t.is_resolved).count() as u32
+}
+
+/// A single inline comment to attach to a review, anchored to a file/line in the diff
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlineReviewComment {
+    pub path: String,
+    pub line: u32,
+    pub body: String,
+}
+
+/// The verdict of a submitted review, mirroring `gh pr review`'s event flags
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewEvent {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+impl ReviewEvent {
+    fn as_gh_flag(&self) -> &'static str {
+        match self {
+            ReviewEvent::Approve => "--approve",
+            ReviewEvent::RequestChanges => "--request-changes",
+            ReviewEvent::Comment => "--comment",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GhPrHeadView {
+    #[serde(rename = "headRefOid")]
+    head_ref_oid: String,
+}
+
+/// Look up the SHA of a PR's current head commit, required by the REST inline-comment API
+fn get_pr_head_sha(
+    gh: &std::path::Path,
+    project_path: &str,
+    pr_number: u32,
+) -> Result<String, String> {
+    let output = silent_command(gh)
+        .args([
+            "pr",
+            "view",
+            &pr_number.to_string(),
+            "--json",
+            "headRefOid",
+        ])
+        .current_dir(project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr view: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to look up PR head commit: {stderr}"));
+    }
+
+    let parsed: GhPrHeadView = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse gh pr view output: {e}"))?;
+    Ok(parsed.head_ref_oid)
+}
+
+/// Submit a full PR review (approve / request changes / comment), optionally with
+/// file/line inline comments.
+///
+/// `gh pr review` doesn't support inline comments directly, so when any are provided
+/// they're posted individually via the GraphQL review-comment API before the overall
+/// review verdict is submitted with `gh pr review`.
+#[tauri::command]
+pub async fn submit_pr_review(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+    event: ReviewEvent,
+    body: Option<String>,
+    inline_comments: Option<Vec<InlineReviewComment>>,
+) -> Result<(), String> {
+    log::trace!("Submitting review for PR #{pr_number} in {project_path}: {event:?}");
+
+    let gh = resolve_gh_binary(&app);
+    let inline_comments = inline_comments.unwrap_or_default();
+
+    if !inline_comments.is_empty() {
+        let head_sha = get_pr_head_sha(&gh, &project_path, pr_number)?;
+
+        for comment in inline_comments {
+            let output = silent_command(&gh)
+                .args([
+                    "api",
+                    &format!("repos/{{owner}}/{{repo}}/pulls/{pr_number}/comments"),
+                    "-f",
+                    &format!("body={}", comment.body),
+                    "-f",
+                    &format!("path={}", comment.path),
+                    "-F",
+                    &format!("line={}", comment.line),
+                    "-f",
+                    "side=RIGHT",
+                    "-f",
+                    &format!("commit_id={head_sha}"),
+                ])
+                .current_dir(&project_path)
+                .output()
+                .map_err(|e| format!("Failed to post inline comment: {e}"))?;
+
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(format!(
+                    "Failed to post inline comment on {}:{}: {stderr}",
+                    comment.path, comment.line
+                ));
+            }
+        }
+    }
+
+    let mut args = vec![
+        "pr".to_string(),
+        "review".to_string(),
+        pr_number.to_string(),
+        event.as_gh_flag().to_string(),
+    ];
+    if let Some(body) = body.filter(|b| !b.trim().is_empty()) {
+        args.push("--body".to_string());
+        args.push(body);
+    }
+
+    let output = silent_command(&gh)
+        .args(&args)
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr review: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to submit review: {stderr}"));
+    }
+
+    log::trace!("Submitted review for PR #{pr_number}");
+    Ok(())
+}
+
+/// Add reviewers to a pull request (users and/or teams)
+#[tauri::command]
+pub async fn request_pr_reviewers(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+    reviewers: Vec<String>,
+) -> Result<(), String> {
+    log::trace!("Requesting reviewers {reviewers:?} on PR #{pr_number} in {project_path}");
+
+    if reviewers.is_empty() {
+        return Ok(());
+    }
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args([
+            "pr",
+            "edit",
+            &pr_number.to_string(),
+            "--add-reviewer",
+            &reviewers.join(","),
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr edit: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to request reviewers: {stderr}"));
+    }
+
+    Ok(())
+}
+
+/// Remove reviewers from a pull request
+#[tauri::command]
+pub async fn remove_pr_reviewers(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+    reviewers: Vec<String>,
+) -> Result<(), String> {
+    log::trace!("Removing reviewers {reviewers:?} from PR #{pr_number} in {project_path}");
+
+    if reviewers.is_empty() {
+        return Ok(());
+    }
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args([
+            "pr",
+            "edit",
+            &pr_number.to_string(),
+            "--remove-reviewer",
+            &reviewers.join(","),
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr edit: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to remove reviewers: {stderr}"));
+    }
+
+    Ok(())
+}
+
+/// Replace the set of assignees on a pull request
+#[tauri::command]
+pub async fn set_pr_assignees(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+    assignees: Vec<String>,
+) -> Result<(), String> {
+    log::trace!("Setting assignees {assignees:?} on PR #{pr_number} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let assignee_arg = if assignees.is_empty() {
+        "".to_string()
+    } else {
+        assignees.join(",")
+    };
+    let output = silent_command(&gh)
+        .args([
+            "pr",
+            "edit",
+            &pr_number.to_string(),
+            "--add-assignee",
+            &assignee_arg,
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr edit: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to set assignees: {stderr}"));
+    }
+
+    Ok(())
+}
+
+/// Suggest reviewers for a set of changed files by matching `CODEOWNERS` patterns.
+///
+/// Looks for CODEOWNERS in the conventional locations (repo root, `.github/`, `docs/`) and
+/// returns the owners of the first matching pattern for each path, most-specific-last as
+/// CODEOWNERS itself specifies (later matches win).
+#[tauri::command]
+pub async fn suggest_pr_reviewers(
+    project_path: String,
+    changed_files: Vec<String>,
+) -> Result<Vec<String>, String> {
+    log::trace!("Suggesting reviewers for {} changed files", changed_files.len());
+
+    let codeowners_path = ["CODEOWNERS", ".github/CODEOWNERS", "docs/CODEOWNERS"]
+        .iter()
+        .map(|p| std::path::Path::new(&project_path).join(p))
+        .find(|p| p.exists());
+
+    let Some(codeowners_path) = codeowners_path else {
+        return Ok(Vec::new());
+    };
+
+    let contents = std::fs::read_to_string(&codeowners_path)
+        .map_err(|e| format!("Failed to read CODEOWNERS: {e}"))?;
+
+    let rules: Vec<(String, Vec<String>)> = contents
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let pattern = parts.next()?.to_string();
+            let owners: Vec<String> = parts.map(|s| s.trim_start_matches('@').to_string()).collect();
+            Some((pattern, owners))
+        })
+        .collect();
+
+    let mut suggested = std::collections::BTreeSet::new();
+    for file in &changed_files {
+        let mut matched_owners: Option<&Vec<String>> = None;
+        for (pattern, owners) in &rules {
+            if codeowners_pattern_matches(pattern, file) {
+                matched_owners = Some(owners);
+            }
+        }
+        if let Some(owners) = matched_owners {
+            suggested.extend(owners.iter().cloned());
+        }
+    }
+
+    Ok(suggested.into_iter().collect())
+}
+
+/// Minimal CODEOWNERS glob matcher: supports `*` as a path-segment wildcard and directory
+/// prefixes ending in `/`. Not a full gitignore-style matcher, but covers the common cases.
+fn codeowners_pattern_matches(pattern: &str, file: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+    let pattern = pattern.trim_start_matches('/');
+    if let Some(dir) = pattern.strip_suffix('/') {
+        return file.starts_with(dir);
+    }
+    if pattern.contains('*') {
+        let prefix = pattern.split('*').next().unwrap_or("");
+        return file.starts_with(prefix);
+    }
+    file == pattern || file.starts_with(&format!("{pattern}/"))
+}
+
+/// Link a pull request to the issue it resolves by appending a closing keyword to its body,
+/// so merging the PR automatically closes the issue on GitHub's side.
+#[tauri::command]
+pub async fn link_pr_to_issue(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+    issue_number: u32,
+) -> Result<(), String> {
+    log::trace!("Linking PR #{pr_number} to issue #{issue_number} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+
+    let view_output = silent_command(&gh)
+        .args(["pr", "view", &pr_number.to_string(), "--json", "body"])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr view: {e}"))?;
+
+    if !view_output.status.success() {
+        let stderr = String::from_utf8_lossy(&view_output.stderr);
+        return Err(format!("Failed to look up PR body: {stderr}"));
+    }
+
+    #[derive(Deserialize)]
+    struct PrBody {
+        body: String,
+    }
+    let current: PrBody = serde_json::from_slice(&view_output.stdout)
+        .map_err(|e| format!("Failed to parse gh pr view output: {e}"))?;
+
+    let closes_line = format!("Closes #{issue_number}");
+    if current.body.contains(&closes_line) {
+        return Ok(());
+    }
+
+    let new_body = if current.body.trim().is_empty() {
+        closes_line
+    } else {
+        format!("{}\n\n{closes_line}", current.body.trim_end())
+    };
+
+    let edit_output = silent_command(&gh)
+        .args(["pr", "edit", &pr_number.to_string(), "--body", &new_body])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr edit: {e}"))?;
+
+    if !edit_output.status.success() {
+        let stderr = String::from_utf8_lossy(&edit_output.stderr);
+        return Err(format!("Failed to link PR to issue: {stderr}"));
+    }
+
+    log::trace!("Linked PR #{pr_number} to issue #{issue_number}");
+    Ok(())
+}
+
+/// Enable or disable auto-merge on a pull request
+#[tauri::command]
+pub async fn set_pr_auto_merge(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+    merge_method: String,
+    enabled: bool,
+) -> Result<(), String> {
+    log::trace!(
+        "Setting auto-merge={enabled} (method={merge_method}) on PR #{pr_number} in {project_path}"
+    );
+
+    let gh = resolve_gh_binary(&app);
+    let args: Vec<String> = if enabled {
+        let method_flag = match merge_method.as_str() {
+            "squash" => "--squash",
+            "rebase" => "--rebase",
+            _ => "--merge",
+        };
+        vec![
+            "pr".to_string(),
+            "merge".to_string(),
+            pr_number.to_string(),
+            "--auto".to_string(),
+            method_flag.to_string(),
+        ]
+    } else {
+        vec![
+            "pr".to_string(),
+            "merge".to_string(),
+            pr_number.to_string(),
+            "--disable-auto".to_string(),
+        ]
+    };
+
+    let output = silent_command(&gh)
+        .args(&args)
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr merge: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to update auto-merge: {stderr}"));
+    }
+
+    Ok(())
+}
+
+/// Toggle a pull request between draft and ready-for-review
+#[tauri::command]
+pub async fn set_pr_ready(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+    ready: bool,
+) -> Result<(), String> {
+    log::trace!("Setting PR #{pr_number} ready={ready} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let args = if ready {
+        vec!["pr", "ready", &pr_number.to_string()]
+    } else {
+        vec!["pr", "ready", &pr_number.to_string(), "--undo"]
+    };
+
+    let output = silent_command(&gh)
+        .args(&args)
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr ready: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to update PR draft state: {stderr}"));
+    }
+
+    Ok(())
+}
+
+/// Post a top-level (non-inline) comment on a pull request
+#[tauri::command]
+pub async fn add_pr_comment(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+    body: String,
+) -> Result<(), String> {
+    log::trace!("Adding comment to PR #{pr_number} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args(["pr", "comment", &pr_number.to_string(), "--body", &body])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr comment: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to add PR comment: {stderr}"));
+    }
+
+    log::trace!("Comment added to PR #{pr_number}");
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct AddReplyResponse {
+    data: Option<AddReplyData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AddReplyData {
+    #[serde(rename = "addPullRequestReviewThreadReply")]
+    add_pull_request_review_thread_reply: Option<serde_json::Value>,
+}
+
+const REPLY_TO_THREAD_MUTATION: &str = r#"
+mutation($threadId: ID!, $body: String!) {
+  addPullRequestReviewThreadReply(input: {pullRequestReviewThreadId: $threadId, body: $body}) {
+    comment { id }
+  }
+}
+"#;
+
+/// Reply inline to an existing review thread (as returned by `get_pr_review_comments`)
+#[tauri::command]
+pub async fn reply_to_review_thread(
+    app: AppHandle,
+    project_path: String,
+    thread_id: String,
+    body: String,
+) -> Result<(), String> {
+    log::trace!("Replying to review thread {thread_id} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args([
+            "api",
+            "graphql",
+            "-f",
+            &format!("query={REPLY_TO_THREAD_MUTATION}"),
+            "-f",
+            &format!("threadId={thread_id}"),
+            "-f",
+            &format!("body={body}"),
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh api graphql: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to reply to review thread: {stderr}"));
+    }
+
+    let parsed: AddReplyResponse = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse reply response: {e}"))?;
+
+    if parsed
+        .data
+        .and_then(|d| d.add_pull_request_review_thread_reply)
+        .is_none()
+    {
+        return Err("GitHub did not confirm the reply".to_string());
+    }
+
+    log::trace!("Replied to review thread {thread_id}");
+    Ok(())
+}
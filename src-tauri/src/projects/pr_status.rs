@@ -1,7 +1,9 @@
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::gh_cli::config::resolve_gh_binary;
 use crate::platform::silent_command;
 use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
 
 /// PR state from GitHub API
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -81,6 +83,9 @@ pub struct PrStatus {
     pub display_status: PrDisplayStatus,
     pub mergeable: Option<MergeableStatus>,
     pub checked_at: u64,
+    /// Number of unresolved review threads, fetched separately via `get_pr_review_comments`
+    #[serde(default)]
+    pub unresolved_thread_count: Option<u32>,
 }
 
 /// Fetch PR status using gh CLI
@@ -151,9 +156,154 @@ pub fn get_pr_status(
         display_status,
         mergeable,
         checked_at,
+        unresolved_thread_count: None,
     })
 }
 
+/// Fetch status for several pull requests in the same repository with a single GraphQL call
+///
+/// `get_pr_status` above shells `gh pr view` once per PR; polling a project with many open
+/// worktree PRs means one `gh` process per PR on every remote-poll tick. This batches all of
+/// them into one `gh api graphql` call using a numbered alias per PR (`pr0`, `pr1`, ...), at the
+/// cost of requiring all PRs to live in the same `owner/repo` — callers with worktrees spanning
+/// multiple projects must group by repository first and call this once per group.
+pub fn get_pr_statuses_batch(
+    repo_owner: &str,
+    repo_name: &str,
+    prs: &[(String, u32, String)],
+    gh_binary: &std::path::Path,
+) -> Result<Vec<PrStatus>, String> {
+    if prs.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    log::trace!(
+        "Fetching batched PR status for {} PRs in {repo_owner}/{repo_name}",
+        prs.len()
+    );
+
+    let fields: Vec<String> = prs
+        .iter()
+        .enumerate()
+        .map(|(i, (_, pr_number, _))| {
+            format!(
+                "pr{i}: pullRequest(number: {pr_number}) {{ \
+                    state isDraft reviewDecision mergeable \
+                    commits(last: 1) {{ nodes {{ commit {{ statusCheckRollup {{ state }} }} }} }} \
+                }}"
+            )
+        })
+        .collect();
+
+    let query = format!(
+        "query {{ repository(owner: \"{repo_owner}\", name: \"{repo_name}\") {{ {} }} }}",
+        fields.join(" ")
+    );
+
+    let output = silent_command(gh_binary)
+        .args(["api", "graphql", "-f", &format!("query={query}")])
+        .output()
+        .map_err(|e| format!("Failed to run gh api graphql: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("gh api graphql failed: {stderr}"));
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct GqlResponse {
+        data: GqlData,
+    }
+    #[derive(Debug, Deserialize)]
+    struct GqlData {
+        repository: serde_json::Value,
+    }
+    #[derive(Debug, Deserialize)]
+    struct GqlRollup {
+        state: String,
+    }
+    #[derive(Debug, Deserialize)]
+    struct GqlCommit {
+        #[serde(rename = "statusCheckRollup")]
+        status_check_rollup: Option<GqlRollup>,
+    }
+    #[derive(Debug, Deserialize)]
+    struct GqlCommitNode {
+        commit: GqlCommit,
+    }
+    #[derive(Debug, Deserialize)]
+    struct GqlCommits {
+        nodes: Vec<GqlCommitNode>,
+    }
+    #[derive(Debug, Deserialize)]
+    struct GqlPr {
+        state: String,
+        #[serde(rename = "isDraft")]
+        is_draft: bool,
+        #[serde(rename = "reviewDecision")]
+        review_decision: Option<String>,
+        mergeable: Option<String>,
+        commits: GqlCommits,
+    }
+
+    let parsed: GqlResponse = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse gh api graphql response: {e}"))?;
+
+    let checked_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let mut statuses = Vec::with_capacity(prs.len());
+    for (i, (worktree_id, pr_number, pr_url)) in prs.iter().enumerate() {
+        let Some(raw) = parsed.data.repository.get(format!("pr{i}")) else {
+            log::warn!("No GraphQL result for PR #{pr_number} (alias pr{i})");
+            continue;
+        };
+        let gql: GqlPr = serde_json::from_value(raw.clone())
+            .map_err(|e| format!("Failed to parse PR #{pr_number} from GraphQL response: {e}"))?;
+
+        let state = parse_pr_state(&gql.state);
+        let review_decision = gql
+            .review_decision
+            .as_deref()
+            .and_then(parse_review_decision);
+        let check_status = gql
+            .commits
+            .nodes
+            .first()
+            .and_then(|n| n.commit.status_check_rollup.as_ref())
+            .map(|rollup| parse_check_rollup_state(&rollup.state));
+        let mergeable = gql.mergeable.as_deref().and_then(parse_mergeable_status);
+        let display_status = compute_display_status(&state, gql.is_draft, &review_decision);
+
+        statuses.push(PrStatus {
+            worktree_id: worktree_id.clone(),
+            pr_number: *pr_number,
+            pr_url: pr_url.clone(),
+            state,
+            is_draft: gql.is_draft,
+            review_decision,
+            check_status,
+            display_status,
+            mergeable,
+            checked_at,
+            unresolved_thread_count: None,
+        });
+    }
+
+    Ok(statuses)
+}
+
+fn parse_check_rollup_state(s: &str) -> CheckStatus {
+    match s.to_uppercase().as_str() {
+        "SUCCESS" => CheckStatus::Success,
+        "FAILURE" => CheckStatus::Failure,
+        "ERROR" => CheckStatus::Error,
+        _ => CheckStatus::Pending,
+    }
+}
+
 fn parse_pr_state(s: &str) -> PrState {
     match s.to_uppercase().as_str() {
         "MERGED" => PrState::Merged,
@@ -236,6 +386,187 @@ fn compute_display_status(
     }
 }
 
+/// A single CI check run, as shown in a PR's "Checks" tab
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrCheckRun {
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub details_url: Option<String>,
+    #[serde(default)]
+    pub is_required: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhChecksResponse {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    #[serde(rename = "startedAt")]
+    started_at: Option<String>,
+    #[serde(default)]
+    #[serde(rename = "completedAt")]
+    completed_at: Option<String>,
+    #[serde(default)]
+    link: Option<String>,
+    #[serde(default)]
+    bucket: String,
+    #[serde(default, rename = "isRequired")]
+    is_required: bool,
+}
+
+/// Fetch a detailed breakdown of every CI check run for a pull request
+#[tauri::command]
+pub async fn get_pr_checks(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+) -> Result<Vec<PrCheckRun>, String> {
+    log::trace!("Fetching check runs for PR #{pr_number} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args(["pr", "checks", &pr_number.to_string(), "--json", "name,state,startedAt,completedAt,link,bucket,isRequired"])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr checks: {e}"))?;
+
+    // `gh pr checks` exits non-zero when any check has failed, even though the
+    // JSON output is still valid - only treat it as an error if stdout is empty.
+    if output.stdout.is_empty() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to fetch PR checks: {stderr}"));
+    }
+
+    let raw: Vec<GhChecksResponse> = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse gh pr checks output: {e}"))?;
+
+    let checks = raw
+        .into_iter()
+        .map(|c| PrCheckRun {
+            name: c.name,
+            status: if c.completed_at.is_some() {
+                "completed".to_string()
+            } else {
+                "in_progress".to_string()
+            },
+            conclusion: Some(c.bucket).filter(|b| !b.is_empty()).or(Some(c.state)),
+            started_at: c.started_at,
+            completed_at: c.completed_at,
+            details_url: c.link,
+            is_required: c.is_required,
+        })
+        .collect();
+
+    Ok(checks)
+}
+
+/// Fetch the unified diff for a pull request without checking it out
+///
+/// Useful for reviewing (or feeding to `run_review_with_ai`) a PR that doesn't have a local
+/// worktree - `gh pr diff` fetches the diff directly from GitHub.
+#[tauri::command]
+pub async fn get_remote_pr_diff(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+) -> Result<String, String> {
+    log::trace!("Fetching remote diff for PR #{pr_number} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args(["pr", "diff", &pr_number.to_string()])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr diff: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("no pull requests found") || stderr.contains("Could not resolve") {
+            return Err("PR not found - may have been deleted".to_string());
+        }
+        return Err(format!("Failed to fetch PR diff: {stderr}"));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Re-run every failed check for a pull request's latest workflow run(s)
+#[tauri::command]
+pub async fn rerun_failed_checks(
+    app: AppHandle,
+    project_path: String,
+    pr_number: u32,
+) -> Result<(), String> {
+    log::trace!("Re-running failed checks for PR #{pr_number} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+
+    // Resolve the head SHA so we can target the run(s) attached to this PR's commit.
+    let view_output = silent_command(&gh)
+        .args(["pr", "view", &pr_number.to_string(), "--json", "headRefOid"])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh pr view: {e}"))?;
+
+    if !view_output.status.success() {
+        let stderr = String::from_utf8_lossy(&view_output.stderr);
+        return Err(format!("Failed to look up PR head commit: {stderr}"));
+    }
+
+    #[derive(Deserialize)]
+    struct HeadSha {
+        #[serde(rename = "headRefOid")]
+        head_ref_oid: String,
+    }
+    let head: HeadSha = serde_json::from_slice(&view_output.stdout)
+        .map_err(|e| format!("Failed to parse gh pr view output: {e}"))?;
+
+    let runs_output = silent_command(&gh)
+        .args(["run", "list", "--commit", &head.head_ref_oid, "--json", "databaseId,conclusion"])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh run list: {e}"))?;
+
+    if !runs_output.status.success() {
+        let stderr = String::from_utf8_lossy(&runs_output.stderr);
+        return Err(format!("Failed to list workflow runs: {stderr}"));
+    }
+
+    #[derive(Deserialize)]
+    struct RunSummary {
+        #[serde(rename = "databaseId")]
+        database_id: u64,
+        conclusion: String,
+    }
+    let runs: Vec<RunSummary> = serde_json::from_slice(&runs_output.stdout)
+        .map_err(|e| format!("Failed to parse gh run list output: {e}"))?;
+
+    for run in runs.into_iter().filter(|r| r.conclusion == "failure") {
+        let rerun_output = silent_command(&gh)
+            .args(["run", "rerun", &run.database_id.to_string(), "--failed"])
+            .current_dir(&project_path)
+            .output()
+            .map_err(|e| format!("Failed to run gh run rerun: {e}"))?;
+
+        if !rerun_output.status.success() {
+            let stderr = String::from_utf8_lossy(&rerun_output.stderr);
+            return Err(format!(
+                "Failed to rerun workflow run {}: {stderr}",
+                run.database_id
+            ));
+        }
+    }
+
+    log::trace!("Re-ran failed checks for PR #{pr_number}");
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,6 +616,7 @@ mod tests {
             display_status: PrDisplayStatus::Review,
             mergeable: Some(MergeableStatus::Mergeable),
             checked_at: 1234567890,
+            unresolved_thread_count: None,
         };
 
         let json = serde_json::to_string(&status).unwrap();
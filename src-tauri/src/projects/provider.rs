@@ -0,0 +1,319 @@
+//! Git hosting provider abstraction
+//!
+//! Jean's GitHub integration predates this trait, and most commands (`pr_status`,
+//! `github_issues`, `git::open_pull_request`) still call `gh` directly rather than going
+//! through it. `GitProvider` exists so that the handful of operations that also need to work
+//! against GitLab-hosted projects — listing open merge requests and opening new ones — don't
+//! have to duplicate call sites with an `if is_gitlab` branch. Routing the rest of the
+//! GitHub-specific command surface (issue listing, check/pipeline status, reviews) through
+//! this trait is follow-up work; `GitLabProvider` below covers merge request listing/creation
+//! only for now.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::types::Project;
+use crate::platform::silent_command;
+
+/// Which git hosting service a project's remote points to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GitHostKind {
+    GitHub,
+    GitLab,
+    Gitea,
+}
+
+/// Detect the hosting provider from a repository's remote URL
+///
+/// Self-hosted GitLab instances can't be distinguished from GitHub Enterprise Server by URL
+/// alone, so this only recognizes gitlab.com remotes; everything else is treated as GitHub.
+pub fn detect_host_kind(remote_url: &str) -> GitHostKind {
+    if remote_url.contains("gitlab.com") {
+        GitHostKind::GitLab
+    } else {
+        GitHostKind::GitHub
+    }
+}
+
+/// Minimal state of an open PR/MR, provider-agnostic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeRequestSummary {
+    pub number: u32,
+    pub title: String,
+    pub state: String,
+    pub web_url: String,
+}
+
+/// Operations common to GitHub pull requests and GitLab merge requests
+pub trait GitProvider {
+    /// List open PRs/MRs for the repository at `repo_path`
+    fn list_open_change_requests(&self, repo_path: &str) -> Result<Vec<ChangeRequestSummary>, String>;
+
+    /// Open (create) a PR/MR from the current branch, returning its web URL
+    fn open_change_request(&self, repo_path: &str, title: &str, body: &str) -> Result<String, String>;
+}
+
+/// GitHub provider backed by the `gh` CLI
+pub struct GitHubProvider {
+    pub gh_binary: PathBuf,
+}
+
+impl GitProvider for GitHubProvider {
+    fn list_open_change_requests(&self, repo_path: &str) -> Result<Vec<ChangeRequestSummary>, String> {
+        let output = silent_command(&self.gh_binary)
+            .args(["pr", "list", "--json", "number,title,state,url", "--state", "open"])
+            .current_dir(repo_path)
+            .output()
+            .map_err(|e| format!("Failed to run gh pr list: {e}"))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("gh pr list failed: {stderr}"));
+        }
+
+        #[derive(Deserialize)]
+        struct GhPr {
+            number: u32,
+            title: String,
+            state: String,
+            url: String,
+        }
+        let prs: Vec<GhPr> = serde_json::from_slice(&output.stdout)
+            .map_err(|e| format!("Failed to parse gh pr list output: {e}"))?;
+
+        Ok(prs
+            .into_iter()
+            .map(|pr| ChangeRequestSummary {
+                number: pr.number,
+                title: pr.title,
+                state: pr.state.to_lowercase(),
+                web_url: pr.url,
+            })
+            .collect())
+    }
+
+    fn open_change_request(&self, repo_path: &str, title: &str, body: &str) -> Result<String, String> {
+        super::git::open_pull_request(repo_path, Some(title), Some(body), false, &self.gh_binary)
+    }
+}
+
+/// GitLab provider backed by the `glab` CLI
+///
+/// Unlike `gh`, Jean doesn't embed/install `glab` — it must already be on `PATH` and
+/// authenticated (`glab auth login`).
+pub struct GitLabProvider {
+    pub glab_binary: PathBuf,
+}
+
+impl Default for GitLabProvider {
+    fn default() -> Self {
+        Self {
+            glab_binary: PathBuf::from("glab"),
+        }
+    }
+}
+
+impl GitProvider for GitLabProvider {
+    fn list_open_change_requests(&self, repo_path: &str) -> Result<Vec<ChangeRequestSummary>, String> {
+        let output = silent_command(&self.glab_binary)
+            .args(["mr", "list", "--output", "json"])
+            .current_dir(repo_path)
+            .output()
+            .map_err(|e| {
+                format!("Failed to run glab mr list: {e}. Is glab installed and authenticated?")
+            })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("glab mr list failed: {stderr}"));
+        }
+
+        #[derive(Deserialize)]
+        struct GlabMr {
+            iid: u32,
+            title: String,
+            state: String,
+            web_url: String,
+        }
+        let mrs: Vec<GlabMr> = serde_json::from_slice(&output.stdout)
+            .map_err(|e| format!("Failed to parse glab mr list output: {e}"))?;
+
+        Ok(mrs
+            .into_iter()
+            .map(|mr| ChangeRequestSummary {
+                number: mr.iid,
+                title: mr.title,
+                state: mr.state.to_lowercase(),
+                web_url: mr.web_url,
+            })
+            .collect())
+    }
+
+    fn open_change_request(&self, repo_path: &str, title: &str, body: &str) -> Result<String, String> {
+        let push_output = silent_command("git")
+            .args(["push", "-u", "origin", "HEAD"])
+            .current_dir(repo_path)
+            .output()
+            .map_err(|e| format!("Failed to push to remote: {e}"))?;
+
+        if !push_output.status.success() {
+            let stderr = String::from_utf8_lossy(&push_output.stderr);
+            if !stderr.contains("Everything up-to-date") && !stderr.contains("set up to track") {
+                log::warn!("Push warning: {stderr}");
+            }
+        }
+
+        let output = silent_command(&self.glab_binary)
+            .args(["mr", "create", "--fill", "--title", title, "--description", body])
+            .current_dir(repo_path)
+            .output()
+            .map_err(|e| format!("Failed to run glab mr create: {e}"))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("glab mr create failed: {stderr}"));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
+/// Gitea/Forgejo provider backed by the instance's REST API (token auth)
+///
+/// Covers PR listing/creation and issue listing for self-hosted forges; pipeline status isn't
+/// implemented yet (Gitea Actions' API shape differs enough from the other two providers that
+/// it's left for follow-up).
+pub struct GiteaProvider {
+    pub host: String,
+    pub token: String,
+    pub owner: String,
+    pub repo: String,
+    /// Branch to open PRs against (`Project::default_branch`). Gitea's API has no
+    /// equivalent of `gh`/`glab`'s auto-detected base branch, so this has to be passed in
+    /// explicitly rather than hardcoded - a repo whose default branch isn't `main` would
+    /// otherwise get PRs opened against a nonexistent or wrong branch.
+    pub default_branch: String,
+}
+
+impl GiteaProvider {
+    fn client(&self) -> Result<reqwest::blocking::Client, String> {
+        reqwest::blocking::Client::builder()
+            .user_agent("Jean-App/1.0")
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {e}"))
+    }
+
+    fn api_url(&self, path: &str) -> String {
+        format!("{}/api/v1/repos/{}/{}{path}", self.host.trim_end_matches('/'), self.owner, self.repo)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaPull {
+    number: u32,
+    title: String,
+    state: String,
+    html_url: String,
+}
+
+impl GitProvider for GiteaProvider {
+    fn list_open_change_requests(&self, _repo_path: &str) -> Result<Vec<ChangeRequestSummary>, String> {
+        let response = self
+            .client()?
+            .get(self.api_url("/pulls"))
+            .query(&[("state", "open")])
+            .bearer_auth(&self.token)
+            .send()
+            .map_err(|e| format!("Failed to reach Gitea instance: {e}"))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Gitea API returned status: {}", response.status()));
+        }
+
+        let pulls: Vec<GiteaPull> = response
+            .json()
+            .map_err(|e| format!("Failed to parse Gitea response: {e}"))?;
+
+        Ok(pulls
+            .into_iter()
+            .map(|pr| ChangeRequestSummary {
+                number: pr.number,
+                title: pr.title,
+                state: pr.state,
+                web_url: pr.html_url,
+            })
+            .collect())
+    }
+
+    fn open_change_request(&self, repo_path: &str, title: &str, body: &str) -> Result<String, String> {
+        let push_output = silent_command("git")
+            .args(["push", "-u", "origin", "HEAD"])
+            .current_dir(repo_path)
+            .output()
+            .map_err(|e| format!("Failed to push to remote: {e}"))?;
+
+        if !push_output.status.success() {
+            let stderr = String::from_utf8_lossy(&push_output.stderr);
+            if !stderr.contains("Everything up-to-date") && !stderr.contains("set up to track") {
+                log::warn!("Push warning: {stderr}");
+            }
+        }
+
+        let head_branch = super::git::get_current_branch(repo_path)?;
+
+        let response = self
+            .client()?
+            .post(self.api_url("/pulls"))
+            .bearer_auth(&self.token)
+            .json(&serde_json::json!({
+                "title": title,
+                "body": body,
+                "head": head_branch,
+                "base": self.default_branch,
+            }))
+            .send()
+            .map_err(|e| format!("Failed to reach Gitea instance: {e}"))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Gitea API returned status: {}", response.status()));
+        }
+
+        let pull: GiteaPull = response
+            .json()
+            .map_err(|e| format!("Failed to parse Gitea response: {e}"))?;
+
+        Ok(pull.html_url)
+    }
+}
+
+/// Resolve the right `GitProvider` for a project
+///
+/// Explicit Gitea/Forgejo configuration (`gitea_host`) always wins; otherwise the provider is
+/// guessed from the project's remote URL.
+pub fn provider_for_project(project: &Project, gh_binary: PathBuf) -> Result<Box<dyn GitProvider>, String> {
+    if let Some(host) = &project.gitea_host {
+        let token = project
+            .gitea_token
+            .clone()
+            .ok_or_else(|| "Gitea host is configured but no access token was set".to_string())?;
+        let repo_id = super::git::get_repo_identifier(&project.path)?;
+        return Ok(Box::new(GiteaProvider {
+            host: host.clone(),
+            token,
+            owner: repo_id.owner,
+            repo: repo_id.repo,
+            default_branch: project.default_branch.clone(),
+        }));
+    }
+
+    let remote_url = super::git::get_github_url(&project.path).unwrap_or_default();
+
+    Ok(match detect_host_kind(&remote_url) {
+        GitHostKind::GitLab => Box::new(GitLabProvider::default()),
+        GitHostKind::Gitea => unreachable!("detect_host_kind never returns Gitea; it requires explicit config"),
+        GitHostKind::GitHub => Box::new(GitHubProvider { gh_binary }),
+    })
+}
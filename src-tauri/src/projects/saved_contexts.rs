@@ -23,10 +23,7 @@ pub async fn attach_saved_context(
 ) -> Result<AttachedSavedContext, String> {
     log::trace!("Attaching saved context '{slug}' for worktree {worktree_id}");
 
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(&app)?;
 
     let saved_contexts_dir = app_data_dir.join("session-context");
     std::fs::create_dir_all(&saved_contexts_dir)
@@ -87,10 +84,7 @@ pub async fn remove_saved_context(
 ) -> Result<(), String> {
     log::trace!("Removing saved context '{slug}' from worktree {worktree_id}");
 
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(&app)?;
 
     let context_file = app_data_dir
         .join("session-context")
@@ -113,10 +107,7 @@ pub async fn list_attached_saved_contexts(
 ) -> Result<Vec<AttachedSavedContext>, String> {
     log::trace!("Listing attached saved contexts for worktree {worktree_id}");
 
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(&app)?;
 
     let saved_contexts_dir = app_data_dir.join("session-context");
 
@@ -183,10 +174,7 @@ pub async fn get_saved_context_content(
     worktree_id: String,
     slug: String,
 ) -> Result<String, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(&app)?;
 
     let context_file = app_data_dir
         .join("session-context")
@@ -208,10 +196,7 @@ pub fn cleanup_saved_contexts_for_worktree(
     app: &tauri::AppHandle,
     worktree_id: &str,
 ) -> Result<(), String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
 
     let saved_contexts_dir = app_data_dir.join("session-context");
     if !saved_contexts_dir.exists() {
@@ -2,7 +2,7 @@ use std::path::PathBuf;
 use std::sync::Mutex;
 
 use once_cell::sync::Lazy;
-use tauri::{AppHandle, Manager};
+use tauri::AppHandle;
 
 use super::types::ProjectsData;
 
@@ -13,10 +13,7 @@ static PROJECTS_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
 
 /// Get the path to the projects.json data file
 pub fn get_projects_path(app: &AppHandle) -> Result<PathBuf, String> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data directory: {e}"))?;
+    let app_data_dir = crate::data_dir::resolve(app)?;
 
     // Ensure the directory exists
     std::fs::create_dir_all(&app_data_dir)
@@ -107,6 +104,7 @@ fn load_projects_data_internal(app: &AppHandle) -> Result<ProjectsData, String>
     let data = ProjectsData {
         projects: data.projects,
         worktrees: valid_worktrees,
+        schema_version: data.schema_version,
     };
 
     // Save cleaned data if any orphans were removed
@@ -126,6 +124,7 @@ fn load_projects_data_internal(app: &AppHandle) -> Result<ProjectsData, String>
 /// Load projects data from disk (with locking for thread safety)
 pub fn load_projects_data(app: &AppHandle) -> Result<ProjectsData, String> {
     let _lock = PROJECTS_LOCK.lock().unwrap();
+    let _file_lock = crate::platform::FileLock::acquire(&get_projects_path(app)?)?;
     load_projects_data_internal(app)
 }
 
@@ -163,6 +162,7 @@ fn save_projects_data_internal(app: &AppHandle, data: &ProjectsData) -> Result<(
 /// Save projects data to disk (with locking for thread safety)
 pub fn save_projects_data(app: &AppHandle, data: &ProjectsData) -> Result<(), String> {
     let _lock = PROJECTS_LOCK.lock().unwrap();
+    let _file_lock = crate::platform::FileLock::acquire(&get_projects_path(app)?)?;
     save_projects_data_internal(app, data)
 }
 
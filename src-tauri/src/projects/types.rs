@@ -28,6 +28,12 @@ pub enum MergeType {
 pub struct JeanConfig {
     #[serde(default)]
     pub scripts: JeanScripts,
+    /// Maximum time a single `send_chat_message` run may take before it's cancelled via
+    /// the process registry with a `"timeout"` reason. Off by default - only set this if
+    /// you want a runaway agent loop to be killed automatically instead of burning tokens
+    /// overnight.
+    #[serde(default)]
+    pub execution_timeout_seconds: Option<u64>,
 }
 
 /// Scripts section of jean.json
@@ -37,10 +43,27 @@ pub struct JeanScripts {
     pub setup: Option<String>,
     /// Script to run the dev environment
     pub run: Option<String>,
+    /// Script to run before a chat run starts (e.g. `git stash` or `make lint`). A
+    /// non-zero exit aborts the run before Claude is invoked.
+    #[serde(default)]
+    pub pre_run: Option<String>,
+    /// Script to run after a chat run completes (e.g. run tests, notify). Runs
+    /// regardless of whether the chat run succeeded; failures never undo the reply.
+    #[serde(default)]
+    pub post_run: Option<String>,
+    /// Test suite command, runnable on demand via `scripts::run_project_script`.
+    #[serde(default)]
+    pub test: Option<String>,
+    /// Linter command, runnable on demand via `scripts::run_project_script`.
+    #[serde(default)]
+    pub lint: Option<String>,
+    /// Build command, runnable on demand via `scripts::run_project_script`.
+    #[serde(default)]
+    pub build: Option<String>,
 }
 
 /// A git project that has been added to Jean, or a folder for organizing projects
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Project {
     /// Unique identifier (UUID v4)
     pub id: String,
@@ -64,10 +87,167 @@ pub struct Project {
     /// Path to custom avatar image (relative to app data dir, e.g., "avatars/abc123.png")
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub avatar_path: Option<String>,
+    /// GitHub account username to use for `gh` commands against this project
+    /// (None = use whichever account is currently active in `gh auth status`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gh_account: Option<String>,
+    /// Base URL of a self-hosted Gitea/Forgejo instance this project's remote points to
+    /// (e.g. "https://git.example.com"). None = not a Gitea/Forgejo project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gitea_host: Option<String>,
+    /// Personal access token for the Gitea/Forgejo instance at `gitea_host`
+    ///
+    /// Stored in plaintext in projects.json, same as the rest of this struct — Jean has no
+    /// secret storage layer yet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gitea_token: Option<String>,
+    /// Monthly AI usage budget in USD for this project's sessions. None = no per-project
+    /// limit (falls back to `AppPreferences::global_monthly_budget_usd`, if set).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monthly_budget_usd: Option<f64>,
+    /// Priority used to order this project's runs in the global run queue (see
+    /// `chat::run_queue`) when `AppPreferences::max_concurrent_runs` is exceeded. Higher
+    /// values are dispatched first; ties are broken FIFO. Defaults to 0 (normal priority).
+    #[serde(default)]
+    pub run_priority: i32,
+    /// Environment variables injected into the Claude CLI process and into `jean.json`
+    /// hook scripts for every session under this project (e.g. `ANTHROPIC_BASE_URL`, proxy
+    /// settings, feature flags). A session-level entry with the same key in
+    /// `SessionMetadata::env_vars` takes precedence over the one here.
+    #[serde(default)]
+    pub env_vars: Vec<EnvVarEntry>,
+    /// Glob patterns (e.g. `"VITE_*"`, `"PUBLIC_*"`) of variable names allowed to be loaded
+    /// from the worktree's `.env`/`.env.local` files (see `projects::env_files`). Empty by
+    /// default - a worktree's `.env` often holds secrets, so nothing is loaded unless a
+    /// pattern opts it in.
+    #[serde(default)]
+    pub dotenv_allowlist: Vec<String>,
+    /// Managed system-prompt instructions appended to every session under this project (see
+    /// `chat::instructions::resolve_instructions`). Separate from any repo-local CLAUDE.md -
+    /// this lives in projects.json and is editable from the frontend, not checked into git.
+    /// A worktree with `Worktree::instructions_override` set uses that instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// If true, after a run completes with uncommitted file changes, Jean automatically
+    /// creates a commit for them via the same AI-generated-message flow as the manual
+    /// "commit" button (see `create_commit_with_ai`), tagged with the session/run id so the
+    /// resulting history stays attributable to a specific AI run. Off by default - most
+    /// users want to review changes before they land in history.
+    #[serde(default)]
+    pub auto_commit_after_run: bool,
+    /// SSH connection details for a project whose repository lives on a remote host.
+    /// None = the project is local (the common case). See `crate::remote` for what
+    /// actually runs over SSH today.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<RemoteConfig>,
+    /// Shell binary used for this project's terminals and run scripts (e.g. `/usr/bin/fish`,
+    /// `nu`). None = `get_default_shell()`. Validated with `executable_exists` before being
+    /// saved (see `update_project_settings`). A terminal's own `TerminalProfile::shell`, if
+    /// set, overrides this.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+    /// Command run immediately after the shell starts, as if typed by the user (e.g. a
+    /// `nvm use` call or sourcing a project-specific rc file not on the shell's own startup
+    /// path). Only applies to interactive terminals, not one-shot `run_project_script` runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shell_startup_command: Option<String>,
+    /// Opt-in OS-level sandboxing of the Claude CLI process for this project's runs (see
+    /// `chat::sandbox`). Off by default - most users run Claude directly.
+    #[serde(default)]
+    pub sandbox: SandboxConfig,
+    /// Slack/Discord webhooks this project posts selected events to (see
+    /// `notifications::integrations`). Empty = no webhooks configured.
+    #[serde(default)]
+    pub notification_webhooks: Vec<NotificationWebhook>,
+    /// Suppress native notifications for this project's worktrees (see
+    /// `notifications::dnd`). Rule/webhook actions other than `native` still fire - this
+    /// only mutes the OS-level popup.
+    #[serde(default)]
+    pub muted: bool,
+}
+
+/// A Slack- or Discord-compatible incoming webhook a project posts selected events to (run
+/// completed, PR merged, review requested) - see `notifications::integrations::evaluate`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NotificationWebhook {
+    pub id: String,
+    /// Which chat service this webhook's payload should be formatted for.
+    pub provider: WebhookProvider,
+    pub url: String,
+    /// Which events this webhook should be posted for. Empty = configured but silent.
+    #[serde(default)]
+    pub events: Vec<IntegrationEvent>,
+}
+
+/// Chat service a `NotificationWebhook` posts to - determines the JSON body shape
+/// (`{"text": ...}` for Slack, `{"content": ...}` for Discord).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookProvider {
+    Slack,
+    Discord,
+}
+
+/// An event a `NotificationWebhook` can subscribe to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrationEvent {
+    /// An AI run finished (`chat:done`).
+    RunCompleted,
+    /// A pull request was merged.
+    PrMerged,
+    /// A pull request now requires review (`review_decision` is `review_required`).
+    ReviewRequested,
+}
+
+/// A project's opt-in sandbox settings for Claude CLI runs (see `chat::sandbox::wrap_command`
+/// for where these are actually applied).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct SandboxConfig {
+    /// Restrict the Claude CLI process to writing only within the run's worktree, using
+    /// `bwrap` on Linux or `sandbox-exec` on macOS. Unsupported on Windows - see
+    /// `chat::sandbox` for why AppContainer support is deferred. If the platform's sandbox
+    /// tool can't be found, the run falls back to unsandboxed rather than failing outright.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Additionally deny the sandboxed process network access. Ignored if `enabled` is
+    /// false.
+    #[serde(default)]
+    pub disable_network: bool,
+}
+
+/// SSH connection details for a remote project (see `Project::remote`).
+///
+/// Stored in plaintext in projects.json, same as `Project::gitea_token` - Jean has no
+/// secret storage layer yet, so use key-based SSH auth rather than a password here.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RemoteConfig {
+    /// Hostname or IP of the remote machine.
+    pub host: String,
+    /// SSH user to connect as (None = ssh's own default, usually `$USER` or `~/.ssh/config`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// SSH port (None = default port 22).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Absolute path to the git repository on the remote host.
+    pub remote_path: String,
+}
+
+/// A single environment variable to inject at Claude CLI / hook-script spawn time.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EnvVarEntry {
+    pub key: String,
+    pub value: String,
+    /// Hint for the frontend to mask `value` in UI (e.g. API keys, proxy credentials).
+    /// Storage is unaffected either way - plaintext in projects.json/session metadata.json,
+    /// same as `gitea_token` above - Jean has no secret storage layer yet.
+    #[serde(default)]
+    pub sensitive: bool,
 }
 
 /// A git worktree created for a project
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Worktree {
     /// Unique identifier (UUID v4)
     pub id: String,
@@ -141,13 +321,34 @@ pub struct Worktree {
     /// Unix timestamp when worktree was archived (None = not archived)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archived_at: Option<u64>,
+    /// Per-worktree override for `Project::instructions`. None = inherit the project's
+    /// instructions document (or nothing, if the project has none set either).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instructions_override: Option<String>,
 }
 
 /// Container for all persisted project data
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectsData {
     pub projects: Vec<Project>,
     pub worktrees: Vec<Worktree>,
+    /// See `storage_migrations.rs`.
+    #[serde(default = "default_projects_schema_version")]
+    pub schema_version: u32,
+}
+
+fn default_projects_schema_version() -> u32 {
+    crate::storage_migrations::PROJECTS_SCHEMA_VERSION
+}
+
+impl Default for ProjectsData {
+    fn default() -> Self {
+        Self {
+            projects: Vec::new(),
+            worktrees: Vec::new(),
+            schema_version: default_projects_schema_version(),
+        }
+    }
 }
 
 impl ProjectsData {
@@ -180,6 +381,11 @@ impl ProjectsData {
         self.worktrees.iter_mut().find(|w| w.id == id)
     }
 
+    /// Find a worktree by its absolute path
+    pub fn find_worktree_by_path(&self, path: &str) -> Option<&Worktree> {
+        self.worktrees.iter().find(|w| w.path == path)
+    }
+
     /// Add a project
     pub fn add_project(&mut self, project: Project) {
         self.projects.push(project);
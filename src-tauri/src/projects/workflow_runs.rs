@@ -0,0 +1,291 @@
+//! GitHub Actions workflow run management via `gh run`
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::gh_cli::config::resolve_gh_binary;
+use crate::http_server::EmitExt;
+use crate::platform::silent_command;
+
+/// Summary of a single workflow run, as shown in `gh run list`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowRun {
+    pub database_id: u64,
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub head_branch: String,
+    pub event: String,
+    pub created_at: String,
+    pub url: String,
+}
+
+/// Status of a single job within a workflow run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowJob {
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+}
+
+/// List recent workflow runs for a branch (defaults to the current branch)
+#[tauri::command]
+pub async fn list_workflow_runs(
+    app: AppHandle,
+    project_path: String,
+    branch: Option<String>,
+) -> Result<Vec<WorkflowRun>, String> {
+    log::trace!("Listing workflow runs for {project_path} (branch: {branch:?})");
+
+    let gh = resolve_gh_binary(&app);
+    let mut args = vec![
+        "run".to_string(),
+        "list".to_string(),
+        "--json".to_string(),
+        "databaseId,name,status,conclusion,headBranch,event,createdAt,url".to_string(),
+        "-L".to_string(),
+        "20".to_string(),
+    ];
+    if let Some(branch) = branch {
+        args.push("--branch".to_string());
+        args.push(branch);
+    }
+
+    let output = silent_command(&gh)
+        .args(&args)
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh run list: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to list workflow runs: {stderr}"));
+    }
+
+    let runs: Vec<WorkflowRun> = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse gh run list output: {e}"))?;
+    Ok(runs)
+}
+
+/// List job-level status for a single workflow run
+#[tauri::command]
+pub async fn get_workflow_run_jobs(
+    app: AppHandle,
+    project_path: String,
+    run_id: u64,
+) -> Result<Vec<WorkflowJob>, String> {
+    log::trace!("Fetching jobs for workflow run {run_id} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args([
+            "run",
+            "view",
+            &run_id.to_string(),
+            "--json",
+            "jobs",
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh run view: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to fetch workflow run: {stderr}"));
+    }
+
+    #[derive(Deserialize)]
+    struct JobsWrapper {
+        jobs: Vec<WorkflowJob>,
+    }
+    let wrapper: JobsWrapper = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse gh run view output: {e}"))?;
+    Ok(wrapper.jobs)
+}
+
+/// Re-run an entire workflow run
+#[tauri::command]
+pub async fn rerun_workflow_run(
+    app: AppHandle,
+    project_path: String,
+    run_id: u64,
+) -> Result<(), String> {
+    log::trace!("Re-running workflow run {run_id} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args(["run", "rerun", &run_id.to_string()])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh run rerun: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to rerun workflow run: {stderr}"));
+    }
+    Ok(())
+}
+
+/// Cancel an in-progress workflow run
+#[tauri::command]
+pub async fn cancel_workflow_run(
+    app: AppHandle,
+    project_path: String,
+    run_id: u64,
+) -> Result<(), String> {
+    log::trace!("Cancelling workflow run {run_id} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args(["run", "cancel", &run_id.to_string()])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh run cancel: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to cancel workflow run: {stderr}"));
+    }
+    Ok(())
+}
+
+/// Event payload emitted when a watched workflow run finishes
+#[derive(Debug, Clone, Serialize)]
+struct WorkflowRunCompleted {
+    project_path: String,
+    run_id: u64,
+    conclusion: Option<String>,
+}
+
+/// Poll a workflow run in the background until it completes, emitting `ci:run-completed`
+#[tauri::command]
+pub async fn watch_workflow_run(
+    app: AppHandle,
+    project_path: String,
+    run_id: u64,
+) -> Result<(), String> {
+    log::trace!("Watching workflow run {run_id} in {project_path}");
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let gh = resolve_gh_binary(&app);
+            let output = silent_command(&gh)
+                .args(["run", "view", &run_id.to_string(), "--json", "status,conclusion"])
+                .current_dir(&project_path)
+                .output();
+
+            let Ok(output) = output else { break };
+            if !output.status.success() {
+                break;
+            }
+
+            #[derive(Deserialize)]
+            struct RunState {
+                status: String,
+                conclusion: Option<String>,
+            }
+            let Ok(state) = serde_json::from_slice::<RunState>(&output.stdout) else {
+                break;
+            };
+
+            if state.status == "completed" {
+                let _ = app.emit_all(
+                    "ci:run-completed",
+                    &WorkflowRunCompleted {
+                        project_path: project_path.clone(),
+                        run_id,
+                        conclusion: state.conclusion,
+                    },
+                );
+                break;
+            }
+
+            tokio::time::sleep(std::time::Duration::from_secs(15)).await;
+        }
+    });
+
+    Ok(())
+}
+
+/// A chunk of log output streamed from a running/completed workflow job
+#[derive(Debug, Clone, Serialize)]
+struct LogChunk {
+    project_path: String,
+    run_id: u64,
+    job_id: u64,
+    chunk: String,
+    done: bool,
+}
+
+/// Tail a workflow job's logs, emitting incremental `ci:log-chunk` events as they arrive.
+///
+/// `gh run view --log` only returns the full log once available, so this streams it to the
+/// frontend in line-batches rather than all at once, keeping large failing-CI output readable
+/// as it comes in.
+#[tauri::command]
+pub async fn stream_workflow_logs(
+    app: AppHandle,
+    project_path: String,
+    run_id: u64,
+    job_id: u64,
+) -> Result<(), String> {
+    log::trace!("Streaming logs for workflow run {run_id}, job {job_id} in {project_path}");
+
+    let gh = resolve_gh_binary(&app);
+    let output = silent_command(&gh)
+        .args([
+            "run",
+            "view",
+            &run_id.to_string(),
+            "--job",
+            &job_id.to_string(),
+            "--log",
+        ])
+        .current_dir(&project_path)
+        .output()
+        .map_err(|e| format!("Failed to run gh run view --log: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("Failed to fetch job logs: {stderr}"));
+    }
+
+    let log_text = String::from_utf8_lossy(&output.stdout);
+    const LINES_PER_CHUNK: usize = 200;
+    let lines: Vec<&str> = log_text.lines().collect();
+
+    if lines.is_empty() {
+        let _ = app.emit_all(
+            "ci:log-chunk",
+            &LogChunk {
+                project_path,
+                run_id,
+                job_id,
+                chunk: String::new(),
+                done: true,
+            },
+        );
+        return Ok(());
+    }
+
+    let batches: Vec<&[&str]> = lines.chunks(LINES_PER_CHUNK).collect();
+    let last_index = batches.len() - 1;
+    for (i, batch) in batches.into_iter().enumerate() {
+        let _ = app.emit_all(
+            "ci:log-chunk",
+            &LogChunk {
+                project_path: project_path.clone(),
+                run_id,
+                job_id,
+                chunk: batch.join("\n"),
+                done: i == last_index,
+            },
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! SSH access to a project whose repository lives on a remote host (`Project::remote`).
+//!
+//! Scope today is deliberately narrow: running `git status`/`git diff` against the remote
+//! repo over `ssh`, so the sidebar can show a remote project's state without a local clone.
+//! Two related asks are NOT handled here and don't need dedicated plumbing:
+//! - **Terminals over SSH**: `TerminalProfile::shell` already accepts an arbitrary shell
+//!   binary; pointing it at `ssh user@host` gives an interactive remote terminal with the
+//!   existing PTY subsystem unchanged.
+//! - **Claude CLI on the remote machine**: not supported. The CLI process is spawned via
+//!   `claude_cli`, which assumes a local filesystem for session state, hooks, and the
+//!   worktree itself - making it work remotely is a much larger change than this module.
+
+use std::process::{Command, Output};
+
+use crate::platform::silent_command;
+use crate::projects::types::RemoteConfig;
+
+fn ssh_target(remote: &RemoteConfig) -> String {
+    match &remote.user {
+        Some(user) => format!("{user}@{}", remote.host),
+        None => remote.host.clone(),
+    }
+}
+
+fn ssh_command(remote: &RemoteConfig, remote_shell_command: &str) -> Command {
+    let mut cmd = silent_command("ssh");
+    if let Some(port) = remote.port {
+        cmd.arg("-p").arg(port.to_string());
+    }
+    cmd.arg(ssh_target(remote));
+    cmd.arg(format!(
+        "cd {} && {remote_shell_command}",
+        shell_escape(&remote.remote_path)
+    ));
+    cmd
+}
+
+/// Quote a path for safe interpolation into the remote shell command string.
+fn shell_escape(path: &str) -> String {
+    format!("'{}'", path.replace('\'', "'\\''"))
+}
+
+fn run_remote(remote: &RemoteConfig, remote_shell_command: &str) -> Result<Output, String> {
+    ssh_command(remote, remote_shell_command)
+        .output()
+        .map_err(|e| format!("Failed to run SSH command on {}: {e}", remote.host))
+}
+
+/// Run `git status --porcelain` against a remote project's repository over SSH.
+#[tauri::command]
+pub async fn get_remote_git_status(remote: RemoteConfig) -> Result<String, String> {
+    let output = run_remote(&remote, "git status --porcelain")?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Run `git diff` against a remote project's repository over SSH.
+#[tauri::command]
+pub async fn get_remote_git_diff(remote: RemoteConfig) -> Result<String, String> {
+    let output = run_remote(&remote, "git diff")?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
@@ -0,0 +1,236 @@
+//! Per-project script registry (dev/test/lint/build), extending `jean.json`'s `scripts.run`
+//! into named, separately runnable commands.
+//!
+//! `run_project_script` streams output to the frontend as `script:output` events while the
+//! process runs, then emits `script:finished` with the exit status and any lines that look
+//! like a test failure or compiler error, so a failing run can be summarized and attached to
+//! chat as context instead of making the user scroll raw output.
+
+use std::io::{BufRead, BufReader, Read};
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::http_server::EmitExt;
+use crate::platform::silent_command;
+use crate::projects::env_files::load_dotenv_vars;
+use crate::projects::git::read_jean_config;
+use crate::projects::storage::load_projects_data;
+use crate::projects::types::JeanScripts;
+
+/// Which named script in `jean.json` to run.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptKind {
+    Dev,
+    Test,
+    Lint,
+    Build,
+}
+
+impl ScriptKind {
+    fn command(self, scripts: &JeanScripts) -> Option<String> {
+        match self {
+            ScriptKind::Dev => scripts.run.clone(),
+            ScriptKind::Test => scripts.test.clone(),
+            ScriptKind::Lint => scripts.lint.clone(),
+            ScriptKind::Build => scripts.build.clone(),
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ScriptKind::Dev => "dev",
+            ScriptKind::Test => "test",
+            ScriptKind::Lint => "lint",
+            ScriptKind::Build => "build",
+        }
+    }
+}
+
+/// What kind of failure a parsed output line looks like.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FailureCategory {
+    Test,
+    Compiler,
+}
+
+/// A single output line that matched a known failure pattern.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedFailure {
+    pub category: FailureCategory,
+    pub line: String,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScriptOutputEvent {
+    run_id: String,
+    stream: &'static str,
+    data: String,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScriptFinishedEvent {
+    run_id: String,
+    kind: ScriptKind,
+    exit_code: Option<i32>,
+    failures: Vec<ParsedFailure>,
+}
+
+/// Scan combined stdout/stderr for lines that look like a test failure or compiler error.
+/// Heuristic pattern matching, not a real parser for any specific toolchain's output format.
+fn parse_failures(output: &str) -> Vec<ParsedFailure> {
+    let compiler_re = Regex::new(r"(?i)error(\[[a-z0-9]+\]|\s+ts\d+)?:").expect("Invalid regex");
+    let test_re =
+        Regex::new(r"(?i)\b(FAILED|FAIL:|AssertionError|not ok \d)\b").expect("Invalid regex");
+
+    output
+        .lines()
+        .filter_map(|line| {
+            if test_re.is_match(line) {
+                Some(ParsedFailure {
+                    category: FailureCategory::Test,
+                    line: line.to_string(),
+                })
+            } else if compiler_re.is_match(line) {
+                Some(ParsedFailure {
+                    category: FailureCategory::Compiler,
+                    line: line.to_string(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Read one stream line-by-line, forwarding each line as a `script:output` event and
+/// appending it to `combined` for failure parsing once the process exits.
+fn spawn_stream_reader<R: Read + Send + 'static>(
+    app: AppHandle,
+    run_id: String,
+    stream: &'static str,
+    reader: R,
+    combined: Arc<Mutex<String>>,
+) {
+    thread::spawn(move || {
+        let mut buf_reader = BufReader::new(reader);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match buf_reader.read_line(&mut line) {
+                Ok(0) => break,
+                Ok(_) => {
+                    combined.lock().unwrap().push_str(&line);
+                    let event = ScriptOutputEvent {
+                        run_id: run_id.clone(),
+                        stream,
+                        data: line.clone(),
+                    };
+                    if let Err(e) = app.emit_all("script:output", &event) {
+                        log::error!("Failed to emit script:output event: {e}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Error reading {stream} for script run {run_id}: {e}");
+                    break;
+                }
+            }
+        }
+    });
+}
+
+/// Start a named project script (dev/test/lint/build) from `jean.json`, streaming its output
+/// to the frontend. Returns the run ID the emitted `script:output`/`script:finished` events
+/// are scoped to.
+#[tauri::command]
+pub async fn run_project_script(
+    app: AppHandle,
+    worktree_path: String,
+    kind: ScriptKind,
+) -> Result<String, String> {
+    let config = read_jean_config(&worktree_path).unwrap_or_default();
+    let script = kind
+        .command(&config.scripts)
+        .ok_or_else(|| format!("No {} script configured in jean.json", kind.label()))?;
+
+    let run_id = uuid::Uuid::new_v4().to_string();
+
+    // Use the owning project's configured shell and allowlisted `.env` vars, if any, same
+    // as interactive terminals.
+    let project = load_projects_data(&app).ok().and_then(|data| {
+        data.find_worktree_by_path(&worktree_path)
+            .and_then(|w| data.find_project(&w.project_id))
+            .cloned()
+    });
+    let shell = project
+        .as_ref()
+        .and_then(|p| p.shell.clone())
+        .unwrap_or_else(crate::platform::get_default_shell);
+    let dotenv_vars = project
+        .map(|p| load_dotenv_vars(&worktree_path, &p.dotenv_allowlist))
+        .unwrap_or_default();
+
+    #[cfg(windows)]
+    let script_args = ["-Command", script.as_str()];
+    #[cfg(not(windows))]
+    let script_args = ["-c", script.as_str()];
+
+    let mut command = silent_command(&shell);
+    command
+        .args(script_args)
+        .current_dir(&worktree_path)
+        .env("JEAN_WORKTREE_PATH", &worktree_path)
+        .envs(dotenv_vars)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to start {} script: {e}", kind.label()))?;
+
+    let stdout = child.stdout.take().expect("stdout was piped");
+    let stderr = child.stderr.take().expect("stderr was piped");
+    let combined = Arc::new(Mutex::new(String::new()));
+
+    spawn_stream_reader(
+        app.clone(),
+        run_id.clone(),
+        "stdout",
+        stdout,
+        combined.clone(),
+    );
+    spawn_stream_reader(
+        app.clone(),
+        run_id.clone(),
+        "stderr",
+        stderr,
+        combined.clone(),
+    );
+
+    let run_id_clone = run_id.clone();
+    thread::spawn(move || {
+        let exit_code = child.wait().ok().and_then(|status| status.code());
+        let output = combined.lock().unwrap().clone();
+        let failures = parse_failures(&output);
+
+        let event = ScriptFinishedEvent {
+            run_id: run_id_clone,
+            kind,
+            exit_code,
+            failures,
+        };
+        if let Err(e) = app.emit_all("script:finished", &event) {
+            log::error!("Failed to emit script:finished event: {e}");
+        }
+    });
+
+    Ok(run_id)
+}
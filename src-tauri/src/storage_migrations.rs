@@ -0,0 +1,210 @@
+//! Schema-versioning and migration framework for persisted top-level documents
+//! (preferences, UI state, projects/worktrees, session metadata).
+//!
+//! These files have historically evolved by adding `#[serde(default)]` fields, which works
+//! for additive changes but gives no way to distinguish "file predates this change" from
+//! "file is current" when a migration needs to do more than default a field - renaming a
+//! key, changing a value's shape, splitting a document, etc. `SessionMetadata` and
+//! `UIState` already carried a `version` field for this purpose; this module extends the
+//! same idea to `AppPreferences` and `ProjectsData` (as a new `schema_version` field) and
+//! adds a `run_startup_migrations` pass, run once per launch from `run()`'s `setup` hook
+//! before anything else reads these files.
+//!
+//! There is no real migration logic yet - every document is currently on version 1 and
+//! `migrate_json_file` only stamps the version field onto files that predate it. The point
+//! of this module is the scaffolding (version field, backup-before-write, a place to add a
+//! `match (from, to)` migration step) so that the next breaking change to one of these
+//! shapes has somewhere to go instead of silently hoping `#[serde(default)]` covers it.
+
+use std::path::Path;
+
+use serde::Serialize;
+use tauri::AppHandle;
+
+pub const PREFERENCES_SCHEMA_VERSION: u32 = 1;
+pub const PROJECTS_SCHEMA_VERSION: u32 = 1;
+pub const UI_STATE_SCHEMA_VERSION: u32 = 1;
+/// `SessionMetadata` already had a `version` field ("storage format version for
+/// migrations") before this module existed - this constant just names its current value
+/// for use alongside the others here.
+pub const SESSION_METADATA_SCHEMA_VERSION: u32 = 1;
+
+/// Copy `path` into an app-data-dir `migrations-backup/` folder before it's overwritten,
+/// suffixed with the schema version it was migrated *from* so a user can recover a
+/// pre-migration copy if a migration turns out to have a bug.
+fn backup_before_migration(app: &AppHandle, path: &Path, from_version: u32) -> Result<(), String> {
+    let app_data_dir = crate::data_dir::resolve(app)?;
+    let backup_dir = app_data_dir.join("migrations-backup");
+    std::fs::create_dir_all(&backup_dir)
+        .map_err(|e| format!("Failed to create migrations backup directory: {e}"))?;
+
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| format!("Invalid file name for migration backup: {}", path.display()))?;
+    let backup_path = backup_dir.join(format!("{file_name}.v{from_version}.bak"));
+    std::fs::copy(path, &backup_path)
+        .map_err(|e| format!("Failed to back up {file_name} before migration: {e}"))?;
+    Ok(())
+}
+
+/// Stamp `version_field` onto a JSON document if it's missing or older than
+/// `current_version`, backing up the pre-migration file first. Patches the version field in
+/// place via `serde_json::Value` rather than round-tripping through a typed struct, so this
+/// works uniformly across document types without needing a trait per shape. The field name
+/// is a parameter because `UIState` already had a `version` field before this module
+/// existed - every other document type uses `schema_version`.
+fn migrate_json_file(
+    app: &AppHandle,
+    path: &Path,
+    version_field: &str,
+    current_version: u32,
+) -> Result<(), String> {
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+
+    // Session metadata can be encrypted at rest (see encryption.rs), which makes it
+    // unparseable as JSON here. That's fine: this app only ever encrypts a document after
+    // stamping its current schema version, so an encrypted file is never one that needs
+    // migrating - skip it rather than failing.
+    if crate::encryption::is_encrypted(&contents) {
+        return Ok(());
+    }
+
+    let mut value: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse {}: {e}", path.display()))?;
+
+    let existing_version = value
+        .get(version_field)
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32;
+
+    if existing_version >= current_version {
+        return Ok(());
+    }
+
+    backup_before_migration(app, path, existing_version)?;
+
+    match value.as_object_mut() {
+        Some(obj) => {
+            obj.insert(version_field.into(), serde_json::json!(current_version));
+        }
+        None => return Err(format!("Expected {} to contain a JSON object", path.display())),
+    }
+
+    let json_content = serde_json::to_string_pretty(&value)
+        .map_err(|e| format!("Failed to serialize migrated {}: {e}", path.display()))?;
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, json_content)
+        .map_err(|e| format!("Failed to write migrated {}: {e}", path.display()))?;
+    std::fs::rename(&temp_path, path)
+        .map_err(|e| format!("Failed to finalize migrated {}: {e}", path.display()))?;
+
+    log::info!(
+        "Migrated {} from schema version {existing_version} to {current_version}",
+        path.display()
+    );
+    Ok(())
+}
+
+/// Run all document migrations once at startup, before anything else on the app handle
+/// reads preferences, UI state, projects, or session metadata.
+pub fn run_startup_migrations(app: &AppHandle) -> Result<(), String> {
+    let app_data_dir = crate::data_dir::resolve(app)?;
+
+    migrate_json_file(
+        app,
+        &app_data_dir.join("preferences.json"),
+        "schema_version",
+        PREFERENCES_SCHEMA_VERSION,
+    )?;
+    migrate_json_file(
+        app,
+        &app_data_dir.join("projects.json"),
+        "schema_version",
+        PROJECTS_SCHEMA_VERSION,
+    )?;
+    migrate_json_file(
+        app,
+        &app_data_dir.join("ui-state.json"),
+        "version",
+        UI_STATE_SCHEMA_VERSION,
+    )?;
+
+    if let Ok(session_ids) = crate::chat::storage::list_all_session_ids(app) {
+        for session_id in &session_ids {
+            if let Ok(path) = crate::chat::storage::get_metadata_path(app, session_id) {
+                migrate_json_file(app, &path, "version", SESSION_METADATA_SCHEMA_VERSION)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Schema versions for each persisted document type, for diagnostics/support purposes.
+#[derive(Debug, Clone, Serialize)]
+pub struct StorageInfo {
+    pub preferences_schema_version: u32,
+    pub projects_schema_version: u32,
+    pub ui_state_schema_version: u32,
+    pub session_metadata_schema_version: u32,
+    pub session_count: usize,
+}
+
+/// Read `version_field` out of a persisted document for diagnostics. `encrypted_version` is
+/// returned for a document that's encrypted at rest (see encryption.rs) rather than trying
+/// to parse ciphertext as JSON - this app only ever encrypts a document after stamping its
+/// current schema version, so that's the correct answer, not 0.
+fn read_version_field(path: &Path, version_field: &str, encrypted_version: u32) -> u32 {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return 0;
+    };
+    if crate::encryption::is_encrypted(&contents) {
+        return encrypted_version;
+    }
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(&contents) else {
+        return 0;
+    };
+    value
+        .get(version_field)
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32
+}
+
+/// Report the on-disk schema version of each persisted document type, for support/debugging.
+#[tauri::command]
+pub async fn get_storage_info(app: AppHandle) -> Result<StorageInfo, String> {
+    let app_data_dir = crate::data_dir::resolve(&app)?;
+
+    let session_ids = crate::chat::storage::list_all_session_ids(&app).unwrap_or_default();
+    let session_metadata_schema_version = session_ids
+        .first()
+        .and_then(|id| crate::chat::storage::get_metadata_path(&app, id).ok())
+        .map(|path| read_version_field(&path, "version", SESSION_METADATA_SCHEMA_VERSION))
+        .unwrap_or(SESSION_METADATA_SCHEMA_VERSION);
+
+    Ok(StorageInfo {
+        preferences_schema_version: read_version_field(
+            &app_data_dir.join("preferences.json"),
+            "schema_version",
+            PREFERENCES_SCHEMA_VERSION,
+        ),
+        projects_schema_version: read_version_field(
+            &app_data_dir.join("projects.json"),
+            "schema_version",
+            PROJECTS_SCHEMA_VERSION,
+        ),
+        ui_state_schema_version: read_version_field(
+            &app_data_dir.join("ui-state.json"),
+            "version",
+            UI_STATE_SCHEMA_VERSION,
+        ),
+        session_metadata_schema_version,
+        session_count: session_ids.len(),
+    })
+}
@@ -0,0 +1,141 @@
+//! A read-only breakdown of where app data directory disk usage is going, grouped by
+//! worktree and by category (sessions, run logs, pasted images, pasted text, archives,
+//! recovery files). Meant to make growth visible before a user's disk fills up rather than
+//! to fix it - for actually reclaiming space, point them at the existing targeted cleanup
+//! commands: `cleanup_old_archives`, `cleanup_old_recovery_files`, and `trash::empty_trash`.
+
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+use tauri::AppHandle;
+
+use crate::chat::storage;
+
+/// Disk usage for a single worktree's sessions and run logs.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WorktreeStorageUsage {
+    pub worktree_id: String,
+    /// Bytes used by session metadata and message history (`sessions/data/{session_id}/metadata.json`).
+    pub session_bytes: u64,
+    /// Bytes used by run logs and their `.input.jsonl` counterparts for this worktree's sessions.
+    pub run_log_bytes: u64,
+    /// Bytes used by sessions in this worktree that are archived (a subset of `session_bytes`).
+    pub archived_session_bytes: u64,
+}
+
+/// Disk usage across the whole app data directory, broken down by category.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StorageUsage {
+    /// Per-worktree breakdown of session and run log usage.
+    pub worktrees: Vec<WorktreeStorageUsage>,
+    /// Bytes used by `pasted-images/` (shared across all worktrees, not attributable to one).
+    pub image_bytes: u64,
+    /// Bytes used by `pasted-texts/` (shared across all worktrees, not attributable to one).
+    pub pasted_text_bytes: u64,
+    /// Bytes used by `recovery/` (crash-recovery drafts, not attributable to a worktree).
+    pub recovery_bytes: u64,
+    /// Bytes used by `trash/` (soft-deleted contexts, sessions, and worktrees awaiting purge).
+    pub trash_bytes: u64,
+    /// Total bytes across every category above.
+    pub total_bytes: u64,
+}
+
+/// Sum the size of every regular file under `dir`, recursing into subdirectories. Missing
+/// directories contribute zero rather than erroring, since most of these are created lazily
+/// on first use.
+fn dir_size(dir: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                dir_size(&path)
+            } else {
+                fs::metadata(&path).map(|m| m.len()).unwrap_or(0)
+            }
+        })
+        .sum()
+}
+
+/// Bytes used by a session's run logs: everything in its data directory except `metadata.json`.
+fn run_log_bytes_for_session(app: &AppHandle, session_id: &str) -> u64 {
+    let Ok(session_dir) = storage::get_session_dir(app, session_id) else {
+        return 0;
+    };
+    let Ok(entries) = fs::read_dir(&session_dir) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .map(|entry| {
+            let path = entry.path();
+            if path.file_name().and_then(|n| n.to_str()) == Some("metadata.json") {
+                0
+            } else {
+                fs::metadata(&path).map(|m| m.len()).unwrap_or(0)
+            }
+        })
+        .sum()
+}
+
+/// Break down app data directory disk usage by sessions, run logs, pasted images, pasted
+/// text, and recovery files, with sessions and run logs further grouped per worktree.
+#[tauri::command]
+pub async fn get_storage_usage(app: AppHandle) -> Result<StorageUsage, String> {
+    let mut by_worktree: std::collections::HashMap<String, WorktreeStorageUsage> =
+        std::collections::HashMap::new();
+
+    for session_id in storage::list_all_session_ids(&app)? {
+        let Some(metadata) = storage::load_metadata(&app, &session_id)? else {
+            continue;
+        };
+
+        let metadata_path = storage::get_metadata_path(&app, &session_id)?;
+        let session_bytes = fs::metadata(&metadata_path).map(|m| m.len()).unwrap_or(0);
+        let run_log_bytes = run_log_bytes_for_session(&app, &session_id);
+
+        let entry = by_worktree
+            .entry(metadata.worktree_id.clone())
+            .or_insert_with(|| WorktreeStorageUsage {
+                worktree_id: metadata.worktree_id.clone(),
+                ..Default::default()
+            });
+        entry.session_bytes += session_bytes;
+        entry.run_log_bytes += run_log_bytes;
+        if metadata.archived_at.is_some() {
+            entry.archived_session_bytes += session_bytes;
+        }
+    }
+
+    let mut worktrees: Vec<WorktreeStorageUsage> = by_worktree.into_values().collect();
+    worktrees.sort_by(|a, b| a.worktree_id.cmp(&b.worktree_id));
+
+    let image_bytes = dir_size(&storage::get_images_dir(&app)?);
+    let pasted_text_bytes = dir_size(&storage::get_pastes_dir(&app)?);
+    let recovery_bytes = dir_size(&crate::data_dir::resolve(&app)?.join("recovery"));
+    let trash_bytes = dir_size(&crate::data_dir::resolve(&app)?.join("trash"));
+
+    let total_bytes = worktrees
+        .iter()
+        .map(|w| w.session_bytes + w.run_log_bytes)
+        .sum::<u64>()
+        + image_bytes
+        + pasted_text_bytes
+        + recovery_bytes
+        + trash_bytes;
+
+    Ok(StorageUsage {
+        worktrees,
+        image_bytes,
+        pasted_text_bytes,
+        recovery_bytes,
+        trash_bytes,
+        total_bytes,
+    })
+}
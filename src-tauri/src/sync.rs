@@ -0,0 +1,431 @@
+//! Opt-in cross-machine sync: keeps `projects.json` (and each session's `metadata.json`
+//! under `sessions/data/`) mirrored into a user-chosen directory - a Dropbox/Syncthing
+//! folder, or the working copy of a private git repo the user commits and pushes
+//! themselves - so two machines pointed at the same directory converge on the same
+//! projects and session history.
+//!
+//! This module does not itself talk to Dropbox, Syncthing, or git: `sync_dir` (see
+//! `AppPreferences`) is just a path, and whatever keeps that path's bytes in sync across
+//! machines is the user's choice of tool. `sync_now` only handles the read-merge-write step
+//! once both machines' files are visible in the same place.
+//!
+//! Merge strategy: projects and worktrees are unioned by id; sessions are unioned by id
+//! within a worktree. Where the same id exists on both sides with different content, this
+//! is a **conflict** - there is no per-field merge here, only whole-document comparison -
+//! and it's resolved by taking whichever file has the newer filesystem mtime, since neither
+//! `Project`, `Worktree`, nor `SessionMetadata` currently carries an `updated_at` field to
+//! compare instead. Every conflict is reported back in `SyncResult::conflicts` so the UI can
+//! surface it, even though it was auto-resolved.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+use crate::platform::FileLock;
+use crate::projects::types::{Project, ProjectsData, Worktree};
+
+/// One id that existed on both sides of a sync with different content, auto-resolved by
+/// mtime. Reported for visibility, not because the resolution needs user input.
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncConflict {
+    pub entity_type: String,
+    pub id: String,
+    pub resolved_with: String, // "local" or "remote"
+}
+
+/// Summary of one `sync_now` run.
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncResult {
+    pub projects_synced: usize,
+    pub worktrees_synced: usize,
+    pub sessions_synced: usize,
+    pub conflicts: Vec<SyncConflict>,
+}
+
+fn mtime(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Merge two lists of ids-having items, preferring whichever side `local_is_newer` says is
+/// newer on conflicts. `local_is_newer` is computed once from the backing file's mtime
+/// (projects and worktrees share one `projects.json`, so there's no finer-grained mtime to
+/// compare per item).
+fn merge_by_id<T, F>(
+    local: Vec<T>,
+    remote: Vec<T>,
+    entity_type: &str,
+    id_of: F,
+    local_is_newer: bool,
+    conflicts: &mut Vec<SyncConflict>,
+) -> Vec<T>
+where
+    T: Clone + PartialEq,
+    F: Fn(&T) -> String,
+{
+    let mut merged: Vec<T> = Vec::new();
+    let mut seen_ids: Vec<String> = Vec::new();
+
+    for local_item in &local {
+        let id = id_of(local_item);
+        let remote_item = remote.iter().find(|r| id_of(r) == id);
+
+        match remote_item {
+            None => merged.push(local_item.clone()),
+            Some(remote_item) if remote_item == local_item => merged.push(local_item.clone()),
+            Some(remote_item) => {
+                conflicts.push(SyncConflict {
+                    entity_type: entity_type.to_string(),
+                    id: id.clone(),
+                    resolved_with: if local_is_newer { "local" } else { "remote" }.to_string(),
+                });
+                merged.push(if local_is_newer {
+                    local_item.clone()
+                } else {
+                    remote_item.clone()
+                });
+            }
+        }
+        seen_ids.push(id);
+    }
+
+    for remote_item in &remote {
+        let id = id_of(remote_item);
+        if !seen_ids.contains(&id) {
+            merged.push(remote_item.clone());
+        }
+    }
+
+    merged
+}
+
+fn load_projects_data_from(path: &Path) -> Result<ProjectsData, String> {
+    if !path.exists() {
+        return Ok(ProjectsData::default());
+    }
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse {}: {e}", path.display()))
+}
+
+/// Write `data` to `path` atomically (temp file + rename), matching the pattern every other
+/// writer of `projects.json` uses (see `projects::storage::save_projects_data_internal`) -
+/// a crash mid-write must never leave a partial/corrupt file on either side of the sync.
+fn write_projects_data_to(path: &Path, data: &ProjectsData) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {e}", parent.display()))?;
+    }
+    let json_content = serde_json::to_string_pretty(data)
+        .map_err(|e| format!("Failed to serialize {}: {e}", path.display()))?;
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, json_content)
+        .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    std::fs::rename(&temp_path, path)
+        .map_err(|e| format!("Failed to finalize {}: {e}", path.display()))
+}
+
+/// Read+merge+write `path` (either side of the sync) under a cross-process `FileLock`, so a
+/// sync pass can't interleave with another process writing the same file concurrently (see
+/// `instance_lock.rs` for why `FileLock` exists at all).
+fn with_projects_file_lock<T>(
+    path: &Path,
+    f: impl FnOnce() -> Result<T, String>,
+) -> Result<T, String> {
+    let _file_lock = FileLock::acquire(path)?;
+    f()
+}
+
+/// Sync session metadata for one worktree's sessions between `local_data_dir` and
+/// `remote_data_dir` (each is a `sessions/data/` directory), unioned by session id with
+/// mtime-wins conflict resolution on the raw JSON.
+fn sync_session_dirs(
+    local_data_dir: &Path,
+    remote_data_dir: &Path,
+    conflicts: &mut Vec<SyncConflict>,
+) -> Result<usize, String> {
+    let mut session_ids: Vec<String> = Vec::new();
+    for dir in [local_data_dir, remote_data_dir] {
+        if let Ok(entries) = std::fs::read_dir(dir) {
+            for entry in entries.flatten() {
+                if let Some(name) = entry.file_name().to_str() {
+                    let has_metadata = entry.path().join("metadata.json").exists();
+                    if has_metadata && !session_ids.contains(&name.to_string()) {
+                        session_ids.push(name.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    let mut synced = 0;
+    for session_id in &session_ids {
+        let local_path = local_data_dir.join(session_id).join("metadata.json");
+        let remote_path = remote_data_dir.join(session_id).join("metadata.json");
+
+        // Hold the same in-process lock `with_metadata_mut`/`save_metadata` take on this
+        // session, plus a cross-process `FileLock` on each side, for the whole
+        // read-compare-copy so a concurrent local save can't interleave with the sync.
+        let _session_lock = crate::chat::storage::get_metadata_lock(session_id);
+        let _session_guard = _session_lock.lock().unwrap();
+        let _local_file_lock = FileLock::acquire(&local_path)?;
+        let _remote_file_lock = FileLock::acquire(&remote_path)?;
+
+        let (source, dest) = match (local_path.exists(), remote_path.exists()) {
+            (true, false) => (local_path.clone(), remote_path.clone()),
+            (false, true) => (remote_path.clone(), local_path.clone()),
+            (true, true) => {
+                let local_contents = std::fs::read_to_string(&local_path).ok();
+                let remote_contents = std::fs::read_to_string(&remote_path).ok();
+                if local_contents == remote_contents {
+                    continue;
+                }
+                let local_is_newer = match (mtime(&local_path), mtime(&remote_path)) {
+                    (Some(l), Some(r)) => l >= r,
+                    _ => true,
+                };
+                conflicts.push(SyncConflict {
+                    entity_type: "session".to_string(),
+                    id: session_id.clone(),
+                    resolved_with: if local_is_newer { "local" } else { "remote" }.to_string(),
+                });
+                if local_is_newer {
+                    (local_path.clone(), remote_path.clone())
+                } else {
+                    (remote_path.clone(), local_path.clone())
+                }
+            }
+            (false, false) => continue,
+        };
+
+        atomic_copy(&source, &dest)?;
+        synced += 1;
+    }
+
+    Ok(synced)
+}
+
+/// Copy `source` to `dest` atomically (copy to a temp file in `dest`'s directory, then
+/// rename) instead of a bare `fs::copy`, so a crash mid-sync can't leave a half-written
+/// `metadata.json` for another process to read.
+fn atomic_copy(source: &Path, dest: &Path) -> Result<(), String> {
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {e}", parent.display()))?;
+    }
+    let temp_path = dest.with_extension("tmp");
+    std::fs::copy(source, &temp_path)
+        .map_err(|e| format!("Failed to sync {}: {e}", source.display()))?;
+    std::fs::rename(&temp_path, dest)
+        .map_err(|e| format!("Failed to finalize {}: {e}", dest.display()))
+}
+
+/// Run one sync pass between this machine's app data and `AppPreferences::sync_dir`.
+/// Returns an error if sync is disabled, no directory is configured, or encryption at rest
+/// is enabled (see below).
+#[tauri::command]
+pub async fn sync_now(app: AppHandle) -> Result<SyncResult, String> {
+    let preferences = crate::load_preferences_sync(&app)?;
+    if !preferences.sync_enabled {
+        return Err("Sync is not enabled (see AppPreferences::sync_enabled)".to_string());
+    }
+    if preferences.encryption_enabled {
+        // `encryption::get_or_create_key` generates a random per-machine key stored only in
+        // that machine's OS keychain (see encryption.rs's module doc) - there is no way yet
+        // to share it with a second machine. Mirroring an encrypted session's metadata.json
+        // there would leave it permanently undecryptable (AES-GCM auth failure) on every
+        // machine but the one that wrote it, so refuse the whole sync rather than risk that.
+        return Err(
+            "Sync is not supported while encryption at rest is enabled (see \
+             AppPreferences::encryption_enabled) - the encryption key lives only in this \
+             machine's OS keychain and can't yet be shared with the sync target"
+                .to_string(),
+        );
+    }
+    let sync_dir = preferences
+        .sync_dir
+        .ok_or_else(|| "No sync directory configured".to_string())?;
+    let sync_dir = PathBuf::from(sync_dir);
+    std::fs::create_dir_all(&sync_dir).map_err(|e| {
+        format!(
+            "Failed to create sync directory {}: {e}",
+            sync_dir.display()
+        )
+    })?;
+
+    let app_data_dir = crate::data_dir::resolve(&app)?;
+
+    let local_projects_path = crate::projects::storage::get_projects_path(&app)?;
+    let remote_projects_path = sync_dir.join("projects.json");
+
+    // Local projects.json goes through the same `PROJECTS_LOCK` + `FileLock` + atomic-write
+    // path every other reader/writer uses, so the sync can't race a concurrent save from the
+    // rest of the app. The remote copy gets its own `FileLock`, since it's outside
+    // `projects::storage`'s purview but still needs the same interleaving protection.
+    let local = crate::projects::storage::load_projects_data(&app)?;
+    let remote = with_projects_file_lock(&remote_projects_path, || {
+        load_projects_data_from(&remote_projects_path)
+    })?;
+
+    let local_is_newer = match (mtime(&local_projects_path), mtime(&remote_projects_path)) {
+        (Some(l), Some(r)) => l >= r,
+        (Some(_), None) => true,
+        _ => false,
+    };
+
+    let mut conflicts = Vec::new();
+
+    let projects: Vec<Project> = merge_by_id(
+        local.projects,
+        remote.projects,
+        "project",
+        |p: &Project| p.id.clone(),
+        local_is_newer,
+        &mut conflicts,
+    );
+    let worktrees: Vec<Worktree> = merge_by_id(
+        local.worktrees,
+        remote.worktrees,
+        "worktree",
+        |w: &Worktree| w.id.clone(),
+        local_is_newer,
+        &mut conflicts,
+    );
+
+    let merged = ProjectsData {
+        projects: projects.clone(),
+        worktrees: worktrees.clone(),
+        schema_version: crate::storage_migrations::PROJECTS_SCHEMA_VERSION,
+    };
+
+    crate::projects::storage::save_projects_data(&app, &merged)?;
+    with_projects_file_lock(&remote_projects_path, || {
+        write_projects_data_to(&remote_projects_path, &merged)
+    })?;
+
+    let local_sessions_dir = app_data_dir.join("sessions").join("data");
+    let remote_sessions_dir = sync_dir.join("sessions").join("data");
+    let sessions_synced =
+        sync_session_dirs(&local_sessions_dir, &remote_sessions_dir, &mut conflicts)?;
+
+    Ok(SyncResult {
+        projects_synced: projects.len(),
+        worktrees_synced: worktrees.len(),
+        sessions_synced,
+        conflicts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Item {
+        id: String,
+        value: i32,
+    }
+
+    fn item(id: &str, value: i32) -> Item {
+        Item {
+            id: id.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_merge_by_id_unions_disjoint_sets() {
+        let local = vec![item("a", 1)];
+        let remote = vec![item("b", 2)];
+        let mut conflicts = Vec::new();
+
+        let merged = merge_by_id(
+            local,
+            remote,
+            "item",
+            |i: &Item| i.id.clone(),
+            true,
+            &mut conflicts,
+        );
+
+        assert_eq!(merged, vec![item("a", 1), item("b", 2)]);
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_merge_by_id_keeps_identical_items_without_conflict() {
+        let local = vec![item("a", 1)];
+        let remote = vec![item("a", 1)];
+        let mut conflicts = Vec::new();
+
+        let merged = merge_by_id(
+            local,
+            remote,
+            "item",
+            |i: &Item| i.id.clone(),
+            true,
+            &mut conflicts,
+        );
+
+        assert_eq!(merged, vec![item("a", 1)]);
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_merge_by_id_conflict_resolves_to_local_when_local_is_newer() {
+        let local = vec![item("a", 1)];
+        let remote = vec![item("a", 2)];
+        let mut conflicts = Vec::new();
+
+        let merged = merge_by_id(
+            local,
+            remote,
+            "item",
+            |i: &Item| i.id.clone(),
+            true,
+            &mut conflicts,
+        );
+
+        assert_eq!(merged, vec![item("a", 1)]);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].id, "a");
+        assert_eq!(conflicts[0].entity_type, "item");
+        assert_eq!(conflicts[0].resolved_with, "local");
+    }
+
+    #[test]
+    fn test_merge_by_id_conflict_resolves_to_remote_when_remote_is_newer() {
+        let local = vec![item("a", 1)];
+        let remote = vec![item("a", 2)];
+        let mut conflicts = Vec::new();
+
+        let merged = merge_by_id(
+            local,
+            remote,
+            "item",
+            |i: &Item| i.id.clone(),
+            false,
+            &mut conflicts,
+        );
+
+        assert_eq!(merged, vec![item("a", 2)]);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].resolved_with, "remote");
+    }
+
+    #[test]
+    fn test_merge_by_id_empty_inputs() {
+        let mut conflicts = Vec::new();
+        let merged: Vec<Item> = merge_by_id(
+            vec![],
+            vec![],
+            "item",
+            |i: &Item| i.id.clone(),
+            true,
+            &mut conflicts,
+        );
+        assert!(merged.is_empty());
+        assert!(conflicts.is_empty());
+    }
+}
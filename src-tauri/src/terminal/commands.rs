@@ -1,18 +1,57 @@
 use tauri::AppHandle;
 
 use super::pty::{
-    kill_all_terminals as pty_kill_all_terminals, kill_terminal, resize_terminal, spawn_terminal,
-    write_to_terminal,
+    kill_all_terminals as pty_kill_all_terminals, kill_terminal,
+    reattach_terminal as pty_reattach_terminal, resize_terminal, spawn_terminal, write_to_terminal,
 };
-use super::registry::{get_all_terminal_ids, has_terminal};
+use super::registry::{get_all_terminal_ids, has_terminal, list_for_worktree};
+use super::types::{ReattachResult, TerminalProfile, TerminalStatus};
+use crate::projects::env_files::load_dotenv_vars;
 use crate::projects::git::read_jean_config;
+use crate::projects::storage::load_projects_data;
 
-/// Start a terminal
+/// Fill in `profile.shell`/`profile.startup_command` from the owning project's settings, and
+/// its allowlisted `.env`/`.env.local` variables into `profile.env`, when the caller didn't
+/// already set them explicitly.
+fn apply_project_defaults(
+    app: &AppHandle,
+    worktree_id: &str,
+    worktree_path: &str,
+    profile: &mut TerminalProfile,
+) {
+    let Ok(data) = load_projects_data(app) else {
+        return;
+    };
+    let Some(project) = data
+        .find_worktree(worktree_id)
+        .and_then(|w| data.find_project(&w.project_id))
+    else {
+        return;
+    };
+
+    if profile.shell.is_none() {
+        profile.shell = project.shell.clone();
+    }
+    if profile.startup_command.is_none() {
+        profile.startup_command = project.shell_startup_command.clone();
+    }
+    for (key, value) in load_dotenv_vars(worktree_path, &project.dotenv_allowlist) {
+        profile.env.entry(key).or_insert(value);
+    }
+}
+
+/// Start a terminal. `name` distinguishes it from other terminals on the same worktree (e.g.
+/// "server", "tests"); `profile` optionally overrides the shell, cwd, and env it starts with -
+/// falling back to the owning project's `shell`/`shell_startup_command` settings and
+/// allowlisted `.env` variables, then finally the user's default shell.
 #[tauri::command]
 pub async fn start_terminal(
     app: AppHandle,
     terminal_id: String,
+    worktree_id: String,
     worktree_path: String,
+    name: String,
+    profile: Option<TerminalProfile>,
     cols: u16,
     rows: u16,
     command: Option<String>,
@@ -24,7 +63,26 @@ pub async fn start_terminal(
         return Err("Terminal already exists".to_string());
     }
 
-    spawn_terminal(&app, terminal_id, worktree_path, cols, rows, command)
+    let mut profile = profile.unwrap_or_default();
+    apply_project_defaults(&app, &worktree_id, &worktree_path, &mut profile);
+
+    spawn_terminal(
+        &app,
+        terminal_id,
+        worktree_id,
+        worktree_path,
+        name,
+        profile,
+        cols,
+        rows,
+        command,
+    )
+}
+
+/// List every tracked terminal for `worktree_id` with its name and current size.
+#[tauri::command]
+pub async fn list_terminals(worktree_id: String) -> Vec<TerminalStatus> {
+    list_for_worktree(&worktree_id)
 }
 
 /// Get the run script from jean.json for a worktree
@@ -65,6 +123,18 @@ pub async fn has_active_terminal(terminal_id: String) -> bool {
     has_terminal(&terminal_id)
 }
 
+/// Reattach to a terminal that's still running (e.g. after a frontend reload), returning its
+/// buffered scrollback to replay. `None` if the terminal isn't running anymore - note this
+/// can't bring a PTY back after the app itself has restarted, since the shell is a child
+/// process of this one and exits with it (see `terminal::persistence`).
+#[tauri::command]
+pub async fn reattach_terminal(
+    worktree_id: String,
+    terminal_id: String,
+) -> Option<ReattachResult> {
+    pty_reattach_terminal(&worktree_id, &terminal_id)
+}
+
 /// Kill all active terminals (used during app shutdown/refresh)
 #[tauri::command]
 pub fn kill_all_terminals() -> usize {
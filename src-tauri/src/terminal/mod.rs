@@ -1,4 +1,5 @@
 mod commands;
+mod persistence;
 mod pty;
 mod registry;
 mod types;
@@ -8,3 +9,7 @@ pub use commands::*;
 
 // Re-export internal functions for app lifecycle cleanup
 pub use pty::kill_all_terminals as cleanup_all_terminals;
+pub use persistence::take_orphaned as take_orphaned_terminals;
+
+// Re-export for resource-usage sampling (see `chat::registry::collect_process_stats`)
+pub use registry::get_all_terminal_pids;
@@ -0,0 +1,89 @@
+//! Lightweight persisted record of which terminals were running, written alongside the
+//! in-memory registry in `registry.rs`.
+//!
+//! This does NOT resurrect a terminal's actual PTY after an app restart - the shell is a
+//! child process of this one and exits when it does, so there's nothing left to reattach
+//! to. What it does give us is a way to tell the difference between "no terminal was ever
+//! open here" and "a terminal was open here when the app last closed", so the UI can
+//! surface the latter instead of silently forgetting it. See `reattach_terminal` in
+//! `commands.rs` for the in-process case (e.g. a frontend reload), which *can* replay
+//! scrollback because the PTY is still alive.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tauri::AppHandle;
+
+/// What we know about a terminal while it's running, persisted so it isn't lost on restart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalMetadata {
+    pub terminal_id: String,
+    pub worktree_id: String,
+    pub worktree_path: String,
+    pub name: String,
+    pub cols: u16,
+    pub rows: u16,
+    pub started_at: u64,
+}
+
+fn index_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(crate::data_dir::resolve(app)?.join("terminals.json"))
+}
+
+fn load_index(app: &AppHandle) -> HashMap<String, TerminalMetadata> {
+    let Ok(path) = index_path(app) else {
+        return HashMap::new();
+    };
+    std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_index(app: &AppHandle, index: &HashMap<String, TerminalMetadata>) -> Result<(), String> {
+    let path = index_path(app)?;
+    let json = serde_json::to_string_pretty(index)
+        .map_err(|e| format!("Failed to serialize terminal index: {e}"))?;
+
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, json).map_err(|e| format!("Failed to write terminal index: {e}"))?;
+    std::fs::rename(&temp_path, &path)
+        .map_err(|e| format!("Failed to finalize terminal index: {e}"))?;
+    Ok(())
+}
+
+/// Record that a terminal started. Best-effort: a failure to persist never blocks the
+/// terminal itself from starting.
+pub fn record_started(app: &AppHandle, metadata: TerminalMetadata) {
+    let mut index = load_index(app);
+    let terminal_id = metadata.terminal_id.clone();
+    index.insert(terminal_id.clone(), metadata);
+    if let Err(e) = save_index(app, &index) {
+        log::warn!("Failed to persist metadata for terminal {terminal_id}: {e}");
+    }
+}
+
+/// Record that a terminal stopped, removing it from the persisted index.
+pub fn record_stopped(app: &AppHandle, terminal_id: &str) {
+    let mut index = load_index(app);
+    if index.remove(terminal_id).is_none() {
+        return;
+    }
+    if let Err(e) = save_index(app, &index) {
+        log::warn!("Failed to remove terminal {terminal_id} from persisted index: {e}");
+    }
+}
+
+/// Drain and return every terminal the index thinks was running. Meant to be called once at
+/// startup: since none of these PTYs survived the restart, the index is cleared as it's read
+/// so later calls don't keep reporting the same orphans.
+pub fn take_orphaned(app: &AppHandle) -> Vec<TerminalMetadata> {
+    let index = load_index(app);
+    if index.is_empty() {
+        return Vec::new();
+    }
+    if let Err(e) = save_index(app, &HashMap::new()) {
+        log::warn!("Failed to clear terminal index after restart: {e}");
+    }
+    index.into_values().collect()
+}
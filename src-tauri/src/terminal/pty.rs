@@ -2,28 +2,44 @@ use portable_pty::{native_pty_system, CommandBuilder, PtySize};
 use std::io::Read;
 use std::sync::Mutex;
 use std::thread;
-use tauri::{AppHandle, Emitter};
+use tauri::AppHandle;
 
+use super::persistence::{self, TerminalMetadata};
 use super::registry::{register_terminal, unregister_terminal};
 use super::types::{
-    TerminalOutputEvent, TerminalSession, TerminalStartedEvent, TerminalStoppedEvent,
+    ReattachResult, TerminalOutputEvent, TerminalProfile, TerminalSession, TerminalStartedEvent,
+    TerminalStoppedEvent,
 };
+use crate::http_server::EmitExt;
+
+/// How much trailing output each terminal keeps around for `reattach_terminal` to replay.
+pub const SCROLLBACK_CAP_BYTES: usize = 256 * 1024;
 
 /// Detect user's default shell (cross-platform)
 fn get_user_shell() -> String {
     crate::platform::get_default_shell()
 }
 
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
 /// Spawn a terminal, optionally running a command
 pub fn spawn_terminal(
     app: &AppHandle,
     terminal_id: String,
+    worktree_id: String,
     worktree_path: String,
+    name: String,
+    profile: TerminalProfile,
     cols: u16,
     rows: u16,
     command: Option<String>,
 ) -> Result<(), String> {
-    log::trace!("Spawning terminal {terminal_id} at {worktree_path}");
+    log::trace!("Spawning terminal {terminal_id} ({name}) at {worktree_path}");
     if let Some(ref cmd) = command {
         log::trace!("Running command: {cmd}");
     }
@@ -40,10 +56,17 @@ pub fn spawn_terminal(
         })
         .map_err(|e| format!("Failed to open PTY: {e}"))?;
 
-    // Get user's shell
-    let shell = get_user_shell();
+    // Get the shell: the profile's choice if given, otherwise the user's default
+    let shell = profile.shell.clone().unwrap_or_else(get_user_shell);
     log::trace!("Using shell: {shell}");
 
+    // Resolve cwd: the profile's path relative to the worktree root, or the root itself
+    let cwd = match &profile.cwd {
+        Some(relative) => std::path::Path::new(&worktree_path).join(relative),
+        None => std::path::PathBuf::from(&worktree_path),
+    };
+    let cwd = crate::platform::paths::normalize(&cwd);
+
     // Build command - either run a specific command or start interactive shell
     let mut cmd = if let Some(ref run_command) = command {
         // Run the command in shell, then keep shell open for inspection
@@ -68,10 +91,13 @@ pub fn spawn_terminal(
     } else {
         CommandBuilder::new(&shell)
     };
-    cmd.cwd(&worktree_path);
+    cmd.cwd(&cwd);
     cmd.env("TERM", "xterm-256color");
     cmd.env("COLORTERM", "truecolor");
     cmd.env("JEAN_WORKTREE_PATH", &worktree_path);
+    for (key, value) in &profile.env {
+        cmd.env(key, value);
+    }
 
     // Spawn the shell
     let child = pair
@@ -88,12 +114,25 @@ pub fn spawn_terminal(
         .map_err(|e| format!("Failed to clone reader: {e}"))?;
 
     // Get writer from master (must be taken once and stored)
-    let writer = pair
+    let mut writer = pair
         .master
         .take_writer()
         .map_err(|e| format!("Failed to take writer: {e}"))?;
 
+    // Type the startup command into the freshly-spawned shell, as if the user had. Skipped
+    // when `command` is set, since that already owns the shell's input via `-c`.
+    if command.is_none() {
+        if let Some(startup_command) = &profile.startup_command {
+            use std::io::Write;
+            if let Err(e) = writeln!(writer, "{startup_command}") {
+                log::warn!("Failed to write startup command to terminal {terminal_id}: {e}");
+            }
+        }
+    }
+
     // Register the session
+    let started_at = now();
+    let pid = child.process_id();
     let session = TerminalSession {
         terminal_id: terminal_id.clone(),
         master: pair.master,
@@ -101,16 +140,43 @@ pub fn spawn_terminal(
         child,
         cols,
         rows,
+        worktree_id: worktree_id.clone(),
+        name: name.clone(),
+        started_at,
+        scrollback: Vec::new(),
     };
     register_terminal(session);
 
+    if let Some(pid) = pid {
+        crate::process_reaper::record_started(
+            app,
+            &terminal_id,
+            crate::chat::registry::ProcessKind::Terminal,
+            pid,
+        );
+    }
+    crate::power::job_started(app);
+
+    persistence::record_started(
+        app,
+        TerminalMetadata {
+            terminal_id: terminal_id.clone(),
+            worktree_id: worktree_id.clone(),
+            worktree_path: worktree_path.clone(),
+            name,
+            cols,
+            rows,
+            started_at,
+        },
+    );
+
     // Emit started event
     let started_event = TerminalStartedEvent {
         terminal_id: terminal_id.clone(),
         cols,
         rows,
     };
-    if let Err(e) = app.emit("terminal:started", &started_event) {
+    if let Err(e) = app.emit_all("terminal:started", &started_event) {
         log::error!("Failed to emit terminal:started event: {e}");
     }
 
@@ -129,11 +195,20 @@ pub fn spawn_terminal(
                 Ok(n) => {
                     // Convert bytes to string (lossy conversion for non-UTF8)
                     let data = String::from_utf8_lossy(&buf[..n]).to_string();
+
+                    super::registry::with_terminal(&terminal_id_clone, |session| {
+                        session.scrollback.extend_from_slice(&buf[..n]);
+                        if session.scrollback.len() > SCROLLBACK_CAP_BYTES {
+                            let excess = session.scrollback.len() - SCROLLBACK_CAP_BYTES;
+                            session.scrollback.drain(0..excess);
+                        }
+                    });
+
                     let event = TerminalOutputEvent {
                         terminal_id: terminal_id_clone.clone(),
                         data,
                     };
-                    if let Err(e) = app_clone.emit("terminal:output", &event) {
+                    if let Err(e) = app_clone.emit_all("terminal:output", &event) {
                         log::error!("Failed to emit terminal:output event: {e}");
                     }
                 }
@@ -146,6 +221,10 @@ pub fn spawn_terminal(
 
         // Terminal has exited, get exit code and cleanup
         if let Some(mut session) = unregister_terminal(&terminal_id_clone) {
+            persistence::record_stopped(&app_clone, &terminal_id_clone);
+            crate::process_reaper::record_stopped(&app_clone, &terminal_id_clone);
+            crate::power::job_stopped();
+
             let exit_code = session.child.wait().ok().and_then(|s| {
                 if s.success() {
                     Some(0)
@@ -155,11 +234,19 @@ pub fn spawn_terminal(
                 }
             });
 
+            crate::activity::record(
+                &app_clone,
+                &session.worktree_id,
+                crate::activity::ActivityKind::Terminal,
+                session.started_at,
+                now().saturating_sub(session.started_at),
+            );
+
             let stopped_event = TerminalStoppedEvent {
                 terminal_id: terminal_id_clone,
                 exit_code,
             };
-            if let Err(e) = app_clone.emit("terminal:stopped", &stopped_event) {
+            if let Err(e) = app_clone.emit_all("terminal:stopped", &stopped_event) {
                 log::error!("Failed to emit terminal:stopped event: {e}");
             }
         }
@@ -207,6 +294,10 @@ pub fn resize_terminal(terminal_id: &str, cols: u16, rows: u16) -> Result<(), St
 /// Kill a terminal
 pub fn kill_terminal(app: &AppHandle, terminal_id: &str) -> Result<bool, String> {
     if let Some(mut session) = unregister_terminal(terminal_id) {
+        persistence::record_stopped(app, terminal_id);
+        crate::process_reaper::record_stopped(app, terminal_id);
+        crate::power::job_stopped();
+
         // Kill the child process - try graceful termination first
         if let Some(pid) = session.child.process_id() {
             if let Err(e) = crate::platform::terminate_process(pid) {
@@ -217,12 +308,20 @@ pub fn kill_terminal(app: &AppHandle, terminal_id: &str) -> Result<bool, String>
         // Wait for the process to exit
         let _ = session.child.kill();
 
+        crate::activity::record(
+            app,
+            &session.worktree_id,
+            crate::activity::ActivityKind::Terminal,
+            session.started_at,
+            now().saturating_sub(session.started_at),
+        );
+
         // Emit stopped event
         let stopped_event = TerminalStoppedEvent {
             terminal_id: terminal_id.to_string(),
             exit_code: None,
         };
-        if let Err(e) = app.emit("terminal:stopped", &stopped_event) {
+        if let Err(e) = app.emit_all("terminal:stopped", &stopped_event) {
             log::error!("Failed to emit terminal:stopped event: {e}");
         }
 
@@ -232,6 +331,23 @@ pub fn kill_terminal(app: &AppHandle, terminal_id: &str) -> Result<bool, String>
     }
 }
 
+/// Reattach to a terminal that's still alive in this process, returning its buffered
+/// scrollback so the caller can replay it into a fresh xterm.js instance. Returns `None` if
+/// the terminal has exited or belongs to a different worktree.
+pub fn reattach_terminal(worktree_id: &str, terminal_id: &str) -> Option<ReattachResult> {
+    super::registry::with_terminal(terminal_id, |session| {
+        if session.worktree_id != worktree_id {
+            return None;
+        }
+        Some(ReattachResult {
+            scrollback: String::from_utf8_lossy(&session.scrollback).to_string(),
+            cols: session.cols,
+            rows: session.rows,
+        })
+    })
+    .flatten()
+}
+
 /// Kill all active terminals (used during app shutdown)
 pub fn kill_all_terminals() -> usize {
     use super::registry::TERMINAL_SESSIONS;
@@ -2,7 +2,7 @@ use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::Mutex;
 
-use super::types::TerminalSession;
+use super::types::{TerminalSession, TerminalStatus};
 
 /// Global registry of active terminal sessions (terminal_id -> session)
 pub static TERMINAL_SESSIONS: Lazy<Mutex<HashMap<String, TerminalSession>>> =
@@ -32,6 +32,38 @@ pub fn get_all_terminal_ids() -> Vec<String> {
     sessions.keys().cloned().collect()
 }
 
+/// PIDs of every live terminal's shell process, keyed by terminal ID, for resource-usage
+/// sampling (see `chat::registry::collect_process_stats`). Terminals whose child process
+/// couldn't report a PID are omitted.
+pub fn get_all_terminal_pids() -> Vec<(String, u32)> {
+    let sessions = TERMINAL_SESSIONS.lock().unwrap();
+    sessions
+        .values()
+        .filter_map(|session| {
+            session
+                .child
+                .process_id()
+                .map(|pid| (session.terminal_id.clone(), pid))
+        })
+        .collect()
+}
+
+/// Status of every tracked terminal belonging to `worktree_id`.
+pub fn list_for_worktree(worktree_id: &str) -> Vec<TerminalStatus> {
+    let sessions = TERMINAL_SESSIONS.lock().unwrap();
+    sessions
+        .values()
+        .filter(|session| session.worktree_id == worktree_id)
+        .map(|session| TerminalStatus {
+            terminal_id: session.terminal_id.clone(),
+            name: session.name.clone(),
+            cols: session.cols,
+            rows: session.rows,
+            started_at: session.started_at,
+        })
+        .collect()
+}
+
 /// Execute a function with mutable access to a terminal session
 pub fn with_terminal<F, R>(terminal_id: &str, f: F) -> Option<R>
 where
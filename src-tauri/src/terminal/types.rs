@@ -1,5 +1,6 @@
 use portable_pty::{Child, MasterPty};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::Write;
 use std::sync::Mutex;
 
@@ -25,6 +26,43 @@ pub struct TerminalStoppedEvent {
     pub exit_code: Option<i32>,
 }
 
+/// Returned by `reattach_terminal` when the requested terminal is still alive.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReattachResult {
+    pub scrollback: String,
+    pub cols: u16,
+    pub rows: u16,
+}
+
+/// How a terminal should be spawned, beyond the defaults (user's shell, worktree root, no
+/// extra env). All fields are optional so most callers can pass `TerminalProfile::default()`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TerminalProfile {
+    /// Shell binary to run instead of the user's default (e.g. `/bin/bash`).
+    pub shell: Option<String>,
+    /// Working directory relative to the worktree root (e.g. `server` or `tests`).
+    pub cwd: Option<String>,
+    /// Extra environment variables to set on top of the usual `TERM`/`COLORTERM`/
+    /// `JEAN_WORKTREE_PATH`.
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Command to type into the shell right after it starts (e.g. `nvm use`). Only applies
+    /// to interactive terminals (ignored when `command` is also set, since that already
+    /// takes over the shell's input).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub startup_command: Option<String>,
+}
+
+/// Summary of a tracked terminal, returned by `list_terminals`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TerminalStatus {
+    pub terminal_id: String,
+    pub name: String,
+    pub cols: u16,
+    pub rows: u16,
+    pub started_at: u64,
+}
+
 /// Active terminal session state
 pub struct TerminalSession {
     pub terminal_id: String,
@@ -33,4 +71,14 @@ pub struct TerminalSession {
     pub child: Box<dyn Child + Send + Sync>,
     pub cols: u16,
     pub rows: u16,
+    /// Worktree this terminal was opened for (see `getOrCreateTerminal`'s synthetic
+    /// `"cli-login"` id for terminals not tied to a real worktree).
+    pub worktree_id: String,
+    /// User-facing name (e.g. "server", "tests"), distinct from `terminal_id`, so several
+    /// terminals for the same worktree can be told apart in `list_terminals`.
+    pub name: String,
+    /// Unix timestamp when the terminal was spawned, for activity tracking (see `crate::activity`).
+    pub started_at: u64,
+    /// Trailing output, capped at `pty::SCROLLBACK_CAP_BYTES`, replayed by `reattach_terminal`.
+    pub scrollback: Vec<u8>,
 }
@@ -0,0 +1,366 @@
+//! A trash layer for destructive operations that used to be irreversible: `delete_context_file`,
+//! `delete_archived_session`, and `permanently_delete_worktree` all now route through here
+//! instead of discarding things outright. What gets kept around, and what "restoring" an entry
+//! means, differs per kind - see [`TrashEntryKind`] - but every entry is purged for good once
+//! [`TRASH_RETENTION_DAYS`] passes, by the same background sweep started from `lib.rs::run()`.
+//!
+//! Entries live in a single `trash/index.json` under the resolved app data directory (so a
+//! custom data directory, see `data_dir`, carries its trash with it), with trashed file
+//! contents (if any) alongside it under `trash/<entry-id>/`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use uuid::Uuid;
+
+use crate::projects::git;
+use crate::projects::types::Worktree;
+
+/// How long a trashed item survives before the background sweep purges it for good.
+const TRASH_RETENTION_DAYS: u64 = 30;
+
+/// How often the background sweep checks for expired trash entries.
+const SWEEP_INTERVAL_SECS: u64 = 6 * 60 * 60; // 6 hours
+
+/// Global mutex to prevent concurrent read-modify-write races on trash/index.json.
+static TRASH_INDEX_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// What a `TrashEntry` holds, and therefore how restoring or finally purging it works.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TrashEntryKind {
+    /// A saved context file moved out of the session-context directory.
+    ContextFile {
+        /// Where the file lived before it was trashed, so restore can put it back.
+        original_path: String,
+        /// Where the file was moved to under the trash directory.
+        trashed_path: String,
+    },
+    /// An archived session removed from its worktree's session index. `delete_archived_session`
+    /// never deleted the session's own data directory (messages, run logs) - only the index
+    /// entry - so restoring just means reinserting this snapshot; purging for good means finally
+    /// deleting that data directory.
+    ArchivedSession {
+        worktree_id: String,
+        worktree_path: String,
+        session: Box<crate::chat::types::Session>,
+    },
+    /// A permanently-deleted worktree. Its checked-out directory is removed immediately (same
+    /// as before this existed), but its branch is kept around until the entry is purged, so
+    /// restoring can recreate the working directory from the branch. Uncommitted changes in the
+    /// deleted worktree are not recoverable - only the branch's committed history is.
+    Worktree {
+        worktree: Box<Worktree>,
+        /// Path to the project's main repository, needed to run git against the branch.
+        project_path: String,
+        /// Raw contents of the worktree's sessions index file, if one existed, restored
+        /// verbatim on undo.
+        sessions_file_contents: Option<String>,
+    },
+}
+
+/// A single trashed item.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub id: String,
+    /// Human-readable label for a trash UI (e.g. a context file's name, a session's name).
+    pub display_name: String,
+    pub trashed_at: u64,
+    pub expires_at: u64,
+    #[serde(flatten)]
+    pub kind: TrashEntryKind,
+}
+
+fn trash_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = crate::data_dir::resolve(app)?.join("trash");
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create trash directory: {e}"))?;
+    Ok(dir)
+}
+
+fn index_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(trash_dir(app)?.join("index.json"))
+}
+
+fn load_index(app: &AppHandle) -> Result<Vec<TrashEntry>, String> {
+    let path = index_path(app)?;
+    match fs::read_to_string(&path) {
+        Ok(contents) => {
+            serde_json::from_str(&contents).map_err(|e| format!("Failed to parse trash index: {e}"))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(format!("Failed to read trash index: {e}")),
+    }
+}
+
+fn save_index(app: &AppHandle, entries: &[TrashEntry]) -> Result<(), String> {
+    let path = index_path(app)?;
+    let json = serde_json::to_string_pretty(entries)
+        .map_err(|e| format!("Failed to serialize trash index: {e}"))?;
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, &json).map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    fs::rename(&temp_path, &path).map_err(|e| format!("Failed to finalize {}: {e}", path.display()))
+}
+
+/// Append `entry` to the trash index (locked against concurrent trashing).
+fn add_entry(app: &AppHandle, entry: TrashEntry) -> Result<(), String> {
+    let _lock = TRASH_INDEX_LOCK.lock().unwrap();
+    let mut entries = load_index(app)?;
+    entries.push(entry);
+    save_index(app, &entries)
+}
+
+fn new_entry(id: String, display_name: String, kind: TrashEntryKind) -> TrashEntry {
+    let trashed_at = now();
+    TrashEntry {
+        id,
+        display_name,
+        trashed_at,
+        expires_at: trashed_at + TRASH_RETENTION_DAYS * 86400,
+        kind,
+    }
+}
+
+/// Move `path` (a file) into the trash directory under `entry_id`, keeping its filename.
+fn move_file_into_trash(app: &AppHandle, path: &Path, entry_id: &str) -> Result<PathBuf, String> {
+    let dir = trash_dir(app)?.join(entry_id);
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create trash entry directory: {e}"))?;
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("Path has no filename: {}", path.display()))?;
+    let dest = dir.join(file_name);
+    fs::rename(path, &dest)
+        .map_err(|e| format!("Failed to move {} to trash: {e}", path.display()))?;
+    Ok(dest)
+}
+
+/// Move a saved context file to the trash. Called by `chat::delete_context_file` in place of
+/// removing the file outright.
+pub fn trash_context_file(app: &AppHandle, path: &Path, display_name: String) -> Result<(), String> {
+    let id = Uuid::new_v4().to_string();
+    let original_path = path.to_string_lossy().to_string();
+    let trashed_path = move_file_into_trash(app, path, &id)?.to_string_lossy().to_string();
+
+    add_entry(
+        app,
+        new_entry(
+            id,
+            display_name,
+            TrashEntryKind::ContextFile { original_path, trashed_path },
+        ),
+    )
+}
+
+/// Record an archived session's removal from its worktree's index. Called by
+/// `chat::delete_archived_session` in place of discarding the session outright - its data
+/// directory on disk is untouched until the entry is purged.
+pub fn trash_archived_session(
+    app: &AppHandle,
+    worktree_id: String,
+    worktree_path: String,
+    session: crate::chat::types::Session,
+) -> Result<(), String> {
+    let id = Uuid::new_v4().to_string();
+    let display_name = session.name.clone();
+
+    add_entry(
+        app,
+        new_entry(
+            id,
+            display_name,
+            TrashEntryKind::ArchivedSession {
+                worktree_id,
+                worktree_path,
+                session: Box::new(session),
+            },
+        ),
+    )
+}
+
+/// Record a permanently-deleted worktree's removal, keeping its branch alive until the entry
+/// is purged. Called by `projects::permanently_delete_worktree` before it tears down the
+/// worktree's working directory.
+pub fn trash_worktree(
+    app: &AppHandle,
+    worktree: Worktree,
+    project_path: String,
+    sessions_file_contents: Option<String>,
+) -> Result<(), String> {
+    let id = Uuid::new_v4().to_string();
+    let display_name = worktree.name.clone();
+
+    add_entry(
+        app,
+        new_entry(
+            id,
+            display_name,
+            TrashEntryKind::Worktree {
+                worktree: Box::new(worktree),
+                project_path,
+                sessions_file_contents,
+            },
+        ),
+    )
+}
+
+/// List everything currently in the trash, newest first.
+#[tauri::command]
+pub async fn list_trash(app: AppHandle) -> Result<Vec<TrashEntry>, String> {
+    let mut entries = load_index(&app)?;
+    entries.sort_by(|a, b| b.trashed_at.cmp(&a.trashed_at));
+    Ok(entries)
+}
+
+/// Restore a trashed item to where it was before it was deleted.
+#[tauri::command]
+pub async fn restore_from_trash(app: AppHandle, id: String) -> Result<(), String> {
+    let _lock = TRASH_INDEX_LOCK.lock().unwrap();
+    let mut entries = load_index(&app)?;
+    let index = entries
+        .iter()
+        .position(|e| e.id == id)
+        .ok_or_else(|| format!("Trash entry not found: {id}"))?;
+    let entry = entries.remove(index);
+
+    match &entry.kind {
+        TrashEntryKind::ContextFile { original_path, trashed_path } => {
+            fs::rename(trashed_path, original_path)
+                .map_err(|e| format!("Failed to restore context file: {e}"))?;
+        }
+        TrashEntryKind::ArchivedSession { worktree_id, worktree_path, session } => {
+            crate::chat::with_sessions_mut(&app, worktree_path, worktree_id, |sessions| {
+                if sessions.sessions.iter().any(|s| s.id == session.id) {
+                    return Err(format!("Session already present: {}", session.id));
+                }
+                sessions.sessions.push((**session).clone());
+                Ok(())
+            })?;
+        }
+        TrashEntryKind::Worktree { worktree, project_path, sessions_file_contents } => {
+            let mut data = crate::projects::storage::load_projects_data(&app)?;
+            if data.find_worktree(&worktree.id).is_some() {
+                return Err(format!("Worktree already present: {}", worktree.id));
+            }
+
+            if worktree.session_type != crate::projects::types::SessionType::Base {
+                git::create_worktree_from_existing_branch(
+                    project_path,
+                    &worktree.path,
+                    &worktree.branch,
+                )?;
+            }
+
+            if let Some(contents) = sessions_file_contents {
+                let sessions_dir = crate::data_dir::resolve(&app)?.join("sessions");
+                fs::create_dir_all(&sessions_dir)
+                    .map_err(|e| format!("Failed to create sessions directory: {e}"))?;
+                fs::write(sessions_dir.join(format!("{}.json", worktree.id)), contents)
+                    .map_err(|e| format!("Failed to restore sessions file: {e}"))?;
+            }
+
+            data.add_worktree((**worktree).clone());
+            crate::projects::storage::save_projects_data(&app, &data)?;
+        }
+    }
+
+    save_index(&app, &entries)
+}
+
+/// Purge a single entry for good: finish the deletion that trashing it had deferred.
+fn purge_entry(app: &AppHandle, entry: &TrashEntry) {
+    match &entry.kind {
+        TrashEntryKind::ContextFile { trashed_path, .. } => {
+            if let Err(e) = fs::remove_file(trashed_path) {
+                log::warn!("Failed to purge trashed context file {trashed_path}: {e}");
+            }
+        }
+        TrashEntryKind::ArchivedSession { session, .. } => {
+            if let Err(e) = crate::chat::storage::delete_session_data(app, &session.id) {
+                log::warn!("Failed to purge trashed session {}: {e}", session.id);
+            }
+        }
+        TrashEntryKind::Worktree { worktree, project_path, .. } => {
+            if worktree.session_type != crate::projects::types::SessionType::Base {
+                if let Err(e) = git::delete_branch(project_path, &worktree.branch) {
+                    log::warn!(
+                        "Failed to delete branch {} while purging trashed worktree {}: {e}",
+                        worktree.branch,
+                        worktree.id
+                    );
+                }
+            }
+        }
+    }
+
+    let trash_entry_dir = trash_dir(app).ok().map(|d| d.join(&entry.id));
+    if let Some(dir) = trash_entry_dir {
+        if dir.exists() {
+            let _ = fs::remove_dir_all(dir);
+        }
+    }
+}
+
+/// Immediately purge everything currently in the trash. Returns how many entries were purged.
+#[tauri::command]
+pub async fn empty_trash(app: AppHandle) -> Result<usize, String> {
+    let _lock = TRASH_INDEX_LOCK.lock().unwrap();
+    let entries = load_index(&app)?;
+    let count = entries.len();
+    for entry in &entries {
+        purge_entry(&app, entry);
+    }
+    save_index(&app, &[])?;
+    Ok(count)
+}
+
+/// Purge whatever in the trash has passed its `expires_at`.
+fn sweep_once(app: &AppHandle) {
+    let _lock = TRASH_INDEX_LOCK.lock().unwrap();
+    let entries = match load_index(app) {
+        Ok(e) => e,
+        Err(e) => {
+            log::warn!("Trash expiry sweep skipped: failed to load index: {e}");
+            return;
+        }
+    };
+
+    let cutoff = now();
+    let (expired, remaining): (Vec<_>, Vec<_>) =
+        entries.into_iter().partition(|e| e.expires_at <= cutoff);
+
+    if expired.is_empty() {
+        return;
+    }
+
+    log::info!("Trash expiry sweep purging {} expired entries", expired.len());
+    for entry in &expired {
+        purge_entry(app, entry);
+    }
+
+    if let Err(e) = save_index(app, &remaining) {
+        log::warn!("Failed to save trash index after expiry sweep: {e}");
+    }
+}
+
+/// Start the background trash expiry sweep.
+///
+/// Spawned once from `lib.rs::run()`, mirroring `chat::retention::start_sweep`'s shape: runs
+/// for the lifetime of the app regardless of window focus.
+pub fn start_expiry_sweep(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(Duration::from_secs(SWEEP_INTERVAL_SECS));
+        sweep_once(&app);
+    });
+}
@@ -0,0 +1,155 @@
+//! System tray icon and dock/taskbar badge reflecting an aggregate "attention count" - runs
+//! awaiting input, failed runs, and PRs with requested changes - so something needing you is
+//! visible without switching back to the app. The count is rebuilt from live events via
+//! [`on_event`], hooked into the same central pipeline as `notifications::on_event` (see
+//! `http_server::EmitExt::emit_all`), not persisted - a restart starts back at zero.
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use serde_json::Value;
+use tauri::menu::{MenuBuilder, MenuItemBuilder};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Emitter, Manager};
+
+const TRAY_ID: &str = "main-tray";
+
+#[derive(Default)]
+struct AttentionState {
+    awaiting_input: HashSet<String>,
+    failed_runs: HashSet<String>,
+    changes_requested: HashSet<String>,
+}
+
+impl AttentionState {
+    fn count(&self) -> usize {
+        self.awaiting_input.len() + self.failed_runs.len() + self.changes_requested.len()
+    }
+}
+
+/// App-managed state backing the tray/badge attention count. Register with `app.manage(...)`
+/// before calling [`create`].
+#[derive(Default)]
+pub struct AttentionTracker(Mutex<AttentionState>);
+
+/// Build the tray icon and its right-click menu ("Cancel All Runs", "Open Last Worktree").
+/// Call once from `run()`'s setup, after `AttentionTracker` has been `app.manage()`d.
+pub fn create(app: &tauri::App) -> tauri::Result<()> {
+    let menu = MenuBuilder::new(app)
+        .item(&MenuItemBuilder::with_id("tray-cancel-all-runs", "Cancel All Runs").build(app)?)
+        .item(
+            &MenuItemBuilder::with_id("tray-open-last-worktree", "Open Last Worktree")
+                .build(app)?,
+        )
+        .build()?;
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .menu(&menu)
+        .tooltip("Jean")
+        .on_menu_event(|app, event| match event.id().as_ref() {
+            "tray-cancel-all-runs" => cancel_all_runs(app),
+            "tray-open-last-worktree" => open_last_worktree(app),
+            _ => {}
+        });
+    if let Some(icon) = app.default_window_icon().cloned() {
+        builder = builder.icon(icon);
+    }
+    builder.build(app)?;
+    Ok(())
+}
+
+/// Cancel every running Claude process across every worktree in every project. Used by the
+/// tray's "Cancel All Runs" action - `chat::registry::cancel_processes_for_worktree` only
+/// takes a worktree id, so this walks `projects.json` rather than the process registry
+/// (which is keyed by session id with no worktree association).
+fn cancel_all_runs(app: &AppHandle) {
+    let Ok(data) = crate::projects::storage::load_projects_data(app) else {
+        return;
+    };
+    for worktree in &data.worktrees {
+        crate::chat::registry::cancel_processes_for_worktree(app, &worktree.id);
+    }
+}
+
+/// Focus the main window and ask the frontend to route to the last-focused worktree. There's
+/// no persistent "last opened" history, so `BackgroundTaskManager::active_worktree_id` (the
+/// currently/most-recently focused worktree) is used as a pragmatic stand-in.
+fn open_last_worktree(app: &AppHandle) {
+    let Some(worktree_id) = app
+        .try_state::<crate::background_tasks::BackgroundTaskManager>()
+        .and_then(|state| state.active_worktree_id())
+    else {
+        return;
+    };
+
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    if let Err(e) = app.emit(
+        "tray:open-worktree",
+        &serde_json::json!({ "worktree_id": worktree_id }),
+    ) {
+        log::warn!("Failed to emit tray:open-worktree: {e}");
+    }
+}
+
+/// Refresh the tray tooltip and OS dock/taskbar badge to reflect `count`.
+fn refresh(app: &AppHandle, count: usize) {
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        let tooltip = if count > 0 {
+            format!("Jean - {count} item(s) need attention")
+        } else {
+            "Jean".to_string()
+        };
+        let _ = tray.set_tooltip(Some(tooltip.as_str()));
+    }
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.set_badge_count(if count > 0 { Some(count as i64) } else { None });
+    }
+}
+
+/// Run every tray-relevant check against an emitted event, updating the attention count and
+/// tray/badge if it changed.
+pub fn on_event(app: &AppHandle, event: &str, payload: &Value) {
+    let Some(tracker) = app.try_state::<AttentionTracker>() else {
+        return;
+    };
+    let worktree_id = payload.get("worktree_id").and_then(Value::as_str);
+
+    let changed = {
+        let mut state = tracker.0.lock().unwrap();
+        match event {
+            "chat:permission_denied" => worktree_id
+                .map(|id| state.awaiting_input.insert(id.to_string()))
+                .unwrap_or(false),
+            "chat:sending" => worktree_id
+                .map(|id| {
+                    let cleared_waiting = state.awaiting_input.remove(id);
+                    let cleared_failed = state.failed_runs.remove(id);
+                    cleared_waiting || cleared_failed
+                })
+                .unwrap_or(false),
+            "chat:error" => worktree_id
+                .map(|id| state.failed_runs.insert(id.to_string()))
+                .unwrap_or(false),
+            "pr:status-update" => worktree_id
+                .map(|id| {
+                    let requests_changes = payload.get("review_decision").and_then(Value::as_str)
+                        == Some("changes_requested");
+                    if requests_changes {
+                        state.changes_requested.insert(id.to_string())
+                    } else {
+                        state.changes_requested.remove(id)
+                    }
+                })
+                .unwrap_or(false),
+            _ => false,
+        }
+    };
+
+    if changed {
+        let count = tracker.0.lock().unwrap().count();
+        refresh(app, count);
+    }
+}